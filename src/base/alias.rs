@@ -1,6 +1,8 @@
 #[cfg(any(feature = "alloc", feature = "std"))]
 use crate::base::dimension::Dynamic;
-use crate::base::dimension::{U1, U2, U3, U4, U5, U6};
+use crate::base::dimension::{NamedDim, U1, U2, U3, U4, U5, U6};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::row_major_vec_storage::RowMajorVecStorage;
 use crate::base::storage::Owned;
 #[cfg(any(feature = "std", feature = "alloc"))]
 use crate::base::vec_storage::VecStorage;
@@ -23,10 +25,27 @@ pub type MatrixMN<N, R, C> = Matrix<N, R, C, Owned<N, R, C>>;
 /// A statically sized column-major square matrix with `D` rows and columns.
 pub type MatrixN<N, D> = MatrixMN<N, D, D>;
 
+/// A statically sized column-major matrix with `R` rows and `C` columns given as integers, e.g.,
+/// `SMatrix<f64, 3, 4>` is `Matrix3x4<f64>`.
+///
+/// This is only an alias to the matrix type with the corresponding named dimensions, which are
+/// available for all the values up to 1024, and for the powers of 2 and of 10 beyond. Generic
+/// code must still be written in terms of the `Dim` and `DimName` traits.
+pub type SMatrix<N, const R: usize, const C: usize> =
+    MatrixMN<N, <typenum::U<R> as NamedDim>::Name, <typenum::U<C> as NamedDim>::Name>;
+
 /// A dynamically sized column-major matrix.
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub type DMatrix<N> = MatrixN<N, Dynamic>;
 
+/// A matrix with `R` rows and `C` columns that owns its components stored in row-major order.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub type RowMajorMatrixMN<N, R, C> = Matrix<N, R, C, RowMajorVecStorage<N, R, C>>;
+
+/// A dynamically sized matrix that owns its components stored in row-major order.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub type DRowMajorMatrix<N> = RowMajorMatrixMN<N, Dynamic, Dynamic>;
+
 /// A stack-allocated, column-major, 1x1 square matrix.
 pub type Matrix1<N> = MatrixN<N, U1>;
 /// A stack-allocated, column-major, 2x2 square matrix.
@@ -124,6 +143,10 @@ pub type DVector<N> = Matrix<N, Dynamic, U1, VecStorage<N, Dynamic, U1>>;
 /// A statically sized D-dimensional column vector.
 pub type VectorN<N, D> = MatrixMN<N, D, U1>;
 
+/// A statically sized D-dimensional column vector with `D` given as an integer, e.g.,
+/// `SVector<f64, 3>` is `Vector3<f64>`. See `SMatrix` for the supported dimensions.
+pub type SVector<N, const D: usize> = VectorN<N, <typenum::U<D> as NamedDim>::Name>;
+
 /// A stack-allocated, 1-dimensional column vector.
 pub type Vector1<N> = VectorN<N, U1>;
 /// A stack-allocated, 2-dimensional column vector.
@@ -21,6 +21,12 @@ pub type MatrixSliceN<'a, N, D, RStride = U1, CStride = D> =
 pub type DMatrixSlice<'a, N, RStride = U1, CStride = Dynamic> =
     MatrixSliceN<'a, N, Dynamic, RStride, CStride>;
 
+/// A matrix slice with `R` rows and `C` columns viewing data stored in row-major order.
+pub type RowMajorMatrixSliceMN<'a, N, R, C> = MatrixSliceMN<'a, N, R, C, C, U1>;
+
+/// A matrix slice with dynamic numbers of rows and columns viewing data stored in row-major order.
+pub type DRowMajorMatrixSlice<'a, N> = RowMajorMatrixSliceMN<'a, N, Dynamic, Dynamic>;
+
 /// A column-major 1x1 matrix slice.
 pub type MatrixSlice1<'a, N, RStride = U1, CStride = U1> =
     MatrixSliceN<'a, N, U1, RStride, CStride>;
@@ -220,6 +226,13 @@ pub type MatrixSliceMutN<'a, N, D, RStride = U1, CStride = D> =
 pub type DMatrixSliceMut<'a, N, RStride = U1, CStride = Dynamic> =
     MatrixSliceMutN<'a, N, Dynamic, RStride, CStride>;
 
+/// A mutable matrix slice with `R` rows and `C` columns viewing data stored in row-major order.
+pub type RowMajorMatrixSliceMutMN<'a, N, R, C> = MatrixSliceMutMN<'a, N, R, C, C, U1>;
+
+/// A mutable matrix slice with dynamic numbers of rows and columns viewing data stored in
+/// row-major order.
+pub type DRowMajorMatrixSliceMut<'a, N> = RowMajorMatrixSliceMutMN<'a, N, Dynamic, Dynamic>;
+
 /// A column-major 1x1 mutable matrix slice.
 pub type MatrixSliceMut1<'a, N, RStride = U1, CStride = U1> =
     MatrixSliceMutN<'a, N, U1, RStride, CStride>;
@@ -1,6 +1,7 @@
 //! Abstract definition of a matrix data storage allocator.
 
 use std::any::Any;
+use std::mem::MaybeUninit;
 
 use crate::base::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
 use crate::base::dimension::{Dim, U1};
@@ -29,6 +30,28 @@ pub trait Allocator<N: Scalar, R: Dim, C: Dim = U1>: Any + Sized {
         ncols: C,
         iter: I,
     ) -> Self::Buffer;
+
+    /// Allocates a buffer with the given number of rows and columns, kept in `MaybeUninit` until
+    /// all its components are written.
+    ///
+    /// Contrary to `allocate_uninitialized`, this is sound for any scalar type. The components
+    /// must only be accessed through `uninit_ptr_mut`.
+    fn allocate_uninit(nrows: R, ncols: C) -> MaybeUninit<Self::Buffer>;
+
+    /// A pointer to the first component of a buffer allocated by `allocate_uninit`. The
+    /// components are stored contiguously in column-major order.
+    fn uninit_ptr_mut(buffer: &mut MaybeUninit<Self::Buffer>) -> *mut N;
+
+    /// Retrieves the buffer allocated by `allocate_uninit`, assuming all its components are
+    /// initialized.
+    ///
+    /// # Safety
+    /// All the components must have been written through `uninit_ptr_mut`.
+    unsafe fn assume_init(buffer: MaybeUninit<Self::Buffer>) -> Self::Buffer;
+
+    /// Frees the memory of a buffer allocated by `allocate_uninit` without dropping any of its
+    /// components.
+    fn deallocate_uninit(buffer: MaybeUninit<Self::Buffer>);
 }
 
 /// A matrix reallocator. Changes the size of the memory buffer that initially contains (RFrom ×
@@ -2,6 +2,7 @@ use std::fmt::{self, Debug, Formatter};
 use std::hash::{Hash, Hasher};
 #[cfg(feature = "abomonation-serialize")]
 use std::io::{Result as IOResult, Write};
+use std::mem::{size_of, ManuallyDrop};
 use std::ops::{Deref, DerefMut, Mul};
 
 #[cfg(feature = "serde-serialize")]
@@ -46,6 +47,35 @@ where
     data: GenericArray<N, Prod<R::Value, C::Value>>,
 }
 
+// Reinterprets an array as an `ArrayStorage`, since a `GenericArray` cannot be built from an
+// array literal in a constant context.
+union ArrayTransmute<A, S> {
+    array: ManuallyDrop<A>,
+    storage: ManuallyDrop<S>,
+}
+
+impl<N, R, C> ArrayStorage<N, R, C>
+where
+    R: DimName,
+    C: DimName,
+    R::Value: Mul<C::Value>,
+    Prod<R::Value, C::Value>: ArrayLength<N>,
+{
+    /// Creates a storage from an array of the matrix components in column-major order.
+    ///
+    /// # Safety
+    /// `A` must be the array type `[N; R::dim() * C::dim()]`.
+    #[inline]
+    pub(crate) const unsafe fn from_array<A>(array: A) -> Self {
+        assert!(size_of::<A>() == size_of::<Self>());
+
+        let array = ArrayTransmute {
+            array: ManuallyDrop::new(array),
+        };
+        ManuallyDrop::into_inner(array.storage)
+    }
+}
+
 #[deprecated(note = "renamed to `ArrayStorage`")]
 /// Renamed to [ArrayStorage].
 pub type MatrixArray<N, R, C> = ArrayStorage<N, R, C>;
@@ -12,8 +12,11 @@ use crate::base::constraint::{
 };
 use crate::base::dimension::{Dim, Dynamic, U1, U2, U3, U4};
 use crate::base::storage::{Storage, StorageMut};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::DMatrix;
 use crate::base::{
-    DVectorSlice, DefaultAllocator, Matrix, Scalar, SquareMatrix, Vector, VectorSliceN,
+    DVectorSlice, DefaultAllocator, Matrix, MatrixMN, RowVectorN, Scalar, SquareMatrix, Vector,
+    VectorN, VectorSliceN,
 };
 
 // FIXME: find a way to avoid code duplication just for complex number support.
@@ -268,6 +271,173 @@ impl<N: Scalar + PartialOrd + Signed, R: Dim, C: Dim, S: Storage<N, R, C>> Matri
     }
 }
 
+impl<N: Scalar + PartialOrd, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
+    /// Computes the index of the matrix component with the largest value.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let mat = Matrix2x3::new(11, -12, 13,
+    ///                          21, 22, -23);
+    /// assert_eq!(mat.imax_full(), (1, 1));
+    /// ```
+    #[inline]
+    pub fn imax_full(&self) -> (usize, usize) {
+        self.iextremum_full(|val, the_max| val > the_max)
+    }
+
+    /// Computes the index of the matrix component with the smallest value.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let mat = Matrix2x3::new(11, -12, 13,
+    ///                          21, 22, -23);
+    /// assert_eq!(mat.imin_full(), (1, 2));
+    /// ```
+    #[inline]
+    pub fn imin_full(&self) -> (usize, usize) {
+        self.iextremum_full(|val, the_min| val < the_min)
+    }
+
+    /// Computes, for each column of this matrix, the row index of its component with the largest
+    /// value.
+    ///
+    /// The result is a row vector, like `.row_max()` which gives the corresponding values.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, RowVector3};
+    /// let mat = Matrix2x3::new(11, -12, 13,
+    ///                          21, 22, -23);
+    /// assert_eq!(mat.row_imax(), RowVector3::new(1, 1, 0));
+    /// ```
+    #[inline]
+    pub fn row_imax(&self) -> RowVectorN<usize, C>
+    where
+        DefaultAllocator: Allocator<usize, U1, C>,
+    {
+        RowVectorN::from_fn_generic(U1, self.data.shape().1, |_, j| self.column(j).imax())
+    }
+
+    /// Computes, for each column of this matrix, the row index of its component with the smallest
+    /// value.
+    ///
+    /// The result is a row vector, like `.row_min()` which gives the corresponding values.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, RowVector3};
+    /// let mat = Matrix2x3::new(11, -12, 13,
+    ///                          21, 22, -23);
+    /// assert_eq!(mat.row_imin(), RowVector3::new(0, 0, 1));
+    /// ```
+    #[inline]
+    pub fn row_imin(&self) -> RowVectorN<usize, C>
+    where
+        DefaultAllocator: Allocator<usize, U1, C>,
+    {
+        RowVectorN::from_fn_generic(U1, self.data.shape().1, |_, j| self.column(j).imin())
+    }
+
+    /// Computes, for each row of this matrix, the column index of its component with the largest
+    /// value.
+    ///
+    /// The result is a column vector, like `.column_max()` which gives the corresponding values.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    /// let mat = Matrix2x3::new(11, -12, 13,
+    ///                          21, 22, -23);
+    /// assert_eq!(mat.column_imax(), Vector2::new(2, 1));
+    /// ```
+    #[inline]
+    pub fn column_imax(&self) -> VectorN<usize, R>
+    where
+        DefaultAllocator: Allocator<usize, R>,
+    {
+        self.column_iextremum(|val, the_max| val > the_max)
+    }
+
+    /// Computes, for each row of this matrix, the column index of its component with the
+    /// smallest value.
+    ///
+    /// The result is a column vector, like `.column_min()` which gives the corresponding values.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    /// let mat = Matrix2x3::new(11, -12, 13,
+    ///                          21, 22, -23);
+    /// assert_eq!(mat.column_imin(), Vector2::new(1, 2));
+    /// ```
+    #[inline]
+    pub fn column_imin(&self) -> VectorN<usize, R>
+    where
+        DefaultAllocator: Allocator<usize, R>,
+    {
+        self.column_iextremum(|val, the_min| val < the_min)
+    }
+
+    // Computes the index of the first extremal component in column-major order, where
+    // `is_better(a, b)` tests if `a` is strictly better than `b`.
+    #[inline(always)]
+    fn iextremum_full(&self, is_better: impl Fn(&N, &N) -> bool) -> (usize, usize) {
+        assert!(!self.is_empty(), "The input matrix must not be empty.");
+
+        let mut the_best = unsafe { self.get_unchecked((0, 0)) };
+        let mut the_ij = (0, 0);
+
+        for j in 0..self.ncols() {
+            for i in 0..self.nrows() {
+                let val = unsafe { self.get_unchecked((i, j)) };
+
+                if is_better(val, the_best) {
+                    the_best = val;
+                    the_ij = (i, j);
+                }
+            }
+        }
+
+        the_ij
+    }
+
+    // Same as `iextremum_full` but independently for each row.
+    #[inline(always)]
+    fn column_iextremum(&self, is_better: impl Fn(&N, &N) -> bool) -> VectorN<usize, R>
+    where
+        DefaultAllocator: Allocator<usize, R>,
+    {
+        assert!(
+            self.ncols() != 0 || self.nrows() == 0,
+            "The input matrix must not have zero columns."
+        );
+
+        VectorN::from_fn_generic(self.data.shape().0, U1, |i, _| {
+            let mut the_best = unsafe { self.get_unchecked((i, 0)) };
+            let mut the_j = 0;
+
+            for j in 1..self.ncols() {
+                let val = unsafe { self.get_unchecked((i, j)) };
+
+                if is_better(val, the_best) {
+                    the_best = val;
+                    the_j = j;
+                }
+            }
+
+            the_j
+        })
+    }
+}
+
 impl<N, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S>
 where
     N: Scalar + Zero + ClosedAdd + ClosedMul,
@@ -590,14 +760,15 @@ where
 
         let rstride1 = self.strides().0;
         let rstride2 = x.strides().0;
+        let len = x.nrows();
 
         let y = self.data.as_mut_slice();
         let x = x.data.as_slice();
 
         if !b.is_zero() {
-            array_axcpy(y, a, x, c, b, rstride1, rstride2, x.len());
+            array_axcpy(y, a, x, c, b, rstride1, rstride2, len);
         } else {
-            array_axc(y, a, x, c, rstride1, rstride2, x.len());
+            array_axc(y, a, x, c, rstride1, rstride2, len);
         }
     }
 
@@ -1336,6 +1507,183 @@ where
             self.column_mut(j1).gemv_ad(alpha, a, &b.column(j1), beta);
         }
     }
+
+    /// Computes `self = alpha * op(a) * op(b) + beta * self`, where `op(a)` is `a.transpose()` if
+    /// `transpose_a` is `true` and `a` otherwise, and likewise for `op(b)`.
+    ///
+    /// This is the general form of the `gemm` operation of BLAS. No transposed matrix or
+    /// temporary is allocated. Because the transpositions are only known at runtime, the
+    /// dimensions are checked at runtime only. If `beta` is zero, `self` is never read.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2x3, Matrix3x2, Matrix2x4, Matrix4x3};
+    /// let mut mat1 = Matrix2x4::identity();
+    /// let mat2 = Matrix3x2::new(1.0, 4.0,
+    ///                           2.0, 5.0,
+    ///                           3.0, 6.0);
+    /// let mat3 = Matrix4x3::new(0.1, 0.2, 0.3,
+    ///                           0.4, 0.5, 0.6,
+    ///                           0.7, 0.8, 0.9,
+    ///                           1.0, 1.1, 1.2);
+    /// let expected = mat2.transpose() * mat3.transpose() * 10.0 + mat1 * 5.0;
+    ///
+    /// mat1.gemm_with_transpositions(10.0, &mat2, &mat3, 5.0, true, true);
+    /// assert_relative_eq!(mat1, expected);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the dimensions of `op(a)`, `op(b)` and `self` are not compatible.
+    pub fn gemm_with_transpositions<R2: Dim, C2: Dim, R3: Dim, C3: Dim, SB, SC>(
+        &mut self,
+        alpha: N,
+        a: &Matrix<N, R2, C2, SB>,
+        b: &Matrix<N, R3, C3, SC>,
+        beta: N,
+        transpose_a: bool,
+        transpose_b: bool,
+    ) where
+        N: One,
+        SB: Storage<N, R2, C2>,
+        SC: Storage<N, R3, C3>,
+    {
+        let (nrows1, ncols1) = self.shape();
+        let (nrows2, ncols2) = a.shape();
+        let (nrows3, ncols3) = b.shape();
+        let (op_nrows2, op_ncols2) = if transpose_a {
+            (ncols2, nrows2)
+        } else {
+            (nrows2, ncols2)
+        };
+        let (op_nrows3, op_ncols3) = if transpose_b {
+            (ncols3, nrows3)
+        } else {
+            (nrows3, ncols3)
+        };
+
+        assert_eq!(
+            op_ncols2, op_nrows3,
+            "gemm: dimensions mismatch for multiplication."
+        );
+        assert_eq!(
+            (nrows1, ncols1),
+            (op_nrows2, op_ncols3),
+            "gemm: dimensions mismatch for addition."
+        );
+
+        // View everything with dynamic dimensions so that the shape constraints of the
+        // specialized kernels are trivially satisfied.
+        let a = a.generic_slice((0, 0), (Dynamic::new(nrows2), Dynamic::new(ncols2)));
+        let b = b.generic_slice((0, 0), (Dynamic::new(nrows3), Dynamic::new(ncols3)));
+        let mut res = self.generic_slice_mut((0, 0), (Dynamic::new(nrows1), Dynamic::new(ncols1)));
+
+        match (transpose_a, transpose_b) {
+            (false, false) => res.gemm(alpha, &a, &b, beta),
+            (true, false) => res.gemm_tr(alpha, &a, &b, beta),
+            _ => {
+                for j in 0..ncols1 {
+                    for i in 0..nrows1 {
+                        // FIXME: avoid bound checks.
+                        let dot = match (transpose_a, transpose_b) {
+                            (false, _) => a.row(i).dot(&b.row(j)),
+                            _ => a.column(i).tr_dot(&b.row(j)),
+                        };
+                        let val = unsafe { res.get_unchecked_mut((i, j)) };
+
+                        if beta.is_zero() {
+                            *val = alpha.inlined_clone() * dot;
+                        } else {
+                            *val = alpha.inlined_clone() * dot
+                                + beta.inlined_clone() * val.inlined_clone();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Computes `self = alpha * a * b + beta * self`, where the components of `a` and `b` are
+    /// converted to `N`.
+    ///
+    /// This allows matrices stored with a compact scalar type, e.g., a half-precision float, to
+    /// be multiplied with the arithmetic of a wider type, e.g., `f32`. The conversion is performed
+    /// by blocks of at most 64 columns of `a` and rows of `b`, which are multiplied with `gemm`,
+    /// so the converted matrices are never allocated entirely.
+    ///
+    /// If `beta` is zero, `self` is never read.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2, Matrix2x3, Matrix3x2};
+    /// let a = Matrix2x3::<u8>::new(1, 2, 3, 4, 5, 6);
+    /// let b = Matrix3x2::<u8>::new(200, 0, 0, 200, 100, 100);
+    /// let mut res = Matrix2::zeros();
+    ///
+    /// // The products would overflow with the arithmetic of `u8`.
+    /// res.gemm_converted(1.0f32, &a, &b, 0.0);
+    /// assert_eq!(res, Matrix2::new(500.0, 700.0, 1400.0, 1600.0));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the dimensions of `self`, `a` and `b` are not compatible.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn gemm_converted<M, R2: Dim, C2: Dim, R3: Dim, C3: Dim, SB, SC>(
+        &mut self,
+        alpha: N,
+        a: &Matrix<M, R2, C2, SB>,
+        b: &Matrix<M, R3, C3, SC>,
+        beta: N,
+    ) where
+        N: One + From<M>,
+        M: Scalar,
+        SB: Storage<M, R2, C2>,
+        SC: Storage<M, R3, C3>,
+    {
+        const BLOCK_SIZE: usize = 64;
+
+        let (nrows1, ncols1) = self.shape();
+        let (nrows2, ncols2) = a.shape();
+        let (nrows3, ncols3) = b.shape();
+
+        assert_eq!(
+            ncols2, nrows3,
+            "gemm: dimensions mismatch for multiplication."
+        );
+        assert_eq!(
+            (nrows1, ncols1),
+            (nrows2, ncols3),
+            "gemm: dimensions mismatch for addition."
+        );
+
+        let mut res = self.generic_slice_mut((0, 0), (Dynamic::new(nrows1), Dynamic::new(ncols1)));
+
+        if ncols2 == 0 {
+            if beta.is_zero() {
+                res.fill(N::zero());
+            } else {
+                res *= beta;
+            }
+
+            return;
+        }
+
+        let mut beta = beta;
+        let mut k = 0;
+
+        while k < ncols2 {
+            let nk = BLOCK_SIZE.min(ncols2 - k);
+            let a_block =
+                DMatrix::from_fn(nrows2, nk, |i, j| N::from(a[(i, k + j)].inlined_clone()));
+            let b_block =
+                DMatrix::from_fn(nk, ncols3, |i, j| N::from(b[(k + i, j)].inlined_clone()));
+
+            res.gemm(alpha.inlined_clone(), &a_block, &b_block, beta);
+            beta = N::one();
+            k += nk;
+        }
+    }
 }
 
 impl<N, R1: Dim, C1: Dim, S: StorageMut<N, R1, C1>> Matrix<N, R1, C1, S>
@@ -1484,6 +1832,245 @@ where
     {
         self.xxgerx(alpha, x, y, beta, SimdComplexField::simd_conjugate)
     }
+
+    /// Computes `self = alpha * a * a.transpose() + beta * self`, where `self` is a **symmetric**
+    /// matrix.
+    ///
+    /// This is the `syrk` operation of BLAS. It performs one `syger` per column of `a`, so only
+    /// about half of the product `a * a.transpose()` is computed. If `beta` is zero, `self` is
+    /// never read. Only the lower-triangular (including the diagonal) part of `self` is
+    /// read/written.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2, Matrix2x3};
+    /// let mut mat = Matrix2::identity();
+    /// let a = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// let expected = a * a.transpose() * 10.0 + mat * 5.0;
+    /// mat.m12 = 99999.99999; // This component is on the upper-triangular part and will not be read/written.
+    ///
+    /// mat.symmetric_rank_k_update(10.0, &a, 5.0);
+    /// assert_relative_eq!(mat.lower_triangle(), expected.lower_triangle());
+    /// assert_eq!(mat.m12, 99999.99999); // This was untouched.
+    /// ```
+    #[inline]
+    pub fn symmetric_rank_k_update<R2: Dim, C2: Dim, SB>(
+        &mut self,
+        alpha: N,
+        a: &Matrix<N, R2, C2, SB>,
+        beta: N,
+    ) where
+        N: One,
+        SB: Storage<N, R2, C2>,
+        ShapeConstraint: DimEq<R1, R2> + DimEq<C1, R2>,
+    {
+        let dim = self.nrows();
+
+        assert!(
+            self.is_square(),
+            "Symmetric rank-k update: the input matrix must be square."
+        );
+        assert!(
+            dim == a.nrows(),
+            "Symmetric rank-k update: dimensions mismatch."
+        );
+
+        if a.ncols() == 0 {
+            // There is no rank-1 update to perform, so only `self` is scaled.
+            for j in 0..dim {
+                for i in j..dim {
+                    let val = unsafe { self.get_unchecked_mut((i, j)) };
+
+                    if beta.is_zero() {
+                        *val = N::zero();
+                    } else {
+                        *val = beta.inlined_clone() * val.inlined_clone();
+                    }
+                }
+            }
+
+            return;
+        }
+
+        for k in 0..a.ncols() {
+            let col = a.column(k);
+            let beta = if k == 0 {
+                beta.inlined_clone()
+            } else {
+                N::one()
+            };
+
+            self.syger(alpha.inlined_clone(), &col, &col, beta);
+        }
+    }
+}
+
+impl<N, D: Dim, S: Storage<N, D, D>> SquareMatrix<N, D, S>
+where
+    N: Scalar + Zero + One + ClosedAdd + ClosedMul,
+{
+    /// Computes the product `self * b` where only the lower-triangular part of `self` (including
+    /// the diagonal) is considered not-zero.
+    ///
+    /// The upper-triangular part of `self` is never read. See `.lower_triangular_mul_mut` for
+    /// details.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2, Matrix2x3};
+    /// let lower = Matrix2::new(1.0, 99999.99999, // This component will not be read.
+    ///                          2.0, 3.0);
+    /// let b = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    ///
+    /// assert_eq!(lower.lower_triangular_mul(&b), lower.lower_triangle() * b);
+    /// ```
+    #[inline]
+    pub fn lower_triangular_mul<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> MatrixMN<N, R2, C2>
+    where
+        S2: Storage<N, R2, C2>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        let mut res = b.clone_owned();
+        self.lower_triangular_mul_mut(&mut res);
+        res
+    }
+
+    /// Computes the product `self * b` where only the upper-triangular part of `self` (including
+    /// the diagonal) is considered not-zero.
+    ///
+    /// The lower-triangular part of `self` is never read. See `.upper_triangular_mul_mut` for
+    /// details.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2, Matrix2x3};
+    /// let upper = Matrix2::new(1.0,         2.0,
+    ///                          99999.99999, 3.0); // This component will not be read.
+    /// let b = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    ///
+    /// assert_eq!(upper.upper_triangular_mul(&b), upper.upper_triangle() * b);
+    /// ```
+    #[inline]
+    pub fn upper_triangular_mul<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> MatrixMN<N, R2, C2>
+    where
+        S2: Storage<N, R2, C2>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        let mut res = b.clone_owned();
+        self.upper_triangular_mul_mut(&mut res);
+        res
+    }
+
+    /// Computes `b = self * b` in-place, where only the lower-triangular part of `self` (including
+    /// the diagonal) is considered not-zero.
+    ///
+    /// This is the `trmm` operation of BLAS. It requires about half the operations of a
+    /// general matrix multiplication and does not allocate. The corresponding triangular solve
+    /// (`trsm`) is `.solve_lower_triangular_mut`.
+    pub fn lower_triangular_mul_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert!(
+            self.is_square(),
+            "Triangular multiplication: the input matrix must be square."
+        );
+        assert_eq!(
+            self.nrows(),
+            b.nrows(),
+            "Triangular multiplication: dimensions mismatch."
+        );
+
+        for i in 0..b.ncols() {
+            self.lower_triangular_mul_vector_mut(&mut b.column_mut(i));
+        }
+    }
+
+    /// Computes `b = self * b` in-place, where only the upper-triangular part of `self` (including
+    /// the diagonal) is considered not-zero.
+    ///
+    /// This is the `trmm` operation of BLAS. It requires about half the operations of a
+    /// general matrix multiplication and does not allocate. The corresponding triangular solve
+    /// (`trsm`) is `.solve_upper_triangular_mut`.
+    pub fn upper_triangular_mul_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert!(
+            self.is_square(),
+            "Triangular multiplication: the input matrix must be square."
+        );
+        assert_eq!(
+            self.nrows(),
+            b.nrows(),
+            "Triangular multiplication: dimensions mismatch."
+        );
+
+        for i in 0..b.ncols() {
+            self.upper_triangular_mul_vector_mut(&mut b.column_mut(i));
+        }
+    }
+
+    // The components of `b` are overwritten from the last to the first, so that each of them is
+    // still available when it is needed by the components below it.
+    fn lower_triangular_mul_vector_mut<R2: Dim, S2>(&self, b: &mut Vector<N, R2, S2>)
+    where
+        S2: StorageMut<N, R2, U1>,
+    {
+        let dim = self.nrows();
+
+        for i in (0..dim).rev() {
+            let coeff;
+
+            unsafe {
+                let b_i = b.vget_unchecked_mut(i);
+                coeff = b_i.inlined_clone();
+                *b_i = self.get_unchecked((i, i)).inlined_clone() * coeff.inlined_clone();
+            }
+
+            b.rows_range_mut(i + 1..)
+                .axpy(coeff, &self.slice_range(i + 1.., i), N::one());
+        }
+    }
+
+    // The components of `b` are overwritten from the first to the last, so that each of them is
+    // still available when it is needed by the components above it.
+    fn upper_triangular_mul_vector_mut<R2: Dim, S2>(&self, b: &mut Vector<N, R2, S2>)
+    where
+        S2: StorageMut<N, R2, U1>,
+    {
+        let dim = self.nrows();
+
+        for i in 0..dim {
+            let coeff;
+
+            unsafe {
+                let b_i = b.vget_unchecked_mut(i);
+                coeff = b_i.inlined_clone();
+                *b_i = self.get_unchecked((i, i)).inlined_clone() * coeff.inlined_clone();
+            }
+
+            b.rows_range_mut(..i)
+                .axpy(coeff, &self.slice_range(..i, i), N::one());
+        }
+    }
 }
 
 impl<N, D1: Dim, S: StorageMut<N, D1, D1>> SquareMatrix<N, D1, S>
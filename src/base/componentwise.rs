@@ -3,14 +3,14 @@
 use num::{Signed, Zero};
 use std::ops::{Add, Mul};
 
-use simba::scalar::{ClosedDiv, ClosedMul};
-use simba::simd::SimdPartialOrd;
+use simba::scalar::{ClosedAdd, ClosedDiv, ClosedMul, ClosedSub};
+use simba::simd::{SimdComplexField, SimdPartialOrd};
 
 use crate::base::allocator::{Allocator, SameShapeAllocator};
 use crate::base::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
-use crate::base::dimension::Dim;
+use crate::base::dimension::{Dim, U1};
 use crate::base::storage::{Storage, StorageMut};
-use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixSum, Scalar};
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixSum, RowVector, Scalar, Vector};
 
 /// The type of the result of a matrix component-wise operation.
 pub type MatrixComponentOp<N, R1, C1, R2, C2> = MatrixSum<N, R1, C1, R2, C2>;
@@ -41,9 +41,190 @@ impl<N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
         res
     }
 
-    // FIXME: add other operators like component_ln, component_pow, etc. ?
+    /// Computes the component-wise power `self[i]ⁿ` with an integer exponent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let v = Vector3::new(1.0, -2.0, 3.0);
+    /// assert_eq!(v.component_powi(3), Vector3::new(1.0, -8.0, 27.0));
+    /// ```
+    #[inline]
+    pub fn component_powi(&self, n: i32) -> MatrixMN<N, R, C>
+    where
+        N: SimdComplexField,
+        DefaultAllocator: Allocator<N, R, C>,
+    {
+        let mut res = self.clone_owned();
+        res.component_powi_mut(n);
+        res
+    }
+
+    /// Computes the component-wise power `self[i]ⁿ` with a real exponent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let v = Vector3::new(1.0, 4.0, 9.0);
+    /// assert_eq!(v.component_powf(0.5), Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn component_powf(&self, n: N::SimdRealField) -> MatrixMN<N, R, C>
+    where
+        N: SimdComplexField,
+        DefaultAllocator: Allocator<N, R, C>,
+    {
+        let mut res = self.clone_owned();
+        res.component_powf_mut(n);
+        res
+    }
+
+    /// Clamps each component of this matrix between `min` and `max`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let v = Vector3::new(-2.0, 0.5, 3.0);
+    /// assert_eq!(v.component_clamp(0.0, 1.0), Vector3::new(0.0, 0.5, 1.0));
+    /// ```
+    #[inline]
+    pub fn component_clamp(&self, min: N, max: N) -> MatrixMN<N, R, C>
+    where
+        N: SimdPartialOrd,
+        DefaultAllocator: Allocator<N, R, C>,
+    {
+        let mut res = self.clone_owned();
+        res.component_clamp_mut(min, max);
+        res
+    }
+}
+
+impl<N: Scalar, R: Dim, C: Dim, S: StorageMut<N, R, C>> Matrix<N, R, C, S> {
+    /// Replaces each component of this matrix by its integer power `self[i]ⁿ`.
+    #[inline]
+    pub fn component_powi_mut(&mut self, n: i32)
+    where
+        N: SimdComplexField,
+    {
+        self.apply(|e| e.simd_powi(n))
+    }
+
+    /// Replaces each component of this matrix by its real power `self[i]ⁿ`.
+    #[inline]
+    pub fn component_powf_mut(&mut self, n: N::SimdRealField)
+    where
+        N: SimdComplexField,
+    {
+        self.apply(|e| e.simd_powf(n))
+    }
+
+    /// Clamps in-place each component of this matrix between `min` and `max`.
+    #[inline]
+    pub fn component_clamp_mut(&mut self, min: N, max: N)
+    where
+        N: SimdPartialOrd,
+    {
+        self.apply(|e| e.simd_clamp(min.inlined_clone(), max.inlined_clone()))
+    }
 }
 
+macro_rules! component_unop_impl(
+    ($($unop: ident, $unop_mut: ident, $op: ident, $desc: expr, $desc_mut: expr);* $(;)*) => {$(
+        impl<N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
+            #[doc = $desc]
+            #[inline]
+            pub fn $unop(&self) -> MatrixMN<N, R, C>
+                where N: SimdComplexField,
+                      DefaultAllocator: Allocator<N, R, C> {
+                let mut res = self.clone_owned();
+                res.$unop_mut();
+                res
+            }
+        }
+
+        impl<N: Scalar, R: Dim, C: Dim, S: StorageMut<N, R, C>> Matrix<N, R, C, S> {
+            #[doc = $desc_mut]
+            #[inline]
+            pub fn $unop_mut(&mut self)
+                where N: SimdComplexField {
+                self.apply(|e| e.$op())
+            }
+        }
+    )*}
+);
+
+component_unop_impl!(
+    component_exp, component_exp_mut, simd_exp,
+    r"
+    Computes the component-wise exponential.
+
+    # Example
+
+    ```
+    # use nalgebra::Vector2;
+    let v = Vector2::new(0.0, 1.0);
+    assert_eq!(v.component_exp(), Vector2::new(1.0, 1.0f64.exp()));
+    ```
+    ",
+    "Replaces each component of this matrix by its exponential.";
+    component_ln, component_ln_mut, simd_ln,
+    r"
+    Computes the component-wise natural logarithm.
+
+    # Example
+
+    ```
+    # use nalgebra::Vector2;
+    let v = Vector2::new(1.0, 2.0);
+    assert_eq!(v.component_ln(), Vector2::new(0.0, 2.0f64.ln()));
+    ```
+    ",
+    "Replaces each component of this matrix by its natural logarithm.";
+    component_sqrt, component_sqrt_mut, simd_sqrt,
+    r"
+    Computes the component-wise square root.
+
+    # Example
+
+    ```
+    # use nalgebra::Vector3;
+    let v = Vector3::new(1.0, 4.0, 9.0);
+    assert_eq!(v.component_sqrt(), Vector3::new(1.0, 2.0, 3.0));
+    ```
+    ",
+    "Replaces each component of this matrix by its square root.";
+    component_sin, component_sin_mut, simd_sin,
+    "Computes the component-wise sine.",
+    "Replaces each component of this matrix by its sine.";
+    component_cos, component_cos_mut, simd_cos,
+    "Computes the component-wise cosine.",
+    "Replaces each component of this matrix by its cosine.";
+    component_tan, component_tan_mut, simd_tan,
+    "Computes the component-wise tangent.",
+    "Replaces each component of this matrix by its tangent.";
+    component_asin, component_asin_mut, simd_asin,
+    "Computes the component-wise arcsine.",
+    "Replaces each component of this matrix by its arcsine.";
+    component_acos, component_acos_mut, simd_acos,
+    "Computes the component-wise arccosine.",
+    "Replaces each component of this matrix by its arccosine.";
+    component_atan, component_atan_mut, simd_atan,
+    "Computes the component-wise arctangent.",
+    "Replaces each component of this matrix by its arctangent.";
+    component_sinh, component_sinh_mut, simd_sinh,
+    "Computes the component-wise hyperbolic sine.",
+    "Replaces each component of this matrix by its hyperbolic sine.";
+    component_cosh, component_cosh_mut, simd_cosh,
+    "Computes the component-wise hyperbolic cosine.",
+    "Replaces each component of this matrix by its hyperbolic cosine.";
+    component_tanh, component_tanh_mut, simd_tanh,
+    "Computes the component-wise hyperbolic tangent.",
+    "Replaces each component of this matrix by its hyperbolic tangent.";
+);
+
 macro_rules! component_binop_impl(
     ($($binop: ident, $binop_mut: ident, $binop_assign: ident, $cmpy: ident, $Trait: ident . $op: ident . $op_assign: ident, $desc:expr, $desc_cmpy:expr, $desc_mut:expr);* $(;)*) => {$(
         impl<N: Scalar, R1: Dim, C1: Dim, SA: Storage<N, R1, C1>> Matrix<N, R1, C1, SA> {
@@ -264,3 +445,376 @@ where
         (self.inf(other), self.sup(other))
     }
 }
+
+/*
+ * Broadcasting.
+ */
+impl<N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
+    /// Adds `row` to each row of this matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, RowVector3};
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// let row = RowVector3::new(10.0, 20.0, 30.0);
+    /// let expected = Matrix2x3::new(11.0, 22.0, 33.0,
+    ///                               14.0, 25.0, 36.0);
+    /// assert_eq!(m.add_row_broadcast(&row), expected);
+    /// ```
+    #[inline]
+    pub fn add_row_broadcast<C2: Dim, S2>(&self, row: &RowVector<N, C2, S2>) -> MatrixMN<N, R, C>
+    where
+        N: ClosedAdd,
+        S2: Storage<N, U1, C2>,
+        ShapeConstraint: SameNumberOfColumns<C, C2>,
+        DefaultAllocator: Allocator<N, R, C>,
+    {
+        let mut res = self.clone_owned();
+        res.add_row_broadcast_mut(row);
+        res
+    }
+
+    /// Subtracts `row` from each row of this matrix.
+    ///
+    /// For example, `m.sub_row_broadcast(&m.row_mean())` centers each column of `m`.
+    #[inline]
+    pub fn sub_row_broadcast<C2: Dim, S2>(&self, row: &RowVector<N, C2, S2>) -> MatrixMN<N, R, C>
+    where
+        N: ClosedSub,
+        S2: Storage<N, U1, C2>,
+        ShapeConstraint: SameNumberOfColumns<C, C2>,
+        DefaultAllocator: Allocator<N, R, C>,
+    {
+        let mut res = self.clone_owned();
+        res.sub_row_broadcast_mut(row);
+        res
+    }
+
+    /// Adds `column` to each column of this matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// let column = Vector2::new(10.0, 20.0);
+    /// let expected = Matrix2x3::new(11.0, 12.0, 13.0,
+    ///                               24.0, 25.0, 26.0);
+    /// assert_eq!(m.add_column_broadcast(&column), expected);
+    /// ```
+    #[inline]
+    pub fn add_column_broadcast<R2: Dim, S2>(&self, column: &Vector<N, R2, S2>) -> MatrixMN<N, R, C>
+    where
+        N: ClosedAdd,
+        S2: Storage<N, R2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+        DefaultAllocator: Allocator<N, R, C>,
+    {
+        let mut res = self.clone_owned();
+        res.add_column_broadcast_mut(column);
+        res
+    }
+
+    /// Subtracts `column` from each column of this matrix.
+    ///
+    /// For example, `m.sub_column_broadcast(&m.column_mean())` centers each row of `m`.
+    #[inline]
+    pub fn sub_column_broadcast<R2: Dim, S2>(&self, column: &Vector<N, R2, S2>) -> MatrixMN<N, R, C>
+    where
+        N: ClosedSub,
+        S2: Storage<N, R2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+        DefaultAllocator: Allocator<N, R, C>,
+    {
+        let mut res = self.clone_owned();
+        res.sub_column_broadcast_mut(column);
+        res
+    }
+}
+
+impl<N: Scalar, R: Dim, C: Dim, S: StorageMut<N, R, C>> Matrix<N, R, C, S> {
+    /// Adds in-place `row` to each row of this matrix.
+    #[inline]
+    pub fn add_row_broadcast_mut<C2: Dim, S2>(&mut self, row: &RowVector<N, C2, S2>)
+    where
+        N: ClosedAdd,
+        S2: Storage<N, U1, C2>,
+        ShapeConstraint: SameNumberOfColumns<C, C2>,
+    {
+        self.row_broadcast_with(row, |e, r| *e += r);
+    }
+
+    /// Subtracts in-place `row` from each row of this matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let mut m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                            5.0, 6.0, 7.0);
+    /// m.sub_row_broadcast_mut(&m.row_mean());
+    /// assert_eq!(m, Matrix2x3::new(-2.0, -2.0, -2.0,
+    ///                              2.0, 2.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn sub_row_broadcast_mut<C2: Dim, S2>(&mut self, row: &RowVector<N, C2, S2>)
+    where
+        N: ClosedSub,
+        S2: Storage<N, U1, C2>,
+        ShapeConstraint: SameNumberOfColumns<C, C2>,
+    {
+        self.row_broadcast_with(row, |e, r| *e -= r);
+    }
+
+    /// Computes in-place `self[(i, j)] = alpha * row[j] + beta * self[(i, j)]` for each row `i`
+    /// of this matrix.
+    ///
+    /// If `beta` is zero, `self` is never read.
+    #[inline]
+    pub fn axpy_row_broadcast<C2: Dim, S2>(&mut self, alpha: N, row: &RowVector<N, C2, S2>, beta: N)
+    where
+        N: Zero + ClosedAdd + ClosedMul,
+        S2: Storage<N, U1, C2>,
+        ShapeConstraint: SameNumberOfColumns<C, C2>,
+    {
+        if beta.is_zero() {
+            self.row_broadcast_with(row, |e, r| *e = alpha.inlined_clone() * r);
+        } else {
+            self.row_broadcast_with(row, |e, r| {
+                *e = alpha.inlined_clone() * r + beta.inlined_clone() * e.inlined_clone()
+            });
+        }
+    }
+
+    /// Adds in-place `column` to each column of this matrix.
+    #[inline]
+    pub fn add_column_broadcast_mut<R2: Dim, S2>(&mut self, column: &Vector<N, R2, S2>)
+    where
+        N: ClosedAdd,
+        S2: Storage<N, R2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+    {
+        self.column_broadcast_with(column, |e, c| *e += c);
+    }
+
+    /// Subtracts in-place `column` from each column of this matrix.
+    #[inline]
+    pub fn sub_column_broadcast_mut<R2: Dim, S2>(&mut self, column: &Vector<N, R2, S2>)
+    where
+        N: ClosedSub,
+        S2: Storage<N, R2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+    {
+        self.column_broadcast_with(column, |e, c| *e -= c);
+    }
+
+    /// Computes in-place `self[(i, j)] = alpha * column[i] + beta * self[(i, j)]` for each
+    /// column `j` of this matrix.
+    ///
+    /// If `beta` is zero, `self` is never read.
+    #[inline]
+    pub fn axpy_column_broadcast<R2: Dim, S2>(
+        &mut self,
+        alpha: N,
+        column: &Vector<N, R2, S2>,
+        beta: N,
+    ) where
+        N: Zero + ClosedAdd + ClosedMul,
+        S2: Storage<N, R2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+    {
+        if beta.is_zero() {
+            self.column_broadcast_with(column, |e, c| *e = alpha.inlined_clone() * c);
+        } else {
+            self.column_broadcast_with(column, |e, c| {
+                *e = alpha.inlined_clone() * c + beta.inlined_clone() * e.inlined_clone()
+            });
+        }
+    }
+
+    #[inline(always)]
+    fn row_broadcast_with<C2: Dim, S2>(
+        &mut self,
+        row: &RowVector<N, C2, S2>,
+        mut f: impl FnMut(&mut N, N),
+    ) where
+        S2: Storage<N, U1, C2>,
+        ShapeConstraint: SameNumberOfColumns<C, C2>,
+    {
+        assert_eq!(
+            self.ncols(),
+            row.ncols(),
+            "Row broadcasting: mismatched matrix dimensions."
+        );
+
+        for j in 0..self.ncols() {
+            for i in 0..self.nrows() {
+                unsafe {
+                    let r = row.get_unchecked((0, j)).inlined_clone();
+                    f(self.get_unchecked_mut((i, j)), r)
+                }
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn column_broadcast_with<R2: Dim, S2>(
+        &mut self,
+        column: &Vector<N, R2, S2>,
+        mut f: impl FnMut(&mut N, N),
+    ) where
+        S2: Storage<N, R2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+    {
+        assert_eq!(
+            self.nrows(),
+            column.nrows(),
+            "Column broadcasting: mismatched matrix dimensions."
+        );
+
+        for j in 0..self.ncols() {
+            for i in 0..self.nrows() {
+                unsafe {
+                    let c = column.vget_unchecked(i).inlined_clone();
+                    f(self.get_unchecked_mut((i, j)), c)
+                }
+            }
+        }
+    }
+}
+
+/*
+ * Masks.
+ */
+macro_rules! component_cmp_impl(
+    ($($cmp: ident, $Trait: ident, $op: tt, $desc: expr);* $(;)*) => {$(
+        impl<N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
+            #[doc = $desc]
+            #[inline]
+            pub fn $cmp<S2>(&self, other: &Matrix<N, R, C, S2>) -> MatrixMN<bool, R, C>
+                where N: $Trait,
+                      S2: Storage<N, R, C>,
+                      DefaultAllocator: Allocator<bool, R, C> {
+                self.zip_map(other, |a, b| a $op b)
+            }
+        }
+    )*}
+);
+
+component_cmp_impl!(
+    component_eq, PartialEq, ==,
+    "Computes the mask of the components of `self` equal to the corresponding components of `other`.";
+    component_ne, PartialEq, !=,
+    "Computes the mask of the components of `self` different from the corresponding components of `other`.";
+    component_lt, PartialOrd, <,
+    r"
+    Computes the mask of the components of `self` smaller than the corresponding components of `other`.
+
+    # Example
+    ```
+    # use nalgebra::Matrix2;
+    let a = Matrix2::new(1, 5, 3, 2);
+    let b = Matrix2::new(4, 4, 3, 3);
+    assert_eq!(a.component_lt(&b), Matrix2::new(true, false, false, true));
+    ```
+    ";
+    component_le, PartialOrd, <=,
+    "Computes the mask of the components of `self` smaller than or equal to the corresponding components of `other`.";
+    component_gt, PartialOrd, >,
+    "Computes the mask of the components of `self` greater than the corresponding components of `other`.";
+    component_ge, PartialOrd, >=,
+    "Computes the mask of the components of `self` greater than or equal to the corresponding components of `other`.";
+);
+
+impl<N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
+    /// Builds a matrix with the components of `self` where `mask` is `true`, and the components
+    /// of `other` elsewhere.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let a = Vector3::new(1.0, -2.0, 3.0);
+    /// let zeros = Vector3::zeros();
+    /// // Replaces the negative components by zero.
+    /// assert_eq!(a.select(&a.component_gt(&zeros), &zeros), Vector3::new(1.0, 0.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn select<S2, S3>(
+        &self,
+        mask: &Matrix<bool, R, C, S2>,
+        other: &Matrix<N, R, C, S3>,
+    ) -> MatrixMN<N, R, C>
+    where
+        S2: Storage<bool, R, C>,
+        S3: Storage<N, R, C>,
+        DefaultAllocator: Allocator<N, R, C>,
+    {
+        self.zip_zip_map(mask, other, |a, m, b| if m { a } else { b })
+    }
+}
+
+impl<N: Scalar, R: Dim, C: Dim, S: StorageMut<N, R, C>> Matrix<N, R, C, S> {
+    /// Replaces the components of `self` by the corresponding components of `other` where
+    /// `mask` is `true`.
+    #[inline]
+    pub fn copy_from_masked<S2, S3>(
+        &mut self,
+        mask: &Matrix<bool, R, C, S2>,
+        other: &Matrix<N, R, C, S3>,
+    ) where
+        S2: Storage<bool, R, C>,
+        S3: Storage<N, R, C>,
+    {
+        assert_eq!(
+            self.shape(),
+            other.shape(),
+            "Masked assignment: mismatched matrix dimensions."
+        );
+        self.masked_apply(mask, |e, i, j| unsafe {
+            *e = other.get_unchecked((i, j)).inlined_clone()
+        });
+    }
+
+    /// Sets to `val` the components of `self` where `mask` is `true`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let mut a = Vector3::new(1.0, -2.0, 3.0);
+    /// let mask = a.component_lt(&Vector3::zeros());
+    /// a.fill_masked(&mask, 0.0);
+    /// assert_eq!(a, Vector3::new(1.0, 0.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn fill_masked<S2>(&mut self, mask: &Matrix<bool, R, C, S2>, val: N)
+    where
+        S2: Storage<bool, R, C>,
+    {
+        self.masked_apply(mask, |e, _, _| *e = val.inlined_clone());
+    }
+
+    #[inline(always)]
+    fn masked_apply<S2>(
+        &mut self,
+        mask: &Matrix<bool, R, C, S2>,
+        mut f: impl FnMut(&mut N, usize, usize),
+    ) where
+        S2: Storage<bool, R, C>,
+    {
+        assert_eq!(
+            self.shape(),
+            mask.shape(),
+            "Masked assignment: mismatched mask dimensions."
+        );
+
+        for j in 0..self.ncols() {
+            for i in 0..self.nrows() {
+                unsafe {
+                    if *mask.get_unchecked((i, j)) {
+                        f(self.get_unchecked_mut((i, j)), i, j)
+                    }
+                }
+            }
+        }
+    }
+}
@@ -3,7 +3,7 @@ use crate::base::storage::Owned;
 #[cfg(feature = "arbitrary")]
 use quickcheck::{Arbitrary, Gen};
 
-use num::{Bounded, One, Zero};
+use num::{Bounded, ConstOne, ConstZero, One, Zero};
 #[cfg(feature = "std")]
 use rand;
 use rand::distributions::{Distribution, Standard};
@@ -11,6 +11,9 @@ use rand::Rng;
 #[cfg(feature = "std")]
 use rand_distr::StandardNormal;
 use std::iter;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
 use typenum::{self, Cmp, Greater};
 
 #[cfg(feature = "std")]
@@ -18,9 +21,13 @@ use simba::scalar::RealField;
 use simba::scalar::{ClosedAdd, ClosedMul};
 
 use crate::base::allocator::Allocator;
+use crate::base::array_storage::ArrayStorage;
 use crate::base::dimension::{Dim, DimName, Dynamic, U1, U2, U3, U4, U5, U6};
 use crate::base::storage::Storage;
-use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, Scalar, Unit, Vector, VectorN};
+use crate::base::{
+    DefaultAllocator, ElementCountMismatch, Matrix, MatrixMN, MatrixN, Scalar, UninitMatrix, Unit,
+    Vector, VectorN,
+};
 
 /*
  *
@@ -103,6 +110,49 @@ where
         Self::from_iterator_generic(nrows, ncols, slice.iter().cloned())
     }
 
+    /// Creates a matrix with its elements filled by an iterator, or returns an error if the
+    /// iterator does not yield exactly `nrows * ncols` elements.
+    ///
+    /// The iterator is consumed entirely, so it must be finite.
+    #[inline]
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn try_from_iterator_generic<I>(
+        nrows: R,
+        ncols: C,
+        iter: I,
+    ) -> Result<Self, ElementCountMismatch>
+    where
+        I: IntoIterator<Item = N>,
+    {
+        Self::try_from_vec_generic(nrows, ncols, iter.into_iter().collect())
+    }
+
+    /// Creates a matrix with its elements filled with the components provided by a slice in
+    /// row-major order, or returns an error if the slice does not contain exactly
+    /// `nrows * ncols` elements.
+    #[inline]
+    pub fn try_from_row_slice_generic(
+        nrows: R,
+        ncols: C,
+        slice: &[N],
+    ) -> Result<Self, ElementCountMismatch> {
+        check_element_count(nrows, ncols, slice.len())?;
+        Ok(Self::from_row_slice_generic(nrows, ncols, slice))
+    }
+
+    /// Creates a matrix with its elements filled with the components provided by a slice in
+    /// column-major order, or returns an error if the slice does not contain exactly
+    /// `nrows * ncols` elements.
+    #[inline]
+    pub fn try_from_column_slice_generic(
+        nrows: R,
+        ncols: C,
+        slice: &[N],
+    ) -> Result<Self, ElementCountMismatch> {
+        check_element_count(nrows, ncols, slice.len())?;
+        Ok(Self::from_column_slice_generic(nrows, ncols, slice))
+    }
+
     /// Creates a matrix filled with the results of a function applied to each of its component
     /// coordinates.
     #[inline]
@@ -257,6 +307,106 @@ where
         })
     }
 
+    /// Builds a new matrix from a two-dimensional arrangement of blocks.
+    ///
+    /// Each element of `blocks` is a row of blocks. All the blocks of the same row must have the
+    /// same number of rows, and all the blocks of the same column must have the same number of
+    /// columns.
+    ///
+    /// Panics if no block is provided, if the dimensions of the blocks are not compatible, or if
+    /// the blocks do not have the dimensions of this matrix (for statically-sized matrices).
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix2, Matrix4};
+    ///
+    /// let a = Matrix2::new(1, 2, 5, 6);
+    /// let b = Matrix2::new(3, 4, 7, 8);
+    /// let c = Matrix2::new(9, 10, 13, 14);
+    /// let d = Matrix2::new(11, 12, 15, 16);
+    /// let m = Matrix4::from_blocks(&[[&a, &b], [&c, &d]]);
+    ///
+    /// assert_eq!(m, Matrix4::new(1, 2, 3, 4,
+    ///                            5, 6, 7, 8,
+    ///                            9, 10, 11, 12,
+    ///                            13, 14, 15, 16));
+    ///
+    /// let a = DMatrix::from_row_slice(1, 1, &[1]);
+    /// let b = DMatrix::from_row_slice(1, 2, &[2, 3]);
+    /// let c = DMatrix::from_row_slice(2, 1, &[4, 7]);
+    /// let d = DMatrix::from_row_slice(2, 2, &[5, 6, 8, 9]);
+    /// let m = DMatrix::from_blocks(&[[&a, &b], [&c, &d]]);
+    ///
+    /// assert_eq!(m, DMatrix::from_row_slice(3, 3, &[1, 2, 3, 4, 5, 6, 7, 8, 9]));
+    /// ```
+    pub fn from_blocks<'a, R2, C2, SB, B>(blocks: &[B]) -> Self
+    where
+        R2: Dim,
+        C2: Dim,
+        SB: Storage<N, R2, C2> + 'a,
+        B: AsRef<[&'a Matrix<N, R2, C2, SB>]>,
+    {
+        assert!(
+            !blocks.is_empty() && !blocks[0].as_ref().is_empty(),
+            "At least one block must be given."
+        );
+
+        let first_row = blocks[0].as_ref();
+        let nblock_cols = first_row.len();
+
+        for row in blocks {
+            let row = row.as_ref();
+            assert!(
+                row.len() == nblock_cols,
+                "All the rows of blocks must have the same number of blocks."
+            );
+            assert!(
+                row.iter().all(|block| block.nrows() == row[0].nrows()),
+                "The blocks of the same row must have the same number of rows."
+            );
+            assert!(
+                row.iter()
+                    .zip(first_row.iter())
+                    .all(|(block, first)| block.ncols() == first.ncols()),
+                "The blocks of the same column must have the same number of columns."
+            );
+        }
+
+        let nrows = blocks.iter().map(|row| row.as_ref()[0].nrows()).sum();
+        let ncols = first_row.iter().map(|block| block.ncols()).sum();
+        assert!(
+            R::try_to_usize().map_or(true, |n| n == nrows)
+                && C::try_to_usize().map_or(true, |n| n == ncols),
+            "The blocks do not have the dimensions of the matrix to build."
+        );
+
+        let mut res =
+            unsafe { Self::new_uninitialized_generic(R::from_usize(nrows), C::from_usize(ncols)) };
+        let mut i0 = 0;
+
+        for row in blocks {
+            let row = row.as_ref();
+            let mut j0 = 0;
+
+            for block in row {
+                for j in 0..block.ncols() {
+                    for i in 0..block.nrows() {
+                        unsafe {
+                            *res.get_unchecked_mut((i0 + i, j0 + j)) =
+                                block.get_unchecked((i, j)).inlined_clone();
+                        }
+                    }
+                }
+
+                j0 += block.ncols();
+            }
+
+            i0 += row[0].nrows();
+        }
+
+        res
+    }
+
     /// Creates a matrix filled with random values.
     #[inline]
     #[cfg(feature = "std")]
@@ -300,6 +450,35 @@ where
     pub fn from_vec_generic(nrows: R, ncols: C, data: Vec<N>) -> Self {
         Self::from_iterator_generic(nrows, ncols, data)
     }
+
+    /// Creates a matrix backed by a given `Vec`, or returns an error if it does not contain
+    /// exactly `nrows * ncols` elements.
+    ///
+    /// The output matrix is filled column-by-column.
+    #[inline]
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn try_from_vec_generic(
+        nrows: R,
+        ncols: C,
+        data: Vec<N>,
+    ) -> Result<Self, ElementCountMismatch> {
+        check_element_count(nrows, ncols, data.len())?;
+        Ok(Self::from_iterator_generic(nrows, ncols, data))
+    }
+}
+
+fn check_element_count<R: Dim, C: Dim>(
+    nrows: R,
+    ncols: C,
+    found: usize,
+) -> Result<(), ElementCountMismatch> {
+    let expected = nrows.value() * ncols.value();
+
+    if found == expected {
+        Ok(())
+    } else {
+        Err(ElementCountMismatch { expected, found })
+    }
 }
 
 impl<N, D: Dim> MatrixN<N, D>
@@ -359,6 +538,13 @@ macro_rules! impl_constructors(
                 Self::new_uninitialized_generic($($gargs),*)
             }
 
+            /// Creates a matrix or vector that is not initialized yet, to be filled
+            /// column-by-column. See `UninitMatrix` for details.
+            #[inline]
+            pub fn new_uninit($($args: usize),*) -> UninitMatrix<N $(, $Dims)*> {
+                Self::new_uninit_generic($($gargs),*)
+            }
+
             /// Creates a matrix or vector with all its elements set to `elem`.
             ///
             /// # Example
@@ -466,6 +652,30 @@ macro_rules! impl_constructors(
                 Self::from_iterator_generic($($gargs, )* iter)
             }
 
+            /// Creates a matrix or vector with all its elements filled by an iterator, or returns
+            /// an error if the iterator does not yield exactly the number of components of the
+            /// matrix.
+            ///
+            /// The output matrix is filled column-by-column. The iterator is consumed entirely, so
+            /// it must be finite.
+            ///
+            /// # Example
+            /// ```
+            /// # use nalgebra::{DMatrix, ElementCountMismatch, Matrix2x3};
+            ///
+            /// let m = Matrix2x3::try_from_iterator(0..6).unwrap();
+            /// assert_eq!(m, Matrix2x3::from_iterator(0..6));
+            ///
+            /// let err = DMatrix::<i32>::try_from_iterator(2, 3, 0..7).unwrap_err();
+            /// assert_eq!(err, ElementCountMismatch { expected: 6, found: 7 });
+            /// ```
+            #[inline]
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            pub fn try_from_iterator<I>($($args: usize,)* iter: I) -> Result<Self, ElementCountMismatch>
+                where I: IntoIterator<Item = N> {
+                Self::try_from_iterator_generic($($gargs, )* iter)
+            }
+
             /// Creates a matrix or vector filled with the results of a function applied to each of its
             /// component coordinates.
             ///
@@ -655,6 +865,25 @@ macro_rules! impl_constructors_from_data(
                 Self::from_row_slice_generic($($gargs, )* $data)
             }
 
+            /// Creates a matrix with its elements filled with the components provided by a slice
+            /// in row-major order, or returns an error if the slice does not contain exactly the
+            /// number of components of the matrix.
+            ///
+            /// # Example
+            /// ```
+            /// # use nalgebra::{DMatrix, ElementCountMismatch, Matrix2x3};
+            ///
+            /// let m = Matrix2x3::try_from_row_slice(&[0, 1, 2, 3, 4, 5]).unwrap();
+            /// assert_eq!(m, Matrix2x3::new(0, 1, 2, 3, 4, 5));
+            ///
+            /// let err = DMatrix::try_from_row_slice(2, 3, &[0, 1, 2, 3]).unwrap_err();
+            /// assert_eq!(err, ElementCountMismatch { expected: 6, found: 4 });
+            /// ```
+            #[inline]
+            pub fn try_from_row_slice($($args: usize,)* $data: &[N]) -> Result<Self, ElementCountMismatch> {
+                Self::try_from_row_slice_generic($($gargs, )* $data)
+            }
+
             /// Creates a matrix with its elements filled with the components provided by a slice
             /// in column-major order.
             ///
@@ -682,6 +911,14 @@ macro_rules! impl_constructors_from_data(
                 Self::from_column_slice_generic($($gargs, )* $data)
             }
 
+            /// Creates a matrix with its elements filled with the components provided by a slice
+            /// in column-major order, or returns an error if the slice does not contain exactly
+            /// the number of components of the matrix.
+            #[inline]
+            pub fn try_from_column_slice($($args: usize,)* $data: &[N]) -> Result<Self, ElementCountMismatch> {
+                Self::try_from_column_slice_generic($($gargs, )* $data)
+            }
+
             /// Creates a matrix backed by a given `Vec`.
             ///
             /// The output matrix is filled column-by-column.
@@ -707,6 +944,24 @@ macro_rules! impl_constructors_from_data(
             pub fn from_vec($($args: usize,)* $data: Vec<N>) -> Self {
                 Self::from_vec_generic($($gargs, )* $data)
             }
+
+            /// Creates a matrix backed by a given `Vec`, or returns an error if it does not
+            /// contain exactly the number of components of the matrix.
+            ///
+            /// The output matrix is filled column-by-column.
+            ///
+            /// # Example
+            /// ```
+            /// # use nalgebra::{ElementCountMismatch, Matrix2x3};
+            ///
+            /// let err = Matrix2x3::try_from_vec(vec![0, 1, 2, 3, 4]).unwrap_err();
+            /// assert_eq!(err.to_string(), "expected 6 matrix components, found 5");
+            /// ```
+            #[inline]
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            pub fn try_from_vec($($args: usize,)* $data: Vec<N>) -> Result<Self, ElementCountMismatch> {
+                Self::try_from_vec_generic($($gargs, )* $data)
+            }
         }
     }
 );
@@ -837,22 +1092,43 @@ where
  * Constructors for small matrices and vectors.
  *
  */
+/// Reorders the rows of components of a statically-sized matrix into an array of its components
+/// in column-major order.
+macro_rules! column_major_array(
+    [$($a: expr),*] => { [$($a),*] };
+    [$($a: expr),*; $($b: expr),*] => { [$($a, $b),*] };
+    [$($a: expr),*; $($b: expr),*; $($c: expr),*] => { [$($a, $b, $c),*] };
+    [$($a: expr),*; $($b: expr),*; $($c: expr),*; $($d: expr),*] => { [$($a, $b, $c, $d),*] };
+    [$($a: expr),*; $($b: expr),*; $($c: expr),*; $($d: expr),*; $($e: expr),*] => {
+        [$($a, $b, $c, $d, $e),*]
+    };
+    [$($a: expr),*; $($b: expr),*; $($c: expr),*; $($d: expr),*; $($e: expr),*; $($f: expr),*] => {
+        [$($a, $b, $c, $d, $e, $f),*]
+    };
+);
+
 macro_rules! componentwise_constructors_impl(
-    ($($R: ty, $C: ty, $($args: ident:($irow: expr,$icol: expr)),*);* $(;)*) => {$(
-        impl<N> MatrixMN<N, $R, $C>
-            where N: Scalar,
-                  DefaultAllocator: Allocator<N, $R, $C> {
+    ($($R: ty, $C: ty, [$($($args: ident:($irow: expr,$icol: expr)),*);*]);* $(;)*) => {$(
+        impl<N: Scalar> MatrixMN<N, $R, $C> {
             /// Initializes this matrix from its components.
+            ///
+            /// This is a `const fn`, so it can be used to initialize constants and statics.
             #[inline]
-            pub fn new($($args: N),*) -> Self {
-                unsafe {
-                    let mut res = Self::new_uninitialized();
-                    $( *res.get_unchecked_mut(($irow, $icol)) = $args; )*
-
-                    res
-                }
+            pub const fn new($($($args: N),*),*) -> Self {
+                let data = column_major_array![$($($args),*);*];
+                unsafe { Self::from_data_statically_unchecked(ArrayStorage::from_array(data)) }
             }
         }
+
+        impl<N: Scalar + ConstZero + ConstOne> MatrixMN<N, $R, $C> {
+            /// The identity matrix, i.e., `Self::identity()` as a constant.
+            ///
+            /// If the matrix is not square, the largest square submatrix starting at index `(0, 0)`
+            /// is set to the identity matrix. All other entries are set to zero.
+            pub const IDENTITY: Self = Self::new(
+                $($(if $irow == $icol { N::ONE } else { N::ZERO }),*),*
+            );
+        }
     )*}
 );
 
@@ -860,145 +1136,160 @@ componentwise_constructors_impl!(
     /*
      * Square matrices 1 .. 6.
      */
-    U2, U2, m11:(0,0), m12:(0,1),
-            m21:(1,0), m22:(1,1);
-    U3, U3, m11:(0,0), m12:(0,1), m13:(0,2),
-            m21:(1,0), m22:(1,1), m23:(1,2),
-            m31:(2,0), m32:(2,1), m33:(2,2);
-    U4, U4, m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3),
-            m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3),
-            m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3),
-            m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3);
-    U5, U5, m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4),
-            m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4),
-            m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3), m35:(2,4),
-            m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3), m45:(3,4),
-            m51:(4,0), m52:(4,1), m53:(4,2), m54:(4,3), m55:(4,4);
-    U6, U6, m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4), m16:(0,5),
-            m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4), m26:(1,5),
-            m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3), m35:(2,4), m36:(2,5),
-            m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3), m45:(3,4), m46:(3,5),
-            m51:(4,0), m52:(4,1), m53:(4,2), m54:(4,3), m55:(4,4), m56:(4,5),
-            m61:(5,0), m62:(5,1), m63:(5,2), m64:(5,3), m65:(5,4), m66:(5,5);
+    U2, U2, [m11:(0,0), m12:(0,1);
+             m21:(1,0), m22:(1,1)];
+    U3, U3, [m11:(0,0), m12:(0,1), m13:(0,2);
+             m21:(1,0), m22:(1,1), m23:(1,2);
+             m31:(2,0), m32:(2,1), m33:(2,2)];
+    U4, U4, [m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3);
+             m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3);
+             m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3);
+             m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3)];
+    U5, U5, [m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4);
+             m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4);
+             m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3), m35:(2,4);
+             m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3), m45:(3,4);
+             m51:(4,0), m52:(4,1), m53:(4,2), m54:(4,3), m55:(4,4)];
+    U6, U6, [m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4), m16:(0,5);
+             m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4), m26:(1,5);
+             m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3), m35:(2,4), m36:(2,5);
+             m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3), m45:(3,4), m46:(3,5);
+             m51:(4,0), m52:(4,1), m53:(4,2), m54:(4,3), m55:(4,4), m56:(4,5);
+             m61:(5,0), m62:(5,1), m63:(5,2), m64:(5,3), m65:(5,4), m66:(5,5)];
 
     /*
      * Rectangular matrices with 2 rows.
      */
-    U2, U3, m11:(0,0), m12:(0,1), m13:(0,2),
-            m21:(1,0), m22:(1,1), m23:(1,2);
-    U2, U4, m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3),
-            m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3);
-    U2, U5, m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4),
-            m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4);
-    U2, U6, m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4), m16:(0,5),
-            m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4), m26:(1,5);
+    U2, U3, [m11:(0,0), m12:(0,1), m13:(0,2);
+             m21:(1,0), m22:(1,1), m23:(1,2)];
+    U2, U4, [m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3);
+             m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3)];
+    U2, U5, [m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4);
+             m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4)];
+    U2, U6, [m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4), m16:(0,5);
+             m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4), m26:(1,5)];
 
     /*
      * Rectangular matrices with 3 rows.
      */
-    U3, U2, m11:(0,0), m12:(0,1),
-            m21:(1,0), m22:(1,1),
-            m31:(2,0), m32:(2,1);
-    U3, U4, m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3),
-            m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3),
-            m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3);
-    U3, U5, m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4),
-            m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4),
-            m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3), m35:(2,4);
-    U3, U6, m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4), m16:(0,5),
-            m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4), m26:(1,5),
-            m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3), m35:(2,4), m36:(2,5);
+    U3, U2, [m11:(0,0), m12:(0,1);
+             m21:(1,0), m22:(1,1);
+             m31:(2,0), m32:(2,1)];
+    U3, U4, [m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3);
+             m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3);
+             m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3)];
+    U3, U5, [m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4);
+             m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4);
+             m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3), m35:(2,4)];
+    U3, U6, [m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4), m16:(0,5);
+             m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4), m26:(1,5);
+             m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3), m35:(2,4), m36:(2,5)];
 
     /*
      * Rectangular matrices with 4 rows.
      */
-    U4, U2, m11:(0,0), m12:(0,1),
-            m21:(1,0), m22:(1,1),
-            m31:(2,0), m32:(2,1),
-            m41:(3,0), m42:(3,1);
-    U4, U3, m11:(0,0), m12:(0,1), m13:(0,2),
-            m21:(1,0), m22:(1,1), m23:(1,2),
-            m31:(2,0), m32:(2,1), m33:(2,2),
-            m41:(3,0), m42:(3,1), m43:(3,2);
-    U4, U5, m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4),
-            m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4),
-            m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3), m35:(2,4),
-            m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3), m45:(3,4);
-    U4, U6, m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4), m16:(0,5),
-            m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4), m26:(1,5),
-            m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3), m35:(2,4), m36:(2,5),
-            m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3), m45:(3,4), m46:(3,5);
+    U4, U2, [m11:(0,0), m12:(0,1);
+             m21:(1,0), m22:(1,1);
+             m31:(2,0), m32:(2,1);
+             m41:(3,0), m42:(3,1)];
+    U4, U3, [m11:(0,0), m12:(0,1), m13:(0,2);
+             m21:(1,0), m22:(1,1), m23:(1,2);
+             m31:(2,0), m32:(2,1), m33:(2,2);
+             m41:(3,0), m42:(3,1), m43:(3,2)];
+    U4, U5, [m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4);
+             m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4);
+             m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3), m35:(2,4);
+             m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3), m45:(3,4)];
+    U4, U6, [m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4), m16:(0,5);
+             m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4), m26:(1,5);
+             m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3), m35:(2,4), m36:(2,5);
+             m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3), m45:(3,4), m46:(3,5)];
 
     /*
      * Rectangular matrices with 5 rows.
      */
-    U5, U2, m11:(0,0), m12:(0,1),
-            m21:(1,0), m22:(1,1),
-            m31:(2,0), m32:(2,1),
-            m41:(3,0), m42:(3,1),
-            m51:(4,0), m52:(4,1);
-    U5, U3, m11:(0,0), m12:(0,1), m13:(0,2),
-            m21:(1,0), m22:(1,1), m23:(1,2),
-            m31:(2,0), m32:(2,1), m33:(2,2),
-            m41:(3,0), m42:(3,1), m43:(3,2),
-            m51:(4,0), m52:(4,1), m53:(4,2);
-    U5, U4, m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3),
-            m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3),
-            m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3),
-            m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3),
-            m51:(4,0), m52:(4,1), m53:(4,2), m54:(4,3);
-    U5, U6, m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4), m16:(0,5),
-            m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4), m26:(1,5),
-            m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3), m35:(2,4), m36:(2,5),
-            m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3), m45:(3,4), m46:(3,5),
-            m51:(4,0), m52:(4,1), m53:(4,2), m54:(4,3), m55:(4,4), m56:(4,5);
+    U5, U2, [m11:(0,0), m12:(0,1);
+             m21:(1,0), m22:(1,1);
+             m31:(2,0), m32:(2,1);
+             m41:(3,0), m42:(3,1);
+             m51:(4,0), m52:(4,1)];
+    U5, U3, [m11:(0,0), m12:(0,1), m13:(0,2);
+             m21:(1,0), m22:(1,1), m23:(1,2);
+             m31:(2,0), m32:(2,1), m33:(2,2);
+             m41:(3,0), m42:(3,1), m43:(3,2);
+             m51:(4,0), m52:(4,1), m53:(4,2)];
+    U5, U4, [m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3);
+             m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3);
+             m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3);
+             m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3);
+             m51:(4,0), m52:(4,1), m53:(4,2), m54:(4,3)];
+    U5, U6, [m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4), m16:(0,5);
+             m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4), m26:(1,5);
+             m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3), m35:(2,4), m36:(2,5);
+             m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3), m45:(3,4), m46:(3,5);
+             m51:(4,0), m52:(4,1), m53:(4,2), m54:(4,3), m55:(4,4), m56:(4,5)];
 
     /*
      * Rectangular matrices with 6 rows.
      */
-    U6, U2, m11:(0,0), m12:(0,1),
-            m21:(1,0), m22:(1,1),
-            m31:(2,0), m32:(2,1),
-            m41:(3,0), m42:(3,1),
-            m51:(4,0), m52:(4,1),
-            m61:(5,0), m62:(5,1);
-    U6, U3, m11:(0,0), m12:(0,1), m13:(0,2),
-            m21:(1,0), m22:(1,1), m23:(1,2),
-            m31:(2,0), m32:(2,1), m33:(2,2),
-            m41:(3,0), m42:(3,1), m43:(3,2),
-            m51:(4,0), m52:(4,1), m53:(4,2),
-            m61:(5,0), m62:(5,1), m63:(5,2);
-    U6, U4, m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3),
-            m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3),
-            m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3),
-            m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3),
-            m51:(4,0), m52:(4,1), m53:(4,2), m54:(4,3),
-            m61:(5,0), m62:(5,1), m63:(5,2), m64:(5,3);
-    U6, U5, m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4),
-            m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4),
-            m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3), m35:(2,4),
-            m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3), m45:(3,4),
-            m51:(4,0), m52:(4,1), m53:(4,2), m54:(4,3), m55:(4,4),
-            m61:(5,0), m62:(5,1), m63:(5,2), m64:(5,3), m65:(5,4);
+    U6, U2, [m11:(0,0), m12:(0,1);
+             m21:(1,0), m22:(1,1);
+             m31:(2,0), m32:(2,1);
+             m41:(3,0), m42:(3,1);
+             m51:(4,0), m52:(4,1);
+             m61:(5,0), m62:(5,1)];
+    U6, U3, [m11:(0,0), m12:(0,1), m13:(0,2);
+             m21:(1,0), m22:(1,1), m23:(1,2);
+             m31:(2,0), m32:(2,1), m33:(2,2);
+             m41:(3,0), m42:(3,1), m43:(3,2);
+             m51:(4,0), m52:(4,1), m53:(4,2);
+             m61:(5,0), m62:(5,1), m63:(5,2)];
+    U6, U4, [m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3);
+             m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3);
+             m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3);
+             m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3);
+             m51:(4,0), m52:(4,1), m53:(4,2), m54:(4,3);
+             m61:(5,0), m62:(5,1), m63:(5,2), m64:(5,3)];
+    U6, U5, [m11:(0,0), m12:(0,1), m13:(0,2), m14:(0,3), m15:(0,4);
+             m21:(1,0), m22:(1,1), m23:(1,2), m24:(1,3), m25:(1,4);
+             m31:(2,0), m32:(2,1), m33:(2,2), m34:(2,3), m35:(2,4);
+             m41:(3,0), m42:(3,1), m43:(3,2), m44:(3,3), m45:(3,4);
+             m51:(4,0), m52:(4,1), m53:(4,2), m54:(4,3), m55:(4,4);
+             m61:(5,0), m62:(5,1), m63:(5,2), m64:(5,3), m65:(5,4)];
 
     /*
      * Row vectors 1 .. 6.
      */
-    U1, U1, x:(0,0);
-    U1, U2, x:(0,0), y:(0,1);
-    U1, U3, x:(0,0), y:(0,1), z:(0,2);
-    U1, U4, x:(0,0), y:(0,1), z:(0,2), w:(0,3);
-    U1, U5, x:(0,0), y:(0,1), z:(0,2), w:(0,3), a:(0,4);
-    U1, U6, x:(0,0), y:(0,1), z:(0,2), w:(0,3), a:(0,4), b:(0,5);
+    U1, U1, [x:(0,0)];
+    U1, U2, [x:(0,0), y:(0,1)];
+    U1, U3, [x:(0,0), y:(0,1), z:(0,2)];
+    U1, U4, [x:(0,0), y:(0,1), z:(0,2), w:(0,3)];
+    U1, U5, [x:(0,0), y:(0,1), z:(0,2), w:(0,3), a:(0,4)];
+    U1, U6, [x:(0,0), y:(0,1), z:(0,2), w:(0,3), a:(0,4), b:(0,5)];
 
     /*
      * Column vectors 1 .. 6.
      */
-    U2, U1, x:(0,0), y:(1,0);
-    U3, U1, x:(0,0), y:(1,0), z:(2,0);
-    U4, U1, x:(0,0), y:(1,0), z:(2,0), w:(3,0);
-    U5, U1, x:(0,0), y:(1,0), z:(2,0), w:(3,0), a:(4,0);
-    U6, U1, x:(0,0), y:(1,0), z:(2,0), w:(3,0), a:(4,0), b:(5,0);
+    U2, U1, [x:(0,0);
+             y:(1,0)];
+    U3, U1, [x:(0,0);
+             y:(1,0);
+             z:(2,0)];
+    U4, U1, [x:(0,0);
+             y:(1,0);
+             z:(2,0);
+             w:(3,0)];
+    U5, U1, [x:(0,0);
+             y:(1,0);
+             z:(2,0);
+             w:(3,0);
+             a:(4,0)];
+    U6, U1, [x:(0,0);
+             y:(1,0);
+             z:(2,0);
+             w:(3,0);
+             a:(4,0);
+             b:(5,0)];
 );
 
 /*
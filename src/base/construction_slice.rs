@@ -1,6 +1,8 @@
 use crate::base::dimension::{Dim, DimName, Dynamic, U1};
 use crate::base::matrix_slice::{SliceStorage, SliceStorageMut};
-use crate::base::{MatrixSliceMN, MatrixSliceMutMN, Scalar};
+use crate::base::{
+    MatrixSliceMN, MatrixSliceMutMN, RowMajorMatrixSliceMN, RowMajorMatrixSliceMutMN, Scalar,
+};
 
 use num_rational::Ratio;
 /*
@@ -297,3 +299,77 @@ impl_constructors_mut!(Dynamic, Dynamic;
                        ;
                        Dynamic::new(nrows), Dynamic::new(ncols);
                        nrows, ncols);
+
+/*
+ *
+ * Row-major slice constructors.
+ *
+ */
+impl<'a, N: Scalar, R: Dim, C: Dim> RowMajorMatrixSliceMN<'a, N, R, C> {
+    /// Creates a matrix slice from an array containing its components in row-major order, and with dimensions specified by generic types instances.
+    ///
+    /// The row stride of the slice is `ncols` and its column stride is one, so the data is not copied and
+    /// the slice can be used like any other matrix, e.g., multiplied or copied into a column-major matrix.
+    /// Panics if the input data array does not contain enough elements.
+    /// The generic types `R` and `C` can either be type-level integers or integers wrapped with `Dynamic::new()`.
+    #[inline]
+    pub fn from_row_major_slice_generic(data: &'a [N], nrows: R, ncols: C) -> Self {
+        Self::from_slice_with_strides_generic(data, nrows, ncols, ncols, U1)
+    }
+}
+
+impl<'a, N: Scalar, R: Dim, C: Dim> RowMajorMatrixSliceMutMN<'a, N, R, C> {
+    /// Creates a mutable matrix slice from an array containing its components in row-major order, and with dimensions specified by generic types instances.
+    ///
+    /// Writing to the slice modifies the array in place, in row-major order.
+    /// Panics if the input data array does not contain enough elements.
+    /// The generic types `R` and `C` can either be type-level integers or integers wrapped with `Dynamic::new()`.
+    #[inline]
+    pub fn from_row_major_slice_generic(data: &'a mut [N], nrows: R, ncols: C) -> Self {
+        Self::from_slice_with_strides_generic(data, nrows, ncols, ncols, U1)
+    }
+}
+
+macro_rules! impl_row_major_constructors(
+    ($R: ty, $C: ty; $(=> $DimIdent: ident: $DimBound: ident),*; $($gargs: expr),*; $($args: ident),*) => {
+        impl<'a, N: Scalar, $($DimIdent: $DimBound),*> RowMajorMatrixSliceMN<'a, N, $R, $C> {
+            /// Creates a new matrix slice from the given data array containing its components in row-major order.
+            ///
+            /// Panics if `data` does not contain enough elements.
+            #[inline]
+            pub fn from_row_major_slice(data: &'a [N], $($args: usize),*) -> Self {
+                Self::from_row_major_slice_generic(data, $($gargs),*)
+            }
+        }
+
+        impl<'a, N: Scalar, $($DimIdent: $DimBound),*> RowMajorMatrixSliceMutMN<'a, N, $R, $C> {
+            /// Creates a new mutable matrix slice from the given data array containing its components in row-major order.
+            ///
+            /// Panics if `data` does not contain enough elements.
+            #[inline]
+            pub fn from_row_major_slice(data: &'a mut [N], $($args: usize),*) -> Self {
+                Self::from_row_major_slice_generic(data, $($gargs),*)
+            }
+        }
+    }
+);
+
+impl_row_major_constructors!(R, C;
+=> R: DimName, => C: DimName;
+R::name(), C::name();
+);
+
+impl_row_major_constructors!(R, Dynamic;
+                             => R: DimName;
+                             R::name(), Dynamic::new(ncols);
+                             ncols);
+
+impl_row_major_constructors!(Dynamic, C;
+                             => C: DimName;
+                             Dynamic::new(nrows), C::name();
+                             nrows);
+
+impl_row_major_constructors!(Dynamic, Dynamic;
+                             ;
+                             Dynamic::new(nrows), Dynamic::new(ncols);
+                             nrows, ncols);
@@ -4,7 +4,7 @@
 //! heap-allocated buffers for matrices with at least one dimension unknown at compile-time.
 
 use std::cmp;
-use std::mem;
+use std::mem::MaybeUninit;
 use std::ops::Mul;
 use std::ptr;
 
@@ -47,7 +47,7 @@ where
     #[inline]
     unsafe fn allocate_uninitialized(_: R, _: C) -> Self::Buffer {
         // TODO: Undefined behavior, see #556
-        mem::MaybeUninit::<Self::Buffer>::uninit().assume_init()
+        MaybeUninit::<Self::Buffer>::uninit().assume_init()
     }
 
     #[inline]
@@ -71,6 +71,25 @@ where
 
         res
     }
+
+    #[inline]
+    fn allocate_uninit(_: R, _: C) -> MaybeUninit<Self::Buffer> {
+        MaybeUninit::uninit()
+    }
+
+    #[inline]
+    fn uninit_ptr_mut(buffer: &mut MaybeUninit<Self::Buffer>) -> *mut N {
+        // `ArrayStorage` has the same layout as an array of `N`.
+        buffer.as_mut_ptr() as *mut N
+    }
+
+    #[inline]
+    unsafe fn assume_init(buffer: MaybeUninit<Self::Buffer>) -> Self::Buffer {
+        buffer.assume_init()
+    }
+
+    #[inline]
+    fn deallocate_uninit(_: MaybeUninit<Self::Buffer>) {}
 }
 
 // Dynamic - Static
@@ -102,6 +121,31 @@ impl<N: Scalar, C: Dim> Allocator<N, Dynamic, C> for DefaultAllocator {
 
         VecStorage::new(nrows, ncols, res)
     }
+
+    #[inline]
+    fn allocate_uninit(nrows: Dynamic, ncols: C) -> MaybeUninit<Self::Buffer> {
+        MaybeUninit::new(VecStorage::with_capacity_uninit(nrows, ncols))
+    }
+
+    #[inline]
+    fn uninit_ptr_mut(buffer: &mut MaybeUninit<Self::Buffer>) -> *mut N {
+        // The `Vec` itself is initialized, but empty.
+        unsafe { (*buffer.as_mut_ptr()).as_vec_mut().as_mut_ptr() }
+    }
+
+    #[inline]
+    unsafe fn assume_init(buffer: MaybeUninit<Self::Buffer>) -> Self::Buffer {
+        let mut res = buffer.assume_init();
+        let (nrows, ncols) = res.shape();
+        res.as_vec_mut().set_len(nrows.value() * ncols.value());
+        res
+    }
+
+    #[inline]
+    fn deallocate_uninit(buffer: MaybeUninit<Self::Buffer>) {
+        // The `Vec` is still empty, so this only frees its memory.
+        drop(unsafe { buffer.assume_init() })
+    }
 }
 
 // Static - Dynamic
@@ -132,6 +176,31 @@ impl<N: Scalar, R: DimName> Allocator<N, R, Dynamic> for DefaultAllocator {
 
         VecStorage::new(nrows, ncols, res)
     }
+
+    #[inline]
+    fn allocate_uninit(nrows: R, ncols: Dynamic) -> MaybeUninit<Self::Buffer> {
+        MaybeUninit::new(VecStorage::with_capacity_uninit(nrows, ncols))
+    }
+
+    #[inline]
+    fn uninit_ptr_mut(buffer: &mut MaybeUninit<Self::Buffer>) -> *mut N {
+        // The `Vec` itself is initialized, but empty.
+        unsafe { (*buffer.as_mut_ptr()).as_vec_mut().as_mut_ptr() }
+    }
+
+    #[inline]
+    unsafe fn assume_init(buffer: MaybeUninit<Self::Buffer>) -> Self::Buffer {
+        let mut res = buffer.assume_init();
+        let (nrows, ncols) = res.shape();
+        res.as_vec_mut().set_len(nrows.value() * ncols.value());
+        res
+    }
+
+    #[inline]
+    fn deallocate_uninit(buffer: MaybeUninit<Self::Buffer>) {
+        // The `Vec` is still empty, so this only frees its memory.
+        drop(unsafe { buffer.assume_init() })
+    }
 }
 
 /*
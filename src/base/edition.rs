@@ -15,7 +15,7 @@ use crate::base::dimension::{
 };
 use crate::base::storage::{ReshapableStorage, Storage, StorageMut};
 #[cfg(any(feature = "std", feature = "alloc"))]
-use crate::base::DMatrix;
+use crate::base::{DMatrix, DVector};
 use crate::base::{DefaultAllocator, Matrix, MatrixMN, RowVector, Scalar, Vector};
 
 impl<N: Scalar + Zero, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
@@ -573,6 +573,50 @@ impl<N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
         res
     }
 
+    /// Inserts the columns of `columns` starting at the `i-th` position.
+    ///
+    /// Panics if `columns` does not have the same number of rows as `self`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix2, Matrix2x4};
+    /// let m = Matrix2::new(1, 4, 5, 8);
+    /// let cols = Matrix2::new(2, 3, 6, 7);
+    /// assert_eq!(m.insert_columns_from(1, &cols), Matrix2x4::new(1, 2, 3, 4, 5, 6, 7, 8));
+    ///
+    /// let m = DMatrix::from_row_slice(2, 1, &[1, 2]);
+    /// let cols = DMatrix::from_row_slice(2, 2, &[3, 4, 5, 6]);
+    /// let expected = DMatrix::from_row_slice(2, 3, &[1, 3, 4, 2, 5, 6]);
+    /// assert_eq!(m.insert_columns_from(1, &cols), expected);
+    /// ```
+    #[inline]
+    pub fn insert_columns_from<R2, C2, S2>(
+        self,
+        i: usize,
+        columns: &Matrix<N, R2, C2, S2>,
+    ) -> MatrixMN<N, R, DimSum<C, C2>>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<N, R2, C2>,
+        C: DimAdd<C2>,
+        ShapeConstraint: SameNumberOfRows<R, R2>,
+        DefaultAllocator: Reallocator<N, R, C, R, DimSum<C, C2>>,
+    {
+        assert_eq!(
+            self.nrows(),
+            columns.nrows(),
+            "Column insertion: the inserted columns must have the same number of rows."
+        );
+        assert!(i <= self.ncols(), "Column insertion index out of range.");
+
+        let (nrows, ninsert) = (self.data.shape().0, columns.data.shape().1);
+        let mut res = unsafe { self.insert_columns_generic_uninitialized(i, ninsert) };
+        res.generic_slice_mut((0, i), (nrows, ninsert))
+            .copy_from(columns);
+        res
+    }
+
     /// Inserts `ninsert.value()` columns starting at the `i-th` place of this matrix.
     ///
     /// The added column values are not initialized.
@@ -651,6 +695,46 @@ impl<N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
         res
     }
 
+    /// Inserts the rows of `rows` starting at the `i-th` position.
+    ///
+    /// Panics if `rows` does not have the same number of columns as `self`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Matrix4x3};
+    /// let m = Matrix2x3::new(1, 2, 3, 10, 11, 12);
+    /// let rows = Matrix2x3::new(4, 5, 6, 7, 8, 9);
+    /// let expected = Matrix4x3::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+    /// assert_eq!(m.insert_rows_from(1, &rows), expected);
+    /// ```
+    #[inline]
+    pub fn insert_rows_from<R2, C2, S2>(
+        self,
+        i: usize,
+        rows: &Matrix<N, R2, C2, S2>,
+    ) -> MatrixMN<N, DimSum<R, R2>, C>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<N, R2, C2>,
+        R: DimAdd<R2>,
+        ShapeConstraint: SameNumberOfColumns<C, C2>,
+        DefaultAllocator: Reallocator<N, R, C, DimSum<R, R2>, C>,
+    {
+        assert_eq!(
+            self.ncols(),
+            rows.ncols(),
+            "Row insertion: the inserted rows must have the same number of columns."
+        );
+        assert!(i <= self.nrows(), "Row insertion index out of range.");
+
+        let (ninsert, ncols) = (rows.data.shape().0, self.data.shape().1);
+        let mut res = unsafe { self.insert_rows_generic_uninitialized(i, ninsert) };
+        res.generic_slice_mut((i, 0), (ninsert, ncols))
+            .copy_from(rows);
+        res
+    }
+
     /// Inserts `ninsert.value()` rows at the `i-th` place of this matrix.
     ///
     /// The added rows values are not initialized.
@@ -885,10 +969,50 @@ where
         let data = self.data.reshape_generic(new_nrows, new_ncols);
         Matrix::from_data(data)
     }
+
+    /// Flattens `self` into a column vector containing all its components in column-major
+    /// order.
+    ///
+    /// This is equivalent to `self.reshape_generic(Dynamic::new(self.len()), U1)`, so the
+    /// components of a dynamically-sized matrix are neither copied nor moved. The original shape
+    /// is recovered with `DMatrix::from_vector`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, DVector};
+    /// let m = DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]);
+    /// let v = m.clone().into_vector();
+    ///
+    /// assert_eq!(v, DVector::from_column_slice(&[1, 4, 2, 5, 3, 6]));
+    /// assert_eq!(DMatrix::from_vector(v, 2, 3), m);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn into_vector(self) -> Matrix<N, Dynamic, U1, S::Output>
+    where
+        S: ReshapableStorage<N, R, C, Dynamic, U1>,
+    {
+        let len = self.len();
+        self.reshape_generic(Dynamic::new(len), U1)
+    }
 }
 
 #[cfg(any(feature = "std", feature = "alloc"))]
 impl<N: Scalar> DMatrix<N> {
+    /// Reinterprets the column vector `vector` as a matrix with `nrows` rows and `ncols`
+    /// columns, filled in column-major order.
+    ///
+    /// The components of `vector` are neither copied nor moved. This is the inverse of
+    /// `.into_vector()`, and panics if `vector.len() != nrows * ncols`.
+    pub fn from_vector(vector: DVector<N>, nrows: usize, ncols: usize) -> Self {
+        assert_eq!(
+            vector.len(),
+            nrows * ncols,
+            "Reshape: the vector length must be equal to the number of components of the matrix."
+        );
+
+        vector.reshape_generic(Dynamic::new(nrows), Dynamic::new(ncols))
+    }
+
     /// Resizes this matrix in-place.
     ///
     /// The values are copied such that `self[(i, j)] == result[(i, j)]`. If the result has more
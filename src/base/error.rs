@@ -0,0 +1,85 @@
+use std::fmt;
+
+/// The error returned by the checked constructors of matrices, e.g., `try_from_row_slice`, when
+/// the number of given components does not match the dimensions of the matrix.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ElementCountMismatch {
+    /// The number of components required by the dimensions of the matrix.
+    pub expected: usize,
+    /// The number of components that were given.
+    pub found: usize,
+}
+
+impl fmt::Display for ElementCountMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "expected {} matrix components, found {}",
+            self.expected, self.found
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ElementCountMismatch {}
+
+/// The error returned when parsing a matrix from a string fails.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParseMatrixError {
+    /// The component at the given zero-based position could not be parsed.
+    InvalidComponent {
+        /// The row of the component.
+        row: usize,
+        /// The column of the component.
+        column: usize,
+        /// The text of the component.
+        text: String,
+    },
+    /// A row does not have the same number of components as the first row.
+    RaggedRow {
+        /// The zero-based index of the row.
+        row: usize,
+        /// The number of components of the first row.
+        expected: usize,
+        /// The number of components of this row.
+        found: usize,
+    },
+    /// The parsed matrix does not have the dimensions required by the matrix type.
+    ShapeMismatch {
+        /// The number of rows of the parsed matrix.
+        nrows: usize,
+        /// The number of columns of the parsed matrix.
+        ncols: usize,
+    },
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for ParseMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseMatrixError::InvalidComponent { row, column, text } => write!(
+                f,
+                "invalid matrix component `{}` at position ({}, {})",
+                text, row, column
+            ),
+            ParseMatrixError::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} has {} components, but the first row has {}",
+                row, found, expected
+            ),
+            ParseMatrixError::ShapeMismatch { nrows, ncols } => write!(
+                f,
+                "the parsed {}x{} matrix does not have the dimensions of the matrix type",
+                nrows, ncols
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseMatrixError {}
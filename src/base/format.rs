@@ -0,0 +1,161 @@
+//! Configurable textual representations of matrices.
+
+use std::fmt;
+
+use crate::base::dimension::Dim;
+use crate::base::storage::Storage;
+use crate::base::{Matrix, Scalar};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Style {
+    Aligned,
+    Latex,
+    Csv,
+}
+
+/// A formatting adaptor of a matrix, created by `Matrix::format`.
+///
+/// This implements `Display` and writes one line per row of the matrix, in the style selected by
+/// `aligned` (the default), `latex` or `csv`. The components are formatted with their `Display`
+/// implementation, using the precision given by `precision` if any, or else the precision of the
+/// format string, e.g., `"{:.2}"`.
+///
+/// # Example
+/// ```
+/// # use nalgebra::Matrix2x3;
+/// let m = Matrix2x3::new(1.0, -2.5, 3.0, 40.0, 5.0, 0.125);
+///
+/// assert_eq!(
+///     m.format().precision(2).to_string(),
+///     " 1.00  -2.50  3.00\n40.00   5.00  0.12\n"
+/// );
+/// assert_eq!(
+///     m.format().latex().to_string(),
+///     "\\begin{pmatrix}\n  1 & -2.5 & 3 \\\\\n  40 & 5 & 0.125\n\\end{pmatrix}\n"
+/// );
+/// assert_eq!(m.format().csv().to_string(), "1,-2.5,3\n40,5,0.125\n");
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct MatrixFormat<'a, N: Scalar, R: Dim, C: Dim, S> {
+    matrix: &'a Matrix<N, R, C, S>,
+    precision: Option<usize>,
+    style: Style,
+}
+
+impl<N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
+    /// A formatting adaptor of this matrix, see `MatrixFormat` for details.
+    #[inline]
+    pub fn format(&self) -> MatrixFormat<'_, N, R, C, S> {
+        MatrixFormat {
+            matrix: self,
+            precision: None,
+            style: Style::Aligned,
+        }
+    }
+}
+
+impl<'a, N: Scalar, R: Dim, C: Dim, S> MatrixFormat<'a, N, R, C, S> {
+    /// Formats the components with `precision` digits after the decimal point.
+    #[inline]
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Formats the matrix as plain text, with its columns right-aligned and separated by two
+    /// spaces.
+    ///
+    /// Unlike the default `Display` output of matrices, each column is as wide as its widest
+    /// component only.
+    #[inline]
+    pub fn aligned(mut self) -> Self {
+        self.style = Style::Aligned;
+        self
+    }
+
+    /// Formats the matrix as a LaTeX `pmatrix` environment.
+    #[inline]
+    pub fn latex(mut self) -> Self {
+        self.style = Style::Latex;
+        self
+    }
+
+    /// Formats the matrix as comma-separated values, with one record per row.
+    #[inline]
+    pub fn csv(mut self) -> Self {
+        self.style = Style::Csv;
+        self
+    }
+}
+
+impl<'a, N, R: Dim, C: Dim, S> fmt::Display for MatrixFormat<'a, N, R, C, S>
+where
+    N: Scalar + fmt::Display,
+    S: Storage<N, R, C>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (nrows, ncols) = self.matrix.shape();
+        let precision = self.precision.or_else(|| f.precision());
+        let entries: Vec<String> = self
+            .matrix
+            .iter()
+            .map(|e| match precision {
+                Some(precision) => format!("{:.1$}", e, precision),
+                None => format!("{}", e),
+            })
+            .collect();
+        let entry = |i: usize, j: usize| &entries[i + j * nrows];
+
+        match self.style {
+            Style::Aligned => {
+                let widths: Vec<usize> = (0..ncols)
+                    .map(|j| {
+                        (0..nrows)
+                            .map(|i| entry(i, j).chars().count())
+                            .max()
+                            .unwrap_or(0)
+                    })
+                    .collect();
+
+                for i in 0..nrows {
+                    for (j, width) in widths.iter().enumerate() {
+                        let sep = if j == 0 { "" } else { "  " };
+                        write!(f, "{}{:>2$}", sep, entry(i, j), width)?;
+                    }
+                    writeln!(f)?;
+                }
+
+                Ok(())
+            }
+            Style::Latex => {
+                writeln!(f, "\\begin{{pmatrix}}")?;
+
+                for i in 0..nrows {
+                    write!(f, " ")?;
+                    for j in 0..ncols {
+                        let sep = if j == 0 { "" } else { " &" };
+                        write!(f, "{} {}", sep, entry(i, j))?;
+                    }
+
+                    if i + 1 < nrows {
+                        write!(f, " \\\\")?;
+                    }
+                    writeln!(f)?;
+                }
+
+                writeln!(f, "\\end{{pmatrix}}")
+            }
+            Style::Csv => {
+                for i in 0..nrows {
+                    for j in 0..ncols {
+                        let sep = if j == 0 { "" } else { "," };
+                        write!(f, "{}{}", sep, entry(i, j))?;
+                    }
+                    writeln!(f)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//! Classical test matrices with known properties, for testing and benchmarking numerical
+//! algorithms.
+//!
+//! All the matrices are dynamically-sized. The random matrices require the `std` feature.
+
+use num::One;
+#[cfg(feature = "std")]
+use rand::Rng;
+#[cfg(feature = "std")]
+use rand_distr::{Distribution, StandardNormal};
+use simba::scalar::{ClosedAdd, ClosedMul, RealField};
+
+use crate::base::dimension::Dim;
+use crate::base::storage::Storage;
+#[cfg(feature = "std")]
+use crate::base::DVector;
+use crate::base::{DMatrix, Scalar, Vector};
+
+/// The `n × n` Hilbert matrix, with components `1 / (i + j + 1)`.
+///
+/// This symmetric definite-positive matrix is notoriously ill-conditioned: its condition number
+/// grows like `e^(3.5 n)`, e.g., it is about `1.5e7` for `n = 6`.
+///
+/// # Example
+/// ```
+/// # use nalgebra::gallery;
+/// let h = gallery::hilbert::<f64>(3);
+/// assert_eq!(h[(1, 2)], 1.0 / 4.0);
+/// ```
+pub fn hilbert<N: RealField>(n: usize) -> DMatrix<N> {
+    DMatrix::from_fn(n, n, |i, j| {
+        N::one() / crate::convert::<f64, N>((i + j + 1) as f64)
+    })
+}
+
+/// The Vandermonde matrix of the given points, with `ncols` columns.
+///
+/// The component `(i, j)` is `points[i]^j`, so multiplying this matrix by the coefficients of a
+/// polynomial of degree `ncols - 1` evaluates it at each point. It is invertible if it is square
+/// and all the points are distinct, but ill-conditioned for even moderate numbers of points.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{gallery, DMatrix, Vector3};
+/// let v = gallery::vandermonde(&Vector3::new(1, 2, 3), 3);
+/// assert_eq!(v, DMatrix::from_row_slice(3, 3, &[1, 1, 1, 1, 2, 4, 1, 3, 9]));
+/// ```
+pub fn vandermonde<N, D, S>(points: &Vector<N, D, S>, ncols: usize) -> DMatrix<N>
+where
+    N: Scalar + One + ClosedMul,
+    D: Dim,
+    S: Storage<N, D>,
+{
+    let mut res = DMatrix::from_element(points.len(), ncols, N::one());
+
+    for j in 1..ncols {
+        for i in 0..points.len() {
+            res[(i, j)] = res[(i, j - 1)].inlined_clone() * points[i].inlined_clone();
+        }
+    }
+
+    res
+}
+
+/// The `n × n` symmetric Pascal matrix, with components `(i + j)! / (i! j!)`.
+///
+/// This symmetric definite-positive matrix has a determinant equal to one and an inverse with
+/// integer components as well. Its eigenvalues come in reciprocal pairs, so it is
+/// ill-conditioned.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{gallery, DMatrix};
+/// let p = gallery::pascal::<u64>(4);
+/// assert_eq!(p, DMatrix::from_row_slice(4, 4, &[1, 1,  1,  1,
+///                                               1, 2,  3,  4,
+///                                               1, 3,  6, 10,
+///                                               1, 4, 10, 20]));
+/// ```
+pub fn pascal<N: Scalar + One + ClosedAdd>(n: usize) -> DMatrix<N> {
+    let mut res = DMatrix::from_element(n, n, N::one());
+
+    for i in 1..n {
+        for j in 1..n {
+            res[(i, j)] = res[(i - 1, j)].inlined_clone() + res[(i, j - 1)].inlined_clone();
+        }
+    }
+
+    res
+}
+
+/// The `n × n` Wilkinson matrix `W⁺`.
+///
+/// This symmetric tridiagonal matrix has ones on its sub- and super-diagonals, and
+/// `|(n - 1) / 2 - i|` on its diagonal. For odd `n`, most of its eigenvalues come in pairs that are
+/// extremely close but distinct, e.g., the two largest eigenvalues of `W⁺` for `n = 21` agree to
+/// about 14 significant digits, which is a classical test of symmetric eigensolvers.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{gallery, DMatrix};
+/// let w = gallery::wilkinson::<f64>(3);
+/// assert_eq!(w, DMatrix::from_row_slice(3, 3, &[1.0, 1.0, 0.0,
+///                                               1.0, 0.0, 1.0,
+///                                               0.0, 1.0, 1.0]));
+/// ```
+pub fn wilkinson<N: RealField>(n: usize) -> DMatrix<N> {
+    let half: N = crate::convert((n as f64 - 1.0) / 2.0);
+
+    DMatrix::from_fn(n, n, |i, j| {
+        if i == j {
+            (half - crate::convert(i as f64)).abs()
+        } else if i + 1 == j || j + 1 == i {
+            N::one()
+        } else {
+            N::zero()
+        }
+    })
+}
+
+/// The `n × n` Frank matrix.
+///
+/// This upper-Hessenberg matrix has the components `n - max(i, j)` on and above its sub-diagonal,
+/// and zero below. Its determinant is one, and its eigenvalues are real and positive, but its
+/// smallest eigenvalues are extremely sensitive to perturbations of the matrix, which makes it a
+/// classical test of nonsymmetric eigensolvers.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{gallery, DMatrix};
+/// let f = gallery::frank::<f64>(3);
+/// assert_eq!(f, DMatrix::from_row_slice(3, 3, &[3.0, 2.0, 1.0,
+///                                               2.0, 2.0, 1.0,
+///                                               0.0, 1.0, 1.0]));
+/// ```
+pub fn frank<N: RealField>(n: usize) -> DMatrix<N> {
+    DMatrix::from_fn(n, n, |i, j| {
+        if j + 1 >= i {
+            crate::convert((n - i.max(j)) as f64)
+        } else {
+            N::zero()
+        }
+    })
+}
+
+/// A random `n × n` orthogonal matrix, uniformly distributed with respect to the Haar measure.
+///
+/// This is computed from the QR decomposition of a matrix with independent normally distributed
+/// components, where the signs of the columns of `Q` are chosen so that the diagonal of `R` is
+/// positive.
+#[cfg(feature = "std")]
+pub fn random_orthogonal<N, G>(n: usize, rng: &mut G) -> DMatrix<N>
+where
+    N: RealField,
+    G: Rng + ?Sized,
+    StandardNormal: Distribution<N>,
+{
+    let qr = DMatrix::from_distribution(n, n, &StandardNormal, rng).qr();
+    let r = qr.r();
+    let mut q = qr.q();
+
+    for j in 0..n {
+        if r[(j, j)] < N::zero() {
+            q.column_mut(j).neg_mut();
+        }
+    }
+
+    q
+}
+
+/// A random `n × n` rotation matrix, i.e., an orthogonal matrix with a determinant equal to one,
+/// uniformly distributed with respect to the Haar measure.
+///
+/// This works in any dimension. For statically-sized rotations in 2 or 3 dimensions, sampling
+/// the `Standard` distribution for `Rotation2` or `Rotation3` is faster.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::gallery;
+/// let rot = gallery::random_rotation::<f64, _>(6, &mut rand::thread_rng());
+///
+/// assert!(rot.is_orthogonal(1.0e-7));
+/// assert_relative_eq!(rot.determinant(), 1.0, epsilon = 1.0e-7);
+/// ```
+#[cfg(feature = "std")]
+pub fn random_rotation<N, G>(n: usize, rng: &mut G) -> DMatrix<N>
+where
+    N: RealField,
+    G: Rng + ?Sized,
+    StandardNormal: Distribution<N>,
+{
+    let mut res = random_orthogonal(n, rng);
+
+    // Flipping a column is a bijection between the orthogonal matrices with a determinant
+    // equal to `-1` and the rotations that preserves the Haar measure.
+    if n > 0 && res.determinant() < N::zero() {
+        res.column_mut(0).neg_mut();
+    }
+
+    res
+}
+
+/// A random `n × n` symmetric definite-positive matrix with the spectral condition number `cond`.
+///
+/// The eigenvalues are geometrically spaced between `1` and `1 / cond`, and the eigenvectors are
+/// the columns of a random orthogonal matrix computed by `random_orthogonal`.
+///
+/// # Panics
+/// Panics if `cond` is smaller than one.
+#[cfg(feature = "std")]
+pub fn random_spd<N, G>(n: usize, cond: N, rng: &mut G) -> DMatrix<N>
+where
+    N: RealField,
+    G: Rng + ?Sized,
+    StandardNormal: Distribution<N>,
+{
+    assert!(
+        cond >= N::one(),
+        "random_spd: the condition number must be greater or equal to one."
+    );
+
+    let q = random_orthogonal(n, rng);
+    let eigenvalues = DVector::from_fn(n, |i, _| {
+        if n == 1 {
+            N::one()
+        } else {
+            cond.powf(-crate::convert::<f64, N>(i as f64 / (n - 1) as f64))
+        }
+    });
+
+    let mut res = &q * DMatrix::from_diagonal(&eigenvalues) * q.transpose();
+
+    // Enforce the exact symmetry of the result.
+    for j in 0..n {
+        for i in j + 1..n {
+            res[(j, i)] = res[(i, j)];
+        }
+    }
+
+    res
+}
+
+/// A random `n × n` correlation matrix, i.e., a symmetric positive semi-definite matrix with a
+/// unit diagonal.
+///
+/// This is the Gram matrix of `n` random unit vectors distributed uniformly on the unit sphere of
+/// dimension `n`, so it is definite-positive with probability one.
+#[cfg(feature = "std")]
+pub fn random_correlation<N, G>(n: usize, rng: &mut G) -> DMatrix<N>
+where
+    N: RealField,
+    G: Rng + ?Sized,
+    StandardNormal: Distribution<N>,
+{
+    let mut vectors = DMatrix::from_distribution(n, n, &StandardNormal, rng);
+
+    for mut col in vectors.column_iter_mut() {
+        let _ = col.normalize_mut();
+    }
+
+    let mut res = vectors.tr_mul(&vectors);
+
+    // Enforce the exact symmetry and the unit diagonal of the result.
+    for j in 0..n {
+        res[(j, j)] = N::one();
+
+        for i in j + 1..n {
+            res[(j, i)] = res[(i, j)];
+        }
+    }
+
+    res
+}
@@ -3,7 +3,7 @@
 use std::marker::PhantomData;
 use std::mem;
 
-use crate::base::dimension::{Dim, U1};
+use crate::base::dimension::{Dim, Dynamic, U1};
 use crate::base::storage::{Storage, StorageMut};
 use crate::base::{Matrix, MatrixSlice, MatrixSliceMut, Scalar};
 
@@ -135,11 +135,16 @@ iterator!(struct MatrixIterMut for StorageMut.ptr_mut -> *mut N, &'a mut N, &'a
 pub struct RowIter<'a, N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> {
     mat: &'a Matrix<N, R, C, S>,
     curr: usize,
+    end: usize,
 }
 
 impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> RowIter<'a, N, R, C, S> {
     pub(crate) fn new(mat: &'a Matrix<N, R, C, S>) -> Self {
-        RowIter { mat, curr: 0 }
+        RowIter {
+            mat,
+            curr: 0,
+            end: mat.nrows(),
+        }
     }
 }
 
@@ -148,7 +153,7 @@ impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> Iterator for RowIt
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.curr < self.mat.nrows() {
+        if self.curr < self.end {
             let res = self.mat.row(self.curr);
             self.curr += 1;
             Some(res)
@@ -159,15 +164,26 @@ impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> Iterator for RowIt
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (
-            self.mat.nrows() - self.curr,
-            Some(self.mat.nrows() - self.curr),
-        )
+        (self.end - self.curr, Some(self.end - self.curr))
     }
 
     #[inline]
     fn count(self) -> usize {
-        self.mat.nrows() - self.curr
+        self.end - self.curr
+    }
+}
+
+impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> DoubleEndedIterator
+    for RowIter<'a, N, R, C, S>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.curr < self.end {
+            self.end -= 1;
+            Some(self.mat.row(self.end))
+        } else {
+            None
+        }
     }
 }
 
@@ -176,7 +192,7 @@ impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> ExactSizeIterator
 {
     #[inline]
     fn len(&self) -> usize {
-        self.mat.nrows() - self.curr
+        self.end - self.curr
     }
 }
 
@@ -184,21 +200,19 @@ impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> ExactSizeIterator
 pub struct RowIterMut<'a, N: Scalar, R: Dim, C: Dim, S: StorageMut<N, R, C>> {
     mat: *mut Matrix<N, R, C, S>,
     curr: usize,
+    end: usize,
     phantom: PhantomData<&'a mut Matrix<N, R, C, S>>,
 }
 
 impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<N, R, C>> RowIterMut<'a, N, R, C, S> {
     pub(crate) fn new(mat: &'a mut Matrix<N, R, C, S>) -> Self {
         RowIterMut {
+            end: mat.nrows(),
             mat,
             curr: 0,
             phantom: PhantomData,
         }
     }
-
-    fn nrows(&self) -> usize {
-        unsafe { (*self.mat).nrows() }
-    }
 }
 
 impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<N, R, C>> Iterator
@@ -208,7 +222,8 @@ impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<N, R, C>> Iterator
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.curr < self.nrows() {
+        if self.curr < self.end {
+            // The row `curr` has not been yielded yet, so this slice aliases no other.
             let res = unsafe { (*self.mat).row_mut(self.curr) };
             self.curr += 1;
             Some(res)
@@ -219,12 +234,26 @@ impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<N, R, C>> Iterator
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.nrows() - self.curr, Some(self.nrows() - self.curr))
+        (self.end - self.curr, Some(self.end - self.curr))
     }
 
     #[inline]
     fn count(self) -> usize {
-        self.nrows() - self.curr
+        self.end - self.curr
+    }
+}
+
+impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<N, R, C>> DoubleEndedIterator
+    for RowIterMut<'a, N, R, C, S>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.curr < self.end {
+            self.end -= 1;
+            Some(unsafe { (*self.mat).row_mut(self.end) })
+        } else {
+            None
+        }
     }
 }
 
@@ -233,7 +262,7 @@ impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<N, R, C>> ExactSizeIterat
 {
     #[inline]
     fn len(&self) -> usize {
-        self.nrows() - self.curr
+        self.end - self.curr
     }
 }
 
@@ -247,11 +276,16 @@ impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<N, R, C>> ExactSizeIterat
 pub struct ColumnIter<'a, N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> {
     mat: &'a Matrix<N, R, C, S>,
     curr: usize,
+    end: usize,
 }
 
 impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> ColumnIter<'a, N, R, C, S> {
     pub(crate) fn new(mat: &'a Matrix<N, R, C, S>) -> Self {
-        ColumnIter { mat, curr: 0 }
+        ColumnIter {
+            mat,
+            curr: 0,
+            end: mat.ncols(),
+        }
     }
 }
 
@@ -262,7 +296,7 @@ impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> Iterator
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.curr < self.mat.ncols() {
+        if self.curr < self.end {
             let res = self.mat.column(self.curr);
             self.curr += 1;
             Some(res)
@@ -273,15 +307,26 @@ impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> Iterator
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (
-            self.mat.ncols() - self.curr,
-            Some(self.mat.ncols() - self.curr),
-        )
+        (self.end - self.curr, Some(self.end - self.curr))
     }
 
     #[inline]
     fn count(self) -> usize {
-        self.mat.ncols() - self.curr
+        self.end - self.curr
+    }
+}
+
+impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> DoubleEndedIterator
+    for ColumnIter<'a, N, R, C, S>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.curr < self.end {
+            self.end -= 1;
+            Some(self.mat.column(self.end))
+        } else {
+            None
+        }
     }
 }
 
@@ -290,7 +335,7 @@ impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> ExactSizeIterator
 {
     #[inline]
     fn len(&self) -> usize {
-        self.mat.ncols() - self.curr
+        self.end - self.curr
     }
 }
 
@@ -298,21 +343,19 @@ impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> ExactSizeIterator
 pub struct ColumnIterMut<'a, N: Scalar, R: Dim, C: Dim, S: StorageMut<N, R, C>> {
     mat: *mut Matrix<N, R, C, S>,
     curr: usize,
+    end: usize,
     phantom: PhantomData<&'a mut Matrix<N, R, C, S>>,
 }
 
 impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<N, R, C>> ColumnIterMut<'a, N, R, C, S> {
     pub(crate) fn new(mat: &'a mut Matrix<N, R, C, S>) -> Self {
         ColumnIterMut {
+            end: mat.ncols(),
             mat,
             curr: 0,
             phantom: PhantomData,
         }
     }
-
-    fn ncols(&self) -> usize {
-        unsafe { (*self.mat).ncols() }
-    }
 }
 
 impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<N, R, C>> Iterator
@@ -322,7 +365,8 @@ impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<N, R, C>> Iterator
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.curr < self.ncols() {
+        if self.curr < self.end {
+            // The column `curr` has not been yielded yet, so this slice aliases no other.
             let res = unsafe { (*self.mat).column_mut(self.curr) };
             self.curr += 1;
             Some(res)
@@ -333,12 +377,26 @@ impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<N, R, C>> Iterator
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.ncols() - self.curr, Some(self.ncols() - self.curr))
+        (self.end - self.curr, Some(self.end - self.curr))
     }
 
     #[inline]
     fn count(self) -> usize {
-        self.ncols() - self.curr
+        self.end - self.curr
+    }
+}
+
+impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<N, R, C>> DoubleEndedIterator
+    for ColumnIterMut<'a, N, R, C, S>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.curr < self.end {
+            self.end -= 1;
+            Some(unsafe { (*self.mat).column_mut(self.end) })
+        } else {
+            None
+        }
     }
 }
 
@@ -347,6 +405,167 @@ impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + StorageMut<N, R, C>> ExactSizeIterat
 {
     #[inline]
     fn len(&self) -> usize {
-        self.ncols() - self.curr
+        self.end - self.curr
+    }
+}
+
+/*
+ *
+ * Column blocks iterators.
+ *
+ */
+#[derive(Clone)]
+/// An iterator through non-overlapping blocks of consecutive columns of a matrix.
+///
+/// This is created by `Matrix::columns_chunks`.
+pub struct ColumnsChunks<'a, N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> {
+    mat: &'a Matrix<N, R, C, S>,
+    chunk_size: usize,
+    curr: usize,
+    end: usize,
+}
+
+impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> ColumnsChunks<'a, N, R, C, S> {
+    pub(crate) fn new(mat: &'a Matrix<N, R, C, S>, chunk_size: usize) -> Self {
+        assert!(chunk_size != 0, "The chunk size must be non-zero.");
+        let nchunks = (mat.ncols() + chunk_size - 1) / chunk_size;
+
+        ColumnsChunks {
+            mat,
+            chunk_size,
+            curr: 0,
+            end: nchunks,
+        }
+    }
+
+    fn chunk(&self, i: usize) -> MatrixSlice<'a, N, R, Dynamic, S::RStride, S::CStride> {
+        let first_col = i * self.chunk_size;
+        let ncols = self.chunk_size.min(self.mat.ncols() - first_col);
+        self.mat.columns(first_col, ncols)
+    }
+}
+
+impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> Iterator
+    for ColumnsChunks<'a, N, R, C, S>
+{
+    type Item = MatrixSlice<'a, N, R, Dynamic, S::RStride, S::CStride>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.curr < self.end {
+            let res = self.chunk(self.curr);
+            self.curr += 1;
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.end - self.curr, Some(self.end - self.curr))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.end - self.curr
+    }
+}
+
+impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> DoubleEndedIterator
+    for ColumnsChunks<'a, N, R, C, S>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.curr < self.end {
+            self.end -= 1;
+            Some(self.chunk(self.end))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> ExactSizeIterator
+    for ColumnsChunks<'a, N, R, C, S>
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.end - self.curr
+    }
+}
+
+#[derive(Clone)]
+/// An iterator through all the overlapping blocks of consecutive columns of a matrix.
+///
+/// This is created by `Matrix::columns_windows`.
+pub struct ColumnsWindows<'a, N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> {
+    mat: &'a Matrix<N, R, C, S>,
+    window_size: usize,
+    curr: usize,
+    end: usize,
+}
+
+impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> ColumnsWindows<'a, N, R, C, S> {
+    pub(crate) fn new(mat: &'a Matrix<N, R, C, S>, window_size: usize) -> Self {
+        assert!(window_size != 0, "The window size must be non-zero.");
+        let nwindows = (mat.ncols() + 1).saturating_sub(window_size);
+
+        ColumnsWindows {
+            mat,
+            window_size,
+            curr: 0,
+            end: nwindows,
+        }
+    }
+}
+
+impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> Iterator
+    for ColumnsWindows<'a, N, R, C, S>
+{
+    type Item = MatrixSlice<'a, N, R, Dynamic, S::RStride, S::CStride>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.curr < self.end {
+            let res = self.mat.columns(self.curr, self.window_size);
+            self.curr += 1;
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.end - self.curr, Some(self.end - self.curr))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.end - self.curr
+    }
+}
+
+impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> DoubleEndedIterator
+    for ColumnsWindows<'a, N, R, C, S>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.curr < self.end {
+            self.end -= 1;
+            Some(self.mat.columns(self.end, self.window_size))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, N: Scalar, R: Dim, C: Dim, S: 'a + Storage<N, R, C>> ExactSizeIterator
+    for ColumnsWindows<'a, N, R, C, S>
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.end - self.curr
     }
 }
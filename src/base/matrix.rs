@@ -23,7 +23,8 @@ use crate::base::allocator::{Allocator, SameShapeAllocator, SameShapeC, SameShap
 use crate::base::constraint::{DimEq, SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
 use crate::base::dimension::{Dim, DimAdd, DimSum, IsNotStaticOne, U1, U2, U3};
 use crate::base::iter::{
-    ColumnIter, ColumnIterMut, MatrixIter, MatrixIterMut, RowIter, RowIterMut,
+    ColumnIter, ColumnIterMut, ColumnsChunks, ColumnsWindows, MatrixIter, MatrixIterMut, RowIter,
+    RowIterMut,
 };
 use crate::base::storage::{
     ContiguousStorage, ContiguousStorageMut, Owned, SameShapeStorage, Storage, StorageMut,
@@ -189,7 +190,7 @@ impl<N: Scalar, R: Dim, C: Dim, S> Matrix<N, R, C, S> {
     /// Creates a new matrix with the given data without statically checking that the matrix
     /// dimension matches the storage dimension.
     #[inline]
-    pub unsafe fn from_data_statically_unchecked(data: S) -> Matrix<N, R, C, S> {
+    pub const unsafe fn from_data_statically_unchecked(data: S) -> Matrix<N, R, C, S> {
         Matrix {
             data,
             _phantoms: PhantomData,
@@ -326,6 +327,55 @@ impl<N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
         ColumnIter::new(self)
     }
 
+    /// Iterate through non-overlapping blocks of `chunk_size` consecutive columns of this matrix.
+    ///
+    /// The last block has fewer columns if `chunk_size` does not divide the number of columns of
+    /// this matrix. Each block is a slice, so this does not copy any component.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix2x5};
+    /// let a = Matrix2x5::new(1, 2, 3, 4, 5,
+    ///                        6, 7, 8, 9, 10);
+    /// let chunks: Vec<_> = a.columns_chunks(2).collect();
+    ///
+    /// assert_eq!(chunks.len(), 3);
+    /// assert_eq!(chunks[1], a.columns(2, 2));
+    /// assert_eq!(chunks[2], DMatrix::from_row_slice(2, 1, &[5, 10]));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is zero.
+    #[inline]
+    pub fn columns_chunks(&self, chunk_size: usize) -> ColumnsChunks<N, R, C, S> {
+        ColumnsChunks::new(self, chunk_size)
+    }
+
+    /// Iterate through all the overlapping blocks of `window_size` consecutive columns of this
+    /// matrix.
+    ///
+    /// The `i`-th block contains the columns `i` to `i + window_size - 1`. There is no block if
+    /// `window_size` is greater than the number of columns of this matrix. Each block is a slice,
+    /// so this does not copy any component.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x4;
+    /// let a = Matrix2x4::new(1, 2, 3, 4,
+    ///                        5, 6, 7, 8);
+    /// let sums: Vec<_> = a.columns_windows(3).map(|w| w.sum()).collect();
+    ///
+    /// assert_eq!(sums, vec![1 + 2 + 3 + 5 + 6 + 7, 2 + 3 + 4 + 6 + 7 + 8]);
+    /// assert_eq!(a.columns_windows(5).count(), 0);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `window_size` is zero.
+    #[inline]
+    pub fn columns_windows(&self, window_size: usize) -> ColumnsWindows<N, R, C, S> {
+        ColumnsWindows::new(self, window_size)
+    }
+
     /// Computes the row and column coordinates of the i-th element of this matrix seen as a
     /// vector.
     ///
@@ -952,7 +1002,7 @@ impl<N: Scalar, R: Dim, C: Dim, S: StorageMut<N, R, C>> Matrix<N, R, C, S> {
         C3: Dim,
         S3: Storage<N3, R3, C3>,
         ShapeConstraint: SameNumberOfRows<R, R2> + SameNumberOfColumns<C, C2>,
-        ShapeConstraint: SameNumberOfRows<R, R2> + SameNumberOfColumns<C, C2>,
+        ShapeConstraint: SameNumberOfRows<R, R3> + SameNumberOfColumns<C, C3>,
     {
         let (nrows, ncols) = self.shape();
 
@@ -978,6 +1028,62 @@ impl<N: Scalar, R: Dim, C: Dim, S: StorageMut<N, R, C>> Matrix<N, R, C, S> {
             }
         }
     }
+
+    /// Returns `self` with each of its components replaced by the result of a closure `f`
+    /// applied on its components joined with the components from `rhs`.
+    #[inline]
+    pub fn zip_apply_into<N2, R2, C2, S2>(
+        mut self,
+        rhs: &Matrix<N2, R2, C2, S2>,
+        f: impl FnMut(N, N2) -> N,
+    ) -> Self
+    where
+        N2: Scalar,
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<N2, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R, R2> + SameNumberOfColumns<C, C2>,
+    {
+        self.zip_apply(rhs, f);
+        self
+    }
+
+    /// Returns `self` with each of its components replaced by the result of a closure `f`
+    /// applied on its components joined with the components from `b` and `c`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let x = Vector3::new(1.0, 2.0, 3.0);
+    /// let y = Vector3::new(4.0, 5.0, 6.0);
+    /// let a = Vector3::new(1.0, 0.5, 2.0);
+    ///
+    /// // The fused component-wise kernel `a * x + y`, without any intermediate allocation.
+    /// let res = a.zip_zip_apply_into(&x, &y, |a, x, y| a * x + y);
+    /// assert_eq!(res, Vector3::new(5.0, 6.0, 12.0));
+    /// ```
+    #[inline]
+    pub fn zip_zip_apply_into<N2, R2, C2, S2, N3, R3, C3, S3>(
+        mut self,
+        b: &Matrix<N2, R2, C2, S2>,
+        c: &Matrix<N3, R3, C3, S3>,
+        f: impl FnMut(N, N2, N3) -> N,
+    ) -> Self
+    where
+        N2: Scalar,
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<N2, R2, C2>,
+        N3: Scalar,
+        R3: Dim,
+        C3: Dim,
+        S3: Storage<N3, R3, C3>,
+        ShapeConstraint: SameNumberOfRows<R, R2> + SameNumberOfColumns<C, C2>,
+        ShapeConstraint: SameNumberOfRows<R, R3> + SameNumberOfColumns<C, C3>,
+    {
+        self.zip_zip_apply(b, c, f);
+        self
+    }
 }
 
 impl<N: Scalar, D: Dim, S: Storage<N, D>> Vector<N, D, S> {
@@ -1750,6 +1856,35 @@ where
     }
 }
 
+impl<N: Scalar + Field, S: Storage<N, U3, U3>> SquareMatrix<N, U3, S>
+where
+    DefaultAllocator: Allocator<N, U3>,
+{
+    /// Computes the vector `v` such that `v.cross_matrix()` is the skew-symmetric part of this
+    /// matrix.
+    ///
+    /// This is the inverse of `.cross_matrix()`, i.e., `v.cross_matrix().vee() == v`. If this
+    /// matrix is not exactly skew-symmetric, e.g., because of rounding errors, its symmetric part
+    /// is ignored.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v.cross_matrix().vee(), v);
+    /// ```
+    #[inline]
+    pub fn vee(&self) -> VectorN<N, U3> {
+        let half = N::one() / (N::one() + N::one());
+
+        VectorN::<N, U3>::new(
+            (self[(2, 1)].inlined_clone() - self[(1, 2)].inlined_clone()) * half.inlined_clone(),
+            (self[(0, 2)].inlined_clone() - self[(2, 0)].inlined_clone()) * half.inlined_clone(),
+            (self[(1, 0)].inlined_clone() - self[(0, 1)].inlined_clone()) * half,
+        )
+    }
+}
+
 impl<N: SimdComplexField, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
     /// The smallest angle between two vectors.
     #[inline]
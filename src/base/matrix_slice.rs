@@ -254,6 +254,7 @@ macro_rules! matrix_slice_impl(
      $row_part: ident,
      $rows: ident,
      $rows_with_step: ident,
+     $rows_step_by: ident,
      $fixed_rows: ident,
      $fixed_rows_with_step: ident,
      $rows_generic: ident,
@@ -262,6 +263,7 @@ macro_rules! matrix_slice_impl(
      $column_part: ident,
      $columns: ident,
      $columns_with_step: ident,
+     $columns_step_by: ident,
      $fixed_columns: ident,
      $fixed_columns_with_step: ident,
      $columns_generic: ident,
@@ -312,6 +314,26 @@ macro_rules! matrix_slice_impl(
                 $me.$rows_generic_with_step(first_row, Dynamic::new(nrows), step)
             }
 
+            /// Extracts from this matrix every `step`-th row, starting with the row `first_row`
+            /// and up to the last row of this matrix.
+            ///
+            /// Unlike `step` in the other slicing methods, which is the number of rows skipped,
+            /// `step` is here the distance between two consecutive rows of the slice, like with
+            /// `Iterator::step_by`. Panics if `step` is zero.
+            #[inline]
+            pub fn $rows_step_by($me: $Me, first_row: usize, step: usize)
+                -> $MatrixSlice<N, Dynamic, C, Dynamic, S::CStride> {
+
+                assert!(step > 0, "Matrix slicing: the step must be positive.");
+                assert!(first_row <= $me.nrows(), "Matrix slicing out of bounds.");
+                let n = $me.nrows() - first_row;
+                let nrows = if n == 0 { 0 } else { (n - 1) / step + 1 };
+                // A step larger than the remaining rows selects at most one of them, so it is
+                // clamped to avoid overflowing when computing the strides.
+                let step = step.min(n.max(1));
+                $me.$rows_generic_with_step(first_row, Dynamic::new(nrows), step - 1)
+            }
+
             /// Extracts a compile-time number of consecutive rows from this matrix.
             #[inline]
             pub fn $fixed_rows<RSlice: DimName>($me: $Me, first_row: usize)
@@ -400,6 +422,26 @@ macro_rules! matrix_slice_impl(
                 $me.$columns_generic_with_step(first_col, Dynamic::new(ncols), step)
             }
 
+            /// Extracts from this matrix every `step`-th column, starting with the column
+            /// `first_col` and up to the last column of this matrix.
+            ///
+            /// Unlike `step` in the other slicing methods, which is the number of columns skipped,
+            /// `step` is here the distance between two consecutive columns of the slice, like with
+            /// `Iterator::step_by`. Panics if `step` is zero.
+            #[inline]
+            pub fn $columns_step_by($me: $Me, first_col: usize, step: usize)
+                -> $MatrixSlice<N, R, Dynamic, S::RStride, Dynamic> {
+
+                assert!(step > 0, "Matrix slicing: the step must be positive.");
+                assert!(first_col <= $me.ncols(), "Matrix slicing out of bounds.");
+                let n = $me.ncols() - first_col;
+                let ncols = if n == 0 { 0 } else { (n - 1) / step + 1 };
+                // A step larger than the remaining columns selects at most one of them, so it is
+                // clamped to avoid overflowing when computing the strides.
+                let step = step.min(n.max(1));
+                $me.$columns_generic_with_step(first_col, Dynamic::new(ncols), step - 1)
+            }
+
             /// Extracts a compile-time number of consecutive columns from this matrix.
             #[inline]
             pub fn $fixed_columns<CSlice: DimName>($me: $Me, first_col: usize)
@@ -640,6 +682,7 @@ matrix_slice_impl!(
      row_part,
      rows,
      rows_with_step,
+     rows_step_by,
      fixed_rows,
      fixed_rows_with_step,
      rows_generic,
@@ -648,6 +691,7 @@ matrix_slice_impl!(
      column_part,
      columns,
      columns_with_step,
+     columns_step_by,
      fixed_columns,
      fixed_columns_with_step,
      columns_generic,
@@ -667,6 +711,7 @@ matrix_slice_impl!(
      row_part_mut,
      rows_mut,
      rows_with_step_mut,
+     rows_step_by_mut,
      fixed_rows_mut,
      fixed_rows_with_step_mut,
      rows_generic_mut,
@@ -675,6 +720,7 @@ matrix_slice_impl!(
      column_part_mut,
      columns_mut,
      columns_with_step_mut,
+     columns_step_by_mut,
      fixed_columns_mut,
      fixed_columns_with_step_mut,
      columns_generic_mut,
@@ -6,6 +6,8 @@ pub mod constraint;
 pub mod coordinates;
 pub mod default_allocator;
 pub mod dimension;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod gallery;
 pub mod iter;
 mod ops;
 pub mod storage;
@@ -19,6 +21,9 @@ mod construction;
 mod construction_slice;
 mod conversion;
 mod edition;
+mod error;
+#[cfg(feature = "std")]
+mod format;
 pub mod indexing;
 mod matrix;
 #[cfg(feature = "alga")]
@@ -26,10 +31,18 @@ mod matrix_alga;
 mod matrix_simba;
 mod matrix_slice;
 mod norm;
+mod ordered;
+#[cfg(feature = "std")]
+mod parse;
 mod properties;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod row_major_vec_storage;
 mod scalar;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod stack;
 mod statistics;
 mod swizzle;
+mod uninit;
 mod unit;
 #[cfg(any(feature = "std", feature = "alloc"))]
 mod vec_storage;
@@ -39,15 +52,24 @@ pub mod helper;
 
 pub use self::matrix::*;
 pub use self::norm::*;
+pub use self::ordered::*;
 pub use self::scalar::*;
+pub use self::uninit::*;
 pub use self::unit::*;
 
 pub use self::default_allocator::*;
 pub use self::dimension::*;
+pub use self::error::*;
+#[cfg(feature = "std")]
+pub use self::format::*;
 
 pub use self::alias::*;
 pub use self::alias_slice::*;
 pub use self::array_storage::*;
 pub use self::matrix_slice::*;
 #[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::row_major_vec_storage::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::stack::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
 pub use self::vec_storage::*;
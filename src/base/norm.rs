@@ -5,10 +5,12 @@ use num::Zero;
 use std::ops::Neg;
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, Dim, DimName, Matrix, MatrixMN, Normed, VectorN};
+use crate::base::{
+    DefaultAllocator, Dim, DimName, Dynamic, Matrix, MatrixMN, MatrixN, Normed, VectorN, U1,
+};
 use crate::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
 use crate::storage::{Storage, StorageMut};
-use crate::{ComplexField, Scalar, SimdComplexField, Unit};
+use crate::{ComplexField, RealField, Scalar, SimdComplexField, Unit};
 use simba::scalar::ClosedNeg;
 use simba::simd::{SimdOption, SimdPartialOrd};
 
@@ -333,6 +335,160 @@ impl<N: ComplexField, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
             Some(self.unscale(n))
         }
     }
+
+    /// The operator norm of this matrix induced by the L1 norm, i.e., its largest column sum.
+    ///
+    /// The sum is computed on the moduli of the components.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1.0, -2.0, 3.0,
+    ///                        -4.0, 5.0, -6.0);
+    /// assert_eq!(m.norm_one(), 9.0);
+    /// ```
+    #[inline]
+    pub fn norm_one(&self) -> N::RealField {
+        self.column_iter()
+            .fold(N::RealField::zero(), |norm, col| norm.max(col.lp_norm(1)))
+    }
+
+    /// The operator norm of this matrix induced by the L-infinite norm, i.e., its largest row sum.
+    ///
+    /// The sum is computed on the moduli of the components. This is not the uniform norm of the
+    /// components of this matrix, which is computed by `.apply_norm(&UniformNorm)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1.0, -2.0, 3.0,
+    ///                        -4.0, 5.0, -6.0);
+    /// assert_eq!(m.norm_infinity_operator(), 15.0);
+    /// ```
+    #[inline]
+    pub fn norm_infinity_operator(&self) -> N::RealField {
+        self.row_iter()
+            .fold(N::RealField::zero(), |norm, row| norm.max(row.lp_norm(1)))
+    }
+
+    /// Computes an orthonormal basis of the whole space, the first vectors of which are an
+    /// orthonormal basis of the space spanned by the columns of this matrix.
+    ///
+    /// The columns of the returned unitary matrix are the basis vectors. Its first `rank` columns,
+    /// where `rank` is returned as well, are the columns of this matrix orthonormalized by
+    /// `.orthonormalize_columns(eps)`, and its remaining columns complete them to a basis of the
+    /// whole space. This works for vectors of any dimension, including dynamic ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{DMatrix, DVector};
+    /// let v = DVector::from_column_slice(&[1.0, 1.0, 0.0, 0.0, 0.0]);
+    /// let (basis, rank) = v.complete_orthonormal_basis(1.0e-7);
+    ///
+    /// assert_eq!(rank, 1);
+    /// assert_relative_eq!(basis.column(0).into_owned(), v.normalize());
+    /// assert_relative_eq!(basis.transpose() * &basis, DMatrix::identity(5, 5), epsilon = 1.0e-7);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn complete_orthonormal_basis(&self, eps: N::RealField) -> (MatrixN<N, R>, usize)
+    where
+        DefaultAllocator: Allocator<N, R, R> + Allocator<N, R, Dynamic>,
+    {
+        let (nrows, ncols) = self.data.shape();
+        let dim = nrows.value();
+        let ncols = ncols.value();
+
+        // The canonical basis vectors are appended after the columns of `self` so that they
+        // complete the basis once orthonormalized.
+        let mut work = MatrixMN::zeros_generic(nrows, Dynamic::new(ncols + dim));
+
+        for j in 0..ncols {
+            work.column_mut(j).copy_from(&self.column(j));
+        }
+
+        for i in 0..dim {
+            work[(i, ncols + i)] = N::one();
+        }
+
+        // Only the columns of `self` are orthonormalized with the tolerance `eps`. As long as the
+        // basis is not complete, the sum of the squared norms of the orthogonal parts of the
+        // canonical basis vectors is at least one, so not all of them can be rejected by this
+        // threshold.
+        let rank = work.columns_range_mut(..ncols).orthonormalize_columns(eps);
+        let threshold = crate::convert::<_, N::RealField>(0.5)
+            / crate::convert::<_, N::RealField>(dim as f64).sqrt();
+        let mut count = rank;
+
+        for j in ncols..ncols + dim {
+            let (basis, mut col) = work.columns_range_pair_mut(..count, j);
+
+            for k in 0..count {
+                let basis_element = basis.column(k);
+                let proj = basis_element.dotc(&col);
+                col.axpy(-proj, &basis_element, N::one());
+            }
+
+            if col.try_normalize_mut(threshold).is_some() {
+                work.swap_columns(count, j);
+                count += 1;
+            }
+        }
+
+        let basis = work.generic_slice((0, 0), (nrows, nrows)).into_owned();
+
+        (basis, rank)
+    }
+
+    /// An estimate of the spectral norm of this matrix, i.e., its largest singular value.
+    ///
+    /// This performs `niter` iterations of the power iteration on `self.adjoint() * self`, which
+    /// is much cheaper than a singular value decomposition. The estimate is never larger than
+    /// the spectral norm, and its accuracy improves with `niter`, quickly if the two largest
+    /// singular values are well separated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix2;
+    /// let m = Matrix2::new(3.0, 0.0,
+    ///                      4.0, 5.0);
+    /// assert_relative_eq!(m.spectral_norm_estimate(30), 45.0f64.sqrt(), epsilon = 1.0e-7);
+    /// ```
+    pub fn spectral_norm_estimate(&self, niter: usize) -> N::RealField
+    where
+        DefaultAllocator: Allocator<N, R> + Allocator<N, C>,
+    {
+        let (nrows, ncols) = self.data.shape();
+
+        if nrows.value() == 0 || ncols.value() == 0 {
+            return N::RealField::zero();
+        }
+
+        // Deterministic starting vector that is unlikely to be orthogonal to the dominant right
+        // singular vector.
+        let mut v = VectorN::from_fn_generic(ncols, U1, |i, _| {
+            N::from_subset(&(1.0 + ((i * 7919) % 1013) as f64 / 1013.0))
+        });
+        let _ = v.normalize_mut();
+        let mut av = VectorN::zeros_generic(nrows, U1);
+
+        for _ in 0..niter {
+            av.gemv(N::one(), self, &v, N::zero());
+            v.gemv_ad(N::one(), self, &av, N::zero());
+
+            if v.normalize_mut().is_zero() {
+                return N::RealField::zero();
+            }
+        }
+
+        av.gemv(N::one(), self, &v, N::zero());
+        av.norm()
+    }
 }
 
 impl<N: SimdComplexField, R: Dim, C: Dim, S: StorageMut<N, R, C>> Matrix<N, R, C, S> {
@@ -382,6 +538,55 @@ impl<N: ComplexField, R: Dim, C: Dim, S: StorageMut<N, R, C>> Matrix<N, R, C, S>
             Some(n)
         }
     }
+
+    /// Orthonormalizes the columns of this matrix in-place with the modified Gram-Schmidt process,
+    /// and returns its numerical rank.
+    ///
+    /// A column is considered dependent on the previous ones if the norm of its component
+    /// orthogonal to them is smaller or equal to `eps`. The independent columns are orthonormalized
+    /// and moved, in order, to the first `rank` columns, and all the other columns are set to zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix3;
+    /// let mut m = Matrix3::new(1.0, 2.0, 0.0,
+    ///                          1.0, 2.0, 1.0,
+    ///                          0.0, 0.0, 1.0);
+    /// let rank = m.orthonormalize_columns(1.0e-7);
+    ///
+    /// assert_eq!(rank, 2);
+    /// assert_relative_eq!(m.column(0).norm(), 1.0);
+    /// assert_relative_eq!(m.column(0).dot(&m.column(1)), 0.0);
+    /// assert_eq!(m.column(2).norm(), 0.0);
+    /// ```
+    pub fn orthonormalize_columns(&mut self, eps: N::RealField) -> usize {
+        let mut rank = 0;
+
+        for j in 0..self.ncols() {
+            if rank == self.nrows() {
+                // All the remaining columns are dependent.
+                self.column_mut(j).fill(N::zero());
+                continue;
+            }
+
+            for k in 0..rank {
+                let (basis_element, mut col) = self.columns_range_pair_mut(k, j);
+                let proj = basis_element.dotc(&col);
+                col.axpy(-proj, &basis_element, N::one());
+            }
+
+            if self.column_mut(j).try_normalize_mut(eps).is_some() {
+                self.swap_columns(rank, j);
+                rank += 1;
+            } else {
+                self.column_mut(j).fill(N::zero());
+            }
+        }
+
+        rank
+    }
 }
 
 impl<N: SimdComplexField, R: Dim, C: Dim> Normed for MatrixMN<N, R, C>
@@ -538,20 +743,36 @@ where
                 #[cfg(any(feature = "std", feature = "alloc"))]
                 {
                     // XXX: use a GenericArray instead.
-                    let mut known_basis = Vec::new();
+                    let mut known_basis: Vec<Self> = Vec::new();
 
                     for v in vs.iter() {
-                        known_basis.push(v.normalize())
+                        let mut elt = v.clone();
+
+                        for b in &known_basis {
+                            elt -= b * b.dotc(&elt)
+                        }
+
+                        if let Some(basis_elt) = elt.try_normalize(N::RealField::zero()) {
+                            known_basis.push(basis_elt);
+                        }
                     }
 
-                    for i in 0..D::dim() - vs.len() {
+                    // See `Matrix::complete_orthonormal_basis` for the choice of this threshold.
+                    let threshold = crate::convert::<_, N::RealField>(0.5)
+                        / crate::convert::<_, N::RealField>(D::dim() as f64).sqrt();
+
+                    for i in 0..D::dim() {
+                        if known_basis.len() == D::dim() {
+                            break;
+                        }
+
                         let mut elt = Self::canonical_basis_element(i);
 
                         for v in &known_basis {
-                            elt -= v * elt.dot(v)
+                            elt -= v * v.dotc(&elt)
                         }
 
-                        if let Some(subsp_elt) = elt.try_normalize(N::RealField::zero()) {
+                        if let Some(subsp_elt) = elt.try_normalize(threshold) {
                             if !f(&subsp_elt) {
                                 return;
                             };
@@ -1,4 +1,5 @@
-use num::{One, Zero};
+use num::ops::saturating::{SaturatingAdd, SaturatingMul, SaturatingSub};
+use num::{CheckedAdd, CheckedMul, CheckedSub, One, WrappingAdd, WrappingMul, WrappingSub, Zero};
 use std::iter;
 use std::ops::{
     Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
@@ -784,6 +785,71 @@ where
         self.xx_mul_to(rhs, out, |a, b| a.dotc(b))
     }
 
+    /// Equivalent to `self * rhs.adjoint()`, without computing the adjoint of `rhs`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Complex, Matrix2x3, Matrix3};
+    /// let a = Matrix2x3::from_fn(|i, j| Complex::new(i as f64, j as f64));
+    /// let b = Matrix3::from_fn(|i, j| Complex::new(1.0, (i + 2 * j) as f64));
+    ///
+    /// assert_eq!(a.mul_ad(&b), a * b.adjoint());
+    /// ```
+    #[inline]
+    pub fn mul_ad<R2: Dim, C2: Dim, SB>(&self, rhs: &Matrix<N, R2, C2, SB>) -> MatrixMN<N, R1, R2>
+    where
+        N: SimdComplexField,
+        SB: Storage<N, R2, C2>,
+        DefaultAllocator: Allocator<N, R1, R2>,
+        ShapeConstraint: SameNumberOfColumns<C1, C2>,
+    {
+        let mut res =
+            unsafe { Matrix::new_uninitialized_generic(self.data.shape().0, rhs.data.shape().0) };
+
+        self.mul_ad_to(rhs, &mut res);
+        res
+    }
+
+    /// Equivalent to `self * rhs.adjoint()` but stores the result into `out` to avoid
+    /// allocations.
+    ///
+    /// This accumulates the products of the columns of `self` by the adjoints of the columns of
+    /// `rhs`, so `rhs.adjoint()` is never computed.
+    #[inline]
+    pub fn mul_ad_to<R2: Dim, C2: Dim, SB, R3: Dim, C3: Dim, SC>(
+        &self,
+        rhs: &Matrix<N, R2, C2, SB>,
+        out: &mut Matrix<N, R3, C3, SC>,
+    ) where
+        N: SimdComplexField,
+        SB: Storage<N, R2, C2>,
+        SC: StorageMut<N, R3, C3>,
+        ShapeConstraint: SameNumberOfColumns<C1, C2> + DimEq<R3, R1> + DimEq<C3, R2>,
+    {
+        assert!(
+            self.ncols() == rhs.ncols(),
+            "Matrix multiplication dimensions mismatch {:?} and {:?}: left cols != right cols.",
+            self.shape(),
+            rhs.shape()
+        );
+        assert!(
+            out.shape() == (self.nrows(), rhs.nrows()),
+            "Matrix multiplication output dimensions mismatch {:?} and {:?}: expected {:?}.",
+            self.shape(),
+            rhs.shape(),
+            (self.nrows(), rhs.nrows())
+        );
+
+        if self.ncols() == 0 {
+            out.fill(N::zero());
+        }
+
+        for k in 0..self.ncols() {
+            let beta = if k == 0 { N::zero() } else { N::one() };
+            out.gerc(N::one(), &self.column(k), &rhs.column(k), beta);
+        }
+    }
+
     /// Equivalent to `self * rhs` but stores the result into `out` to avoid allocations.
     #[inline]
     pub fn mul_to<R2: Dim, C2: Dim, SB, R3: Dim, C3: Dim, SC>(
@@ -841,6 +907,57 @@ where
 
         res
     }
+
+    /// Equivalent to `self.kronecker(rhs)` but stores the result into `out` to avoid
+    /// allocations.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2, Matrix4};
+    /// let a = Matrix2::new(1, 2, 3, 4);
+    /// let b = Matrix2::new(0, 1, 1, 0);
+    /// let mut out = Matrix4::zeros();
+    /// a.kronecker_to(&b, &mut out);
+    ///
+    /// assert_eq!(out, a.kronecker(&b));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `out` does not have `self.nrows() * rhs.nrows()` rows and
+    /// `self.ncols() * rhs.ncols()` columns.
+    pub fn kronecker_to<R2: Dim, C2: Dim, SB, R3: Dim, C3: Dim, SC>(
+        &self,
+        rhs: &Matrix<N, R2, C2, SB>,
+        out: &mut Matrix<N, R3, C3, SC>,
+    ) where
+        N: ClosedMul,
+        SB: Storage<N, R2, C2>,
+        SC: StorageMut<N, R3, C3>,
+    {
+        let (nrows1, ncols1) = self.shape();
+        let (nrows2, ncols2) = rhs.shape();
+
+        assert_eq!(
+            out.shape(),
+            (nrows1 * nrows2, ncols1 * ncols2),
+            "Kronecker product: the output matrix has the wrong dimensions."
+        );
+
+        for j1 in 0..ncols1 {
+            for j2 in 0..ncols2 {
+                for i1 in 0..nrows1 {
+                    unsafe {
+                        let coeff = self.get_unchecked((i1, j1)).inlined_clone();
+
+                        for i2 in 0..nrows2 {
+                            *out.get_unchecked_mut((i1 * nrows2 + i2, j1 * ncols2 + j2)) =
+                                coeff.inlined_clone() * rhs.get_unchecked((i2, j2)).inlined_clone();
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
 
 impl<N: Scalar + ClosedAdd, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
@@ -868,6 +985,315 @@ impl<N: Scalar + ClosedAdd, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C,
     }
 }
 
+impl<N: Scalar, R1: Dim, C1: Dim, SA: Storage<N, R1, C1>> Matrix<N, R1, C1, SA> {
+    /// Computes `self + rhs`, or returns `None` if any component addition overflows.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let a = Matrix2::new(1u8, 2, 3, 4);
+    ///
+    /// assert_eq!(a.checked_add(&a), Some(Matrix2::new(2, 4, 6, 8)));
+    /// assert_eq!(a.checked_add(&Matrix2::repeat(253)), None);
+    /// ```
+    #[inline]
+    pub fn checked_add<R2: Dim, C2: Dim, SB>(
+        &self,
+        rhs: &Matrix<N, R2, C2, SB>,
+    ) -> Option<MatrixSum<N, R1, C1, R2, C2>>
+    where
+        N: CheckedAdd,
+        SB: Storage<N, R2, C2>,
+        DefaultAllocator: SameShapeAllocator<N, R1, C1, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        self.try_zip_map_sum(rhs, N::checked_add)
+    }
+
+    /// Computes `self - rhs`, or returns `None` if any component subtraction overflows.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let a = Vector3::new(5u32, 6, 7);
+    ///
+    /// assert_eq!(a.checked_sub(&Vector3::new(1, 2, 3)), Some(Vector3::new(4, 4, 4)));
+    /// assert_eq!(a.checked_sub(&Vector3::new(1, 7, 3)), None);
+    /// ```
+    #[inline]
+    pub fn checked_sub<R2: Dim, C2: Dim, SB>(
+        &self,
+        rhs: &Matrix<N, R2, C2, SB>,
+    ) -> Option<MatrixSum<N, R1, C1, R2, C2>>
+    where
+        N: CheckedSub,
+        SB: Storage<N, R2, C2>,
+        DefaultAllocator: SameShapeAllocator<N, R1, C1, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        self.try_zip_map_sum(rhs, N::checked_sub)
+    }
+
+    /// Computes the matrix product `self * rhs`, or returns `None` if any of the component
+    /// multiplications or additions overflows.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let a = Matrix2::new(1i16, 2, 3, 4);
+    /// assert_eq!(a.checked_mul(&a), Some(a * a));
+    ///
+    /// // The products fit into an `i16`, but not their sums.
+    /// let b = Matrix2::new(200i16, 150, 0, 0);
+    /// assert_eq!(b.checked_mul(&Matrix2::repeat(100)), None);
+    /// ```
+    #[inline]
+    pub fn checked_mul<R2: Dim, C2: Dim, SB>(
+        &self,
+        rhs: &Matrix<N, R2, C2, SB>,
+    ) -> Option<MatrixMN<N, R1, C2>>
+    where
+        N: Zero + CheckedAdd + CheckedMul,
+        SB: Storage<N, R2, C2>,
+        DefaultAllocator: Allocator<N, R1, C2>,
+        ShapeConstraint: AreMultipliable<R1, C1, R2, C2>,
+    {
+        self.try_fold_mul(rhs, |acc, a, b| acc.checked_add(&a.checked_mul(b)?))
+    }
+
+    /// Computes the component-wise `f(self[i], rhs[i])`.
+    #[inline]
+    fn zip_map_sum<R2: Dim, C2: Dim, SB>(
+        &self,
+        rhs: &Matrix<N, R2, C2, SB>,
+        mut f: impl FnMut(&N, &N) -> N,
+    ) -> MatrixSum<N, R1, C1, R2, C2>
+    where
+        SB: Storage<N, R2, C2>,
+        DefaultAllocator: SameShapeAllocator<N, R1, C1, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        self.try_zip_map_sum(rhs, |a, b| Some(f(a, b))).unwrap()
+    }
+
+    /// Computes the component-wise `f(self[i], rhs[i])`, or returns `None` as soon as `f` does.
+    #[inline]
+    fn try_zip_map_sum<R2: Dim, C2: Dim, SB>(
+        &self,
+        rhs: &Matrix<N, R2, C2, SB>,
+        mut f: impl FnMut(&N, &N) -> Option<N>,
+    ) -> Option<MatrixSum<N, R1, C1, R2, C2>>
+    where
+        SB: Storage<N, R2, C2>,
+        DefaultAllocator: SameShapeAllocator<N, R1, C1, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        assert_eq!(
+            self.shape(),
+            rhs.shape(),
+            "Matrix addition/subtraction dimensions mismatch."
+        );
+
+        let mut res = self.clone_owned_sum::<R2, C2>();
+
+        for (e, rhs) in res.iter_mut().zip(rhs.iter()) {
+            *e = f(e, rhs)?;
+        }
+
+        Some(res)
+    }
+
+    /// Computes `self + rhs` where each component addition saturates at the numeric bounds of `N`
+    /// instead of overflowing.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let pixels = Matrix2::new(10u8, 100, 200, 250);
+    /// assert_eq!(pixels.saturating_add(&Matrix2::repeat(50)), Matrix2::new(60, 150, 250, 255));
+    /// assert_eq!(pixels.saturating_sub(&Matrix2::repeat(50)), Matrix2::new(0, 50, 150, 200));
+    /// ```
+    #[inline]
+    pub fn saturating_add<R2: Dim, C2: Dim, SB>(
+        &self,
+        rhs: &Matrix<N, R2, C2, SB>,
+    ) -> MatrixSum<N, R1, C1, R2, C2>
+    where
+        N: SaturatingAdd,
+        SB: Storage<N, R2, C2>,
+        DefaultAllocator: SameShapeAllocator<N, R1, C1, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        self.zip_map_sum(rhs, N::saturating_add)
+    }
+
+    /// Computes `self - rhs` where each component subtraction saturates at the numeric bounds of
+    /// `N` instead of overflowing.
+    #[inline]
+    pub fn saturating_sub<R2: Dim, C2: Dim, SB>(
+        &self,
+        rhs: &Matrix<N, R2, C2, SB>,
+    ) -> MatrixSum<N, R1, C1, R2, C2>
+    where
+        N: SaturatingSub,
+        SB: Storage<N, R2, C2>,
+        DefaultAllocator: SameShapeAllocator<N, R1, C1, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        self.zip_map_sum(rhs, N::saturating_sub)
+    }
+
+    /// Computes the matrix product `self * rhs` where each component multiplication and addition
+    /// saturates at the numeric bounds of `N` instead of overflowing.
+    ///
+    /// The terms of each dot product are accumulated in order, so, with signed integers, the result
+    /// may differ from the exact product clamped to the numeric bounds of `N` if the partial sums
+    /// saturate.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2, Vector2};
+    /// let m = Matrix2::new(1u8, 2, 30, 40);
+    /// assert_eq!(m.saturating_mul(&Vector2::new(3, 5)), Vector2::new(13, 255));
+    /// ```
+    #[inline]
+    pub fn saturating_mul<R2: Dim, C2: Dim, SB>(
+        &self,
+        rhs: &Matrix<N, R2, C2, SB>,
+    ) -> MatrixMN<N, R1, C2>
+    where
+        N: Zero + SaturatingAdd + SaturatingMul,
+        SB: Storage<N, R2, C2>,
+        DefaultAllocator: Allocator<N, R1, C2>,
+        ShapeConstraint: AreMultipliable<R1, C1, R2, C2>,
+    {
+        self.fold_mul(rhs, |acc, a, b| acc.saturating_add(&a.saturating_mul(b)))
+    }
+
+    /// Computes `self + rhs` where each component addition wraps around at the numeric bounds of
+    /// `N`, even if overflow checks are enabled.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let v = Vector3::new(1u8, 128, 255);
+    /// assert_eq!(v.wrapping_add(&v), Vector3::new(2, 0, 254));
+    /// assert_eq!(Vector3::zeros().wrapping_sub(&v), Vector3::new(255, 128, 1));
+    /// ```
+    #[inline]
+    pub fn wrapping_add<R2: Dim, C2: Dim, SB>(
+        &self,
+        rhs: &Matrix<N, R2, C2, SB>,
+    ) -> MatrixSum<N, R1, C1, R2, C2>
+    where
+        N: WrappingAdd,
+        SB: Storage<N, R2, C2>,
+        DefaultAllocator: SameShapeAllocator<N, R1, C1, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        self.zip_map_sum(rhs, N::wrapping_add)
+    }
+
+    /// Computes `self - rhs` where each component subtraction wraps around at the numeric bounds
+    /// of `N`, even if overflow checks are enabled.
+    #[inline]
+    pub fn wrapping_sub<R2: Dim, C2: Dim, SB>(
+        &self,
+        rhs: &Matrix<N, R2, C2, SB>,
+    ) -> MatrixSum<N, R1, C1, R2, C2>
+    where
+        N: WrappingSub,
+        SB: Storage<N, R2, C2>,
+        DefaultAllocator: SameShapeAllocator<N, R1, C1, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        self.zip_map_sum(rhs, N::wrapping_sub)
+    }
+
+    /// Computes the matrix product `self * rhs` where each component multiplication and addition
+    /// wraps around at the numeric bounds of `N`, even if overflow checks are enabled.
+    ///
+    /// The result is the exact product modulo `2^b` where `b` is the number of bits of `N`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2, Vector2};
+    /// let m = Matrix2::new(1u8, 2, 30, 40);
+    ///
+    /// // 30 * 3 + 40 * 5 = 290 = 256 + 34
+    /// assert_eq!(m.wrapping_mul(&Vector2::new(3, 5)), Vector2::new(13, 34));
+    /// ```
+    #[inline]
+    pub fn wrapping_mul<R2: Dim, C2: Dim, SB>(
+        &self,
+        rhs: &Matrix<N, R2, C2, SB>,
+    ) -> MatrixMN<N, R1, C2>
+    where
+        N: Zero + WrappingAdd + WrappingMul,
+        SB: Storage<N, R2, C2>,
+        DefaultAllocator: Allocator<N, R1, C2>,
+        ShapeConstraint: AreMultipliable<R1, C1, R2, C2>,
+    {
+        self.fold_mul(rhs, |acc, a, b| acc.wrapping_add(&a.wrapping_mul(b)))
+    }
+
+    /// Computes the matrix product `self * rhs` where each component is the fold
+    /// `acc = f(acc, self[(i, k)], rhs[(k, j)])` over `k`, starting with zero.
+    #[inline]
+    fn fold_mul<R2: Dim, C2: Dim, SB>(
+        &self,
+        rhs: &Matrix<N, R2, C2, SB>,
+        mut f: impl FnMut(N, &N, &N) -> N,
+    ) -> MatrixMN<N, R1, C2>
+    where
+        N: Zero,
+        SB: Storage<N, R2, C2>,
+        DefaultAllocator: Allocator<N, R1, C2>,
+        ShapeConstraint: AreMultipliable<R1, C1, R2, C2>,
+    {
+        self.try_fold_mul(rhs, |acc, a, b| Some(f(acc, a, b)))
+            .unwrap()
+    }
+
+    /// Computes the matrix product `self * rhs` where each component is the fold
+    /// `acc = f(acc, self[(i, k)], rhs[(k, j)])?` over `k`, starting with zero, or returns `None`
+    /// as soon as `f` does.
+    #[inline]
+    fn try_fold_mul<R2: Dim, C2: Dim, SB>(
+        &self,
+        rhs: &Matrix<N, R2, C2, SB>,
+        mut f: impl FnMut(N, &N, &N) -> Option<N>,
+    ) -> Option<MatrixMN<N, R1, C2>>
+    where
+        N: Zero,
+        SB: Storage<N, R2, C2>,
+        DefaultAllocator: Allocator<N, R1, C2>,
+        ShapeConstraint: AreMultipliable<R1, C1, R2, C2>,
+    {
+        assert_eq!(
+            self.ncols(),
+            rhs.nrows(),
+            "Matrix multiplication dimensions mismatch."
+        );
+
+        let mut res = MatrixMN::zeros_generic(self.data.shape().0, rhs.data.shape().1);
+
+        for j in 0..res.ncols() {
+            for i in 0..res.nrows() {
+                let mut acc = N::zero();
+
+                for k in 0..self.ncols() {
+                    acc = f(acc, &self[(i, k)], &rhs[(k, j)])?;
+                }
+
+                res[(i, j)] = acc;
+            }
+        }
+
+        Some(res)
+    }
+}
+
 impl<N, D: DimName> iter::Product for MatrixN<N, D>
 where
     N: Scalar + Zero + One + ClosedMul + ClosedAdd,
@@ -888,6 +1314,54 @@ where
     }
 }
 
+impl<N, D: Dim> MatrixN<N, D>
+where
+    N: Scalar + Zero + One + ClosedAdd + ClosedMul,
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    /// Computes `self^exp` for an unsigned integer exponent `exp`, by repeated squaring.
+    ///
+    /// This requires `O(log(exp))` matrix multiplications and works with any scalar type,
+    /// including integers, e.g., to count the paths of a given length in a graph from its
+    /// adjacency matrix. `self^0` is the identity matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// // The Fibonacci numbers.
+    /// let m = Matrix2::new(1u64, 1,
+    ///                      1,    0);
+    ///
+    /// assert_eq!(m.pow(10), Matrix2::new(89, 55, 55, 34));
+    /// assert_eq!(m.pow(0), Matrix2::identity());
+    /// ```
+    pub fn pow(&self, exp: u32) -> Self {
+        assert!(
+            self.is_square(),
+            "Unable to compute the power of a non-square matrix."
+        );
+
+        let (nrows, ncols) = self.data.shape();
+        let mut res = Self::identity_generic(nrows, ncols);
+        let mut base = self.clone();
+        let mut exp = exp;
+
+        while exp > 0 {
+            if exp % 2 == 1 {
+                res = &res * &base;
+            }
+
+            exp /= 2;
+
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+
+        res
+    }
+}
+
 impl<N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
     /// Returns the absolute value of the component with the largest absolute value.
     /// # Example
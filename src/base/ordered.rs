@@ -0,0 +1,262 @@
+//! Total and lexicographic orderings of matrices, including matrices of floating-point numbers.
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, DerefMut};
+
+use crate::base::dimension::Dim;
+use crate::base::storage::Storage;
+use crate::base::{Matrix, Scalar};
+
+/// A scalar type with a total order, used by `Matrix::total_cmp` and `OrderedMatrix`.
+///
+/// For integers, this is their usual order. For floating-point numbers, this is the total order
+/// defined by IEEE 754, i.e., `-NaN < -∞ < ... < -0.0 < 0.0 < ... < ∞ < NaN`, with the NaNs
+/// ordered by their payloads, as with `f64::total_cmp`.
+pub trait TotalOrder: Scalar {
+    /// Compares `self` and `other` with the total order of this type.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Feeds `self` into `state`, consistently with `total_cmp`: two values that compare equal
+    /// have the same hash.
+    fn total_hash<H: Hasher>(&self, state: &mut H);
+}
+
+macro_rules! impl_total_order_for_ord(
+    ($($T: ty),*) => {$(
+        impl TotalOrder for $T {
+            #[inline]
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                self.cmp(other)
+            }
+
+            #[inline]
+            fn total_hash<H: Hasher>(&self, state: &mut H) {
+                self.hash(state)
+            }
+        }
+    )*}
+);
+
+impl_total_order_for_ord!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool, char
+);
+
+macro_rules! impl_total_order_for_float(
+    ($($T: ty, $Bits: ty, $SignedBits: ty, $sign_shift: expr);*) => {$(
+        impl TotalOrder for $T {
+            #[inline]
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                // Flip all the bits except the sign bit of negative numbers, so that the
+                // two's-complement integer order matches the IEEE 754 total order.
+                let key = |x: $T| {
+                    let bits = x.to_bits() as $SignedBits;
+                    bits ^ (((bits >> $sign_shift) as $Bits) >> 1) as $SignedBits
+                };
+
+                key(*self).cmp(&key(*other))
+            }
+
+            #[inline]
+            fn total_hash<H: Hasher>(&self, state: &mut H) {
+                self.to_bits().hash(state)
+            }
+        }
+    )*}
+);
+
+impl_total_order_for_float!(f32, u32, i32, 31; f64, u64, i64, 63);
+
+impl<N: TotalOrder, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
+    /// Compares `self` and `other` with a total order.
+    ///
+    /// Matrices are ordered by number of rows first, then by number of columns, and finally
+    /// lexicographically by their components in column-major order, each compared with
+    /// `TotalOrder::total_cmp`. Unlike the partial order implemented by `PartialOrd`, this is
+    /// suitable for sorting matrices deterministically, even with NaN components.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector2;
+    /// # use std::cmp::Ordering;
+    /// let a = Vector2::new(1.0, 2.0);
+    /// let b = Vector2::new(1.0, std::f64::NAN);
+    ///
+    /// assert_eq!(a.partial_cmp(&b), None);
+    /// assert_eq!(a.total_cmp(&b), Ordering::Less);
+    /// assert_eq!(b.total_cmp(&b), Ordering::Equal);
+    /// ```
+    pub fn total_cmp<R2: Dim, C2: Dim, S2>(&self, other: &Matrix<N, R2, C2, S2>) -> Ordering
+    where
+        S2: Storage<N, R2, C2>,
+    {
+        self.shape().cmp(&other.shape()).then_with(|| {
+            self.iter()
+                .zip(other.iter())
+                .map(|(a, b)| a.total_cmp(b))
+                .find(|ord| *ord != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        })
+    }
+}
+
+impl<N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
+    /// Compares `self` and `other` lexicographically.
+    ///
+    /// The components are compared in column-major order, i.e., in order for vectors, and the
+    /// first pair of different components determines the result. If all the components of one
+    /// matrix are equal to the first components of the other, the matrix with the fewest
+    /// components is the smallest, and matrices with the same components are ordered by shape.
+    ///
+    /// Unlike the component-wise partial order implemented by `PartialOrd`, this can be used to
+    /// sort vectors, e.g., with `slice::sort_by`. See `lexicographic_partial_cmp` for
+    /// floating-point components.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector2;
+    /// # use std::cmp::Ordering;
+    /// let a = Vector2::new(1, 5);
+    /// let b = Vector2::new(2, 0);
+    ///
+    /// // The component-wise order cannot compare these vectors.
+    /// assert_eq!(a.partial_cmp(&b), None);
+    /// assert_eq!(a.lexicographic_cmp(&b), Ordering::Less);
+    ///
+    /// let mut points = vec![Vector2::new(2, 1), Vector2::new(1, 3), Vector2::new(1, 2)];
+    /// points.sort_by(|a, b| a.lexicographic_cmp(b));
+    /// assert_eq!(points, vec![Vector2::new(1, 2), Vector2::new(1, 3), Vector2::new(2, 1)]);
+    /// ```
+    pub fn lexicographic_cmp<R2: Dim, C2: Dim, S2>(&self, other: &Matrix<N, R2, C2, S2>) -> Ordering
+    where
+        N: Ord,
+        S2: Storage<N, R2, C2>,
+    {
+        self.iter()
+            .cmp(other.iter())
+            .then_with(|| self.shape().cmp(&other.shape()))
+    }
+
+    /// Compares `self` and `other` lexicographically, see `lexicographic_cmp`.
+    ///
+    /// This returns `None` if a pair of components that cannot be compared, e.g., because one of
+    /// them is NaN, is reached before the first pair of different components.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// # use std::cmp::Ordering;
+    /// let a = Vector3::new(1.0, 2.0, std::f64::NAN);
+    ///
+    /// assert_eq!(a.lexicographic_partial_cmp(&Vector3::new(1.0, 3.0, 0.0)), Some(Ordering::Less));
+    /// assert_eq!(a.lexicographic_partial_cmp(&Vector3::new(1.0, 2.0, 0.0)), None);
+    /// ```
+    pub fn lexicographic_partial_cmp<R2: Dim, C2: Dim, S2>(
+        &self,
+        other: &Matrix<N, R2, C2, S2>,
+    ) -> Option<Ordering>
+    where
+        N: PartialOrd,
+        S2: Storage<N, R2, C2>,
+    {
+        self.iter()
+            .partial_cmp(other.iter())
+            .map(|ord| ord.then_with(|| self.shape().cmp(&other.shape())))
+    }
+}
+
+/// A matrix wrapper that implements `Eq`, `Ord` and `Hash` with the total order of
+/// `Matrix::total_cmp`.
+///
+/// This allows matrices of floating-point numbers to be used as keys of a `HashMap` or a
+/// `BTreeMap`, or to be sorted. Matrices with the same shape, e.g., vectors or points (which can
+/// be converted into `OrderedMatrix` with `From`) of the same dimension, are thus ordered
+/// lexicographically by their components. Two wrapped matrices are equal if they have the same
+/// shape and the same components bit-for-bit, so `0.0` and `-0.0` are different, and a NaN is
+/// equal to itself.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{Matrix2, OrderedMatrix};
+/// # use std::collections::HashMap;
+/// let mut cache = HashMap::new();
+/// let _ = cache.insert(OrderedMatrix(Matrix2::new(1.0, 2.0, 3.0, 4.0)), "a");
+/// let _ = cache.insert(OrderedMatrix(Matrix2::new(std::f64::NAN, 2.0, 3.0, 4.0)), "b");
+///
+/// assert_eq!(cache[&OrderedMatrix(Matrix2::new(1.0, 2.0, 3.0, 4.0))], "a");
+/// assert_eq!(cache[&OrderedMatrix(Matrix2::new(std::f64::NAN, 2.0, 3.0, 4.0))], "b");
+///
+/// let mut sorted = vec![
+///     OrderedMatrix(Matrix2::new(1.0, 0.0, 0.0, 1.0)),
+///     OrderedMatrix(Matrix2::new(-1.0, 0.0, 0.0, 1.0)),
+/// ];
+/// sorted.sort();
+/// assert_eq!(sorted[0].0, Matrix2::new(-1.0, 0.0, 0.0, 1.0));
+/// ```
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug)]
+pub struct OrderedMatrix<N: Scalar, R: Dim, C: Dim, S>(pub Matrix<N, R, C, S>);
+
+impl<N: Scalar, R: Dim, C: Dim, S> OrderedMatrix<N, R, C, S> {
+    /// Retrieves the wrapped matrix.
+    #[inline]
+    pub fn into_inner(self) -> Matrix<N, R, C, S> {
+        self.0
+    }
+}
+
+impl<N: Scalar, R: Dim, C: Dim, S> From<Matrix<N, R, C, S>> for OrderedMatrix<N, R, C, S> {
+    #[inline]
+    fn from(matrix: Matrix<N, R, C, S>) -> Self {
+        OrderedMatrix(matrix)
+    }
+}
+
+impl<N: Scalar, R: Dim, C: Dim, S> Deref for OrderedMatrix<N, R, C, S> {
+    type Target = Matrix<N, R, C, S>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<N: Scalar, R: Dim, C: Dim, S> DerefMut for OrderedMatrix<N, R, C, S> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<N: TotalOrder, R: Dim, C: Dim, S: Storage<N, R, C>> PartialEq for OrderedMatrix<N, R, C, S> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_cmp(&other.0) == Ordering::Equal
+    }
+}
+
+impl<N: TotalOrder, R: Dim, C: Dim, S: Storage<N, R, C>> Eq for OrderedMatrix<N, R, C, S> {}
+
+impl<N: TotalOrder, R: Dim, C: Dim, S: Storage<N, R, C>> PartialOrd for OrderedMatrix<N, R, C, S> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: TotalOrder, R: Dim, C: Dim, S: Storage<N, R, C>> Ord for OrderedMatrix<N, R, C, S> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<N: TotalOrder, R: Dim, C: Dim, S: Storage<N, R, C>> Hash for OrderedMatrix<N, R, C, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.shape().hash(state);
+
+        for e in self.0.iter() {
+            e.total_hash(state);
+        }
+    }
+}
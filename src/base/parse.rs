@@ -0,0 +1,114 @@
+//! Parsing of matrices from their textual representation.
+
+use std::str::FromStr;
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::Dim;
+use crate::base::{DefaultAllocator, MatrixMN, ParseMatrixError, Scalar};
+
+impl<N, R: Dim, C: Dim> MatrixMN<N, R, C>
+where
+    N: Scalar + FromStr,
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    /// Parses a matrix given row-by-row.
+    ///
+    /// The rows are separated by semicolons or line breaks, and the components of each row by
+    /// commas or whitespaces, so this accepts the MATLAB-like layout `"[1 2; 3 4]"` as well as
+    /// whitespace-separated or CSV tables. The enclosing brackets are optional, and empty rows are
+    /// ignored. Each component is parsed with the `FromStr` implementation of `N`.
+    ///
+    /// An error is returned if a component cannot be parsed, if the rows do not all have the same
+    /// number of components, or if the parsed matrix does not have the dimensions of the matrix
+    /// type.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix2x3, ParseMatrixError};
+    /// let m = DMatrix::<i32>::from_str_rows("[1 2 3; 4 5 6]").unwrap();
+    /// assert_eq!(m, DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]));
+    ///
+    /// let csv = "1.0, 2.0, 3.0\n4.0, 5.0, 6.0\n";
+    /// let m = Matrix2x3::<f64>::from_str_rows(csv).unwrap();
+    /// assert_eq!(m, Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0));
+    ///
+    /// let err = DMatrix::<i32>::from_str_rows("1 2; 3").unwrap_err();
+    /// assert_eq!(err, ParseMatrixError::RaggedRow { row: 1, expected: 2, found: 1 });
+    /// ```
+    pub fn from_str_rows(s: &str) -> Result<Self, ParseMatrixError> {
+        let mut s = s.trim();
+
+        if s.starts_with('[') && s.ends_with(']') {
+            s = &s[1..s.len() - 1];
+        }
+
+        let mut components = Vec::new();
+        let mut nrows = 0;
+        let mut ncols = 0;
+
+        for line in s.split([';', '\n']) {
+            let row_start = components.len();
+
+            for text in line.split(|c: char| c == ',' || c.is_whitespace()) {
+                if text.is_empty() {
+                    continue;
+                }
+
+                match text.parse() {
+                    Ok(component) => components.push(component),
+                    Err(_) => {
+                        return Err(ParseMatrixError::InvalidComponent {
+                            row: nrows,
+                            column: components.len() - row_start,
+                            text: text.to_string(),
+                        })
+                    }
+                }
+            }
+
+            let found = components.len() - row_start;
+
+            if found == 0 {
+                continue;
+            }
+
+            if nrows == 0 {
+                ncols = found;
+            } else if found != ncols {
+                return Err(ParseMatrixError::RaggedRow {
+                    row: nrows,
+                    expected: ncols,
+                    found,
+                });
+            }
+
+            nrows += 1;
+        }
+
+        let shape_matches = |dim: Option<usize>, value: usize| dim.map_or(true, |d| d == value);
+
+        if !shape_matches(R::try_to_usize(), nrows) || !shape_matches(C::try_to_usize(), ncols) {
+            return Err(ParseMatrixError::ShapeMismatch { nrows, ncols });
+        }
+
+        Ok(Self::from_row_slice_generic(
+            R::from_usize(nrows),
+            C::from_usize(ncols),
+            &components,
+        ))
+    }
+}
+
+impl<N, R: Dim, C: Dim> FromStr for MatrixMN<N, R, C>
+where
+    N: Scalar + FromStr,
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    type Err = ParseMatrixError;
+
+    /// Parses a matrix given row-by-row, see `from_str_rows` for the accepted layouts.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, ParseMatrixError> {
+        Self::from_str_rows(s)
+    }
+}
@@ -0,0 +1,230 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use crate::base::allocator::Allocator;
+use crate::base::default_allocator::DefaultAllocator;
+use crate::base::dimension::{Dim, DimName, Dynamic, U1};
+use crate::base::storage::{Owned, Storage, StorageMut};
+use crate::base::{Matrix, RowMajorMatrixMN, Scalar};
+
+/*
+ *
+ * Storage.
+ *
+ */
+/// A Vec-based matrix data storage with its components in row-major order. It may be
+/// dynamically-sized.
+///
+/// This allows buffers stored in row-major order, e.g., by C libraries or images, to be owned by
+/// a matrix without transposing them. The row stride of this storage is the number of columns
+/// and its column stride is one, so all the matrix operations that take the strides into account,
+/// e.g., the products computed with `gemm`, work efficiently with this layout. The matrices
+/// resulting from arithmetic operations are allocated with the usual column-major storage.
+#[repr(C)]
+#[derive(Eq, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct RowMajorVecStorage<N, R: Dim, C: Dim> {
+    data: Vec<N>,
+    nrows: R,
+    ncols: C,
+}
+
+impl<N, R: Dim, C: Dim> RowMajorVecStorage<N, R, C> {
+    /// Creates a new row-major matrix data storage from the given vector and shape.
+    #[inline]
+    pub fn new(nrows: R, ncols: C, data: Vec<N>) -> Self {
+        assert!(
+            nrows.value() * ncols.value() == data.len(),
+            "Data storage buffer dimension mismatch."
+        );
+        Self { data, nrows, ncols }
+    }
+
+    /// The underlying data storage, in row-major order.
+    #[inline]
+    pub fn as_vec(&self) -> &Vec<N> {
+        &self.data
+    }
+
+    /// The number of elements on the underlying vector.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns true if the underlying vector contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl<N, R: Dim, C: Dim> From<RowMajorVecStorage<N, R, C>> for Vec<N> {
+    fn from(storage: RowMajorVecStorage<N, R, C>) -> Self {
+        storage.data
+    }
+}
+
+unsafe impl<N: Scalar, R: Dim, C: Dim> Storage<N, R, C> for RowMajorVecStorage<N, R, C> {
+    type RStride = C;
+    type CStride = U1;
+
+    #[inline]
+    fn ptr(&self) -> *const N {
+        self.data.as_ptr()
+    }
+
+    #[inline]
+    fn shape(&self) -> (R, C) {
+        (self.nrows, self.ncols)
+    }
+
+    #[inline]
+    fn strides(&self) -> (Self::RStride, Self::CStride) {
+        (self.ncols, U1::name())
+    }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        // The components are in column-major order only if this is a vector.
+        self.nrows.value() <= 1 || self.ncols.value() <= 1
+    }
+
+    #[inline]
+    fn into_owned(self) -> Owned<N, R, C>
+    where
+        DefaultAllocator: Allocator<N, R, C>,
+    {
+        self.clone_owned()
+    }
+
+    #[inline]
+    fn clone_owned(&self) -> Owned<N, R, C>
+    where
+        DefaultAllocator: Allocator<N, R, C>,
+    {
+        let (nrows, ncols) = (self.nrows.value(), self.ncols.value());
+        let it = (0..ncols)
+            .flat_map(|j| (0..nrows).map(move |i| self.data[i * ncols + j].inlined_clone()));
+
+        DefaultAllocator::allocate_from_iterator(self.nrows, self.ncols, it)
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[N] {
+        &self.data
+    }
+}
+
+unsafe impl<N: Scalar, R: Dim, C: Dim> StorageMut<N, R, C> for RowMajorVecStorage<N, R, C> {
+    #[inline]
+    fn ptr_mut(&mut self) -> *mut N {
+        self.data.as_mut_ptr()
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [N] {
+        &mut self.data[..]
+    }
+}
+
+/*
+ *
+ * Constructors and conversions.
+ *
+ */
+impl<N: Scalar, R: Dim, C: Dim> RowMajorMatrixMN<N, R, C> {
+    /// Creates a matrix that owns the given vector containing its components in row-major order,
+    /// and with dimensions specified by generic types instances.
+    ///
+    /// The vector is not copied. Panics if it does not contain exactly `nrows * ncols` elements.
+    /// The generic types `R` and `C` can either be type-level integers or integers wrapped with
+    /// `Dynamic::new()`.
+    #[inline]
+    pub fn from_row_major_vec_generic(nrows: R, ncols: C, data: Vec<N>) -> Self {
+        Self::from_data(RowMajorVecStorage::new(nrows, ncols, data))
+    }
+
+    /// The components of this matrix in row-major order.
+    #[inline]
+    pub fn as_row_major_slice(&self) -> &[N] {
+        self.data.as_slice()
+    }
+
+    /// The mutable components of this matrix in row-major order.
+    #[inline]
+    pub fn as_row_major_mut_slice(&mut self) -> &mut [N] {
+        self.data.as_mut_slice()
+    }
+
+    /// Retrieves the components of this matrix in row-major order, without copying them.
+    #[inline]
+    pub fn into_row_major_vec(self) -> Vec<N> {
+        self.data.into()
+    }
+}
+
+macro_rules! impl_row_major_vec_constructors(
+    ($R: ty, $C: ty; $(=> $DimIdent: ident: $DimBound: ident),*; $($gargs: expr),*; $($args: ident),*) => {
+        impl<N: Scalar, $($DimIdent: $DimBound),*> RowMajorMatrixMN<N, $R, $C> {
+            /// Creates a matrix that owns the given vector containing its components in
+            /// row-major order.
+            ///
+            /// Panics if `data` does not contain exactly as many elements as the matrix.
+            #[inline]
+            pub fn from_row_major_vec($($args: usize,)* data: Vec<N>) -> Self {
+                Self::from_row_major_vec_generic($($gargs,)* data)
+            }
+        }
+    }
+);
+
+impl_row_major_vec_constructors!(R, C;
+=> R: DimName, => C: DimName;
+R::name(), C::name();
+);
+
+impl_row_major_vec_constructors!(R, Dynamic;
+                                 => R: DimName;
+                                 R::name(), Dynamic::new(ncols);
+                                 ncols);
+
+impl_row_major_vec_constructors!(Dynamic, C;
+                                 => C: DimName;
+                                 Dynamic::new(nrows), C::name();
+                                 nrows);
+
+impl_row_major_vec_constructors!(Dynamic, Dynamic;
+                                 ;
+                                 Dynamic::new(nrows), Dynamic::new(ncols);
+                                 nrows, ncols);
+
+impl<N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
+    /// Copies the components of this matrix into a new matrix storing them in row-major order.
+    ///
+    /// The matrix is converted back to the usual column-major storage with `.clone_owned()`
+    /// or `.into_owned()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DRowMajorMatrix, Matrix2x3};
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    /// let row_major = m.to_row_major();
+    ///
+    /// assert_eq!(row_major, m);
+    /// assert_eq!(row_major.as_row_major_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(row_major.into_owned(), m);
+    ///
+    /// let image = DRowMajorMatrix::from_row_major_vec(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(image, m);
+    /// ```
+    pub fn to_row_major(&self) -> RowMajorMatrixMN<N, R, C> {
+        let (nrows, ncols) = self.data.shape();
+        let data = (0..nrows.value())
+            .flat_map(|i| (0..ncols.value()).map(move |j| self[(i, j)].inlined_clone()))
+            .collect();
+
+        RowMajorMatrixMN::from_row_major_vec_generic(nrows, ncols, data)
+    }
+}
@@ -0,0 +1,152 @@
+//! Concatenation of matrices along their rows or columns.
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{Dim, Dynamic};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixSlice, Scalar};
+
+/// Concatenates horizontally all the matrices of `matrices`.
+///
+/// All the matrices must have the same number of rows. The result has this number of rows and
+/// as many columns as the sum of the numbers of columns of the concatenated matrices.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{hstack, Matrix2, Matrix2x4};
+/// let a = Matrix2::new(1, 2, 3, 4);
+/// let b = Matrix2::new(5, 6, 7, 8);
+///
+/// assert_eq!(hstack(&[a, b]), Matrix2x4::new(1, 2, 5, 6, 3, 4, 7, 8));
+/// ```
+///
+/// # Panics
+/// Panics if `matrices` is empty or if the matrices do not have the same number of rows.
+pub fn hstack<N, R, C, S>(matrices: &[Matrix<N, R, C, S>]) -> MatrixMN<N, R, Dynamic>
+where
+    N: Scalar,
+    R: Dim,
+    C: Dim,
+    S: Storage<N, R, C>,
+    DefaultAllocator: Allocator<N, R, Dynamic>,
+{
+    assert!(
+        !matrices.is_empty(),
+        "hstack: at least one matrix must be given."
+    );
+
+    let nrows = matrices[0].data.shape().0;
+    assert!(
+        matrices.iter().all(|m| m.nrows() == nrows.value()),
+        "hstack: all the matrices must have the same number of rows."
+    );
+
+    let ncols = matrices.iter().map(|m| m.ncols()).sum();
+    let mut res = unsafe { MatrixMN::new_uninitialized_generic(nrows, Dynamic::new(ncols)) };
+    let mut j = 0;
+
+    for m in matrices {
+        for (k, col) in m.column_iter().enumerate() {
+            res.column_mut(j + k).copy_from(&col);
+        }
+
+        j += m.ncols();
+    }
+
+    res
+}
+
+/// Concatenates vertically all the matrices of `matrices`.
+///
+/// All the matrices must have the same number of columns. The result has this number of columns
+/// and as many rows as the sum of the numbers of rows of the concatenated matrices.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{vstack, Matrix4x2, RowVector2};
+/// let a = RowVector2::new(1, 2);
+/// let b = RowVector2::new(3, 4);
+///
+/// assert_eq!(vstack(&[a, b, b, a]), Matrix4x2::new(1, 2, 3, 4, 3, 4, 1, 2));
+/// ```
+///
+/// # Panics
+/// Panics if `matrices` is empty or if the matrices do not have the same number of columns.
+pub fn vstack<N, R, C, S>(matrices: &[Matrix<N, R, C, S>]) -> MatrixMN<N, Dynamic, C>
+where
+    N: Scalar,
+    R: Dim,
+    C: Dim,
+    S: Storage<N, R, C>,
+    DefaultAllocator: Allocator<N, Dynamic, C>,
+{
+    assert!(
+        !matrices.is_empty(),
+        "vstack: at least one matrix must be given."
+    );
+
+    let ncols = matrices[0].data.shape().1;
+    assert!(
+        matrices.iter().all(|m| m.ncols() == ncols.value()),
+        "vstack: all the matrices must have the same number of columns."
+    );
+
+    let nrows = matrices.iter().map(|m| m.nrows()).sum();
+    let mut res = unsafe { MatrixMN::new_uninitialized_generic(Dynamic::new(nrows), ncols) };
+    let mut i = 0;
+
+    for m in matrices {
+        for (k, row) in m.row_iter().enumerate() {
+            res.row_mut(i + k).copy_from(&row);
+        }
+
+        i += m.nrows();
+    }
+
+    res
+}
+
+// Views any matrix as a slice with dynamic dimensions and strides, so that blocks of different
+// types can be concatenated by the `stack!` macro.
+#[doc(hidden)]
+pub fn stack_block<N, R, C, S>(
+    m: &Matrix<N, R, C, S>,
+) -> MatrixSlice<'_, N, Dynamic, Dynamic, Dynamic, Dynamic>
+where
+    N: Scalar,
+    R: Dim,
+    C: Dim,
+    S: Storage<N, R, C>,
+{
+    m.slice_with_steps((0, 0), m.shape(), (0, 0))
+}
+
+/// Assembles a dynamically-sized matrix from blocks.
+///
+/// The blocks of the same row are separated by commas, and the rows are separated by semicolons.
+/// The blocks may have different types, e.g., statically and dynamically-sized matrices can be
+/// mixed. All the blocks of the same row must have the same number of rows, and all the rows of
+/// blocks must have the same total number of columns.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate nalgebra;
+/// # use nalgebra::{DMatrix, Matrix2, RowVector2, Vector2, Vector1};
+/// # fn main() {
+/// let a = Matrix2::new(1, 2, 3, 4);
+/// let b = Vector2::new(5, 6);
+/// let c = RowVector2::new(7, 8);
+/// let d = Vector1::new(9);
+///
+/// let m = stack![a, b; c, d];
+/// assert_eq!(m, DMatrix::from_row_slice(3, 3, &[1, 2, 5, 3, 4, 6, 7, 8, 9]));
+/// # }
+/// ```
+///
+/// # Panics
+/// Panics if the dimensions of the blocks are not compatible.
+#[macro_export]
+macro_rules! stack {
+    ($($($block: expr),+ $(,)?);+ $(;)?) => {
+        $crate::vstack(&[$($crate::hstack(&[$($crate::stack_block(&$block)),+])),+])
+    };
+}
@@ -1,8 +1,12 @@
 use crate::allocator::Allocator;
 use crate::storage::Storage;
-use crate::{DefaultAllocator, Dim, Matrix, RowVectorN, Scalar, VectorN, VectorSliceN, U1};
+use crate::{
+    DefaultAllocator, Dim, DimDiff, DimSub, Matrix, MatrixMN, RowVectorN, Scalar, VectorN,
+    VectorSliceN, U1,
+};
 use num::Zero;
-use simba::scalar::{ClosedAdd, Field, SupersetOf};
+use simba::scalar::{ClosedAdd, ClosedSub, Field, SupersetOf};
+use simba::simd::SimdPartialOrd;
 
 impl<N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
     /// Returns a row vector where each element is the result of the application of `f` on the
@@ -170,6 +174,152 @@ impl<N: Scalar + ClosedAdd + Zero, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N
     }
 }
 
+impl<N: Scalar, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
+    /*
+     *
+     * Cumulative sums and differences.
+     *
+     */
+    /// The cumulative sums of the rows of this matrix, i.e., the `i`-th row of the result is the
+    /// sum of the first `i + 1` rows of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix3x2;
+    ///
+    /// let m = Matrix3x2::new(1.0, 2.0,
+    ///                        3.0, 4.0,
+    ///                        5.0, 6.0);
+    /// assert_eq!(m.cumsum_rows(), Matrix3x2::new(1.0, 2.0,
+    ///                                            4.0, 6.0,
+    ///                                            9.0, 12.0));
+    /// ```
+    #[inline]
+    pub fn cumsum_rows(&self) -> MatrixMN<N, R, C>
+    where
+        N: ClosedAdd,
+        DefaultAllocator: Allocator<N, R, C>,
+    {
+        let mut res = self.clone_owned();
+
+        for j in 0..res.ncols() {
+            for i in 1..res.nrows() {
+                unsafe {
+                    let prev = res.get_unchecked((i - 1, j)).inlined_clone();
+                    *res.get_unchecked_mut((i, j)) += prev;
+                }
+            }
+        }
+
+        res
+    }
+
+    /// The cumulative sums of the columns of this matrix, i.e., the `j`-th column of the result
+    /// is the sum of the first `j + 1` columns of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// assert_eq!(m.cumsum_columns(), Matrix2x3::new(1.0, 3.0, 6.0,
+    ///                                               4.0, 9.0, 15.0));
+    /// ```
+    #[inline]
+    pub fn cumsum_columns(&self) -> MatrixMN<N, R, C>
+    where
+        N: ClosedAdd,
+        DefaultAllocator: Allocator<N, R, C>,
+    {
+        let mut res = self.clone_owned();
+
+        for j in 1..res.ncols() {
+            for i in 0..res.nrows() {
+                unsafe {
+                    let prev = res.get_unchecked((i, j - 1)).inlined_clone();
+                    *res.get_unchecked_mut((i, j)) += prev;
+                }
+            }
+        }
+
+        res
+    }
+
+    /// The differences between consecutive rows of this matrix, i.e., the `i`-th row of the
+    /// result is `self.row(i + 1) - self.row(i)`.
+    ///
+    /// This is the inverse of `.cumsum_rows()`, up to the first row. Panics if this matrix has no
+    /// row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Matrix3};
+    ///
+    /// let m = Matrix3::new(1.0, 2.0, 3.0,
+    ///                      4.0, 6.0, 8.0,
+    ///                      5.0, 5.0, 5.0);
+    /// assert_eq!(m.diff_rows(), Matrix2x3::new(3.0, 4.0, 5.0,
+    ///                                          1.0, -1.0, -3.0));
+    /// ```
+    #[inline]
+    pub fn diff_rows(&self) -> MatrixMN<N, DimDiff<R, U1>, C>
+    where
+        N: ClosedSub,
+        R: DimSub<U1>,
+        DefaultAllocator: Allocator<N, DimDiff<R, U1>, C>,
+    {
+        let (nrows, ncols) = self.data.shape();
+        assert!(
+            nrows.value() != 0,
+            "Cannot compute the differences between the rows of a matrix without row."
+        );
+
+        MatrixMN::from_fn_generic(nrows.sub(U1), ncols, |i, j| unsafe {
+            self.get_unchecked((i + 1, j)).inlined_clone()
+                - self.get_unchecked((i, j)).inlined_clone()
+        })
+    }
+
+    /// The differences between consecutive columns of this matrix, i.e., the `j`-th column of
+    /// the result is `self.column(j + 1) - self.column(j)`.
+    ///
+    /// This is the inverse of `.cumsum_columns()`, up to the first column. Panics if this matrix
+    /// has no column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Matrix2};
+    ///
+    /// let m = Matrix2x3::new(1.0, 3.0, 6.0,
+    ///                        4.0, 9.0, 15.0);
+    /// assert_eq!(m.diff_columns(), Matrix2::new(2.0, 3.0,
+    ///                                           5.0, 6.0));
+    /// ```
+    #[inline]
+    pub fn diff_columns(&self) -> MatrixMN<N, R, DimDiff<C, U1>>
+    where
+        N: ClosedSub,
+        C: DimSub<U1>,
+        DefaultAllocator: Allocator<N, R, DimDiff<C, U1>>,
+    {
+        let (nrows, ncols) = self.data.shape();
+        assert!(
+            ncols.value() != 0,
+            "Cannot compute the differences between the columns of a matrix without column."
+        );
+
+        MatrixMN::from_fn_generic(nrows, ncols.sub(U1), |i, j| unsafe {
+            self.get_unchecked((i, j + 1)).inlined_clone()
+                - self.get_unchecked((i, j)).inlined_clone()
+        })
+    }
+}
+
 impl<N: Scalar + Field + SupersetOf<f64>, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
     /*
      *
@@ -363,3 +513,151 @@ impl<N: Scalar + Field + SupersetOf<f64>, R: Dim, C: Dim, S: Storage<N, R, C>> M
         })
     }
 }
+
+impl<N: Scalar + SimdPartialOrd + Zero, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
+    /*
+     *
+     * Extrema computation.
+     *
+     */
+    /// The smallest element of each column of this matrix, i.e., the component-wise minimum of
+    /// all the rows of this matrix.
+    ///
+    /// Use `.row_min_tr` if you need the result in a column vector instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, RowVector3};
+    ///
+    /// let m = Matrix2x3::new(1.0, 5.0, 3.0,
+    ///                        4.0, 2.0, 6.0);
+    /// assert_eq!(m.row_min(), RowVector3::new(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn row_min(&self) -> RowVectorN<N, C>
+    where
+        DefaultAllocator: Allocator<N, U1, C>,
+    {
+        self.compress_rows(|col| col.min())
+    }
+
+    /// The smallest element of each column of this matrix. The result is transposed and returned
+    /// as a column vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector3};
+    ///
+    /// let m = Matrix2x3::new(1.0, 5.0, 3.0,
+    ///                        4.0, 2.0, 6.0);
+    /// assert_eq!(m.row_min_tr(), Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn row_min_tr(&self) -> VectorN<N, C>
+    where
+        DefaultAllocator: Allocator<N, C>,
+    {
+        self.compress_rows_tr(|col| col.min())
+    }
+
+    /// The largest element of each column of this matrix, i.e., the component-wise maximum of
+    /// all the rows of this matrix.
+    ///
+    /// Use `.row_max_tr` if you need the result in a column vector instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, RowVector3};
+    ///
+    /// let m = Matrix2x3::new(1.0, 5.0, 3.0,
+    ///                        4.0, 2.0, 6.0);
+    /// assert_eq!(m.row_max(), RowVector3::new(4.0, 5.0, 6.0));
+    /// ```
+    #[inline]
+    pub fn row_max(&self) -> RowVectorN<N, C>
+    where
+        DefaultAllocator: Allocator<N, U1, C>,
+    {
+        self.compress_rows(|col| col.max())
+    }
+
+    /// The largest element of each column of this matrix. The result is transposed and returned
+    /// as a column vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector3};
+    ///
+    /// let m = Matrix2x3::new(1.0, 5.0, 3.0,
+    ///                        4.0, 2.0, 6.0);
+    /// assert_eq!(m.row_max_tr(), Vector3::new(4.0, 5.0, 6.0));
+    /// ```
+    #[inline]
+    pub fn row_max_tr(&self) -> VectorN<N, C>
+    where
+        DefaultAllocator: Allocator<N, C>,
+    {
+        self.compress_rows_tr(|col| col.max())
+    }
+
+    /// The smallest element of each row of this matrix, i.e., the component-wise minimum of all
+    /// the columns of this matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    ///
+    /// let m = Matrix2x3::new(1.0, 5.0, 3.0,
+    ///                        4.0, 2.0, 6.0);
+    /// assert_eq!(m.column_min(), Vector2::new(1.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn column_min(&self) -> VectorN<N, R>
+    where
+        DefaultAllocator: Allocator<N, R>,
+    {
+        self.compress_columns_with(|a, b| a.simd_min(b))
+    }
+
+    /// The largest element of each row of this matrix, i.e., the component-wise maximum of all
+    /// the columns of this matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    ///
+    /// let m = Matrix2x3::new(1.0, 5.0, 3.0,
+    ///                        4.0, 2.0, 6.0);
+    /// assert_eq!(m.column_max(), Vector2::new(5.0, 6.0));
+    /// ```
+    #[inline]
+    pub fn column_max(&self) -> VectorN<N, R>
+    where
+        DefaultAllocator: Allocator<N, R>,
+    {
+        self.compress_columns_with(|a, b| a.simd_max(b))
+    }
+
+    // Folds `f` component-wise on all the columns of this matrix, starting from the first one.
+    // Returns zeros if this matrix has no column, like `.min()` and `.max()`.
+    fn compress_columns_with(&self, f: impl Fn(N, N) -> N) -> VectorN<N, R>
+    where
+        DefaultAllocator: Allocator<N, R>,
+    {
+        let nrows = self.data.shape().0;
+
+        if self.ncols() == 0 {
+            return VectorN::zeros_generic(nrows, U1);
+        }
+
+        self.compress_columns(self.column(0).into_owned(), |out, col| {
+            out.zip_apply(&col, &f)
+        })
+    }
+}
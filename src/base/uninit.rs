@@ -0,0 +1,200 @@
+//! Matrices that are filled column-by-column without being initialized first.
+
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+use std::slice;
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::Dim;
+use crate::base::storage::{Owned, Storage};
+use crate::base::{DefaultAllocator, MatrixMN, Scalar, Vector};
+
+/// A matrix whose components are not initialized yet.
+///
+/// This is created by `MatrixMN::new_uninit` or `MatrixMN::new_uninit_generic`. Its columns are
+/// then written in order with `push_column` or `push_column_from_fn`, and the initialized matrix
+/// is retrieved with `into_init` once all the columns have been written. This avoids the cost of
+/// the zero-initialization of the matrix without any `unsafe` code.
+///
+/// Alternatively, the components of the columns that are not initialized yet can be written
+/// directly through `uninit_slice_mut`, in which case the matrix is retrieved with the `unsafe`
+/// method `assume_init`.
+///
+/// If this is dropped before being completely initialized, only the components of the columns
+/// pushed so far are dropped.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{Matrix2x3, Vector2};
+/// let mut m = Matrix2x3::new_uninit();
+///
+/// m.push_column(&Vector2::new(1, 4));
+/// m.push_column_from_fn(|i| 2 + 3 * i);
+///
+/// // Not all the columns are initialized yet.
+/// assert_eq!(m.ncols_init(), 2);
+///
+/// m.push_column(&Vector2::new(3, 6));
+/// assert_eq!(m.into_init(), Some(Matrix2x3::new(1, 2, 3, 4, 5, 6)));
+/// ```
+pub struct UninitMatrix<N: Scalar, R: Dim, C: Dim>
+where
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    data: MaybeUninit<Owned<N, R, C>>,
+    nrows: R,
+    ncols: C,
+    ncols_init: usize,
+}
+
+impl<N: Scalar, R: Dim, C: Dim> MatrixMN<N, R, C>
+where
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    /// Creates a matrix that is not initialized yet, to be filled column-by-column. If the
+    /// matrix has a compile-time dimension, this panics if `nrows != R::to_usize()` or
+    /// `ncols != C::to_usize()`.
+    #[inline]
+    pub fn new_uninit_generic(nrows: R, ncols: C) -> UninitMatrix<N, R, C> {
+        assert!(
+            R::try_to_usize().map_or(true, |n| n == nrows.value())
+                && C::try_to_usize().map_or(true, |n| n == ncols.value()),
+            "UninitMatrix: dimension mismatch."
+        );
+
+        UninitMatrix {
+            data: DefaultAllocator::allocate_uninit(nrows, ncols),
+            nrows,
+            ncols,
+            ncols_init: 0,
+        }
+    }
+}
+
+impl<N: Scalar, R: Dim, C: Dim> UninitMatrix<N, R, C>
+where
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    /// The number of rows of this matrix.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows.value()
+    }
+
+    /// The number of columns of this matrix.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols.value()
+    }
+
+    /// The number of columns initialized so far.
+    #[inline]
+    pub fn ncols_init(&self) -> usize {
+        self.ncols_init
+    }
+
+    /// Whether all the columns of this matrix have been initialized.
+    #[inline]
+    pub fn is_init(&self) -> bool {
+        self.ncols_init == self.ncols()
+    }
+
+    /// Initializes the next column of this matrix with the components of `column`.
+    ///
+    /// # Panics
+    /// Panics if all the columns are already initialized, or if `column` does not have as many
+    /// components as this matrix has rows.
+    #[inline]
+    pub fn push_column<R2: Dim, S2: Storage<N, R2>>(&mut self, column: &Vector<N, R2, S2>) {
+        assert_eq!(
+            column.len(),
+            self.nrows(),
+            "UninitMatrix::push_column: dimension mismatch."
+        );
+
+        self.push_column_from_fn(|i| unsafe { column.vget_unchecked(i).inlined_clone() })
+    }
+
+    /// Initializes the next column of this matrix with the components `f(i)` where `i` is the
+    /// row index.
+    ///
+    /// # Panics
+    /// Panics if all the columns are already initialized.
+    #[inline]
+    pub fn push_column_from_fn(&mut self, mut f: impl FnMut(usize) -> N) {
+        assert!(
+            !self.is_init(),
+            "UninitMatrix::push_column: all the columns are already initialized."
+        );
+
+        let nrows = self.nrows();
+        let column = unsafe { self.ptr_mut().add(self.ncols_init * nrows) };
+
+        for i in 0..nrows {
+            // If `f` panics, the components written so far are leaked, but not dropped.
+            unsafe { ptr::write(column.add(i), f(i)) }
+        }
+
+        self.ncols_init += 1;
+    }
+
+    /// The components of all the columns that are not initialized yet, in column-major order.
+    #[inline]
+    pub fn uninit_slice_mut(&mut self) -> &mut [MaybeUninit<N>] {
+        let nrows = self.nrows();
+        let offset = self.ncols_init * nrows;
+        let len = (self.ncols() - self.ncols_init) * nrows;
+
+        unsafe {
+            let ptr = self.ptr_mut().add(offset) as *mut MaybeUninit<N>;
+            slice::from_raw_parts_mut(ptr, len)
+        }
+    }
+
+    /// Retrieves the initialized matrix, or `None` if not all its columns have been pushed.
+    ///
+    /// Components written through `uninit_slice_mut` are not taken into account, use
+    /// `assume_init` instead in this case.
+    #[inline]
+    pub fn into_init(self) -> Option<MatrixMN<N, R, C>> {
+        if self.is_init() {
+            Some(unsafe { self.assume_init() })
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves the matrix, assuming all its components are initialized.
+    ///
+    /// # Safety
+    /// All the components of the columns that were not pushed must have been written through
+    /// `uninit_slice_mut`.
+    #[inline]
+    pub unsafe fn assume_init(self) -> MatrixMN<N, R, C> {
+        let this = mem::ManuallyDrop::new(self);
+        let data = ptr::read(&this.data);
+        MatrixMN::from_data(DefaultAllocator::assume_init(data))
+    }
+
+    #[inline]
+    fn ptr_mut(&mut self) -> *mut N {
+        DefaultAllocator::uninit_ptr_mut(&mut self.data)
+    }
+}
+
+impl<N: Scalar, R: Dim, C: Dim> Drop for UninitMatrix<N, R, C>
+where
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    fn drop(&mut self) {
+        let len = self.ncols_init * self.nrows();
+
+        unsafe {
+            let init = slice::from_raw_parts_mut(self.ptr_mut(), len);
+            ptr::drop_in_place(init);
+
+            let data = ptr::read(&self.data);
+            DefaultAllocator::deallocate_uninit(data)
+        }
+    }
+}
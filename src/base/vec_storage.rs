@@ -46,6 +46,19 @@ impl<N, R: Dim, C: Dim> VecStorage<N, R, C> {
         Self { data, nrows, ncols }
     }
 
+    /// Creates a data storage with an empty `Vec` that can hold `nrows * ncols` components.
+    ///
+    /// This breaks the invariants of this data storage until the length of the `Vec` is set, so
+    /// this is only used by `Allocator::allocate_uninit`.
+    #[inline]
+    pub(crate) fn with_capacity_uninit(nrows: R, ncols: C) -> Self {
+        Self {
+            data: Vec::with_capacity(nrows.value() * ncols.value()),
+            nrows,
+            ncols,
+        }
+    }
+
     /// The underlying data storage.
     #[inline]
     pub fn as_vec(&self) -> &Vec<N> {
@@ -0,0 +1,843 @@
+//! Dual numbers for forward-mode automatic differentiation.
+//!
+//! A dual number `re + eps ε`, where `ε² = 0`, carries the value of a real number together with
+//! its derivative with respect to some variable. Evaluating a function on dual numbers computes its
+//! derivative along with its value, to the precision of the underlying real numbers. Because
+//! `Dual<N>` implements `RealField`, this works through any generic function of this crate, e.g.,
+//! matrix products, decompositions, or geometric transformations, see `jacobian`.
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num::{Bounded, FromPrimitive, Num, One, Signed, Zero};
+use simba::scalar::{ComplexField, Field, RealField, SubsetOf};
+use simba::simd::SimdValue;
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::Dim;
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, MatrixMN, VectorN};
+
+/// A dual number `re + eps ε` where `ε² = 0`.
+///
+/// The comparison operators, including `==`, and the `approx` traits only consider the real parts
+/// `re`, so that the control flow of a function evaluated on dual numbers is the same as on real
+/// numbers.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{ComplexField, Dual};
+/// // The derivative of `x * sin(x)` at `x = 2` is `sin(2) + 2 cos(2)`.
+/// let x = Dual::variable(2.0f64);
+/// let y = x * x.sin();
+///
+/// assert_eq!(y.re, 2.0 * 2.0f64.sin());
+/// assert_eq!(y.eps, 2.0f64.sin() + 2.0 * 2.0f64.cos());
+/// ```
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Dual<N> {
+    /// The real part of this dual number, i.e., the value.
+    pub re: N,
+    /// The dual part of this dual number, i.e., the derivative.
+    pub eps: N,
+}
+
+impl<N> Dual<N> {
+    /// Creates the dual number `re + eps ε`.
+    #[inline]
+    pub const fn new(re: N, eps: N) -> Self {
+        Dual { re, eps }
+    }
+}
+
+impl<N: RealField> Dual<N> {
+    /// Creates a constant, i.e., a dual number with a zero derivative.
+    #[inline]
+    pub fn constant(re: N) -> Self {
+        Dual::new(re, N::zero())
+    }
+
+    /// Creates a variable, i.e., a dual number with a derivative equal to one.
+    #[inline]
+    pub fn variable(re: N) -> Self {
+        Dual::new(re, N::one())
+    }
+
+    /// Applies the function `f` with the derivative `df` evaluated at `self.re`.
+    #[inline]
+    fn chain(self, f: N, df: N) -> Self {
+        Dual::new(f, df * self.eps)
+    }
+}
+
+/// Computes the Jacobian matrix of `f` at `x` by forward-mode automatic differentiation.
+///
+/// The function `f` is evaluated once per component of `x`, on dual numbers whose dual parts
+/// select that component. The column `j` of the result is the derivative of `f` with respect to
+/// the component `j` of `x`.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{dual, Dual, Matrix3x2, Vector1, Vector2, Vector3};
+/// let a = Matrix3x2::new(1.0, 2.0,
+///                        3.0, 4.0,
+///                        5.0, 6.0);
+///
+/// // The Jacobian of `x -> a * x` is `a`.
+/// let j = dual::jacobian(|x: &Vector2<Dual<f64>>| a.map(Dual::constant) * x, &Vector2::new(0.5, -1.0));
+/// assert_eq!(j, a);
+///
+/// // The Jacobian of `x -> x.norm_squared()` is `2 * x^T`.
+/// let x = Vector3::new(1.0, 2.0, 3.0);
+/// let j = dual::jacobian(|x: &Vector3<Dual<f64>>| Vector1::new(x.norm_squared()), &x);
+/// assert_eq!(j, 2.0 * x.transpose());
+/// ```
+pub fn jacobian<N, D1, D2, F>(f: F, x: &VectorN<N, D1>) -> MatrixMN<N, D2, D1>
+where
+    N: RealField,
+    D1: Dim,
+    D2: Dim,
+    F: Fn(&VectorN<Dual<N>, D1>) -> VectorN<Dual<N>, D2>,
+    DefaultAllocator:
+        Allocator<N, D1> + Allocator<Dual<N>, D1> + Allocator<Dual<N>, D2> + Allocator<N, D2, D1>,
+{
+    let mut dx = x.map(Dual::constant);
+    let eval_column = |dx: &mut VectorN<Dual<N>, D1>, j: usize| {
+        dx[j].eps = N::one();
+        let column = f(dx);
+        dx[j].eps = N::zero();
+        column
+    };
+
+    let (ncols, _) = x.data.shape();
+
+    if ncols.value() == 0 {
+        let (nrows, _) = f(&dx).data.shape();
+        return MatrixMN::zeros_generic(nrows, ncols);
+    }
+
+    let first = eval_column(&mut dx, 0);
+    let (nrows, _) = first.data.shape();
+    let mut res = MatrixMN::zeros_generic(nrows, ncols);
+    let mut set_column = |j: usize, column: &VectorN<Dual<N>, D2>| {
+        assert_eq!(
+            column.len(),
+            nrows.value(),
+            "jacobian: the output of the function must have a constant dimension."
+        );
+
+        for (out, e) in res.column_mut(j).iter_mut().zip(column.iter()) {
+            *out = e.eps;
+        }
+    };
+
+    set_column(0, &first);
+
+    for j in 1..ncols.value() {
+        set_column(j, &eval_column(&mut dx, j));
+    }
+
+    res
+}
+
+/*
+ *
+ * Arithmetic.
+ *
+ */
+impl<N: RealField> Neg for Dual<N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Dual::new(-self.re, -self.eps)
+    }
+}
+
+impl<N: RealField> Add for Dual<N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Dual::new(self.re + rhs.re, self.eps + rhs.eps)
+    }
+}
+
+impl<N: RealField> Sub for Dual<N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Dual::new(self.re - rhs.re, self.eps - rhs.eps)
+    }
+}
+
+impl<N: RealField> Mul for Dual<N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Dual::new(self.re * rhs.re, self.re * rhs.eps + self.eps * rhs.re)
+    }
+}
+
+impl<N: RealField> Div for Dual<N> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        let re = self.re / rhs.re;
+        Dual::new(re, (self.eps - re * rhs.eps) / rhs.re)
+    }
+}
+
+impl<N: RealField> Rem for Dual<N> {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        // `a % b = a - b * trunc(a / b)` where the truncation is locally constant.
+        let quotient = (self.re / rhs.re).trunc();
+        Dual::new(self.re % rhs.re, self.eps - rhs.eps * quotient)
+    }
+}
+
+macro_rules! impl_op_assign(
+    ($($Trait: ident, $method: ident, $op: tt);* $(;)*) => {$(
+        impl<N: RealField> $Trait for Dual<N> {
+            #[inline]
+            fn $method(&mut self, rhs: Self) {
+                *self = *self $op rhs
+            }
+        }
+    )*}
+);
+
+impl_op_assign!(
+    AddAssign, add_assign, +;
+    SubAssign, sub_assign, -;
+    MulAssign, mul_assign, *;
+    DivAssign, div_assign, /;
+    RemAssign, rem_assign, %;
+);
+
+/*
+ *
+ * Comparison.
+ *
+ */
+impl<N: RealField> PartialEq for Dual<N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.re == other.re
+    }
+}
+
+impl<N: RealField> PartialOrd for Dual<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.re.partial_cmp(&other.re)
+    }
+}
+
+impl<N: RealField> AbsDiffEq for Dual<N> {
+    type Epsilon = Self;
+
+    #[inline]
+    fn default_epsilon() -> Self {
+        Dual::constant(N::default_epsilon())
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self) -> bool {
+        self.re.abs_diff_eq(&other.re, epsilon.re)
+    }
+}
+
+impl<N: RealField> RelativeEq for Dual<N> {
+    #[inline]
+    fn default_max_relative() -> Self {
+        Dual::constant(N::default_max_relative())
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: Self, max_relative: Self) -> bool {
+        self.re.relative_eq(&other.re, epsilon.re, max_relative.re)
+    }
+}
+
+impl<N: RealField> UlpsEq for Dual<N> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        N::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self, max_ulps: u32) -> bool {
+        self.re.ulps_eq(&other.re, epsilon.re, max_ulps)
+    }
+}
+
+impl<N: RealField> fmt::Display for Dual<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.eps >= N::zero() {
+            write!(f, "{} + {}ε", self.re, self.eps)
+        } else {
+            write!(f, "{} - {}ε", self.re, -self.eps)
+        }
+    }
+}
+
+/*
+ *
+ * Num traits.
+ *
+ */
+impl<N: RealField> Zero for Dual<N> {
+    #[inline]
+    fn zero() -> Self {
+        Dual::constant(N::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.re.is_zero()
+    }
+}
+
+impl<N: RealField> One for Dual<N> {
+    #[inline]
+    fn one() -> Self {
+        Dual::constant(N::one())
+    }
+}
+
+impl<N: RealField> Num for Dual<N> {
+    type FromStrRadixErr = N::FromStrRadixErr;
+
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        N::from_str_radix(s, radix).map(Dual::constant)
+    }
+}
+
+impl<N: RealField> Signed for Dual<N> {
+    #[inline]
+    fn abs(&self) -> Self {
+        ComplexField::abs(*self)
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {
+            Self::zero()
+        } else {
+            *self - *other
+        }
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        Dual::constant(Signed::signum(&self.re))
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        self.re.is_positive()
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        self.re.is_negative()
+    }
+}
+
+impl<N: RealField> Bounded for Dual<N> {
+    #[inline]
+    fn min_value() -> Self {
+        Dual::constant(N::min_value())
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Dual::constant(N::max_value())
+    }
+}
+
+impl<N: RealField> FromPrimitive for Dual<N> {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        N::from_i64(n).map(Dual::constant)
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        N::from_u64(n).map(Dual::constant)
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        N::from_f64(n).map(Dual::constant)
+    }
+}
+
+/*
+ *
+ * Simba traits.
+ *
+ */
+impl<N: RealField> SimdValue for Dual<N> {
+    type Element = Self;
+    type SimdBool = bool;
+
+    #[inline(always)]
+    fn lanes() -> usize {
+        1
+    }
+
+    #[inline(always)]
+    fn splat(val: Self) -> Self {
+        val
+    }
+
+    #[inline(always)]
+    fn extract(&self, _: usize) -> Self {
+        *self
+    }
+
+    #[inline(always)]
+    unsafe fn extract_unchecked(&self, _: usize) -> Self {
+        *self
+    }
+
+    #[inline(always)]
+    fn replace(&mut self, _: usize, val: Self) {
+        *self = val
+    }
+
+    #[inline(always)]
+    unsafe fn replace_unchecked(&mut self, _: usize, val: Self) {
+        *self = val
+    }
+
+    #[inline(always)]
+    fn select(self, cond: bool, other: Self) -> Self {
+        if cond {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+impl<N: RealField> Field for Dual<N> {}
+
+impl<N: RealField> SubsetOf<Dual<N>> for Dual<N> {
+    #[inline]
+    fn to_superset(&self) -> Self {
+        *self
+    }
+
+    #[inline]
+    fn from_superset_unchecked(element: &Self) -> Self {
+        *element
+    }
+
+    #[inline]
+    fn is_in_subset(_: &Self) -> bool {
+        true
+    }
+}
+
+impl<N: RealField> SubsetOf<Dual<N>> for f64 {
+    #[inline]
+    fn to_superset(&self) -> Dual<N> {
+        Dual::constant(crate::convert(*self))
+    }
+
+    #[inline]
+    fn from_superset_unchecked(element: &Dual<N>) -> Self {
+        crate::convert_unchecked(element.re)
+    }
+
+    #[inline]
+    fn is_in_subset(element: &Dual<N>) -> bool {
+        element.eps.is_zero() && crate::is_convertible::<_, f64>(&element.re)
+    }
+}
+
+macro_rules! impl_constants(
+    ($($name: ident),*) => {
+        $(
+            #[inline]
+            fn $name() -> Self {
+                Dual::constant(N::$name())
+            }
+        )*
+    }
+);
+
+impl<N: RealField> ComplexField for Dual<N> {
+    type RealField = Self;
+
+    #[inline]
+    fn from_real(re: Self) -> Self {
+        re
+    }
+
+    #[inline]
+    fn real(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn imaginary(self) -> Self {
+        Self::zero()
+    }
+
+    #[inline]
+    fn modulus(self) -> Self {
+        ComplexField::abs(self)
+    }
+
+    #[inline]
+    fn modulus_squared(self) -> Self {
+        self * self
+    }
+
+    #[inline]
+    fn argument(self) -> Self {
+        if self >= Self::zero() {
+            Self::zero()
+        } else {
+            Self::pi()
+        }
+    }
+
+    #[inline]
+    fn norm1(self) -> Self {
+        ComplexField::abs(self)
+    }
+
+    #[inline]
+    fn scale(self, factor: Self) -> Self {
+        self * factor
+    }
+
+    #[inline]
+    fn unscale(self, factor: Self) -> Self {
+        self / factor
+    }
+
+    #[inline]
+    fn floor(self) -> Self {
+        Dual::constant(self.re.floor())
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        Dual::constant(self.re.ceil())
+    }
+
+    #[inline]
+    fn round(self) -> Self {
+        Dual::constant(self.re.round())
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        Dual::constant(self.re.trunc())
+    }
+
+    #[inline]
+    fn fract(self) -> Self {
+        Dual::new(self.re.fract(), self.eps)
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        if self.re.is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn hypot(self, other: Self) -> Self {
+        let re = self.re.hypot(other.re);
+
+        if re.is_zero() {
+            Dual::new(re, self.eps.hypot(other.eps))
+        } else {
+            Dual::new(re, (self.re * self.eps + other.re * other.eps) / re)
+        }
+    }
+
+    #[inline]
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+
+    #[inline]
+    fn conjugate(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        let (sin, cos) = self.re.sin_cos();
+        self.chain(sin, cos)
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        let (sin, cos) = self.re.sin_cos();
+        self.chain(cos, -sin)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.re.sin_cos();
+        (self.chain(sin, cos), self.chain(cos, -sin))
+    }
+
+    #[inline]
+    fn tan(self) -> Self {
+        let tan = self.re.tan();
+        self.chain(tan, N::one() + tan * tan)
+    }
+
+    #[inline]
+    fn asin(self) -> Self {
+        let df = (N::one() - self.re * self.re).sqrt().recip();
+        self.chain(self.re.asin(), df)
+    }
+
+    #[inline]
+    fn acos(self) -> Self {
+        let df = -(N::one() - self.re * self.re).sqrt().recip();
+        self.chain(self.re.acos(), df)
+    }
+
+    #[inline]
+    fn atan(self) -> Self {
+        let df = (N::one() + self.re * self.re).recip();
+        self.chain(self.re.atan(), df)
+    }
+
+    #[inline]
+    fn sinh(self) -> Self {
+        self.chain(self.re.sinh(), self.re.cosh())
+    }
+
+    #[inline]
+    fn cosh(self) -> Self {
+        self.chain(self.re.cosh(), self.re.sinh())
+    }
+
+    #[inline]
+    fn tanh(self) -> Self {
+        let tanh = self.re.tanh();
+        self.chain(tanh, N::one() - tanh * tanh)
+    }
+
+    #[inline]
+    fn asinh(self) -> Self {
+        let df = (self.re * self.re + N::one()).sqrt().recip();
+        self.chain(self.re.asinh(), df)
+    }
+
+    #[inline]
+    fn acosh(self) -> Self {
+        let df = (self.re * self.re - N::one()).sqrt().recip();
+        self.chain(self.re.acosh(), df)
+    }
+
+    #[inline]
+    fn atanh(self) -> Self {
+        let df = (N::one() - self.re * self.re).recip();
+        self.chain(self.re.atanh(), df)
+    }
+
+    #[inline]
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    #[inline]
+    fn log2(self) -> Self {
+        self.chain(self.re.log2(), (self.re * N::ln_2()).recip())
+    }
+
+    #[inline]
+    fn log10(self) -> Self {
+        self.chain(self.re.log10(), (self.re * N::ln_10()).recip())
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        self.chain(self.re.ln(), self.re.recip())
+    }
+
+    #[inline]
+    fn ln_1p(self) -> Self {
+        self.chain(self.re.ln_1p(), (N::one() + self.re).recip())
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        let sqrt = self.re.sqrt();
+        self.chain(sqrt, (sqrt + sqrt).recip())
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        let exp = self.re.exp();
+        self.chain(exp, exp)
+    }
+
+    #[inline]
+    fn exp2(self) -> Self {
+        let exp2 = self.re.exp2();
+        self.chain(exp2, exp2 * N::ln_2())
+    }
+
+    #[inline]
+    fn exp_m1(self) -> Self {
+        self.chain(self.re.exp_m1(), self.re.exp())
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        let df = if n == 0 {
+            N::zero()
+        } else {
+            crate::convert::<f64, N>(n as f64) * self.re.powi(n - 1)
+        };
+
+        self.chain(self.re.powi(n), df)
+    }
+
+    #[inline]
+    fn powf(self, n: Self) -> Self {
+        let re = self.re.powf(n.re);
+        let mut eps = if n.re.is_zero() {
+            N::zero()
+        } else {
+            n.re * self.re.powf(n.re - N::one()) * self.eps
+        };
+
+        // Avoid `0 * ln(0)` if the exponent is constant.
+        if !n.eps.is_zero() {
+            eps += re * self.re.ln() * n.eps;
+        }
+
+        Dual::new(re, eps)
+    }
+
+    #[inline]
+    fn powc(self, n: Self) -> Self {
+        self.powf(n)
+    }
+
+    #[inline]
+    fn cbrt(self) -> Self {
+        let cbrt = self.re.cbrt();
+        let df = (crate::convert::<f64, N>(3.0) * cbrt * cbrt).recip();
+        self.chain(cbrt, df)
+    }
+
+    #[inline]
+    fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.eps.is_finite()
+    }
+
+    #[inline]
+    fn try_sqrt(self) -> Option<Self> {
+        if self.re >= N::zero() {
+            Some(self.sqrt())
+        } else {
+            None
+        }
+    }
+}
+
+impl<N: RealField> RealField for Dual<N> {
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        self.re.is_sign_positive()
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        self.re.is_sign_negative()
+    }
+
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        if self.re.is_sign_negative() == sign.re.is_sign_negative() {
+            self
+        } else {
+            -self
+        }
+    }
+
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn clamp(self, min: Self, max: Self) -> Self {
+        RealField::min(RealField::max(self, min), max)
+    }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        let re = self.re.atan2(other.re);
+        let denom = self.re * self.re + other.re * other.re;
+        let eps = (other.re * self.eps - self.re * other.eps) / denom;
+        Dual::new(re, eps)
+    }
+
+    impl_constants!(
+        pi,
+        two_pi,
+        frac_pi_2,
+        frac_pi_3,
+        frac_pi_4,
+        frac_pi_6,
+        frac_pi_8,
+        frac_1_pi,
+        frac_2_pi,
+        frac_2_sqrt_pi,
+        e,
+        log2_e,
+        log10_e,
+        ln_2,
+        ln_10
+    );
+}
@@ -355,6 +355,53 @@ where
     }
 }
 
+impl<N: Scalar, D: DimName> Point<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    /// Compares the coordinates of `self` and `other` lexicographically.
+    ///
+    /// Unlike the component-wise partial order implemented by `PartialOrd`, this is a total order
+    /// that can be used to sort points, e.g., for sweep-line algorithms. To use points as keys of
+    /// a `BTreeMap`, convert them into `OrderedMatrix`, which orders them lexicographically.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Point2;
+    /// let mut points = vec![Point2::new(2, 1), Point2::new(1, 3), Point2::new(1, 2)];
+    /// points.sort_by(|a, b| a.lexicographic_cmp(b));
+    ///
+    /// assert_eq!(points, vec![Point2::new(1, 2), Point2::new(1, 3), Point2::new(2, 1)]);
+    /// ```
+    #[inline]
+    pub fn lexicographic_cmp(&self, other: &Self) -> Ordering
+    where
+        N: Ord,
+    {
+        self.coords.lexicographic_cmp(&other.coords)
+    }
+
+    /// Compares the coordinates of `self` and `other` lexicographically, or returns `None` if a
+    /// pair of coordinates that cannot be compared is reached before the first pair of different
+    /// coordinates.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Point2;
+    /// let mut points = vec![Point2::new(0.5, 1.0), Point2::new(-1.0, 3.0), Point2::new(0.5, -2.0)];
+    /// points.sort_by(|a, b| a.lexicographic_partial_cmp(b).unwrap());
+    ///
+    /// assert_eq!(points, vec![Point2::new(-1.0, 3.0), Point2::new(0.5, -2.0), Point2::new(0.5, 1.0)]);
+    /// ```
+    #[inline]
+    pub fn lexicographic_partial_cmp(&self, other: &Self) -> Option<Ordering>
+    where
+        N: PartialOrd,
+    {
+        self.coords.lexicographic_partial_cmp(&other.coords)
+    }
+}
+
 /*
  * inf/sup
  */
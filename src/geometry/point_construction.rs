@@ -167,13 +167,8 @@ macro_rules! componentwise_constructors_impl(
             #[doc = $doc]
             #[doc = "```"]
             #[inline]
-            pub fn new($($args: N),*) -> Self {
-                unsafe {
-                    let mut res = Self::new_uninitialized();
-                    $( *res.get_unchecked_mut($irow) = $args; )*
-
-                    res
-                }
+            pub const fn new($($args: N),*) -> Self {
+                Self { coords: VectorN::<N, $D>::new($($args),*) }
             }
         }
     )*}
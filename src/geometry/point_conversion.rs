@@ -4,7 +4,8 @@ use simba::simd::PrimitiveSimdValue;
 
 use crate::base::allocator::Allocator;
 use crate::base::dimension::{DimName, DimNameAdd, DimNameSum, U1};
-use crate::base::{DefaultAllocator, Matrix, Scalar, VectorN};
+use crate::base::storage::Owned;
+use crate::base::{DefaultAllocator, Matrix, OrderedMatrix, Scalar, VectorN};
 
 #[cfg(feature = "mint")]
 use crate::base::dimension::{U2, U3};
@@ -21,6 +22,7 @@ use std::convert::{AsMut, AsRef, From, Into};
  *
  * Point -> Point
  * Point -> Vector (homogeneous)
+ * Point -> OrderedMatrix
  *
  * mint::Point <-> Point
  */
@@ -152,6 +154,16 @@ where
     }
 }
 
+impl<N: Scalar, D: DimName> From<Point<N, D>> for OrderedMatrix<N, D, U1, Owned<N, D>>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    #[inline]
+    fn from(p: Point<N, D>) -> Self {
+        OrderedMatrix(p.coords)
+    }
+}
+
 impl<N: Scalar + Copy + PrimitiveSimdValue, D: DimName> From<[Point<N::Element, D>; 2]>
     for Point<N, D>
 where
@@ -42,8 +42,10 @@ impl<N: Scalar + SimdValue> Quaternion<N> {
     /// assert_eq!(*q.as_vector(), Vector4::new(2.0, 3.0, 4.0, 1.0));
     /// ```
     #[inline]
-    pub fn new(w: N, i: N, j: N, k: N) -> Self {
-        Self::from(Vector4::new(i, j, k, w))
+    pub const fn new(w: N, i: N, j: N, k: N) -> Self {
+        Self {
+            coords: Vector4::new(i, j, k, w),
+        }
     }
 }
 
@@ -237,6 +237,23 @@ impl<N: SimdRealField> Rotation2<N> {
         Vector1::new(self.angle())
     }
 
+    /// The matrix logarithm of this rotation, i.e., the skew-symmetric matrix `m` such that
+    /// `m.exp()` is this rotation matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2, Rotation2};
+    /// let rot = Rotation2::new(0.78);
+    /// assert_relative_eq!(rot.log(), Matrix2::new(0.0, -0.78, 0.78, 0.0));
+    /// assert_relative_eq!(rot.log().exp(), *rot.matrix(), epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn log(&self) -> Matrix2<N> {
+        let angle = self.angle();
+        Matrix2::new(N::zero(), -angle, angle, N::zero())
+    }
+
     /// Spherical linear interpolation between two rotation matrices.
     ///
     /// # Examples:
@@ -795,6 +812,28 @@ where
         }
     }
 
+    /// The matrix logarithm of this rotation, i.e., the skew-symmetric matrix `m` such that
+    /// `m.exp()` is this rotation matrix.
+    ///
+    /// This is the cross-product matrix of the rotation axis multiplied by the rotation angle.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let axisangle = Vector3::new(0.1, 0.2, 0.3);
+    /// let rot = Rotation3::new(axisangle);
+    /// assert_relative_eq!(rot.log(), axisangle.cross_matrix(), epsilon = 1.0e-6);
+    /// assert_relative_eq!(rot.log().exp(), *rot.matrix(), epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn log(&self) -> Matrix3<N>
+    where
+        N: RealField,
+    {
+        self.scaled_axis().cross_matrix()
+    }
+
     /// The rotation axis and angle in ]0, pi] of this unit quaternion.
     ///
     /// Returns `None` if the angle is zero.
@@ -87,8 +87,8 @@ macro_rules! componentwise_constructors_impl(
             #[doc = $doc]
             #[doc = "```"]
             #[inline]
-            pub fn new($($args: N),*) -> Self {
-                Self::from(VectorN::<N, $D>::new($($args),*))
+            pub const fn new($($args: N),*) -> Self {
+                Self { vector: VectorN::<N, $D>::new($($args),*) }
             }
         }
     )*}
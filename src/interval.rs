@@ -0,0 +1,385 @@
+//! Interval arithmetic for validated numerics.
+//!
+//! An `Interval<N>` is a closed interval `[lo, hi]` of real numbers. All the arithmetic operations
+//! round their bounds outward, so the result of an operation is guaranteed to contain the exact
+//! result of the same operation applied to any numbers contained in the operands. Because
+//! intervals implement `Zero`, `One` and the closed arithmetic traits, matrices of intervals can be
+//! added and multiplied like any other matrices, which yields rigorous enclosures of the exact
+//! results, see `verified_solve`.
+
+use num::{One, Zero};
+use simba::scalar::RealField;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::DimMin;
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, Matrix, MatrixN, Vector, VectorN};
+
+/// A floating-point type that can be used as the bounds of an `Interval`.
+pub trait IntervalBound: RealField {
+    /// The smallest representable number greater than `self`.
+    fn next_up(self) -> Self;
+
+    /// The greatest representable number smaller than `self`.
+    fn next_down(self) -> Self;
+}
+
+macro_rules! impl_interval_bound(
+    ($($T: ident),*) => {$(
+        impl IntervalBound for $T {
+            #[inline]
+            fn next_up(self) -> Self {
+                if self.is_nan() || self == $T::INFINITY {
+                    self
+                } else if self == 0.0 {
+                    $T::from_bits(1)
+                } else if self > 0.0 {
+                    $T::from_bits(self.to_bits() + 1)
+                } else {
+                    $T::from_bits(self.to_bits() - 1)
+                }
+            }
+
+            #[inline]
+            fn next_down(self) -> Self {
+                -IntervalBound::next_up(-self)
+            }
+        }
+    )*}
+);
+
+impl_interval_bound!(f32, f64);
+
+/// A closed interval `[lo, hi]` of real numbers, with outward-rounded arithmetic.
+///
+/// The division by an interval containing zero results in the interval `[-∞, +∞]`.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{Interval, Matrix2, Vector2};
+/// let tenth = Interval::point(1.0) / Interval::point(10.0);
+///
+/// // `0.1` is not representable, so the result is a tiny interval that contains it.
+/// assert!(tenth.lo() < tenth.hi());
+/// assert!(tenth.contains(0.1));
+///
+/// // The product of interval matrices encloses the exact product.
+/// let m = Matrix2::new(1.0, 0.1, 0.2, 1.0).map(Interval::point);
+/// let v = Vector2::new(3.0, 0.3).map(Interval::point);
+/// assert!((m * v)[0].contains(1.0 * 3.0 + 0.1 * 0.3));
+/// ```
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Interval<N> {
+    lo: N,
+    hi: N,
+}
+
+impl<N: IntervalBound> Interval<N> {
+    /// Creates the interval `[lo, hi]`.
+    ///
+    /// # Panics
+    /// Panics if `lo > hi`, or if `lo` or `hi` is NaN.
+    #[inline]
+    pub fn new(lo: N, hi: N) -> Self {
+        assert!(lo <= hi, "Interval::new: the bounds must be ordered.");
+        Interval { lo, hi }
+    }
+
+    /// Creates the interval `[x, x]` that contains only `x`.
+    #[inline]
+    pub fn point(x: N) -> Self {
+        Self::new(x, x)
+    }
+
+    /// Creates the interval `[center - radius, center + radius]`, rounded outward.
+    #[inline]
+    pub fn from_radius(center: N, radius: N) -> Self {
+        let radius = radius.abs();
+        Interval {
+            lo: (center - radius).next_down(),
+            hi: (center + radius).next_up(),
+        }
+    }
+
+    /// The interval `[-∞, +∞]` containing all the real numbers.
+    #[inline]
+    pub fn entire() -> Self {
+        Interval {
+            lo: -N::max_value().next_up(),
+            hi: N::max_value().next_up(),
+        }
+    }
+
+    /// The lower bound of this interval.
+    #[inline]
+    pub fn lo(&self) -> N {
+        self.lo
+    }
+
+    /// The upper bound of this interval.
+    #[inline]
+    pub fn hi(&self) -> N {
+        self.hi
+    }
+
+    /// The midpoint of this interval, rounded to nearest.
+    #[inline]
+    pub fn mid(&self) -> N {
+        let two: N = crate::convert(2.0);
+        self.lo / two + self.hi / two
+    }
+
+    /// An upper bound of the width `hi - lo` of this interval.
+    #[inline]
+    pub fn width(&self) -> N {
+        (self.hi - self.lo).next_up()
+    }
+
+    /// The magnitude of this interval, i.e., the greatest absolute value of the numbers it
+    /// contains.
+    #[inline]
+    pub fn mag(&self) -> N {
+        self.lo.abs().max(self.hi.abs())
+    }
+
+    /// Whether `x` is contained in this interval.
+    #[inline]
+    pub fn contains(&self, x: N) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Whether this interval is contained in `other`.
+    #[inline]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        other.lo <= self.lo && self.hi <= other.hi
+    }
+
+    /// The smallest interval containing both `self` and `other`.
+    #[inline]
+    pub fn hull(&self, other: &Self) -> Self {
+        Interval {
+            lo: self.lo.min(other.lo),
+            hi: self.hi.max(other.hi),
+        }
+    }
+
+    /// The intersection of `self` and `other`, or `None` if they are disjoint.
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let lo = self.lo.max(other.lo);
+        let hi = self.hi.min(other.hi);
+
+        if lo <= hi {
+            Some(Interval { lo, hi })
+        } else {
+            None
+        }
+    }
+
+    /// The smallest interval containing the four given bounds, rounded outward.
+    #[inline]
+    fn enclose(a: N, b: N, c: N, d: N) -> Self {
+        Interval {
+            lo: a.min(b).min(c.min(d)).next_down(),
+            hi: a.max(b).max(c.max(d)).next_up(),
+        }
+    }
+}
+
+/// Computes a rigorous enclosure of the solution of the linear system `a * x = b`.
+///
+/// An approximate solution `x̃` and an approximate inverse `R` of `a` are computed with
+/// floating-point arithmetic first. If the infinity norm `β` of `I - R a`, computed with interval
+/// arithmetic, is smaller than one, then `a` is invertible and the exact solution satisfies
+/// `|x - x̃| ≤ |R (b - a x̃)| / (1 - β)` component-wise in the infinity norm, which is returned as a
+/// vector of intervals. Otherwise, e.g., if `a` is singular or too ill-conditioned, this returns
+/// `None`.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{interval, Matrix3, Vector3};
+/// let a = Matrix3::new(4.0, 1.0, 0.0,
+///                      1.0, 3.0, 1.0,
+///                      0.0, 1.0, 2.0);
+/// let b = Vector3::new(6.0, 6.0, 0.0);
+///
+/// // The exact solution is `(1, 2, -1)`.
+/// let x = interval::verified_solve(&a, &b).unwrap();
+/// assert!(x[0].contains(1.0) && x[1].contains(2.0) && x[2].contains(-1.0));
+/// assert!(x.iter().all(|e| e.width() < 1.0e-14));
+///
+/// let singular = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+/// assert!(interval::verified_solve(&singular, &b).is_none());
+/// ```
+pub fn verified_solve<N, D, S1, S2>(
+    a: &Matrix<N, D, D, S1>,
+    b: &Vector<N, D, S2>,
+) -> Option<VectorN<Interval<N>, D>>
+where
+    N: IntervalBound,
+    D: DimMin<D, Output = D>,
+    S1: Storage<N, D, D>,
+    S2: Storage<N, D>,
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, D>
+        + Allocator<Interval<N>, D, D>
+        + Allocator<Interval<N>, D>
+        + Allocator<(usize, usize), D>,
+{
+    assert_eq!(a.nrows(), b.len(), "verified_solve: dimension mismatch.");
+
+    let r = a.clone_owned().try_inverse()?;
+    let x = a.clone_owned().lu().solve(b)?;
+
+    let ai = a.map(Interval::point);
+    let ri = r.map(Interval::point);
+    let xi = x.map(Interval::point);
+
+    let residual = &ri * (b.map(Interval::point) - &ai * &xi);
+    let c =
+        MatrixN::<Interval<N>, D>::identity_generic(a.data.shape().0, a.data.shape().1) - &ri * &ai;
+
+    let mut beta = N::zero();
+
+    for i in 0..c.nrows() {
+        let row_norm = c
+            .row(i)
+            .iter()
+            .fold(Interval::zero(), |acc, e| acc + Interval::point(e.mag()));
+        beta = beta.max(row_norm.hi);
+    }
+
+    if !(beta < N::one()) {
+        return None;
+    }
+
+    let rho = residual
+        .iter()
+        .fold(N::zero(), |acc: N, e: &Interval<N>| acc.max(e.mag()));
+    let delta = (Interval::point(rho) / (Interval::one() - Interval::point(beta))).hi;
+
+    Some(xi.map(|e| e + Interval::new(-delta, delta)))
+}
+
+impl<N: IntervalBound> Neg for Interval<N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl<N: IntervalBound> Add for Interval<N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Interval {
+            lo: (self.lo + rhs.lo).next_down(),
+            hi: (self.hi + rhs.hi).next_up(),
+        }
+    }
+}
+
+impl<N: IntervalBound> Sub for Interval<N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Interval {
+            lo: (self.lo - rhs.hi).next_down(),
+            hi: (self.hi - rhs.lo).next_up(),
+        }
+    }
+}
+
+impl<N: IntervalBound> Mul for Interval<N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        // Exact zeros are kept exact, which is important for sparse matrices.
+        if self.is_zero() || rhs.is_zero() {
+            return Self::zero();
+        }
+
+        Self::enclose(
+            self.lo * rhs.lo,
+            self.lo * rhs.hi,
+            self.hi * rhs.lo,
+            self.hi * rhs.hi,
+        )
+    }
+}
+
+impl<N: IntervalBound> Div for Interval<N> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        if rhs.contains(N::zero()) {
+            return Self::entire();
+        }
+
+        if self.is_zero() {
+            return Self::zero();
+        }
+
+        Self::enclose(
+            self.lo / rhs.lo,
+            self.lo / rhs.hi,
+            self.hi / rhs.lo,
+            self.hi / rhs.hi,
+        )
+    }
+}
+
+macro_rules! impl_op_assign(
+    ($($Trait: ident, $method: ident, $op: tt);* $(;)*) => {$(
+        impl<N: IntervalBound> $Trait for Interval<N> {
+            #[inline]
+            fn $method(&mut self, rhs: Self) {
+                *self = *self $op rhs
+            }
+        }
+    )*}
+);
+
+impl_op_assign!(
+    AddAssign, add_assign, +;
+    SubAssign, sub_assign, -;
+    MulAssign, mul_assign, *;
+    DivAssign, div_assign, /;
+);
+
+impl<N: IntervalBound> Zero for Interval<N> {
+    #[inline]
+    fn zero() -> Self {
+        Self::point(N::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.lo.is_zero() && self.hi.is_zero()
+    }
+}
+
+impl<N: IntervalBound> One for Interval<N> {
+    #[inline]
+    fn one() -> Self {
+        Self::point(N::one())
+    }
+}
+
+impl<N: IntervalBound> fmt::Display for Interval<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
@@ -66,7 +66,7 @@ an optimized set of tools for computer graphics and physics. Those features incl
 * General transformations that does not have to be invertible, stored as a homogeneous matrix:
   `Transform2`, `Transform3`.
 * 3D projections for computer graphics: `Perspective3`, `Orthographic3`.
-* Matrix factorizations: `Cholesky`, `QR`, `LU`, `FullPivLU`, `SVD`, `Schur`, `Hessenberg`, `SymmetricEigen`.
+* Matrix factorizations: `Cholesky`, `LDL`, `QR`, `ColPivQR`, `UpdatableQR`, `LU`, `FullPivLU`, `SVD`, `IncrementalSVD`, `Schur`, `Hessenberg`, `SymmetricEigen`, `GeneralizedSymmetricEigen`, `Eigen`, `PolarDecomposition`.
 * Insertion and removal of rows of columns of a matrix.
 */
 
@@ -123,7 +123,11 @@ extern crate pest_derive;
 pub mod base;
 #[cfg(feature = "debug")]
 pub mod debug;
+#[cfg(feature = "dual")]
+pub mod dual;
 pub mod geometry;
+#[cfg(feature = "interval")]
+pub mod interval;
 #[cfg(feature = "io")]
 pub mod io;
 pub mod linalg;
@@ -131,7 +135,11 @@ pub mod linalg;
 pub mod sparse;
 
 pub use crate::base::*;
+#[cfg(feature = "dual")]
+pub use crate::dual::Dual;
 pub use crate::geometry::*;
+#[cfg(feature = "interval")]
+pub use crate::interval::Interval;
 pub use crate::linalg::*;
 #[cfg(feature = "sparse")]
 pub use crate::sparse::*;
@@ -1,16 +1,46 @@
 //! Functions for balancing a matrix.
 
-use simba::scalar::RealField;
+use simba::scalar::{ComplexField, RealField};
 use std::ops::{DivAssign, MulAssign};
 
 use crate::allocator::Allocator;
 use crate::base::dimension::{Dim, U1};
-use crate::base::storage::Storage;
-use crate::base::{DefaultAllocator, MatrixN, VectorN};
+use crate::base::storage::{Storage, StorageMut};
+use crate::base::{DefaultAllocator, Matrix, MatrixN, VectorN};
+
+/// Computes in-place `D * v`, where `D` is the matrix with diagonal `d`.
+///
+/// If the columns of `v` are eigenvectors of a matrix balanced with `balance` and `d` is the
+/// scaling returned by `balance`, then this turns them into eigenvectors of the original matrix.
+/// The columns are not normalized.
+pub fn unbalance_eigenvectors<N, D, C, S>(v: &mut Matrix<N, D, C, S>, d: &VectorN<N::RealField, D>)
+where
+    N: ComplexField,
+    D: Dim,
+    C: Dim,
+    S: StorageMut<N, D, C>,
+    DefaultAllocator: Allocator<N::RealField, D>,
+{
+    assert_eq!(v.nrows(), d.len(), "Unbalancing: mismatched dimensions.");
+
+    for (i, f) in d.iter().enumerate() {
+        v.row_mut(i).apply(|e| e.scale(*f));
+    }
+}
 
 /// Applies in-place a modified Parlett and Reinsch matrix balancing with 2-norm to the matrix `m` and returns
 /// the corresponding diagonal transformation.
 ///
+/// The matrix `m` is replaced by `D⁻¹ * m * D` where `D` is the matrix with the returned diagonal
+/// `d`. The scaling factors are powers of two chosen so that each row and the corresponding column
+/// have comparable 2-norms. This does not change the eigenvalues, but reduces the norm of badly
+/// scaled matrices and thus improves the accuracy of the computed eigenvalues. Since the scaling
+/// factors are powers of two, the transformation does not introduce any rounding error. They are
+/// also bounded so that they neither overflow nor underflow.
+///
+/// If `y` is an eigenvector of the balanced matrix, then `D * y` is an eigenvector of the original
+/// matrix for the same eigenvalue, see `unbalance_eigenvectors`.
+///
 /// See https://arxiv.org/pdf/1401.5766.pdf
 pub fn balance_parlett_reinsch<N: RealField, D: Dim>(m: &mut MatrixN<N, D>) -> VectorN<N, D>
 where
@@ -22,6 +52,10 @@ where
     let radix: N = crate::convert(2.0f64);
     let mut d = VectorN::from_element_generic(dim, U1, N::one());
 
+    // Bounds the scaling factors so that they neither overflow nor underflow.
+    let sfmax = N::max_value().sqrt();
+    let sfmin = N::one() / sfmax;
+
     let mut converged = false;
 
     while !converged {
@@ -40,13 +74,13 @@ where
                 continue;
             }
 
-            while c < r / radix {
+            while c < r / radix && d[i] * f < sfmax {
                 c *= radix;
                 r /= radix;
                 f *= radix;
             }
 
-            while c >= r * radix {
+            while c >= r * radix && d[i] * f > sfmin {
                 c /= radix;
                 r *= radix;
                 f /= radix;
@@ -0,0 +1,229 @@
+//! Square matrices with a limited number of non-zero diagonals.
+
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use num::Zero;
+use simba::scalar::{ClosedAdd, ClosedMul, ComplexField};
+
+use crate::base::dimension::Dim;
+use crate::base::storage::Storage;
+use crate::base::{DMatrix, DVector, Matrix, Scalar, Vector};
+
+use crate::linalg::{BandedCholesky, BandedLU};
+
+/// A square matrix where all the elements outside of a band around the diagonal are zero.
+///
+/// The element `(i, j)` may be non-zero only if `j <= i + ku` and `i <= j + kl`, where `kl` is
+/// the lower bandwidth and `ku` is the upper bandwidth of the matrix. Only the `kl + ku + 1`
+/// diagonals of the band are stored, so a banded matrix of dimension `n` requires
+/// `O((kl + ku) * n)` memory instead of `O(n²)`. Such matrices arise, e.g., from finite-difference
+/// discretizations of differential equations.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DMatrix<N>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DMatrix<N>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BandedMatrix<N: Scalar> {
+    // The element `(i, j)` of the band is stored at `(ku + i - j, j)`.
+    data: DMatrix<N>,
+    kl: usize,
+    ku: usize,
+}
+
+impl<N: Scalar + Zero> BandedMatrix<N> {
+    /// Creates a banded matrix of dimension `n` with all its elements set to zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `n`  − the number of rows and columns of the matrix.
+    /// * `kl` − the lower bandwidth, i.e., the number of non-zero diagonals below the diagonal.
+    /// * `ku` − the upper bandwidth, i.e., the number of non-zero diagonals above the diagonal.
+    pub fn zeros(n: usize, kl: usize, ku: usize) -> Self {
+        BandedMatrix {
+            data: DMatrix::zeros(kl + ku + 1, n),
+            kl,
+            ku,
+        }
+    }
+
+    /// Creates a banded matrix from the elements of the band of the square matrix `m`.
+    ///
+    /// The elements of `m` outside of the band are ignored.
+    pub fn from_matrix<D: Dim, S: Storage<N, D, D>>(
+        m: &Matrix<N, D, D, S>,
+        kl: usize,
+        ku: usize,
+    ) -> Self {
+        assert!(
+            m.is_square(),
+            "BandedMatrix: the input matrix must be square."
+        );
+
+        let n = m.nrows();
+        let mut res = Self::zeros(n, kl, ku);
+
+        for j in 0..n {
+            for i in j.saturating_sub(ku)..n.min(j + kl + 1) {
+                res.data[(ku + i - j, j)] = m[(i, j)].inlined_clone();
+            }
+        }
+
+        res
+    }
+
+    /// Creates a banded matrix from its diagonals.
+    ///
+    /// The diagonals are given from the uppermost to the lowermost. The `k`-th diagonal must
+    /// have `n - |ku - k|` elements, where `n` is the dimension of the matrix.
+    pub fn from_diagonals(kl: usize, ku: usize, diagonals: &[&[N]]) -> Self {
+        assert_eq!(
+            diagonals.len(),
+            kl + ku + 1,
+            "BandedMatrix: the number of diagonals must be equal to kl + ku + 1."
+        );
+
+        let n = diagonals[ku].len();
+        let mut res = Self::zeros(n, kl, ku);
+
+        for (k, diagonal) in diagonals.iter().enumerate() {
+            // The diagonal `k` starts at `(0, ku - k)` above the diagonal, and at `(k - ku, 0)`
+            // below.
+            let offset = k.abs_diff(ku);
+            assert_eq!(
+                diagonal.len(),
+                n.saturating_sub(offset),
+                "BandedMatrix: invalid diagonal length."
+            );
+
+            for (l, e) in diagonal.iter().enumerate() {
+                let j = if k <= ku { l + offset } else { l };
+                res.data[(k, j)] = e.inlined_clone();
+            }
+        }
+
+        res
+    }
+
+    /// Converts this banded matrix to a dense matrix.
+    pub fn to_dense(&self) -> DMatrix<N> {
+        let n = self.nrows();
+        let mut res = DMatrix::zeros(n, n);
+
+        for j in 0..n {
+            for i in j.saturating_sub(self.ku)..n.min(j + self.kl + 1) {
+                res[(i, j)] = self.data[(self.ku + i - j, j)].inlined_clone();
+            }
+        }
+
+        res
+    }
+}
+
+impl<N: Scalar> BandedMatrix<N> {
+    /// The number of rows of this matrix.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.data.ncols()
+    }
+
+    /// The number of columns of this matrix.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.data.ncols()
+    }
+
+    /// The lower bandwidth of this matrix, i.e., the number of diagonals below the diagonal
+    /// that may contain non-zero elements.
+    #[inline]
+    pub fn lower_bandwidth(&self) -> usize {
+        self.kl
+    }
+
+    /// The upper bandwidth of this matrix, i.e., the number of diagonals above the diagonal
+    /// that may contain non-zero elements.
+    #[inline]
+    pub fn upper_bandwidth(&self) -> usize {
+        self.ku
+    }
+
+    /// Indicates if the element `(i, j)` lies in the band of this matrix.
+    #[inline]
+    pub fn is_in_band(&self, i: usize, j: usize) -> bool {
+        i < self.nrows() && j < self.ncols() && j <= i + self.ku && i <= j + self.kl
+    }
+
+    /// A reference to the element `(i, j)` of this matrix, or `None` if it lies outside of the
+    /// band.
+    #[inline]
+    pub fn get(&self, i: usize, j: usize) -> Option<&N> {
+        if self.is_in_band(i, j) {
+            Some(&self.data[(self.ku + i - j, j)])
+        } else {
+            None
+        }
+    }
+
+    /// A mutable reference to the element `(i, j)` of this matrix, or `None` if it lies outside
+    /// of the band.
+    #[inline]
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut N> {
+        if self.is_in_band(i, j) {
+            Some(&mut self.data[(self.ku + i - j, j)])
+        } else {
+            None
+        }
+    }
+
+    /// The matrix storing the diagonals of the band of this matrix.
+    ///
+    /// The element `(i, j)` of this banded matrix is stored at `(ku + i - j, j)`, where `ku` is
+    /// the upper bandwidth. This is the storage scheme used by LAPACK. The elements of this
+    /// storage that do not correspond to any element of the banded matrix are set to zero.
+    #[inline]
+    pub fn band_storage(&self) -> &DMatrix<N> {
+        &self.data
+    }
+}
+
+impl<N: Scalar + Zero + ClosedAdd + ClosedMul> BandedMatrix<N> {
+    /// Computes the product of this matrix with the vector `x`.
+    ///
+    /// This requires `O((kl + ku) * n)` operations.
+    pub fn mul_vector<R: Dim, S: Storage<N, R>>(&self, x: &Vector<N, R, S>) -> DVector<N> {
+        let n = self.ncols();
+        assert_eq!(x.nrows(), n, "BandedMatrix product: dimension mismatch.");
+
+        let mut res = DVector::zeros(n);
+
+        for j in 0..n {
+            let xj = x[j].inlined_clone();
+
+            for i in j.saturating_sub(self.ku)..n.min(j + self.kl + 1) {
+                res[i] += self.data[(self.ku + i - j, j)].inlined_clone() * xj.inlined_clone();
+            }
+        }
+
+        res
+    }
+}
+
+impl<N: ComplexField> BandedMatrix<N> {
+    /// Computes the LU decomposition with partial (row) pivoting of this matrix.
+    pub fn lu(self) -> BandedLU<N> {
+        BandedLU::new(self)
+    }
+
+    /// Attempts to compute the Cholesky decomposition of this matrix.
+    ///
+    /// Returns `None` if the input matrix is not definite-positive. The input matrix is assumed
+    /// to be hermitian and only its diagonal and its lower band are read.
+    pub fn cholesky(self) -> Option<BandedCholesky<N>> {
+        BandedCholesky::new(self)
+    }
+}
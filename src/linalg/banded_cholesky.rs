@@ -0,0 +1,169 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use num::One;
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::dimension::Dim;
+use crate::base::storage::{Storage, StorageMut};
+use crate::base::{DMatrix, DefaultAllocator, Matrix, MatrixMN};
+
+use crate::linalg::BandedMatrix;
+
+/// The Cholesky decomposition of a hermitian definite-positive banded matrix.
+///
+/// With a bandwidth `k`, the lower-triangular factor `L` has the same lower bandwidth `k` as the
+/// decomposed matrix, so computing this decomposition requires `O(k² * n)` operations instead of
+/// `O(n³)`.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DMatrix<N>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DMatrix<N>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct BandedCholesky<N: ComplexField> {
+    // The element `(i, j)` of `L` is stored at `(i - j, j)`.
+    chol: DMatrix<N>,
+}
+
+impl<N: ComplexField> BandedCholesky<N> {
+    /// Attempts to compute the Cholesky decomposition of the banded matrix `matrix`.
+    ///
+    /// Returns `None` if the input matrix is not definite-positive. The input matrix is assumed
+    /// to be hermitian and only its diagonal and its lower band are read, so its upper bandwidth
+    /// is ignored.
+    pub fn new(matrix: BandedMatrix<N>) -> Option<Self> {
+        let n = matrix.nrows();
+        let k = matrix.lower_bandwidth();
+        let ku = matrix.upper_bandwidth();
+
+        let mut chol = matrix.band_storage().rows(ku, k + 1).into_owned();
+
+        for j in 0..n {
+            let last = (n - 1).min(j + k);
+            let diag = chol[(0, j)];
+
+            // Fail if the diagonal element is either zero or its square root could not
+            // be taken (e.g. for negative real numbers).
+            if diag.is_zero() {
+                return None;
+            }
+
+            let denom = diag.try_sqrt()?;
+            chol[(0, j)] = denom;
+
+            for i in j + 1..=last {
+                chol[(i - j, j)] /= denom;
+            }
+
+            for c in j + 1..=last {
+                let factor = chol[(c - j, j)].conjugate();
+
+                for i in c..=last {
+                    let l = chol[(i - j, j)];
+                    chol[(i - c, c)] -= l * factor;
+                }
+            }
+        }
+
+        Some(BandedCholesky { chol })
+    }
+
+    /// The dimension of the decomposed matrix.
+    #[inline]
+    pub fn dim(&self) -> usize {
+        self.chol.ncols()
+    }
+
+    /// The bandwidth of the decomposed matrix and of its lower-triangular factor.
+    #[inline]
+    pub fn bandwidth(&self) -> usize {
+        self.chol.nrows() - 1
+    }
+
+    /// Retrieves the lower-triangular factor of the Cholesky decomposition, as a banded matrix.
+    pub fn l(&self) -> BandedMatrix<N> {
+        let n = self.dim();
+        let k = self.bandwidth();
+        let mut res = BandedMatrix::zeros(n, k, 0);
+
+        for j in 0..n {
+            for i in j..n.min(j + k + 1) {
+                *res.get_mut(i, j).unwrap() = self.chol[(i - j, j)];
+            }
+        }
+
+        res
+    }
+
+    /// Solves the system `self * x = b` where `self` is the decomposed matrix and `x` the unknown.
+    ///
+    /// The result is stored on `b`.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+    {
+        let n = self.dim();
+        let k = self.bandwidth();
+
+        assert_eq!(
+            n,
+            b.nrows(),
+            "BandedCholesky solve matrix dimension mismatch."
+        );
+
+        for c in 0..b.ncols() {
+            let mut x = b.column_mut(c);
+
+            // Solve `L * y = b`.
+            for j in 0..n {
+                let xj = x[j] / self.chol[(0, j)];
+                x[j] = xj;
+
+                for i in j + 1..n.min(j + k + 1) {
+                    x[i] -= self.chol[(i - j, j)] * xj;
+                }
+            }
+
+            // Solve `Lᴴ * x = y`.
+            for j in (0..n).rev() {
+                let mut xj = x[j];
+
+                for i in j + 1..n.min(j + k + 1) {
+                    xj -= self.chol[(i - j, j)].conjugate() * x[i];
+                }
+
+                x[j] = xj / self.chol[(0, j)].conjugate();
+            }
+        }
+    }
+
+    /// Returns the solution of the system `self * x = b` where `self` is the decomposed matrix
+    /// and `x` the unknown.
+    pub fn solve<R2: Dim, C2: Dim, S2>(&self, b: &Matrix<N, R2, C2, S2>) -> MatrixMN<N, R2, C2>
+    where
+        S2: Storage<N, R2, C2>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        self.solve_mut(&mut res);
+        res
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    pub fn determinant(&self) -> N::RealField {
+        let mut res = N::RealField::one();
+
+        for j in 0..self.dim() {
+            let diag = self.chol[(0, j)].real();
+            res *= diag * diag;
+        }
+
+        res
+    }
+}
@@ -0,0 +1,184 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::dimension::Dim;
+use crate::base::storage::{Storage, StorageMut};
+use crate::base::{DMatrix, DefaultAllocator, Matrix, MatrixMN};
+
+use crate::linalg::BandedMatrix;
+
+/// LU decomposition with partial (row) pivoting of a banded matrix.
+///
+/// With a lower bandwidth `kl` and an upper bandwidth `ku`, the unit lower-triangular factor has
+/// at most `kl` non-zero elements per column, and the upper-triangular factor has an upper
+/// bandwidth of at most `kl + ku` because of the fill-in caused by row interchanges. Computing
+/// this decomposition thus requires `O(kl * (kl + ku) * n)` operations instead of `O(n³)`.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DMatrix<N>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DMatrix<N>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct BandedLU<N: ComplexField> {
+    // The element `(i, j)` of `U` is stored at `(kl + ku + i - j, j)`. The multipliers of the
+    // `j`-th elimination step are stored below the diagonal, in the same layout.
+    lu: DMatrix<N>,
+    kl: usize,
+    ku: usize,
+    // The row interchanged with the row `j` at the `j`-th elimination step.
+    ipiv: Vec<usize>,
+}
+
+impl<N: ComplexField> BandedLU<N> {
+    /// Computes the LU decomposition with partial (row) pivoting of the banded matrix `matrix`.
+    pub fn new(matrix: BandedMatrix<N>) -> Self {
+        let n = matrix.nrows();
+        let kl = matrix.lower_bandwidth();
+        let ku = matrix.upper_bandwidth();
+        let kv = kl + ku;
+
+        // Leave `kl` additional diagonals on top of the band for the fill-in.
+        let mut lu = DMatrix::zeros(2 * kl + ku + 1, n);
+        lu.rows_mut(kl, kv + 1).copy_from(matrix.band_storage());
+        let mut ipiv = vec![0; n];
+
+        for j in 0..n {
+            let km = kl.min(n - 1 - j);
+            // The last column affected by the elimination of the column `j`.
+            let last = (n - 1).min(j + kv);
+
+            let piv = lu.column(j).rows(kv, km + 1).icamax() + j;
+            let diag = lu[(kv + piv - j, j)];
+            ipiv[j] = piv;
+
+            if diag.is_zero() {
+                // No non-zero entries on this column.
+                continue;
+            }
+
+            if piv != j {
+                for c in j..=last {
+                    lu.swap((kv + j - c, c), (kv + piv - c, c));
+                }
+            }
+
+            for i in 1..=km {
+                lu[(kv + i, j)] /= diag;
+            }
+
+            for c in j + 1..=last {
+                let factor = lu[(kv + j - c, c)];
+
+                if !factor.is_zero() {
+                    for i in j + 1..=j + km {
+                        let l = lu[(kv + i - j, j)];
+                        lu[(kv + i - c, c)] -= l * factor;
+                    }
+                }
+            }
+        }
+
+        BandedLU { lu, kl, ku, ipiv }
+    }
+
+    /// The dimension of the decomposed matrix.
+    #[inline]
+    pub fn dim(&self) -> usize {
+        self.lu.ncols()
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `None` if `self` is not invertible.
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> Option<MatrixMN<N, R2, C2>>
+    where
+        S2: Storage<N, R2, C2>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
+    /// be overwritten with garbage.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<N, R2, C2>,
+    {
+        let n = self.dim();
+        let kv = self.kl + self.ku;
+
+        assert_eq!(n, b.nrows(), "BandedLU solve matrix dimension mismatch.");
+
+        if !self.is_invertible() {
+            return false;
+        }
+
+        for k in 0..b.ncols() {
+            let mut x = b.column_mut(k);
+
+            // Apply the row interchanges and the eliminations in the order they were computed.
+            for j in 0..n {
+                let piv = self.ipiv[j];
+                x.swap_rows(j, piv);
+                let xj = x[j];
+
+                for i in j + 1..n.min(j + self.kl + 1) {
+                    x[i] -= self.lu[(kv + i - j, j)] * xj;
+                }
+            }
+
+            for j in (0..n).rev() {
+                let xj = x[j] / self.lu[(kv, j)];
+                x[j] = xj;
+
+                for i in j.saturating_sub(kv)..j {
+                    x[i] -= self.lu[(kv + i - j, j)] * xj;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    pub fn determinant(&self) -> N {
+        let kv = self.kl + self.ku;
+        let mut res = N::one();
+
+        for j in 0..self.dim() {
+            res *= self.lu[(kv, j)];
+
+            if self.ipiv[j] != j {
+                res = -res;
+            }
+        }
+
+        res
+    }
+
+    /// Indicates if the decomposed matrix is invertible.
+    pub fn is_invertible(&self) -> bool {
+        let kv = self.kl + self.ku;
+        (0..self.dim()).all(|j| !self.lu[(kv, j)].is_zero())
+    }
+}
@@ -1,16 +1,19 @@
 #[cfg(feature = "serde-serialize")]
 use serde::{Deserialize, Serialize};
 
-use num::One;
-use simba::scalar::ComplexField;
+use num::{One, Zero};
+use simba::scalar::{ComplexField, RealField};
 use simba::simd::SimdComplexField;
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, SquareMatrix, Vector};
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, SquareMatrix, Vector, VectorN};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Dim, DimAdd, DimDiff, DimSub, DimSum, U1};
 use crate::storage::{Storage, StorageMut};
 
+use crate::linalg::norm1_estimate::norm1_estimate;
+use crate::linalg::PermutationSequence;
+
 /// The Cholesky decomposition of a symmetric-definite-positive matrix.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[cfg_attr(
@@ -38,6 +41,46 @@ where
 {
 }
 
+/// A Cholesky decomposition computed in-place with `Cholesky::new_in_place`.
+///
+/// This borrows the buffer containing the lower-triangular factor `L`.
+#[derive(Debug)]
+pub struct CholeskyView<'a, N: ComplexField, D: Dim, S: Storage<N, D, D>> {
+    chol: &'a Matrix<N, D, D, S>,
+}
+
+impl<'a, N: ComplexField, D: Dim, S: Storage<N, D, D>> CholeskyView<'a, N, D, S> {
+    /// The lower-triangular factor of the Cholesky decomposition, with its strictly
+    /// upper-triangular part filled with zeros.
+    #[inline]
+    pub fn l(&self) -> &'a Matrix<N, D, D, S> {
+        self.chol
+    }
+
+    /// Solves the system `self * x = b` where `self` is the decomposed matrix and `x` the unknown.
+    ///
+    /// The result is stored on `b`.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        self.chol.solve_lower_triangular_unchecked_mut(b);
+        self.chol.ad_solve_lower_triangular_unchecked_mut(b);
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    pub fn determinant(&self) -> N::RealField {
+        let mut prod_diag = N::one();
+
+        for i in 0..self.chol.nrows() {
+            prod_diag *= self.chol[(i, i)];
+        }
+
+        prod_diag.modulus_squared()
+    }
+}
+
 impl<N: SimdComplexField, D: Dim> Cholesky<N, D>
 where
     DefaultAllocator: Allocator<N, D, D>,
@@ -149,13 +192,30 @@ where
     /// Returns `None` if the input matrix is not definite-positive. The input matrix is assumed
     /// to be symmetric and only the lower-triangular part is read.
     pub fn new(mut matrix: MatrixN<N, D>) -> Option<Self> {
+        let _ = Self::new_in_place(&mut matrix)?;
+        Some(Cholesky { chol: matrix })
+    }
+
+    /// Attempts to compute the Cholesky decomposition of `matrix` in-place, without any
+    /// allocation.
+    ///
+    /// On success, `matrix` is overwritten by the lower-triangular factor `L`, with its strictly
+    /// upper-triangular part filled with zeros. `matrix` may be any mutable matrix or matrix
+    /// slice, so that the decomposition can be performed inside of a pre-allocated buffer.
+    ///
+    /// Returns `None` if the input matrix is not definite-positive, in which case `matrix`
+    /// contains garbage. The input matrix is assumed to be symmetric and only the
+    /// lower-triangular part is read.
+    pub fn new_in_place<S: StorageMut<N, D, D>>(
+        matrix: &mut Matrix<N, D, D, S>,
+    ) -> Option<CholeskyView<'_, N, D, S>> {
         assert!(matrix.is_square(), "The input matrix must be square.");
 
         let n = matrix.nrows();
 
         for j in 0..n {
             for k in 0..j {
-                let factor = unsafe { -*matrix.get_unchecked((j, k)) };
+                let factor = -matrix[(j, k)];
 
                 let (mut col_j, col_k) = matrix.columns_range_pair_mut(j, k);
                 let mut col_j = col_j.rows_range_mut(j..);
@@ -164,29 +224,175 @@ where
                 col_j.axpy(factor.conjugate(), &col_k, N::one());
             }
 
-            let diag = unsafe { *matrix.get_unchecked((j, j)) };
-            if !diag.is_zero() {
-                if let Some(denom) = diag.try_sqrt() {
-                    unsafe {
-                        *matrix.get_unchecked_mut((j, j)) = denom;
-                    }
-
-                    let mut col = matrix.slice_range_mut(j + 1.., j);
-                    col /= denom;
-                    continue;
+            let diag = matrix[(j, j)];
+            let denom = if diag.is_zero() {
+                None
+            } else {
+                diag.try_sqrt()
+            }?;
+
+            matrix[(j, j)] = denom;
+            let mut col = matrix.slice_range_mut(j + 1.., j);
+            col /= denom;
+        }
+
+        matrix.fill_upper_triangle(N::zero(), 1);
+
+        Some(CholeskyView { chol: matrix })
+    }
+
+    /// Computes the Cholesky decomposition with diagonal pivoting of the symmetric
+    /// positive-semidefinite matrix `matrix`.
+    ///
+    /// Returns the decomposition of `P * matrix * Pᵀ`, the permutation `P`, and the numerical
+    /// rank `r` of `matrix`, i.e., the number of pivots greater than `tol` times the largest
+    /// diagonal element of `matrix`, so that the rank does not depend on the scaling of
+    /// `matrix`. Only the first `r` columns of the resulting lower-triangular factor are
+    /// non-zero, so its `solve` and `inverse` methods are only meaningful if `r` is equal to the
+    /// dimension of `matrix`.
+    ///
+    /// Returns `None` if a pivot is smaller than the opposite of this threshold, i.e., if the
+    /// input matrix is not positive-semidefinite. The input matrix is assumed to be symmetric
+    /// and only the lower-triangular part is read.
+    pub fn new_pivoted(
+        mut matrix: MatrixN<N, D>,
+        tol: N::RealField,
+    ) -> Option<(Self, PermutationSequence<D>, usize)>
+    where
+        DefaultAllocator: Allocator<(usize, usize), D>,
+    {
+        assert!(matrix.is_square(), "The input matrix must be square.");
+
+        let dim = matrix.data.shape().0;
+        let n = dim.value();
+        let mut p = PermutationSequence::identity_generic(dim);
+
+        // The symmetric interchanges and updates are performed on the full matrix.
+        for j in 0..n {
+            for i in j + 1..n {
+                matrix[(j, i)] = matrix[(i, j)].conjugate();
+            }
+        }
+
+        // The tolerance is relative to the largest diagonal element.
+        let max_diag = (0..n).fold(N::RealField::zero(), |max, i| {
+            max.max(matrix[(i, i)].real())
+        });
+        let tol = tol * max_diag;
+
+        for k in 0..n {
+            // Select the largest remaining diagonal element as the pivot.
+            let mut piv = k;
+
+            for i in k + 1..n {
+                if matrix[(i, i)].real() > matrix[(piv, piv)].real() {
+                    piv = i;
                 }
             }
 
-            // The diagonal element is either zero or its square root could not
-            // be taken (e.g. for negative real numbers).
-            return None;
+            let diag = matrix[(piv, piv)].real();
+
+            if diag <= tol {
+                if (k..n).any(|i| matrix[(i, i)].real() < -tol) {
+                    return None;
+                }
+
+                // The remaining diagonal block is numerically zero.
+                matrix
+                    .slice_range_mut(k.., k..)
+                    .fill_lower_triangle(N::zero(), 0);
+                return Some((Cholesky { chol: matrix }, p, k));
+            }
+
+            matrix.swap_rows(k, piv);
+            matrix.swap_columns(k, piv);
+            p.append_permutation(k, piv);
+
+            let denom = N::from_real(diag.sqrt());
+            matrix[(k, k)] = denom;
+
+            let mut col = matrix.slice_range_mut(k + 1.., k);
+            col /= denom;
+
+            for j in k + 1..n {
+                let (col_k, mut col_j) = matrix.columns_range_pair_mut(k, j);
+                let factor = -col_k[j].conjugate();
+                col_j
+                    .rows_range_mut(k + 1..)
+                    .axpy(factor, &col_k.rows_range(k + 1..), N::one());
+            }
         }
 
-        Some(Cholesky { chol: matrix })
+        Some((Cholesky { chol: matrix }, p, n))
+    }
+
+    /// Computes the sign and the natural logarithm of the determinant of the decomposed matrix.
+    ///
+    /// Returns `(sign, logdet)` such that the determinant is equal to `sign * exp(logdet)`.
+    /// Because the decomposed matrix is positive-semidefinite, `sign` is always one and `logdet`
+    /// is twice the sum of the logarithms of the diagonal elements of `L`, or `-inf` for a
+    /// rank-deficient decomposition computed by `Cholesky::new_pivoted`. Unlike the product of
+    /// the diagonal elements, this does not overflow nor underflow for large matrices, which
+    /// makes it suitable for, e.g., the evaluation of Gaussian log-likelihoods.
+    pub fn logdet(&self) -> (N::RealField, N::RealField) {
+        let two: N::RealField = crate::convert(2.0f64);
+        let mut logdet = N::RealField::zero();
+
+        for i in 0..self.chol.nrows() {
+            logdet += self.chol[(i, i)].real().ln();
+        }
+
+        (N::RealField::one(), logdet * two)
+    }
+
+    /// Estimates the reciprocal of the condition number of the decomposed matrix in 1-norm,
+    /// i.e., `1 / (‖A‖₁ * ‖A⁻¹‖₁)`.
+    ///
+    /// Both norms are estimated with the Hager-Higham estimator from products with the
+    /// triangular factor of this decomposition, so this requires only `O(n²)` operations. The
+    /// result is close to zero if the decomposed matrix is ill-conditioned.
+    pub fn rcond(&self) -> N::RealField
+    where
+        DefaultAllocator: Allocator<N, D>,
+    {
+        let dim = self.chol.data.shape().0;
+        let n = dim.value();
+
+        if n == 0 {
+            return N::RealField::one();
+        }
+
+        // A = L * Lᴴ is hermitian.
+        let mul = |x: &mut VectorN<N, D>| {
+            for i in 0..n {
+                x[i] = self.chol.slice_range(i.., i).dotc(&x.rows_range(i..));
+            }
+
+            for i in (0..n).rev() {
+                x[i] = self
+                    .chol
+                    .slice_range(i, ..i + 1)
+                    .tr_dot(&x.rows_range(..i + 1));
+            }
+        };
+        let norm = norm1_estimate(dim, mul, mul);
+
+        let inv_mul = |x: &mut VectorN<N, D>| self.solve_mut(x);
+        let inv_norm = norm1_estimate(dim, inv_mul, inv_mul);
+
+        if norm.is_zero() {
+            N::RealField::zero()
+        } else {
+            N::RealField::one() / (norm * inv_norm)
+        }
     }
 
     /// Given the Cholesky decomposition of a matrix `M`, a scalar `sigma` and a vector `v`,
     /// performs a rank one update such that we end up with the decomposition of `M + sigma * (v * v.adjoint())`.
+    ///
+    /// This is a downdate if `sigma` is negative, in which case `M + sigma * (v * v.adjoint())`
+    /// must still be definite-positive, otherwise the decomposition will contain trash values
+    /// (Inf, NaN, etc.)
     #[inline]
     pub fn rank_one_update<R2: Dim, S2>(&mut self, x: &Vector<N, R2, S2>, sigma: N::RealField)
     where
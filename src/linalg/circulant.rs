@@ -0,0 +1,179 @@
+//! Square matrices where each column is a cyclic shift of the previous one.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use num_complex::Complex as NumComplex;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::base::dimension::Dim;
+use crate::base::storage::Storage;
+use crate::base::{DMatrix, DVector, Vector};
+
+use crate::linalg::fft::fft;
+
+/// A real square circulant matrix, i.e., a matrix where each column is obtained by cyclically
+/// shifting the previous column down by one element.
+///
+/// The element `(i, j)` is `c[(i - j) mod n]` where `c` is the first column of the matrix, which
+/// is the only stored data. The product of a circulant matrix with a vector is the circular
+/// convolution of its first column with this vector. Circulant matrices are all diagonalized by the
+/// discrete Fourier transform, so their products, inverses, and eigenvalues are computed in
+/// `O(n log(n))` operations with fast Fourier transforms.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DVector<N>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DVector<N>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Circulant<N: RealField> {
+    first_column: DVector<N>,
+}
+
+impl<N: RealField> Circulant<N> {
+    /// Creates a circulant matrix from its first column.
+    pub fn new(first_column: DVector<N>) -> Self {
+        Circulant { first_column }
+    }
+
+    /// The number of rows of this matrix.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.first_column.len()
+    }
+
+    /// The number of columns of this matrix.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.first_column.len()
+    }
+
+    /// The first column of this matrix.
+    #[inline]
+    pub fn first_column(&self) -> &DVector<N> {
+        &self.first_column
+    }
+
+    /// A reference to the element `(i, j)` of this matrix.
+    #[inline]
+    pub fn get(&self, i: usize, j: usize) -> Option<&N> {
+        let n = self.nrows();
+
+        if i >= n || j >= n {
+            None
+        } else {
+            Some(&self.first_column[(i + n - j) % n])
+        }
+    }
+
+    /// Converts this circulant matrix to a dense matrix.
+    pub fn to_dense(&self) -> DMatrix<N> {
+        let n = self.nrows();
+        DMatrix::from_fn(n, n, |i, j| self.first_column[(i + n - j) % n])
+    }
+
+    /// The eigenvalues of this matrix.
+    ///
+    /// The `k`-th eigenvalue is associated to the eigenvector `v` with components
+    /// `v[j] = exp(2iπ * j * k / n)`, so these are the components of the discrete Fourier transform
+    /// of the first column of this matrix.
+    pub fn eigenvalues(&self) -> DVector<NumComplex<N>> {
+        let eigenvalues = transform(self.first_column.iter().cloned());
+        DVector::from_iterator(eigenvalues.len(), eigenvalues)
+    }
+
+    /// Computes the product of this matrix with the vector `x`.
+    ///
+    /// This is the circular convolution of the first column of this matrix with `x`, computed in
+    /// `O(n log(n))` operations with fast Fourier transforms.
+    pub fn mul_vector<R: Dim, S: Storage<N, R>>(&self, x: &Vector<N, R, S>) -> DVector<N> {
+        assert_eq!(
+            x.nrows(),
+            self.ncols(),
+            "Circulant product: dimension mismatch."
+        );
+
+        let eigenvalues = transform(self.first_column.iter().cloned());
+        let mut res = transform(x.iter().cloned());
+
+        for (e, lambda) in res.iter_mut().zip(eigenvalues.iter()) {
+            *e *= *lambda;
+        }
+
+        inverse_transform(res)
+    }
+
+    /// Computes the product of this matrix with the circulant matrix `rhs`, which is circulant
+    /// too.
+    pub fn mul_circulant(&self, rhs: &Self) -> Self {
+        Circulant::new(self.mul_vector(&rhs.first_column))
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// This requires `O(n log(n))` operations. Returns `None` if `self` is numerically singular,
+    /// i.e., if the modulus of one of its eigenvalues is smaller than `n * ε` times the largest
+    /// modulus, where `ε` is the machine epsilon.
+    pub fn solve<R: Dim, S: Storage<N, R>>(&self, b: &Vector<N, R, S>) -> Option<DVector<N>> {
+        assert_eq!(
+            b.nrows(),
+            self.nrows(),
+            "Circulant solve matrix dimension mismatch."
+        );
+
+        let eigenvalues = self.invertible_eigenvalues()?;
+        let mut res = transform(b.iter().cloned());
+
+        for (e, lambda) in res.iter_mut().zip(eigenvalues.iter()) {
+            *e /= *lambda;
+        }
+
+        Some(inverse_transform(res))
+    }
+
+    /// Attempts to compute the inverse of this matrix, which is circulant too.
+    ///
+    /// This requires `O(n log(n))` operations. Returns `None` if `self` is numerically singular.
+    /// See `.solve(b)` for details.
+    pub fn try_inverse(&self) -> Option<Self> {
+        let eigenvalues = self.invertible_eigenvalues()?;
+        let inv = eigenvalues.into_iter().map(|e| e.inv()).collect();
+
+        Some(Circulant::new(inverse_transform(inv)))
+    }
+
+    fn invertible_eigenvalues(&self) -> Option<Vec<NumComplex<N>>> {
+        let eigenvalues = transform(self.first_column.iter().cloned());
+        let max_norm = eigenvalues
+            .iter()
+            .fold(N::zero(), |max, e| max.max(e.modulus()));
+        let eps = N::default_epsilon() * crate::convert(self.nrows() as f64) * max_norm;
+
+        if eigenvalues.iter().all(|e| e.modulus() > eps) {
+            Some(eigenvalues)
+        } else {
+            None
+        }
+    }
+}
+
+// The discrete Fourier transform of the real sequence `data`.
+fn transform<N: RealField>(data: impl Iterator<Item = N>) -> Vec<NumComplex<N>> {
+    let mut res: Vec<_> = data.map(|e| NumComplex::new(e, N::zero())).collect();
+    fft(&mut res, false);
+    res
+}
+
+// The real part of the normalized inverse discrete Fourier transform of `data`.
+fn inverse_transform<N: RealField>(mut data: Vec<NumComplex<N>>) -> DVector<N> {
+    fft(&mut data, true);
+    let scale = N::one() / crate::convert(data.len() as f64);
+    DVector::from_iterator(data.len(), data.into_iter().map(|e| e.re * scale))
+}
@@ -0,0 +1,292 @@
+use num::Zero;
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::allocator::{Allocator, Reallocator};
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, Unit, VectorN};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::dimension::{Dim, DimMin, DimMinimum, U1};
+use crate::storage::{Storage, StorageMut};
+use simba::scalar::ComplexField;
+
+use crate::geometry::Reflection;
+use crate::linalg::householder;
+use crate::linalg::PermutationSequence;
+
+/// The QR decomposition with column pivoting of a general matrix.
+///
+/// The columns are reordered so that the moduli of the diagonal elements of `R` are
+/// non-increasing, which makes this decomposition rank-revealing.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DefaultAllocator: Allocator<N, R, C> +
+                           Allocator<N, DimMinimum<R, C>> +
+                           Allocator<(usize, usize), DimMinimum<R, C>>,
+         MatrixMN<N, R, C>: Serialize,
+         PermutationSequence<DimMinimum<R, C>>: Serialize,
+         VectorN<N, DimMinimum<R, C>>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<N, R, C> +
+                           Allocator<N, DimMinimum<R, C>> +
+                           Allocator<(usize, usize), DimMinimum<R, C>>,
+         MatrixMN<N, R, C>: Deserialize<'de>,
+         PermutationSequence<DimMinimum<R, C>>: Deserialize<'de>,
+         VectorN<N, DimMinimum<R, C>>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct ColPivQR<N: ComplexField, R: DimMin<C>, C: Dim>
+where
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>,
+{
+    col_piv_qr: MatrixMN<N, R, C>,
+    p: PermutationSequence<DimMinimum<R, C>>,
+    diag: VectorN<N, DimMinimum<R, C>>,
+}
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim> Copy for ColPivQR<N, R, C>
+where
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>,
+    MatrixMN<N, R, C>: Copy,
+    PermutationSequence<DimMinimum<R, C>>: Copy,
+    VectorN<N, DimMinimum<R, C>>: Copy,
+{
+}
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim> ColPivQR<N, R, C>
+where
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, R>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>,
+{
+    /// Computes the QR decomposition with column pivoting using householder reflections.
+    ///
+    /// This effectively computes `Q, R, P` such that `matrix * P = QR`.
+    pub fn new(mut matrix: MatrixMN<N, R, C>) -> Self {
+        let (nrows, ncols) = matrix.data.shape();
+        let min_nrows_ncols = nrows.min(ncols);
+
+        let mut p = PermutationSequence::identity_generic(min_nrows_ncols);
+        let mut diag = unsafe { MatrixMN::new_uninitialized_generic(min_nrows_ncols, U1) };
+
+        if min_nrows_ncols.value() == 0 {
+            return ColPivQR {
+                col_piv_qr: matrix,
+                p,
+                diag,
+            };
+        }
+
+        for ite in 0..min_nrows_ncols.value() {
+            // Select the remaining column with the largest norm.
+            let mut col_piv = ite;
+            let mut max_norm_squared = N::RealField::zero();
+
+            for j in ite..ncols.value() {
+                let norm_squared = matrix.slice_range(ite.., j).norm_squared();
+
+                if norm_squared > max_norm_squared {
+                    col_piv = j;
+                    max_norm_squared = norm_squared;
+                }
+            }
+
+            matrix.swap_columns(ite, col_piv);
+            p.append_permutation(ite, col_piv);
+
+            householder::clear_column_unchecked(&mut matrix, &mut diag[ite], ite, 0, None);
+        }
+
+        ColPivQR {
+            col_piv_qr: matrix,
+            p,
+            diag,
+        }
+    }
+
+    /// Retrieves the upper trapezoidal submatrix `R` of this decomposition.
+    #[inline]
+    pub fn r(&self) -> MatrixMN<N, DimMinimum<R, C>, C>
+    where
+        DefaultAllocator: Allocator<N, DimMinimum<R, C>, C>,
+    {
+        let (nrows, ncols) = self.col_piv_qr.data.shape();
+        let mut res = self
+            .col_piv_qr
+            .rows_generic(0, nrows.min(ncols))
+            .upper_triangle();
+        res.set_partial_diagonal(self.diag.iter().map(|e| N::from_real(e.modulus())));
+        res
+    }
+
+    /// Retrieves the upper trapezoidal submatrix `R` of this decomposition.
+    ///
+    /// This is usually faster than `r` but consumes `self`.
+    #[inline]
+    pub fn unpack_r(self) -> MatrixMN<N, DimMinimum<R, C>, C>
+    where
+        DefaultAllocator: Reallocator<N, R, C, DimMinimum<R, C>, C>,
+    {
+        let (nrows, ncols) = self.col_piv_qr.data.shape();
+        let mut res = self
+            .col_piv_qr
+            .resize_generic(nrows.min(ncols), ncols, N::zero());
+        res.fill_lower_triangle(N::zero(), 1);
+        res.set_partial_diagonal(self.diag.iter().map(|e| N::from_real(e.modulus())));
+        res
+    }
+
+    /// Computes the orthogonal matrix `Q` of this decomposition.
+    pub fn q(&self) -> MatrixMN<N, R, DimMinimum<R, C>>
+    where
+        DefaultAllocator: Allocator<N, R, DimMinimum<R, C>>,
+    {
+        let (nrows, ncols) = self.col_piv_qr.data.shape();
+
+        // NOTE: we could build the identity matrix and call q_mul on it.
+        // Instead we don't so that we take in account the matrix sparseness.
+        let mut res = Matrix::identity_generic(nrows, nrows.min(ncols));
+        let dim = self.diag.len();
+
+        for i in (0..dim).rev() {
+            let axis = self.col_piv_qr.slice_range(i.., i);
+            // FIXME: sometimes, the axis might have a zero magnitude.
+            let refl = Reflection::new(Unit::new_unchecked(axis), N::zero());
+
+            let mut res_rows = res.slice_range_mut(i.., i..);
+            refl.reflect_with_sign(&mut res_rows, self.diag[i].signum());
+        }
+
+        res
+    }
+
+    /// The column permutations of this decomposition.
+    #[inline]
+    pub fn p(&self) -> &PermutationSequence<DimMinimum<R, C>> {
+        &self.p
+    }
+
+    /// Unpacks this decomposition into its two matrix factors and the column permutations:
+    /// `(Q, R, P)`.
+    pub fn unpack(
+        self,
+    ) -> (
+        MatrixMN<N, R, DimMinimum<R, C>>,
+        MatrixMN<N, DimMinimum<R, C>, C>,
+        PermutationSequence<DimMinimum<R, C>>,
+    )
+    where
+        DimMinimum<R, C>: DimMin<C, Output = DimMinimum<R, C>>,
+        DefaultAllocator:
+            Allocator<N, R, DimMinimum<R, C>> + Reallocator<N, R, C, DimMinimum<R, C>, C>,
+    {
+        let q = self.q();
+        let p = self.p.clone();
+
+        (q, self.unpack_r(), p)
+    }
+
+    #[doc(hidden)]
+    pub fn col_piv_qr_internal(&self) -> &MatrixMN<N, R, C> {
+        &self.col_piv_qr
+    }
+
+    /// Multiplies the provided matrix by the transpose of the `Q` matrix of this decomposition.
+    pub fn q_tr_mul<R2: Dim, C2: Dim, S2>(&self, rhs: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+    {
+        let dim = self.diag.len();
+
+        for i in 0..dim {
+            let axis = self.col_piv_qr.slice_range(i.., i);
+            let refl = Reflection::new(Unit::new_unchecked(axis), N::zero());
+
+            let mut rhs_rows = rhs.rows_range_mut(i..);
+            refl.reflect_with_sign(&mut rhs_rows, self.diag[i].signum().conjugate());
+        }
+    }
+
+    /// Estimates the numerical rank of the decomposed matrix, i.e., the number of diagonal
+    /// elements of `R` with a modulus greater than `eps`.
+    pub fn rank(&self, eps: N::RealField) -> usize {
+        assert!(
+            eps >= N::RealField::zero(),
+            "ColPivQR rank: the epsilon must be non-negative."
+        );
+
+        // The moduli of the diagonal elements are non-increasing.
+        self.diag.iter().take_while(|e| e.modulus() > eps).count()
+    }
+
+    /// Computes a least-squares solution of the linear system `self * x = b`, where `x` is the
+    /// unknown to be determined.
+    ///
+    /// The decomposed matrix is considered to have the rank given by `self.rank(eps)`. If it is
+    /// rank-deficient, the basic solution is computed, i.e., the components of `x` associated to
+    /// the last `self.ncols() - rank` columns of `self * P` are set to zero.
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+        eps: N::RealField,
+    ) -> MatrixMN<N, C, C2>
+    where
+        S2: Storage<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, R>,
+        DefaultAllocator: Allocator<N, R2, C2> + Allocator<N, C, C2>,
+    {
+        assert_eq!(
+            self.col_piv_qr.nrows(),
+            b.nrows(),
+            "ColPivQR solve matrix dimension mismatch."
+        );
+
+        let rank = self.rank(eps);
+        let ncols = self.col_piv_qr.data.shape().1;
+        let mut qtb = b.clone_owned();
+        self.q_tr_mul(&mut qtb);
+
+        let mut res = MatrixMN::zeros_generic(ncols, b.data.shape().1);
+
+        for k in 0..qtb.ncols() {
+            let mut qtb = qtb.column_mut(k);
+
+            for i in (0..rank).rev() {
+                let diag = self.diag[i].modulus();
+                let coeff = qtb[i].unscale(diag);
+
+                res[(i, k)] = coeff;
+                qtb.rows_range_mut(..i).axpy(
+                    -coeff,
+                    &self.col_piv_qr.slice_range(..i, i),
+                    N::one(),
+                );
+            }
+        }
+
+        self.p.inv_permute_rows(&mut res);
+        res
+    }
+}
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S>
+where
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, R>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>,
+{
+    /// Computes the QR decomposition with column pivoting of this matrix.
+    ///
+    /// This effectively computes `Q, R, P` such that `self * P = QR`.
+    pub fn col_piv_qr(self) -> ColPivQR<N, R, C> {
+        ColPivQR::new(self.into_owned())
+    }
+}
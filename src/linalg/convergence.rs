@@ -0,0 +1,27 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+/// The convergence status of an iterative algorithm that may have been stopped early.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ConvergenceInfo {
+    /// Whether the algorithm converged.
+    pub converged: bool,
+    /// The number of iterations performed by the algorithm.
+    pub iterations: usize,
+}
+
+/// The state of an iterative algorithm after one of its iterations, given to the observers of
+/// the `*_with_observer` methods.
+///
+/// The observer returns `ControlFlow::Continue(())` to let the algorithm proceed, or
+/// `ControlFlow::Break(())` to abort it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IterationInfo<N> {
+    /// The number of iterations performed so far, starting at 1.
+    pub iteration: usize,
+    /// The measure of the distance to convergence used by the algorithm, e.g., the norm of the
+    /// residual of an iterative solver. See the documentation of each `*_with_observer` method
+    /// for its exact definition.
+    pub residual: N,
+}
@@ -1,113 +1,165 @@
 #[cfg(feature = "serde-serialize")]
 use serde::{Deserialize, Serialize};
 
-use num_complex::Complex;
-use simba::scalar::ComplexField;
-use std::cmp;
-use std::fmt::Display;
-use std::ops::MulAssign;
+use num_complex::Complex as NumComplex;
+use simba::scalar::{ComplexField, RealField};
 
 use crate::allocator::Allocator;
-use crate::base::dimension::{Dim, DimDiff, DimSub, Dynamic, U1, U2, U3};
+use crate::base::dimension::{Dim, DimDiff, DimSub, U1};
 use crate::base::storage::Storage;
-use crate::base::{
-    DefaultAllocator, Hessenberg, MatrixN, SquareMatrix, Unit, Vector2, Vector3, VectorN,
-};
-use crate::constraint::{DimEq, ShapeConstraint};
+use crate::base::{DefaultAllocator, MatrixN, SquareMatrix, VectorN};
 
-use crate::geometry::{Reflection, UnitComplex};
-use crate::linalg::householder;
-use crate::linalg::Schur;
+use crate::linalg::{balancing, LinalgError, Schur};
 
-/// Eigendecomposition of a real matrix with real eigenvalues (or complex eigen values for complex matrices).
+/// Eigendecomposition of a general (not necessarily symmetric) real square matrix.
+///
+/// The eigenvalues and eigenvectors of such a matrix are complex in general.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde-serialize",
-    serde(bound(serialize = "DefaultAllocator: Allocator<N, D>,
-         VectorN<N, D>: Serialize,
-         MatrixN<N, D>: Serialize"))
+    serde(bound(serialize = "DefaultAllocator: Allocator<NumComplex<N>, D, D> +
+                           Allocator<NumComplex<N>, D>,
+         VectorN<NumComplex<N>, D>: Serialize,
+         MatrixN<NumComplex<N>, D>: Serialize"))
 )]
 #[cfg_attr(
     feature = "serde-serialize",
-    serde(bound(deserialize = "DefaultAllocator: Allocator<N, D>,
-         VectorN<N, D>: Serialize,
-         MatrixN<N, D>: Deserialize<'de>"))
+    serde(bound(deserialize = "DefaultAllocator:
+                           Allocator<NumComplex<N>, D, D> +
+                           Allocator<NumComplex<N>, D>,
+         VectorN<NumComplex<N>, D>: Deserialize<'de>,
+         MatrixN<NumComplex<N>, D>: Deserialize<'de>"))
 )]
 #[derive(Clone, Debug)]
-pub struct Eigen<N: ComplexField, D: Dim>
-where DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>
+pub struct Eigen<N: RealField, D: Dim>
+where
+    DefaultAllocator: Allocator<NumComplex<N>, D, D> + Allocator<NumComplex<N>, D>,
 {
-    pub eigenvectors: MatrixN<N, D>,
-    pub eigenvalues: VectorN<N, D>,
+    /// The unit-length eigenvectors of the decomposed matrix.
+    ///
+    /// The i-th column is the eigenvector associated to the i-th eigenvalue.
+    pub eigenvectors: MatrixN<NumComplex<N>, D>,
+
+    /// The unsorted eigenvalues of the decomposed matrix.
+    pub eigenvalues: VectorN<NumComplex<N>, D>,
 }
 
-impl<N: ComplexField, D: Dim> Copy for Eigen<N, D>
+impl<N: RealField, D: Dim> Copy for Eigen<N, D>
 where
-    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
-    MatrixN<N, D>: Copy,
-    VectorN<N, D>: Copy,
+    DefaultAllocator: Allocator<NumComplex<N>, D, D> + Allocator<NumComplex<N>, D>,
+    MatrixN<NumComplex<N>, D>: Copy,
+    VectorN<NumComplex<N>, D>: Copy,
 {
 }
 
-impl<N: ComplexField, D: Dim> Eigen<N, D>
+impl<N: RealField, D: Dim> Eigen<N, D>
 where
-    D: DimSub<U1>,                                   // For Hessenberg.
-    ShapeConstraint: DimEq<Dynamic, DimDiff<D, U1>>, // For Hessenberg.
-    DefaultAllocator: Allocator<N, D, DimDiff<D, U1>>
-        + Allocator<N, DimDiff<D, U1>>
-        + Allocator<N, D, D>
-        + Allocator<N, D>,
-    // XXX: for debug
-    DefaultAllocator: Allocator<usize, D, D>,
-    MatrixN<N, D>: Display,
+    D: DimSub<U1>, // For Hessenberg.
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, D>
+        + Allocator<NumComplex<N>, D, DimDiff<D, U1>>
+        + Allocator<NumComplex<N>, DimDiff<D, U1>>
+        + Allocator<NumComplex<N>, D, D>
+        + Allocator<NumComplex<N>, D>,
 {
-    /// Computes the eigendecomposition of a diagonalizable matrix with Complex eigenvalues.
-    pub fn new(m: MatrixN<N, D>) -> Option<Eigen<N, D>> {
-        assert!(
-            m.is_square(),
-            "Unable to compute the eigendecomposition of a non-square matrix."
-        );
-
-        let dim = m.nrows();
-        let (mut eigenvectors, mut eigenvalues) = Schur::new(m, 0).unwrap().unpack();
-
-        println!("Schur eigenvalues: {}", eigenvalues);
-
-        // Check that the eigenvalues are all Complex.
-        for i in 0..dim - 1 {
-            if !eigenvalues[(i + 1, i)].is_zero() {
-                return None;
-            }
+    /// Computes the eigendecomposition of a real square matrix.
+    pub fn new(m: MatrixN<N, D>) -> Self {
+        Self::try_new(m, N::default_epsilon(), 0).unwrap()
+    }
+
+    /// Attempts to compute the eigendecomposition of a real square matrix.
+    ///
+    /// If the matrix is not diagonalizable, the eigenvectors associated to repeated eigenvalues
+    /// may be (almost) colinear.
+    ///
+    /// The matrix is balanced first, see `balancing::balance_parlett_reinsch`, so that the
+    /// eigenvalues of badly scaled matrices are computed accurately. The returned eigenvectors are
+    /// those of the original matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `LinalgError::NotConverged` is returned. If `niter == 0`,
+    /// then the algorithm continues indefinitely until convergence.
+    pub fn try_new(m: MatrixN<N, D>, eps: N, max_niter: usize) -> Result<Self, LinalgError> {
+        if !m.is_square() {
+            return Err(LinalgError::DimensionMismatch);
         }
 
-        for j in 1..dim {
-            for i in 0..j {
-                let diff = eigenvalues[(i, i)] - eigenvalues[(j, j)];
+        let mut m = m;
+        let scaling = balancing::balance_parlett_reinsch(&mut m);
+        let m = m.map(|e| NumComplex::new(e, N::zero()));
+        let (q, t) = Schur::try_new(m, eps, max_niter)?.unpack();
+        let dim = t.data.shape().0;
+        let eigenvalues = t.diagonal();
+
+        // Threshold used to perturb the diagonal when two eigenvalues are (nearly) equal.
+        let mut smin = eps * t.camax();
+        if smin.is_zero() {
+            smin = eps;
+        }
 
-                if diff.is_zero() && !eigenvalues[(i, j)].is_zero() {
-                    return None;
-                }
+        // Compute the eigenvectors of the upper-triangular matrix `t` by back-substitution.
+        let mut x = MatrixN::zeros_generic(dim, dim);
 
-                let z = -eigenvalues[(i, j)] / diff;
+        for k in 0..dim.value() {
+            let eigval = eigenvalues[k];
+            x[(k, k)] = NumComplex::new(N::one(), N::zero());
 
-                for k in j + 1..dim {
-                    eigenvalues[(i, k)] -= z * eigenvalues[(j, k)];
+            for i in (0..k).rev() {
+                let mut sum = NumComplex::new(N::zero(), N::zero());
+                for j in i + 1..=k {
+                    sum += t[(i, j)] * x[(j, k)];
                 }
 
-                for k in 0..dim {
-                    eigenvectors[(k, j)] += z * eigenvectors[(k, i)];
+                let mut diff = t[(i, i)] - eigval;
+                if diff.norm1() < smin {
+                    diff = NumComplex::new(smin, N::zero());
                 }
+
+                x[(i, k)] = -sum / diff;
             }
         }
 
-        // Normalize the eigenvector basis.
-        for i in 0..dim {
+        let mut eigenvectors = q * x;
+        balancing::unbalance_eigenvectors(&mut eigenvectors, &scaling);
+
+        for i in 0..dim.value() {
             let _ = eigenvectors.column_mut(i).normalize_mut();
         }
 
-        Some(Eigen {
+        Ok(Eigen {
             eigenvectors,
-            eigenvalues: eigenvalues.diagonal(),
+            eigenvalues,
         })
     }
 }
+
+impl<N: RealField, D: Dim, S: Storage<N, D, D>> SquareMatrix<N, D, S>
+where
+    D: DimSub<U1>, // For Hessenberg.
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, D>
+        + Allocator<NumComplex<N>, D, DimDiff<D, U1>>
+        + Allocator<NumComplex<N>, DimDiff<D, U1>>
+        + Allocator<NumComplex<N>, D, D>
+        + Allocator<NumComplex<N>, D>,
+{
+    /// Computes the eigendecomposition of this real square matrix.
+    pub fn eigen(self) -> Eigen<N, D> {
+        Eigen::new(self.into_owned())
+    }
+
+    /// Attempts to compute the eigendecomposition of this real square matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `LinalgError::NotConverged` is returned. If `niter == 0`,
+    /// then the algorithm continues indefinitely until convergence.
+    pub fn try_eigen(self, eps: N, max_niter: usize) -> Result<Eigen<N, D>, LinalgError> {
+        Eigen::try_new(self.into_owned(), eps, max_niter)
+    }
+}
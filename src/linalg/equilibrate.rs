@@ -0,0 +1,82 @@
+use simba::scalar::{ComplexField, RealField};
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{Dim, U1};
+use crate::base::storage::{Storage, StorageMut};
+use crate::base::{DefaultAllocator, Matrix, VectorN};
+
+impl<N: ComplexField, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
+    /// Computes the row and column scaling factors that equilibrate this matrix.
+    ///
+    /// Returns the vectors `r` and `c` such that the largest modulus of each row and each column
+    /// of `diag(r) * self * diag(c)` is close to 1. The scaling factors are powers of two, so that
+    /// scaling the matrix does not introduce any rounding error. Rows (resp. columns) that are
+    /// zero get a scaling factor of 1.
+    ///
+    /// Equilibrating the matrix of a badly scaled linear system before computing its
+    /// decomposition improves the accuracy of the solution, see `LU::solve_equilibrated`.
+    pub fn equilibrate(&self) -> (VectorN<N::RealField, R>, VectorN<N::RealField, C>)
+    where
+        DefaultAllocator: Allocator<N::RealField, R> + Allocator<N::RealField, C>,
+    {
+        let (nrows, ncols) = self.data.shape();
+        let mut row_scale = VectorN::zeros_generic(nrows, U1);
+        let mut col_scale = VectorN::zeros_generic(ncols, U1);
+
+        for j in 0..ncols.value() {
+            for i in 0..nrows.value() {
+                let modulus = self[(i, j)].modulus();
+
+                if modulus > row_scale[i] {
+                    row_scale[i] = modulus;
+                }
+            }
+        }
+
+        row_scale.apply(inverse_power_of_two);
+
+        for j in 0..ncols.value() {
+            for i in 0..nrows.value() {
+                let modulus = self[(i, j)].modulus() * row_scale[i];
+
+                if modulus > col_scale[j] {
+                    col_scale[j] = modulus;
+                }
+            }
+        }
+
+        col_scale.apply(inverse_power_of_two);
+
+        (row_scale, col_scale)
+    }
+
+    /// Equilibrates this matrix in-place, and returns the row and column scaling factors `r` and
+    /// `c` such that `self` has been replaced by `diag(r) * self * diag(c)`.
+    ///
+    /// See `.equilibrate()` for details.
+    pub fn equilibrate_mut(&mut self) -> (VectorN<N::RealField, R>, VectorN<N::RealField, C>)
+    where
+        S: StorageMut<N, R, C>,
+        DefaultAllocator: Allocator<N::RealField, R> + Allocator<N::RealField, C>,
+    {
+        let (row_scale, col_scale) = self.equilibrate();
+
+        for j in 0..self.ncols() {
+            for i in 0..self.nrows() {
+                self[(i, j)] = self[(i, j)].scale(row_scale[i] * col_scale[j]);
+            }
+        }
+
+        (row_scale, col_scale)
+    }
+}
+
+// The power of two closest to `1 / x`, or 1 if `x` is zero or not finite.
+fn inverse_power_of_two<N: RealField>(x: N) -> N {
+    if x.is_zero() || !x.is_finite() {
+        return N::one();
+    }
+
+    let two: N = crate::convert(2.0);
+    two.powf(-x.log2().round())
+}
@@ -0,0 +1,35 @@
+use std::fmt;
+
+/// The error returned by the `try_*` constructors of the matrix decompositions when they fail.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LinalgError {
+    /// The iterative algorithm did not converge within the maximum number of iterations.
+    NotConverged {
+        /// The number of iterations performed before giving up.
+        iterations: usize,
+    },
+    /// The matrix is singular, or too ill-conditioned for the result to make sense.
+    Singular,
+    /// The matrix is not definite-positive.
+    NotPositiveDefinite,
+    /// The dimensions of the inputs are not compatible, e.g., a square matrix was expected.
+    DimensionMismatch,
+}
+
+impl fmt::Display for LinalgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LinalgError::NotConverged { iterations } => write!(
+                f,
+                "the algorithm did not converge after {} iterations",
+                iterations
+            ),
+            LinalgError::Singular => write!(f, "the matrix is singular"),
+            LinalgError::NotPositiveDefinite => write!(f, "the matrix is not definite-positive"),
+            LinalgError::DimensionMismatch => write!(f, "mismatched matrix dimensions"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LinalgError {}
@@ -425,6 +425,67 @@ where
     max
 }
 
+/// Evaluates `(cosh(sqrt(z)), sinh(sqrt(z)) / sqrt(z))`, which are entire functions of `z`.
+fn cosh_sinhc_sqrt<N: ComplexField>(z: N) -> (N, N) {
+    match z.try_sqrt() {
+        Some(w) if w.is_zero() => (N::one(), N::one()),
+        Some(w) => (w.cosh(), w.sinh() / w),
+        // Only happens for a negative real `z`.
+        None => {
+            let w = (-z).sqrt();
+            (w.cos(), w.sin() / w)
+        }
+    }
+}
+
+/// Computes the exponential of the 2x2 matrix `m`.
+fn exp_2x2<N: ComplexField, D: Dim>(m: &MatrixN<N, D>) -> MatrixN<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    // With `s = tr(m) / 2`, the matrix `m - s * I` squares to `q * I` with `q = -det(m - s * I)`.
+    let half: N = convert(0.5);
+    let s = (m[(0, 0)] + m[(1, 1)]) * half;
+    let a = (m[(0, 0)] - m[(1, 1)]) * half;
+    let q = a * a + m[(0, 1)] * m[(1, 0)];
+    let (c, sc) = cosh_sinhc_sqrt(q);
+    let es = s.exp();
+
+    let mut res = m.clone();
+    res[(0, 0)] = es * (c + sc * a);
+    res[(0, 1)] = es * sc * m[(0, 1)];
+    res[(1, 0)] = es * sc * m[(1, 0)];
+    res[(1, 1)] = es * (c - sc * a);
+    res
+}
+
+fn is_skew_symmetric<N: ComplexField, D: Dim>(m: &MatrixN<N, D>) -> bool
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let dim = m.nrows();
+    (0..dim).all(|j| (j..dim).all(|i| m[(i, j)] == -m[(j, i)]))
+}
+
+/// Computes the exponential of the 3x3 skew-symmetric matrix `m` with Rodrigues' formula.
+fn exp_skew_3x3<N: ComplexField, D: Dim>(m: &MatrixN<N, D>) -> MatrixN<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let (nrows, ncols) = m.data.shape();
+    let theta_sq = m[(2, 1)] * m[(2, 1)] + m[(0, 2)] * m[(0, 2)] + m[(1, 0)] * m[(1, 0)];
+
+    // `exp(m) = I + sin(θ) / θ * m + (1 - cos(θ)) / θ² * m²`, where the last coefficient is
+    // computed as `(sin(θ / 2) / (θ / 2))² / 2` to avoid cancellations.
+    let quarter: N = convert(0.25);
+    let half: N = convert(0.5);
+    let (_, sinc) = cosh_sinhc_sqrt(-theta_sq);
+    let (_, sinc_half) = cosh_sinhc_sqrt(-theta_sq * quarter);
+
+    let m2 = m * m;
+    MatrixN::identity_generic(nrows, ncols) + m * sinc + m2 * (sinc_half * sinc_half * half)
+}
+
 impl<N: ComplexField, D> MatrixN<N, D>
 where
     D: DimMin<D, Output = D>,
@@ -435,12 +496,23 @@ where
         + Allocator<N::RealField, D, D>,
 {
     /// Computes exponential of this matrix
+    ///
+    /// Closed-form expressions are used for 2x2 matrices and for 3x3 skew-symmetric matrices
+    /// (Rodrigues' formula). Other matrices use a scaling-and-squaring Padé approximation.
     pub fn exp(&self) -> Self {
         // Simple case
         if self.nrows() == 1 {
             return self.map(|v| v.exp());
         }
 
+        if self.nrows() == 2 {
+            return exp_2x2(self);
+        }
+
+        if self.nrows() == 3 && is_skew_symmetric(self) {
+            return exp_skew_3x3(self);
+        }
+
         let mut h = ExpmPadeHelper::new(self.clone(), true);
 
         let eta_1 = N::RealField::max(h.d4_loose(), h.d6_loose());
@@ -0,0 +1,117 @@
+//! Fast Fourier transforms of arbitrary length, for internal use by the structured matrices.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
+
+use num::Zero;
+use num_complex::Complex as NumComplex;
+use simba::scalar::RealField;
+
+/// Computes in-place the discrete Fourier transform `X[k] = Σ x[j] * exp(∓2iπ * j * k / n)` of
+/// `data`, where the sign of the exponent is negative unless `inverse` is `true`.
+///
+/// The result is not normalized, so the inverse transform of the transform of `x` is `n * x`.
+/// This requires `O(n log(n))` operations for any length `n`: powers of two are handled by the
+/// radix-2 Cooley-Tukey algorithm, and other lengths are reduced to a convolution of a
+/// power-of-two length with the Bluestein algorithm.
+pub(crate) fn fft<N: RealField>(data: &mut [NumComplex<N>], inverse: bool) {
+    let n = data.len();
+
+    if n <= 1 {
+        return;
+    }
+
+    if n.is_power_of_two() {
+        radix2_fft(data, inverse);
+    } else {
+        bluestein_fft(data, inverse);
+    }
+}
+
+fn radix2_fft<N: RealField>(data: &mut [NumComplex<N>], inverse: bool) {
+    let n = data.len();
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { N::one() } else { -N::one() };
+    let mut len = 2;
+
+    while len <= n {
+        let angle = sign * N::two_pi() / crate::convert(len as f64);
+        let half = len / 2;
+
+        for start in (0..n).step_by(len) {
+            for k in 0..half {
+                // Recomputing the twiddle factors instead of accumulating them avoids the
+                // accumulation of rounding errors.
+                let (sin, cos) = (angle * crate::convert(k as f64)).sin_cos();
+                let twiddle = NumComplex::new(cos, sin);
+                let even = data[start + k];
+                let odd = data[start + k + half] * twiddle;
+
+                data[start + k] = even + odd;
+                data[start + k + half] = even - odd;
+            }
+        }
+
+        len *= 2;
+    }
+}
+
+fn bluestein_fft<N: RealField>(data: &mut [NumComplex<N>], inverse: bool) {
+    let n = data.len();
+    let m = (2 * n - 1).next_power_of_two();
+    let sign = if inverse { N::one() } else { -N::one() };
+
+    // The chirp `w[k] = exp(∓iπ * k² / n)`. Because `k²` is only needed modulo `2n`, it is
+    // reduced first to keep the angles small.
+    let chirp: Vec<_> = (0..n)
+        .map(|k| {
+            let k2 = (k * k) % (2 * n);
+            let angle = sign * N::pi() * crate::convert(k2 as f64) / crate::convert(n as f64);
+            let (sin, cos) = angle.sin_cos();
+            NumComplex::new(cos, sin)
+        })
+        .collect();
+
+    // Since `j * k = (j² + k² - (k - j)²) / 2`, `X = w * ((w * x) ⋆ conj(w))`.
+    let mut a = vec![NumComplex::zero(); m];
+    let mut b = vec![NumComplex::zero(); m];
+
+    for k in 0..n {
+        a[k] = data[k] * chirp[k];
+        b[k] = chirp[k].conj();
+
+        if k > 0 {
+            b[m - k] = chirp[k].conj();
+        }
+    }
+
+    radix2_fft(&mut a, false);
+    radix2_fft(&mut b, false);
+
+    for (a, b) in a.iter_mut().zip(b.iter()) {
+        *a *= *b;
+    }
+
+    radix2_fft(&mut a, true);
+
+    let scale = N::one() / crate::convert(m as f64);
+
+    for k in 0..n {
+        data[k] = a[k] * chirp[k] * scale;
+    }
+}
@@ -1,9 +1,13 @@
 #[cfg(feature = "serde-serialize")]
 use serde::{Deserialize, Serialize};
 
+use num::Zero;
+
 use crate::allocator::Allocator;
 use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::dimension::Dynamic;
 use crate::dimension::{Dim, DimMin, DimMinimum};
 use crate::storage::{Storage, StorageMut};
 use simba::scalar::ComplexField;
@@ -150,6 +154,83 @@ where
 
         (p, l, u, q)
     }
+
+    /// Estimates the numerical rank of the decomposed matrix, i.e., the number of diagonal
+    /// elements of `U` with a modulus greater than `eps`.
+    pub fn rank(&self, eps: N::RealField) -> usize {
+        assert!(
+            eps >= N::RealField::zero(),
+            "FullPivLU rank: the epsilon must be non-negative."
+        );
+
+        let (nrows, ncols) = self.lu.data.shape();
+
+        // Because of the full pivoting, the moduli of the diagonal elements are non-increasing.
+        (0..nrows.min(ncols).value())
+            .take_while(|i| self.lu[(*i, *i)].modulus() > eps)
+            .count()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<N: ComplexField, R: DimMin<C>, C: Dim> FullPivLU<N, R, C>
+where
+    DefaultAllocator: Allocator<N, R, C> + Allocator<(usize, usize), DimMinimum<R, C>>,
+{
+    /// Computes a basis of the kernel (the null space) of the decomposed matrix, i.e., a matrix
+    /// `K` with `self.ncols() - self.rank(eps)` linearly independent columns such that
+    /// `self * K` is zero.
+    ///
+    /// The columns of `K` are not orthonormal.
+    pub fn kernel(&self, eps: N::RealField) -> MatrixMN<N, C, Dynamic>
+    where
+        DefaultAllocator: Allocator<N, C, Dynamic>,
+    {
+        let rank = self.rank(eps);
+        let ncols = self.lu.data.shape().1;
+        let dim = ncols.value() - rank;
+
+        // With `U = [U1, U2]` where `U1` is the invertible `rank x rank` upper-triangular
+        // block, the kernel of `U` is spanned by the columns of `[-U1⁻¹ * U2; I]`.
+        let mut res = MatrixMN::zeros_generic(ncols, Dynamic::new(dim));
+        {
+            let mut top = res.rows_mut(0, rank);
+            top.copy_from(&self.lu.slice((0, rank), (rank, dim)));
+            top.neg_mut();
+            let _ = self
+                .lu
+                .slice((0, 0), (rank, rank))
+                .solve_upper_triangular_mut(&mut top);
+        }
+        res.rows_mut(rank, dim).fill_with_identity();
+
+        self.q.inv_permute_rows(&mut res);
+        res
+    }
+
+    /// Computes a basis of the image (the column space) of the decomposed matrix, i.e., a matrix
+    /// with `self.rank(eps)` linearly independent columns spanning the same space as the columns
+    /// of the decomposed matrix.
+    ///
+    /// The columns of the result are not orthonormal.
+    pub fn image(&self, eps: N::RealField) -> MatrixMN<N, R, Dynamic>
+    where
+        DefaultAllocator: Allocator<N, R, Dynamic>,
+    {
+        let rank = self.rank(eps);
+        let nrows = self.lu.data.shape().0;
+
+        // The image is spanned by the first `rank` columns of `P⁻¹ * L`.
+        let mut res = self
+            .lu
+            .generic_slice((0, 0), (nrows, Dynamic::new(rank)))
+            .into_owned();
+        res.fill_upper_triangle(N::zero(), 1);
+        res.fill_diagonal(N::one());
+
+        self.p.inv_permute_rows(&mut res);
+        res
+    }
 }
 
 impl<N: ComplexField, D: DimMin<D, Output = D>> FullPivLU<N, D, D>
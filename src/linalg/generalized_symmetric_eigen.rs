@@ -0,0 +1,160 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, MatrixN, SquareMatrix, VectorN};
+use crate::dimension::{Dim, DimDiff, DimSub, U1};
+use crate::storage::Storage;
+use simba::scalar::ComplexField;
+
+use crate::linalg::{Cholesky, LinalgError, SymmetricEigen};
+
+/// Eigendecomposition of a symmetric-definite matrix pencil, i.e., solution of the generalized
+/// eigenproblem `A * x = λ * B * x` where `A` is symmetric and `B` is symmetric
+/// definite-positive.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<N::RealField, D>,
+         VectorN<N::RealField, D>: Serialize,
+         MatrixN<N, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<N::RealField, D>,
+         VectorN<N::RealField, D>: Deserialize<'de>,
+         MatrixN<N, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct GeneralizedSymmetricEigen<N: ComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N::RealField, D>,
+{
+    /// The eigenvectors of the decomposed pencil.
+    ///
+    /// They are orthonormal with regard to the inner product defined by `B`, i.e.,
+    /// `eigenvectors.adjoint() * B * eigenvectors` is the identity matrix.
+    pub eigenvectors: MatrixN<N, D>,
+
+    /// The unsorted eigenvalues of the decomposed pencil.
+    pub eigenvalues: VectorN<N::RealField, D>,
+}
+
+impl<N: ComplexField, D: Dim> Copy for GeneralizedSymmetricEigen<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N::RealField, D>,
+    MatrixN<N, D>: Copy,
+    VectorN<N::RealField, D>: Copy,
+{
+}
+
+impl<N: ComplexField, D: DimSub<U1>> GeneralizedSymmetricEigen<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N::RealField, D>
+        + Allocator<N::RealField, DimDiff<D, U1>>,
+{
+    /// Computes the eigendecomposition of the pencil `(a, b)`.
+    ///
+    /// Only the lower-triangular parts (including the diagonals) of `a` and `b` are read.
+    /// Returns `None` if `b` is not definite-positive.
+    pub fn new(a: MatrixN<N, D>, b: MatrixN<N, D>) -> Option<Self> {
+        Self::try_new(a, b, N::RealField::default_epsilon(), 0).ok()
+    }
+
+    /// Computes the eigendecomposition of the pencil `(a, b)` with user-specified convergence
+    /// parameters.
+    ///
+    /// Only the lower-triangular parts (including the diagonals) of `a` and `b` are read.
+    /// Returns `LinalgError::NotPositiveDefinite` if `b` is not definite-positive, and
+    /// `LinalgError::DimensionMismatch` if `a` and `b` are not square matrices of the same
+    /// dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `LinalgError::NotConverged` is returned. If `niter == 0`,
+    /// then the algorithm continues indefinitely until convergence.
+    pub fn try_new(
+        mut a: MatrixN<N, D>,
+        b: MatrixN<N, D>,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Result<Self, LinalgError> {
+        if !a.is_square() || a.shape() != b.shape() {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
+        let chol = Cholesky::new(b).ok_or(LinalgError::NotPositiveDefinite)?;
+        let l = chol.l_dirty();
+
+        // Reduce to the standard problem `C * y = λ * y` with `C = L⁻¹ * A * L⁻ᴴ`.
+        let dim = a.nrows();
+        for j in 1..dim {
+            for i in 0..j {
+                a[(i, j)] = a[(j, i)].conjugate();
+            }
+        }
+
+        l.solve_lower_triangular_unchecked_mut(&mut a);
+        a.adjoint_mut();
+        l.solve_lower_triangular_unchecked_mut(&mut a);
+
+        let eig = SymmetricEigen::try_new(a, eps, max_niter)?;
+
+        // Recover the eigenvectors of the pencil as `x = L⁻ᴴ * y`.
+        let mut eigenvectors = eig.eigenvectors;
+        l.ad_solve_lower_triangular_unchecked_mut(&mut eigenvectors);
+
+        Ok(GeneralizedSymmetricEigen {
+            eigenvectors,
+            eigenvalues: eig.eigenvalues,
+        })
+    }
+}
+
+impl<N: ComplexField, D: Dim> GeneralizedSymmetricEigen<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N::RealField, D>,
+{
+    /// Rebuild the matrix `A` of the pencil, given its matrix `B`.
+    ///
+    /// This is useful if some of the eigenvalues have been manually modified.
+    pub fn recompose<S: Storage<N, D, D>>(&self, b: &Matrix<N, D, D, S>) -> MatrixN<N, D> {
+        // Since `X.adjoint() * B * X = I`, we have `A = B * X * Λ * X.adjoint() * B`.
+        let bx = b * &self.eigenvectors;
+        let mut bxl = bx.clone();
+        for i in 0..self.eigenvalues.len() {
+            let val = self.eigenvalues[i];
+            bxl.column_mut(i).scale_mut(val);
+        }
+
+        bxl * bx.adjoint()
+    }
+}
+
+impl<N: ComplexField, D: DimSub<U1>, S: Storage<N, D, D>> SquareMatrix<N, D, S>
+where
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N::RealField, D>
+        + Allocator<N::RealField, DimDiff<D, U1>>,
+{
+    /// Computes the eigendecomposition of the pencil `(self, b)`, i.e., solves the generalized
+    /// eigenproblem `self * x = λ * b * x`.
+    ///
+    /// Only the lower-triangular parts (including the diagonals) of `self` and `b` are read.
+    /// Returns `None` if `b` is not definite-positive.
+    pub fn generalized_symmetric_eigen(
+        self,
+        b: MatrixN<N, D>,
+    ) -> Option<GeneralizedSymmetricEigen<N, D>> {
+        GeneralizedSymmetricEigen::new(self.into_owned(), b)
+    }
+}
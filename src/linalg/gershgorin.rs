@@ -0,0 +1,52 @@
+use num::Zero;
+use simba::scalar::ComplexField;
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{Dim, U1};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, SquareMatrix, VectorN};
+
+impl<N: ComplexField, D: Dim, S: Storage<N, D, D>> SquareMatrix<N, D, S> {
+    /// Computes the Gershgorin discs of this matrix.
+    ///
+    /// The i-th element is the pair `(center, radius)` of the disc centered at the i-th diagonal
+    /// element, with a radius equal to the sum of the moduli of the other elements of the i-th row.
+    /// Every eigenvalue of this matrix lies in the union of these discs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// let m = Matrix3::new(4.0, 1.0, 0.0,
+    ///                      1.0, 5.0, -2.0,
+    ///                      0.0, -2.0, 9.0);
+    /// let discs = m.gershgorin_discs();
+    ///
+    /// assert_eq!(discs[0], (4.0, 1.0));
+    /// assert_eq!(discs[1], (5.0, 3.0));
+    /// assert_eq!(discs[2], (9.0, 2.0));
+    /// ```
+    pub fn gershgorin_discs(&self) -> VectorN<(N, N::RealField), D>
+    where
+        DefaultAllocator: Allocator<(N, N::RealField), D>,
+    {
+        assert!(
+            self.is_square(),
+            "Unable to compute the Gershgorin discs of a non-square matrix."
+        );
+
+        let (nrows, ncols) = self.data.shape();
+
+        VectorN::from_fn_generic(nrows, U1, |i, _| {
+            let mut radius = N::RealField::zero();
+
+            for j in 0..ncols.value() {
+                if j != i {
+                    radius += self[(i, j)].modulus();
+                }
+            }
+
+            (self[(i, i)], radius)
+        })
+    }
+}
@@ -6,9 +6,14 @@ use simba::scalar::ComplexField;
 use crate::base::constraint::{DimEq, ShapeConstraint};
 use crate::base::dimension::{Dim, U2};
 use crate::base::storage::{Storage, StorageMut};
-use crate::base::{Matrix, Vector};
+use crate::base::{Matrix, Matrix2, Vector};
 
 /// A Givens rotation.
+///
+/// This is the unitary matrix `[c, -s*; s, c]` where the cosine component `c` is real and the
+/// sine component `s` may be complex, with `c² + |s|² = 1`. Applied to a vector, or to two rows or
+/// two columns of a matrix, it can be chosen to cancel one of the two components. For real
+/// scalars, this is a plane rotation of angle `atan2(s, c)`.
 #[derive(Debug, Clone, Copy)]
 pub struct GivensRotation<N: ComplexField> {
     c: N::RealField,
@@ -40,6 +45,12 @@ impl<N: ComplexField> GivensRotation<N> {
     }
 
     /// Initializes a Givens rotation form its non-normalized cosine an sine components.
+    ///
+    /// Returns `None` if the norm of the components is smaller than or equal to `eps`.
+    /// Otherwise, this returns the rotation and the complex number `norm` such that the components
+    /// are `c * norm` and `s * norm`. Since the cosine component of a Givens rotation is real, the
+    /// phase of `norm` is that of the input cosine component, and its modulus is the norm of the
+    /// input components.
     pub fn try_new(c: N, s: N, eps: N::RealField) -> Option<(Self, N)> {
         let (mod0, sign0) = c.to_exp();
         let denom = (mod0 * mod0 + s.modulus_squared()).sqrt();
@@ -56,8 +67,9 @@ impl<N: ComplexField> GivensRotation<N> {
 
     /// Computes the rotation `R` required such that the `y` component of `R * v` is zero.
     ///
-    /// Returns `None` if no rotation is needed (i.e. if `v.y == 0`). Otherwise, this returns the norm
-    /// of `v` and the rotation `r` such that `R * v = [ |v|, 0.0 ]^t` where `|v|` is the norm of `v`.
+    /// Returns `None` if no rotation is needed (i.e. if `v.y == 0`). Otherwise, this returns the
+    /// rotation `R` and the scalar `r` such that `R * v = [ r, 0.0 ]^t`, where `r` has the modulus
+    /// `|v|` of `v` and the phase (or the sign, for real scalars) of `v.x`.
     pub fn cancel_y<S: Storage<N, U2>>(v: &Vector<N, U2, S>) -> Option<(Self, N)> {
         if !v[1].is_zero() {
             let (mod0, sign0) = v[0].to_exp();
@@ -73,8 +85,9 @@ impl<N: ComplexField> GivensRotation<N> {
 
     /// Computes the rotation `R` required such that the `x` component of `R * v` is zero.
     ///
-    /// Returns `None` if no rotation is needed (i.e. if `v.x == 0`). Otherwise, this returns the norm
-    /// of `v` and the rotation `r` such that `R * v = [ 0.0, |v| ]^t` where `|v|` is the norm of `v`.
+    /// Returns `None` if no rotation is needed (i.e. if `v.x == 0`). Otherwise, this returns the
+    /// rotation `R` and the scalar `r` such that `R * v = [ 0.0, r ]^t`, where `r` has the modulus
+    /// `|v|` of `v` and the phase (or the sign, for real scalars) of `v.y`.
     pub fn cancel_x<S: Storage<N, U2>>(v: &Vector<N, U2, S>) -> Option<(Self, N)> {
         if !v[0].is_zero() {
             let (mod1, sign1) = v[1].to_exp();
@@ -99,6 +112,8 @@ impl<N: ComplexField> GivensRotation<N> {
     }
 
     /// The inverse of this givens rotation.
+    ///
+    /// Since a Givens rotation is unitary, this is also its adjoint.
     pub fn inverse(&self) -> Self {
         Self {
             c: self.c,
@@ -106,6 +121,12 @@ impl<N: ComplexField> GivensRotation<N> {
         }
     }
 
+    /// The 2x2 unitary matrix `[c, -s*; s, c]` of this rotation.
+    pub fn to_rotation_matrix(&self) -> Matrix2<N> {
+        let c = N::from_real(self.c);
+        Matrix2::new(c, -self.s.conjugate(), self.s, c)
+    }
+
     /// Performs the multiplication `rhs = self * rhs` in-place.
     pub fn rotate<R2: Dim, C2: Dim, S2: StorageMut<N, R2, C2>>(
         &self,
@@ -158,4 +179,62 @@ impl<N: ComplexField> GivensRotation<N> {
             }
         }
     }
+
+    /// Performs the multiplication `rhs = self * rhs` in-place, where `self` acts on the rows `i`
+    /// and `k` of `rhs`.
+    ///
+    /// This is the same as `rotate` applied to the matrix made of the row `i` on top of the row
+    /// `k`, and panics if `i == k` or if one of these rows is out of bounds.
+    pub fn rotate_pair<R2: Dim, C2: Dim, S2: StorageMut<N, R2, C2>>(
+        &self,
+        rhs: &mut Matrix<N, R2, C2, S2>,
+        i: usize,
+        k: usize,
+    ) {
+        assert!(
+            i != k && i < rhs.nrows() && k < rhs.nrows(),
+            "Givens rotation: invalid pair of rows."
+        );
+        let s = self.s;
+        let c = self.c;
+
+        for j in 0..rhs.ncols() {
+            unsafe {
+                let a = *rhs.get_unchecked((i, j));
+                let b = *rhs.get_unchecked((k, j));
+
+                *rhs.get_unchecked_mut((i, j)) = a.scale(c) - s.conjugate() * b;
+                *rhs.get_unchecked_mut((k, j)) = s * a + b.scale(c);
+            }
+        }
+    }
+
+    /// Performs the multiplication `lhs = lhs * self` in-place, where `self` acts on the columns
+    /// `i` and `k` of `lhs`.
+    ///
+    /// This is the same as `rotate_rows` applied to the matrix made of the column `i` followed by
+    /// the column `k`, and panics if `i == k` or if one of these columns is out of bounds.
+    pub fn rotate_rows_pair<R2: Dim, C2: Dim, S2: StorageMut<N, R2, C2>>(
+        &self,
+        lhs: &mut Matrix<N, R2, C2, S2>,
+        i: usize,
+        k: usize,
+    ) {
+        assert!(
+            i != k && i < lhs.ncols() && k < lhs.ncols(),
+            "Givens rotation: invalid pair of columns."
+        );
+        let s = self.s;
+        let c = self.c;
+
+        for j in 0..lhs.nrows() {
+            unsafe {
+                let a = *lhs.get_unchecked((j, i));
+                let b = *lhs.get_unchecked((j, k));
+
+                *lhs.get_unchecked_mut((j, i)) = a.scale(c) + s * b;
+                *lhs.get_unchecked_mut((j, k)) = -s.conjugate() * a + b.scale(c);
+            }
+        }
+    }
 }
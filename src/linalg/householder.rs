@@ -1,13 +1,154 @@
 //! Construction of householder elementary reflections.
 
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, MatrixMN, MatrixN, Unit, Vector, VectorN};
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, Unit, Vector, VectorN};
+use crate::constraint::{DimEq, SameNumberOfRows, ShapeConstraint};
 use crate::dimension::Dim;
 use crate::storage::{Storage, StorageMut};
 use num::Zero;
 use simba::scalar::ComplexField;
 
-use crate::geometry::Reflection;
+use crate::geometry::Reflection as GeometricReflection;
+
+/// An householder elementary reflection `H = I - 2 * v * v*`, where `v` is a unit vector.
+///
+/// An householder reflection is both hermitian and unitary, i.e., `H⁻¹ = H* = H`. It can be
+/// applied to any matrix, or matrix slice, from the left or from the right without ever forming
+/// `H` explicitly, which requires `O(n)` operations per column (resp. per row). This is the
+/// building block of the QR, Hessenberg, bidiagonal and tridiagonal decompositions and can be
+/// used to implement other structured factorizations.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DefaultAllocator: Allocator<N, D>,
+         VectorN<N, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<N, D>,
+         VectorN<N, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct Reflection<N: ComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    axis: VectorN<N, D>,
+}
+
+impl<N: ComplexField, D: Dim> Copy for Reflection<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+    VectorN<N, D>: Copy,
+{
+}
+
+impl<N: ComplexField, D: Dim> Reflection<N, D>
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    /// Creates the householder reflection wrt. the hyperplane orthogonal to the given axis.
+    #[inline]
+    pub fn new(axis: Unit<VectorN<N, D>>) -> Self {
+        Self {
+            axis: axis.into_inner(),
+        }
+    }
+
+    /// Computes the householder reflection that transforms `x` into a multiple of the first
+    /// canonical basis vector.
+    ///
+    /// Returns the reflection `H` and the scalar `beta` such that `H * x = (beta, 0, ..., 0)`.
+    /// The modulus of `beta` is the norm of `x`, and its sign is chosen to avoid cancellations
+    /// so that the reflection is computed accurately. Returns `None` if `x` is zero.
+    pub fn annihilating<S: Storage<N, D>>(x: &Vector<N, D, S>) -> Option<(Self, N)> {
+        if x.is_empty() {
+            return None;
+        }
+
+        let mut axis = x.clone_owned();
+        let (beta, not_zero) = reflection_axis_mut(&mut axis);
+
+        if not_zero {
+            Some((Self { axis }, beta))
+        } else {
+            None
+        }
+    }
+
+    /// The unit axis `v` of this reflection.
+    #[inline]
+    pub fn axis(&self) -> &VectorN<N, D> {
+        &self.axis
+    }
+
+    /// The number of rows and columns of this reflection.
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.axis.len()
+    }
+
+    /// Computes `H * m` in-place, i.e., applies this reflection to each column of `m`.
+    ///
+    /// Panics if the number of rows of `m` is not equal to the dimension of this reflection.
+    pub fn apply_left<R2: Dim, C2: Dim, S2>(&self, m: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            m.nrows(),
+            self.dimension(),
+            "Householder reflection: dimension mismatch."
+        );
+
+        let m_two: N = crate::convert(-2.0f64);
+
+        for j in 0..m.ncols() {
+            let factor = self.axis.dotc(&m.column(j)) * m_two;
+            m.column_mut(j).axpy(factor, &self.axis, N::one());
+        }
+    }
+
+    /// Computes `m * H` in-place, i.e., applies this reflection to each row of `m`.
+    ///
+    /// Panics if the number of columns of `m` is not equal to the dimension of this reflection.
+    pub fn apply_right<R2: Dim, C2: Dim, S2>(&self, m: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: DimEq<C2, D>,
+    {
+        assert_eq!(
+            m.ncols(),
+            self.dimension(),
+            "Householder reflection: dimension mismatch."
+        );
+
+        let m_two: N = crate::convert(-2.0f64);
+
+        for i in 0..m.nrows() {
+            let factor = m.row(i).tr_dot(&self.axis) * m_two;
+
+            for (k, v) in self.axis.iter().enumerate() {
+                m[(i, k)] += factor * v.conjugate();
+            }
+        }
+    }
+
+    /// Builds the `n × n` matrix of this reflection.
+    pub fn to_matrix(&self) -> MatrixN<N, D>
+    where
+        DefaultAllocator: Allocator<N, D, D>,
+    {
+        let dim = self.axis.data.shape().0;
+        let mut res = MatrixN::identity_generic(dim, dim);
+        self.apply_left(&mut res);
+        res
+    }
+}
 
 /// Replaces `column` by the axis of the householder reflection that transforms `column` into
 /// `(+/-|column|, 0, ..., 0)`.
@@ -60,7 +201,7 @@ pub fn clear_column_unchecked<N: ComplexField, R: Dim, C: Dim>(
     *diag_elt = reflection_norm;
 
     if not_zero {
-        let refl = Reflection::new(Unit::new_unchecked(axis), N::zero());
+        let refl = GeometricReflection::new(Unit::new_unchecked(axis), N::zero());
         let sign = reflection_norm.signum();
         if let Some(mut work) = bilateral {
             refl.reflect_rows_with_sign(&mut right, &mut work, sign);
@@ -91,7 +232,7 @@ pub fn clear_row_unchecked<N: ComplexField, R: Dim, C: Dim>(
     *diag_elt = reflection_norm;
 
     if not_zero {
-        let refl = Reflection::new(Unit::new_unchecked(axis), N::zero());
+        let refl = GeometricReflection::new(Unit::new_unchecked(axis), N::zero());
         refl.reflect_rows_with_sign(
             &mut bottom.columns_range_mut(irow + shift..),
             &mut work.rows_range_mut(irow + 1..),
@@ -121,7 +262,7 @@ where
 
     for i in (0..dim.value() - 1).rev() {
         let axis = m.slice_range(i + 1.., i);
-        let refl = Reflection::new(Unit::new_unchecked(axis), N::zero());
+        let refl = GeometricReflection::new(Unit::new_unchecked(axis), N::zero());
 
         let mut res_rows = res.slice_range_mut(i + 1.., i..);
         refl.reflect_with_sign(&mut res_rows, signs[i].signum());
@@ -0,0 +1,178 @@
+//! Truncated singular value decomposition of a matrix whose columns are received one at a time.
+
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use approx::AbsDiffEq;
+use num::Zero;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::base::dimension::{Dim, Dynamic};
+use crate::base::storage::Storage;
+use crate::base::{DMatrix, DVector, Matrix, Vector};
+
+use crate::linalg::SVD;
+
+/// A truncated singular value decomposition, updated each time a column is appended to the
+/// decomposed matrix.
+///
+/// Only the left singular vectors and the singular values are stored, so the memory used does
+/// not depend on the number of columns ingested so far. This makes it suitable for online
+/// principal component analysis over data streams, where each column is a (centered) sample.
+///
+/// Singular triplets are discarded whenever the rank of the decomposition would exceed
+/// `max_rank`, so the result is an approximation of the truncated SVD of the whole matrix. It is
+/// exact as long as the rank of the ingested columns does not exceed `max_rank`.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DVector<N::RealField>: Serialize,
+         DMatrix<N>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DVector<N::RealField>: Deserialize<'de>,
+         DMatrix<N>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct IncrementalSVD<N: ComplexField> {
+    u: DMatrix<N>,
+    singular_values: DVector<N::RealField>,
+    max_rank: usize,
+    ncols: usize,
+}
+
+impl<N: ComplexField> IncrementalSVD<N> {
+    /// Initializes the decomposition of a matrix with `nrows` rows and no column yet.
+    ///
+    /// At most `max_rank` singular values, and their left singular vectors, will be kept.
+    pub fn new(nrows: usize, max_rank: usize) -> Self {
+        IncrementalSVD {
+            u: DMatrix::zeros(nrows, 0),
+            singular_values: DVector::zeros(0),
+            max_rank,
+            ncols: 0,
+        }
+    }
+
+    /// The left singular vectors, sorted by decreasing singular value.
+    #[inline]
+    pub fn u(&self) -> &DMatrix<N> {
+        &self.u
+    }
+
+    /// The singular values, sorted by decreasing order.
+    #[inline]
+    pub fn singular_values(&self) -> &DVector<N::RealField> {
+        &self.singular_values
+    }
+
+    /// The number of singular values currently kept by this decomposition.
+    #[inline]
+    pub fn rank(&self) -> usize {
+        self.singular_values.len()
+    }
+
+    /// The maximum number of singular values kept by this decomposition.
+    #[inline]
+    pub fn max_rank(&self) -> usize {
+        self.max_rank
+    }
+
+    /// The number of rows of the decomposed matrix.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.u.nrows()
+    }
+
+    /// The number of columns ingested so far.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Updates this decomposition such that it becomes the decomposition of the decomposed
+    /// matrix with `col` appended as its last column.
+    pub fn push_column<S: Storage<N, Dynamic>>(&mut self, col: &Vector<N, Dynamic, S>) {
+        let nrows = self.nrows();
+        assert_eq!(
+            col.len(),
+            nrows,
+            "The new column must have the same number of rows as the decomposed matrix."
+        );
+
+        self.ncols += 1;
+
+        let eps = N::RealField::default_epsilon() * crate::convert(nrows.max(1) as f64);
+        let smax = self
+            .singular_values
+            .iter()
+            .fold(N::RealField::zero(), |a, b| a.max(*b));
+        let tol = eps * smax.max(col.norm());
+
+        if tol.is_zero() {
+            return;
+        }
+
+        // Project the column on the current left singular vectors. The projection is repeated
+        // once to keep the residual orthogonal to them despite rounding errors.
+        let mut p: DVector<N> = self.u.ad_mul(col);
+        let mut e: DVector<N> = col - &self.u * &p;
+        let p2: DVector<N> = self.u.ad_mul(&e);
+        e -= &self.u * &p2;
+        p += p2;
+
+        let rank = self.rank();
+        let rho = e.norm();
+        let extended = rho > tol;
+        let k_nrows = if extended { rank + 1 } else { rank };
+
+        // The decomposed matrix is `[U, e / rho] * K * [V, 0; 0, 1]^*` where
+        // `K = [diag(S), p; 0, rho]`.
+        let mut k = DMatrix::zeros(k_nrows, rank + 1);
+        for i in 0..rank {
+            k[(i, i)] = N::from_real(self.singular_values[i]);
+            k[(i, rank)] = p[i];
+        }
+
+        if extended {
+            k[(rank, rank)] = N::from_real(rho);
+        }
+
+        let svd = SVD::new(k, true, false);
+        let k_u = svd.u.unwrap();
+        let k_svals = svd.singular_values;
+
+        let mut ids: Vec<usize> = (0..k_svals.len()).filter(|&i| k_svals[i] > tol).collect();
+        ids.sort_by(|&i, &j| k_svals[j].partial_cmp(&k_svals[i]).unwrap());
+        ids.truncate(self.max_rank);
+
+        let rotation = DMatrix::from_fn(k_nrows, ids.len(), |i, j| k_u[(i, ids[j])]);
+        let mut u = &self.u * rotation.rows(0, rank);
+
+        if extended {
+            e.unscale_mut(rho);
+
+            for (mut u_j, r) in u.column_iter_mut().zip(rotation.row(rank).iter()) {
+                u_j.axpy(*r, &e, N::one());
+            }
+        }
+
+        self.u = u;
+        self.singular_values = DVector::from_iterator(ids.len(), ids.iter().map(|&i| k_svals[i]));
+    }
+
+    /// Updates this decomposition such that it becomes the decomposition of the decomposed
+    /// matrix with the columns of `cols` appended, in order, after its last column.
+    pub fn push_columns<C: Dim, S: Storage<N, Dynamic, C>>(
+        &mut self,
+        cols: &Matrix<N, Dynamic, C, S>,
+    ) {
+        for j in 0..cols.ncols() {
+            self.push_column(&cols.column(j));
+        }
+    }
+}
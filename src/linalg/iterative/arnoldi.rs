@@ -0,0 +1,162 @@
+use approx::AbsDiffEq;
+use num::Zero;
+use num_complex::Complex as NumComplex;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::base::dimension::{Dynamic, U1};
+use crate::base::storage::Storage;
+use crate::base::{DMatrix, DMatrixSlice, DVector, Vector};
+
+use crate::linalg::iterative::MatVec;
+use crate::linalg::Schur;
+
+/// The Arnoldi iteration, building an orthonormal basis of the Krylov subspace
+/// `span(v, a * v, a² * v, ...)` of a linear operator `a`.
+///
+/// After `k` steps, the orthonormal basis `V` of `k + 1` vectors and the `(k + 1) × k`
+/// upper-Hessenberg matrix `H` satisfy the Arnoldi relation `a * V[:, ..k] = V * H`. Thus the
+/// square matrix `H[..k, ..k] = V[:, ..k]* * a * V[:, ..k]` is the projection of `a` onto the
+/// Krylov subspace, and its eigenvalues (the Ritz values) approximate the eigenvalues of `a` on
+/// the boundary of its spectrum. The Arnoldi relation is also the core of Krylov solvers for
+/// linear systems like GMRES. For hermitian operators, `H` is tridiagonal and this is equivalent
+/// to the Lanczos iteration.
+///
+/// Each step requires one product with `a` and the orthogonalization of the result against the
+/// whole basis, i.e., `O(n * k)` operations.
+#[derive(Clone, Debug)]
+pub struct Arnoldi<N: ComplexField> {
+    basis: DMatrix<N>,
+    h: DMatrix<N>,
+    nsteps: usize,
+    invariant: bool,
+}
+
+impl<N: ComplexField> Arnoldi<N> {
+    /// Initializes the Arnoldi iteration with the starting vector `start`, for at most
+    /// `max_steps` steps.
+    ///
+    /// The storage for the basis and the Hessenberg matrix is allocated once for all. The starting
+    /// vector does not need to be normalized, but must not be zero.
+    pub fn new<S: Storage<N, Dynamic>>(start: &Vector<N, Dynamic, S>, max_steps: usize) -> Self {
+        let n = start.len();
+        let max_steps = max_steps.min(n);
+        let mut basis = DMatrix::zeros(n, max_steps + 1);
+
+        let norm = start.norm();
+        assert!(
+            !norm.is_zero(),
+            "Arnoldi: the starting vector must not be zero."
+        );
+        basis.set_column(0, &start.unscale(norm));
+
+        Arnoldi {
+            basis,
+            h: DMatrix::zeros(max_steps + 1, max_steps),
+            nsteps: 0,
+            invariant: false,
+        }
+    }
+
+    /// Performs one step of the Arnoldi iteration with the operator `a`, i.e., adds one vector to
+    /// the basis and one column to the Hessenberg matrix.
+    ///
+    /// Returns `false` and does nothing if the maximum number of steps has already been reached,
+    /// or if the Krylov subspace has been found to be invariant under `a`. If the Krylov subspace
+    /// becomes invariant during this step, `true` is returned but no vector is added to the basis,
+    /// and the last row of the Hessenberg matrix is zero.
+    pub fn step<A: MatVec<N>>(&mut self, a: &A) -> bool {
+        let n = self.basis.nrows();
+        let k = self.nsteps;
+
+        assert!(
+            a.nrows() == n && a.ncols() == n,
+            "Arnoldi: dimension mismatch."
+        );
+
+        if self.invariant || k == self.h.ncols() {
+            return false;
+        }
+
+        let v = self.basis.column(k).into_owned();
+        let mut w = DVector::zeros(n);
+        a.mul_vec_to(&v, &mut w);
+        let norm0 = w.norm();
+
+        // Applying the modified Gram-Schmidt process twice is enough to maintain orthogonality
+        // up to the machine precision.
+        for _ in 0..2 {
+            for i in 0..=k {
+                let col = self.basis.column(i);
+                let coeff = col.dotc(&w);
+                w.axpy(-coeff, &col, N::one());
+                self.h[(i, k)] += coeff;
+            }
+        }
+
+        let norm = w.norm();
+        self.nsteps += 1;
+
+        if norm.is_zero() || norm <= norm0 * N::RealField::default_epsilon() {
+            self.invariant = true;
+        } else {
+            self.h[(k + 1, k)] = N::from_real(norm);
+            self.basis.set_column(k + 1, &w.unscale(norm));
+        }
+
+        true
+    }
+
+    /// The number of steps performed so far.
+    #[inline]
+    pub fn nsteps(&self) -> usize {
+        self.nsteps
+    }
+
+    /// Whether the Krylov subspace has been found to be invariant under the operator.
+    ///
+    /// In this case, the eigenvalues of the square Hessenberg matrix are eigenvalues of the
+    /// operator too.
+    #[inline]
+    pub fn is_invariant(&self) -> bool {
+        self.invariant
+    }
+
+    /// The orthonormal basis of the Krylov subspace, with one column per step performed plus
+    /// one, except if the subspace is invariant.
+    #[inline]
+    pub fn basis(&self) -> DMatrixSlice<N> {
+        let nvectors = if self.invariant {
+            self.nsteps
+        } else {
+            self.nsteps + 1
+        };
+
+        self.basis.columns(0, nvectors)
+    }
+
+    /// The `(k + 1) × k` upper-Hessenberg matrix of the Arnoldi relation, where `k` is the number
+    /// of steps performed.
+    #[inline]
+    pub fn hessenberg(&self) -> DMatrixSlice<N> {
+        self.h.slice((0, 0), (self.nsteps + 1, self.nsteps))
+    }
+
+    /// The `k × k` upper-Hessenberg projection of the operator onto the `k` first basis vectors,
+    /// where `k` is the number of steps performed.
+    #[inline]
+    pub fn square_hessenberg(&self) -> DMatrixSlice<N> {
+        self.h.slice((0, 0), (self.nsteps, self.nsteps))
+    }
+}
+
+impl<N: RealField> Arnoldi<N> {
+    /// The Ritz values, i.e., the eigenvalues of the square Hessenberg matrix, which approximate
+    /// some eigenvalues of the operator.
+    pub fn ritz_values(&self) -> DVector<NumComplex<N>> {
+        if self.nsteps == 0 {
+            return DVector::zeros_generic(Dynamic::new(0), U1);
+        }
+
+        Schur::new(self.square_hessenberg().into_owned()).complex_eigenvalues()
+    }
+}
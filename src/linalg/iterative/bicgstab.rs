@@ -0,0 +1,209 @@
+use num::Zero;
+use simba::scalar::ComplexField;
+use std::ops::ControlFlow;
+
+use crate::base::dimension::Dynamic;
+use crate::base::storage::Storage;
+use crate::base::{DVector, Vector};
+
+use crate::linalg::iterative::{IdentityPreconditioner, MatVec, Preconditioner, StoppingCriterion};
+use crate::linalg::IterationInfo;
+
+/// The preconditioned biconjugate gradient stabilized method (BiCGSTAB), for linear systems
+/// with a general square matrix.
+///
+/// Each iteration requires two products with the matrix of the system, two applications of the
+/// preconditioner, and `O(n)` additional operations. Unlike methods based on an orthonormal
+/// basis of the Krylov subspace, only a fixed number of vectors is stored, so the memory does not
+/// grow with the number of iterations. The preconditioner is applied on the right, so the stopping
+/// criterion is evaluated on the residual of the original system.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BiCGStab<N: ComplexField> {
+    criterion: StoppingCriterion<N::RealField>,
+}
+
+impl<N: ComplexField> BiCGStab<N> {
+    /// Creates a BiCGSTAB solver with the default stopping criterion.
+    pub fn new() -> Self {
+        Self::with_criterion(StoppingCriterion::default())
+    }
+
+    /// Creates a BiCGSTAB solver with the given stopping criterion.
+    pub fn with_criterion(criterion: StoppingCriterion<N::RealField>) -> Self {
+        BiCGStab { criterion }
+    }
+
+    /// The stopping criterion of this solver.
+    #[inline]
+    pub fn criterion(&self) -> &StoppingCriterion<N::RealField> {
+        &self.criterion
+    }
+
+    /// Solves the linear system `a * x = b` without preconditioning, starting from `x = 0`.
+    ///
+    /// Returns `None` if the solver did not converge, see `solve_mut` for details.
+    pub fn solve<A, S>(&self, a: &A, b: &Vector<N, Dynamic, S>) -> Option<DVector<N>>
+    where
+        A: MatVec<N>,
+        S: Storage<N, Dynamic>,
+    {
+        self.solve_preconditioned(a, b, &IdentityPreconditioner)
+    }
+
+    /// Solves the linear system `a * x = b` with the preconditioner `p`, starting from `x = 0`.
+    ///
+    /// Returns `None` if the solver did not converge, see `solve_mut` for details.
+    pub fn solve_preconditioned<A, P, S>(
+        &self,
+        a: &A,
+        b: &Vector<N, Dynamic, S>,
+        p: &P,
+    ) -> Option<DVector<N>>
+    where
+        A: MatVec<N>,
+        P: Preconditioner<N>,
+        S: Storage<N, Dynamic>,
+    {
+        let mut x = DVector::zeros(b.len());
+        self.solve_mut(a, b, p, &mut x).map(|_| x)
+    }
+
+    /// Solves the linear system `a * x = b` with the preconditioner `p`, using the initial value
+    /// of `x` as the initial guess.
+    ///
+    /// On success, `x` is overwritten by the solution and the number of iterations performed is
+    /// returned. Returns `None`, and `x` contains the last iterate, if the maximum number of
+    /// iterations is exceeded, or if the method breaks down, which may happen even if `a` is
+    /// invertible.
+    pub fn solve_mut<A, P, S>(
+        &self,
+        a: &A,
+        b: &Vector<N, Dynamic, S>,
+        p: &P,
+        x: &mut DVector<N>,
+    ) -> Option<usize>
+    where
+        A: MatVec<N>,
+        P: Preconditioner<N>,
+        S: Storage<N, Dynamic>,
+    {
+        self.solve_mut_with_observer(a, b, p, x, |_| ControlFlow::Continue(()))
+    }
+
+    /// Solves the linear system `a * x = b` with the preconditioner `p`, using the initial value
+    /// of `x` as the initial guess, and calling `observer` after each iteration.
+    ///
+    /// The `residual` given to the observer is the norm of the residual `b - a * x`. If the
+    /// observer returns `ControlFlow::Break(())`, the solver stops and returns `None`, and `x`
+    /// contains the last iterate. Otherwise, this behaves like `solve_mut`.
+    pub fn solve_mut_with_observer<A, P, S>(
+        &self,
+        a: &A,
+        b: &Vector<N, Dynamic, S>,
+        p: &P,
+        x: &mut DVector<N>,
+        mut observer: impl FnMut(IterationInfo<N::RealField>) -> ControlFlow<()>,
+    ) -> Option<usize>
+    where
+        A: MatVec<N>,
+        P: Preconditioner<N>,
+        S: Storage<N, Dynamic>,
+    {
+        let n = b.len();
+
+        assert!(
+            a.nrows() == n && a.ncols() == n && x.len() == n,
+            "BiCGStab: dimension mismatch."
+        );
+
+        let b_norm = b.norm();
+        let mut v = DVector::zeros(n);
+
+        // r = b - a * x
+        a.mul_vec_to(x, &mut v);
+        let mut r = b - &v;
+        let shadow = r.clone();
+
+        let mut dir = DVector::zeros(n);
+        let mut y = DVector::zeros(n);
+        let mut z = DVector::zeros(n);
+        let mut t = DVector::zeros(n);
+        v.fill(N::zero());
+
+        let mut rho = N::one();
+        let mut alpha = N::one();
+        let mut omega = N::one();
+        let mut r_norm = r.norm();
+        let mut niter = 0;
+
+        loop {
+            if self.criterion.is_converged(r_norm, b_norm) {
+                return Some(niter);
+            }
+
+            if self.criterion.is_exhausted(niter, n) {
+                return None;
+            }
+
+            niter += 1;
+
+            let new_rho = shadow.dotc(&r);
+
+            if new_rho.is_zero() || omega.is_zero() {
+                return None;
+            }
+
+            // dir = r + beta * (dir - omega * v)
+            let beta = (new_rho / rho) * (alpha / omega);
+            rho = new_rho;
+            dir.axpy(-omega, &v, N::one());
+            dir.axpy(N::one(), &r, beta);
+
+            p.apply(&dir, &mut y);
+            a.mul_vec_to(&y, &mut v);
+            let shadow_v = shadow.dotc(&v);
+
+            if shadow_v.is_zero() {
+                return None;
+            }
+
+            alpha = rho / shadow_v;
+
+            // The residual `s = r - alpha * v` of the intermediate iterate is stored in `r`.
+            x.axpy(alpha, &y, N::one());
+            r.axpy(-alpha, &v, N::one());
+
+            if self.criterion.is_converged(r.norm(), b_norm) {
+                return Some(niter);
+            }
+
+            p.apply(&r, &mut z);
+            a.mul_vec_to(&z, &mut t);
+            let t_norm_squared = t.norm_squared();
+
+            if t_norm_squared.is_zero() {
+                return None;
+            }
+
+            omega = t.dotc(&r).unscale(t_norm_squared);
+            x.axpy(omega, &z, N::one());
+            r.axpy(-omega, &t, N::one());
+
+            r_norm = r.norm();
+            let info = IterationInfo {
+                iteration: niter,
+                residual: r_norm,
+            };
+
+            if let ControlFlow::Break(()) = observer(info) {
+                return None;
+            }
+        }
+    }
+}
+
+impl<N: ComplexField> Default for BiCGStab<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,179 @@
+use num::Zero;
+use simba::scalar::ComplexField;
+use std::ops::ControlFlow;
+
+use crate::base::dimension::Dynamic;
+use crate::base::storage::Storage;
+use crate::base::{DVector, Vector};
+
+use crate::linalg::iterative::{IdentityPreconditioner, MatVec, Preconditioner, StoppingCriterion};
+use crate::linalg::IterationInfo;
+
+/// The preconditioned conjugate gradient method, for linear systems with a hermitian
+/// definite-positive matrix.
+///
+/// Each iteration requires one product with the matrix of the system, one application of the
+/// preconditioner, and `O(n)` additional operations. In exact arithmetic, the solution is found in
+/// at most `n` iterations, but much less iterations are usually needed to reach a reasonable
+/// tolerance if the matrix (or the preconditioned matrix) is well-conditioned.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ConjugateGradient<N: ComplexField> {
+    criterion: StoppingCriterion<N::RealField>,
+}
+
+impl<N: ComplexField> ConjugateGradient<N> {
+    /// Creates a conjugate gradient solver with the default stopping criterion.
+    pub fn new() -> Self {
+        Self::with_criterion(StoppingCriterion::default())
+    }
+
+    /// Creates a conjugate gradient solver with the given stopping criterion.
+    pub fn with_criterion(criterion: StoppingCriterion<N::RealField>) -> Self {
+        ConjugateGradient { criterion }
+    }
+
+    /// The stopping criterion of this solver.
+    #[inline]
+    pub fn criterion(&self) -> &StoppingCriterion<N::RealField> {
+        &self.criterion
+    }
+
+    /// Solves the linear system `a * x = b` without preconditioning, starting from `x = 0`.
+    ///
+    /// Returns `None` if the solver did not converge, see `solve_mut` for details.
+    pub fn solve<A, S>(&self, a: &A, b: &Vector<N, Dynamic, S>) -> Option<DVector<N>>
+    where
+        A: MatVec<N>,
+        S: Storage<N, Dynamic>,
+    {
+        self.solve_preconditioned(a, b, &IdentityPreconditioner)
+    }
+
+    /// Solves the linear system `a * x = b` with the preconditioner `p`, starting from `x = 0`.
+    ///
+    /// Returns `None` if the solver did not converge, see `solve_mut` for details.
+    pub fn solve_preconditioned<A, P, S>(
+        &self,
+        a: &A,
+        b: &Vector<N, Dynamic, S>,
+        p: &P,
+    ) -> Option<DVector<N>>
+    where
+        A: MatVec<N>,
+        P: Preconditioner<N>,
+        S: Storage<N, Dynamic>,
+    {
+        let mut x = DVector::zeros(b.len());
+        self.solve_mut(a, b, p, &mut x).map(|_| x)
+    }
+
+    /// Solves the linear system `a * x = b` with the preconditioner `p`, using the initial value
+    /// of `x` as the initial guess.
+    ///
+    /// The matrix `a` and the preconditioner `p` must both be hermitian definite-positive. On
+    /// success, `x` is overwritten by the solution and the number of iterations performed is
+    /// returned. Returns `None`, and `x` contains the last iterate, if the maximum number of
+    /// iterations is exceeded, or if a breakdown occurs because `a` or `p` is not
+    /// definite-positive.
+    pub fn solve_mut<A, P, S>(
+        &self,
+        a: &A,
+        b: &Vector<N, Dynamic, S>,
+        p: &P,
+        x: &mut DVector<N>,
+    ) -> Option<usize>
+    where
+        A: MatVec<N>,
+        P: Preconditioner<N>,
+        S: Storage<N, Dynamic>,
+    {
+        self.solve_mut_with_observer(a, b, p, x, |_| ControlFlow::Continue(()))
+    }
+
+    /// Solves the linear system `a * x = b` with the preconditioner `p`, using the initial value
+    /// of `x` as the initial guess, and calling `observer` after each iteration.
+    ///
+    /// The `residual` given to the observer is the norm of the residual `b - a * x`. If the
+    /// observer returns `ControlFlow::Break(())`, the solver stops and returns `None`, and `x`
+    /// contains the last iterate. Otherwise, this behaves like `solve_mut`.
+    pub fn solve_mut_with_observer<A, P, S>(
+        &self,
+        a: &A,
+        b: &Vector<N, Dynamic, S>,
+        p: &P,
+        x: &mut DVector<N>,
+        mut observer: impl FnMut(IterationInfo<N::RealField>) -> ControlFlow<()>,
+    ) -> Option<usize>
+    where
+        A: MatVec<N>,
+        P: Preconditioner<N>,
+        S: Storage<N, Dynamic>,
+    {
+        let n = b.len();
+
+        assert!(
+            a.nrows() == n && a.ncols() == n && x.len() == n,
+            "ConjugateGradient: dimension mismatch."
+        );
+
+        let b_norm = b.norm();
+        let mut ap = DVector::zeros(n);
+
+        // r = b - a * x
+        a.mul_vec_to(x, &mut ap);
+        let mut r = b - &ap;
+        let mut z = DVector::zeros(n);
+        p.apply(&r, &mut z);
+        let mut dir = z.clone();
+        let mut rz = r.dotc(&z);
+        let mut r_norm = r.norm();
+        let mut niter = 0;
+
+        loop {
+            if self.criterion.is_converged(r_norm, b_norm) {
+                return Some(niter);
+            }
+
+            if self.criterion.is_exhausted(niter, n) {
+                return None;
+            }
+
+            niter += 1;
+
+            a.mul_vec_to(&dir, &mut ap);
+            let curvature = dir.dotc(&ap);
+
+            if curvature.real() <= N::RealField::zero() || rz.real() <= N::RealField::zero() {
+                return None;
+            }
+
+            let alpha = rz / curvature;
+            x.axpy(alpha, &dir, N::one());
+            r.axpy(-alpha, &ap, N::one());
+
+            p.apply(&r, &mut z);
+            let new_rz = r.dotc(&z);
+            let beta = new_rz / rz;
+            rz = new_rz;
+
+            // dir = z + beta * dir
+            dir.axpy(N::one(), &z, beta);
+
+            r_norm = r.norm();
+            let info = IterationInfo {
+                iteration: niter,
+                residual: r_norm,
+            };
+
+            if let ControlFlow::Break(()) = observer(info) {
+                return None;
+            }
+        }
+    }
+}
+
+impl<N: ComplexField> Default for ConjugateGradient<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,80 @@
+use simba::scalar::RealField;
+
+/// The stopping criterion of an iterative solver.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct StoppingCriterion<N: RealField> {
+    /// The tolerance on the norm of the residual `b - a * x` relative to the norm of `b`.
+    pub tolerance: N,
+
+    /// The maximum number of iterations performed by the solver. If `max_niter == 0`, then the
+    /// limit is given by `max_niter_per_dim` instead.
+    pub max_niter: usize,
+
+    /// The maximum number of iterations performed by the solver, relative to the dimension of the
+    /// system to solve. This is only used if `max_niter == 0`. If both are zero, then the solver
+    /// continues indefinitely until convergence.
+    pub max_niter_per_dim: usize,
+}
+
+impl<N: RealField> StoppingCriterion<N> {
+    /// Creates a stopping criterion with the given relative tolerance and maximum number of
+    /// iterations.
+    ///
+    /// If `max_niter == 0`, then the solver continues indefinitely until convergence.
+    pub fn new(tolerance: N, max_niter: usize) -> Self {
+        assert!(
+            tolerance >= N::zero(),
+            "StoppingCriterion: the tolerance must be non-negative."
+        );
+
+        StoppingCriterion {
+            tolerance,
+            max_niter,
+            max_niter_per_dim: 0,
+        }
+    }
+
+    /// Creates a stopping criterion with the given relative tolerance and a maximum number of
+    /// iterations equal to `max_niter_per_dim` times the dimension of the system to solve.
+    pub fn new_per_dim(tolerance: N, max_niter_per_dim: usize) -> Self {
+        StoppingCriterion {
+            max_niter_per_dim,
+            ..StoppingCriterion::new(tolerance, 0)
+        }
+    }
+
+    /// Tests if the residual of norm `residual_norm` is small enough for the right-hand-side of
+    /// norm `rhs_norm`.
+    #[inline]
+    pub fn is_converged(&self, residual_norm: N, rhs_norm: N) -> bool {
+        residual_norm <= self.tolerance * rhs_norm
+    }
+
+    /// The maximum number of iterations for a system of dimension `dim`, or `None` if the number
+    /// of iterations is not limited.
+    #[inline]
+    pub fn max_niter_for(&self, dim: usize) -> Option<usize> {
+        if self.max_niter != 0 {
+            Some(self.max_niter)
+        } else if self.max_niter_per_dim != 0 {
+            Some(self.max_niter_per_dim * dim)
+        } else {
+            None
+        }
+    }
+
+    /// Tests if `niter` iterations exceed the maximum number of iterations for a system of
+    /// dimension `dim`.
+    #[inline]
+    pub fn is_exhausted(&self, niter: usize, dim: usize) -> bool {
+        self.max_niter_for(dim).map_or(false, |max| niter >= max)
+    }
+}
+
+impl<N: RealField> Default for StoppingCriterion<N> {
+    /// A relative tolerance equal to the square root of the machine epsilon, and at most ten
+    /// times as many iterations as the dimension of the system to solve.
+    fn default() -> Self {
+        StoppingCriterion::new_per_dim(N::default_epsilon().sqrt(), 10)
+    }
+}
@@ -0,0 +1,257 @@
+use approx::AbsDiffEq;
+use num::{One, Zero};
+use simba::scalar::{ComplexField, RealField};
+use std::ops::ControlFlow;
+
+use crate::base::dimension::Dynamic;
+use crate::base::storage::Storage;
+use crate::base::{DVector, Vector};
+
+use crate::linalg::iterative::{IdentityPreconditioner, MatVec, Preconditioner, StoppingCriterion};
+use crate::linalg::IterationInfo;
+
+/// The preconditioned minimum residual method (MINRES), for linear systems with a hermitian, but
+/// possibly indefinite, matrix.
+///
+/// Each iteration minimizes the norm of the residual over a Krylov subspace built with the
+/// Lanczos process, so unlike the conjugate gradient method it does not break down on indefinite
+/// matrices, e.g., the saddle-point matrices of constrained optimization or of mixed finite-element
+/// formulations. Each iteration requires one product with the matrix of the system, one
+/// application of the preconditioner, and `O(n)` additional operations.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MinRes<N: ComplexField> {
+    criterion: StoppingCriterion<N::RealField>,
+}
+
+impl<N: ComplexField> MinRes<N> {
+    /// Creates a MINRES solver with the default stopping criterion.
+    pub fn new() -> Self {
+        Self::with_criterion(StoppingCriterion::default())
+    }
+
+    /// Creates a MINRES solver with the given stopping criterion.
+    pub fn with_criterion(criterion: StoppingCriterion<N::RealField>) -> Self {
+        MinRes { criterion }
+    }
+
+    /// The stopping criterion of this solver.
+    #[inline]
+    pub fn criterion(&self) -> &StoppingCriterion<N::RealField> {
+        &self.criterion
+    }
+
+    /// Solves the linear system `a * x = b` without preconditioning, starting from `x = 0`.
+    ///
+    /// Returns `None` if the solver did not converge, see `solve_mut` for details.
+    pub fn solve<A, S>(&self, a: &A, b: &Vector<N, Dynamic, S>) -> Option<DVector<N>>
+    where
+        A: MatVec<N>,
+        S: Storage<N, Dynamic>,
+    {
+        self.solve_preconditioned(a, b, &IdentityPreconditioner)
+    }
+
+    /// Solves the linear system `a * x = b` with the preconditioner `p`, starting from `x = 0`.
+    ///
+    /// Returns `None` if the solver did not converge, see `solve_mut` for details.
+    pub fn solve_preconditioned<A, P, S>(
+        &self,
+        a: &A,
+        b: &Vector<N, Dynamic, S>,
+        p: &P,
+    ) -> Option<DVector<N>>
+    where
+        A: MatVec<N>,
+        P: Preconditioner<N>,
+        S: Storage<N, Dynamic>,
+    {
+        let mut x = DVector::zeros(b.len());
+        self.solve_mut(a, b, p, &mut x).map(|_| x)
+    }
+
+    /// Solves the linear system `a * x = b` with the preconditioner `p`, using the initial value
+    /// of `x` as the initial guess.
+    ///
+    /// The matrix `a` must be hermitian, and the preconditioner `p` must be hermitian
+    /// definite-positive, even if `a` is indefinite. With a preconditioner, the norms of the
+    /// stopping criterion are the ones induced by `p⁻¹`, i.e., `‖r‖ = sqrt(r* * p⁻¹ * r)`.
+    ///
+    /// On success, `x` is overwritten by the solution and the number of iterations performed is
+    /// returned. Returns `None`, and `x` contains the last iterate, if the maximum number of
+    /// iterations is exceeded, if `p` is not definite-positive, or if the system is singular and
+    /// has no solution.
+    pub fn solve_mut<A, P, S>(
+        &self,
+        a: &A,
+        b: &Vector<N, Dynamic, S>,
+        p: &P,
+        x: &mut DVector<N>,
+    ) -> Option<usize>
+    where
+        A: MatVec<N>,
+        P: Preconditioner<N>,
+        S: Storage<N, Dynamic>,
+    {
+        self.solve_mut_with_observer(a, b, p, x, |_| ControlFlow::Continue(()))
+    }
+
+    /// Solves the linear system `a * x = b` with the preconditioner `p`, using the initial value
+    /// of `x` as the initial guess, and calling `observer` after each iteration.
+    ///
+    /// The `residual` given to the observer is the norm (induced by `p⁻¹`) of the residual
+    /// `b - a * x` estimated by the recurrence of the method. If the observer returns
+    /// `ControlFlow::Break(())`, the solver stops and returns `None`, and `x` contains the last
+    /// iterate. Otherwise, this behaves like `solve_mut`.
+    pub fn solve_mut_with_observer<A, P, S>(
+        &self,
+        a: &A,
+        b: &Vector<N, Dynamic, S>,
+        p: &P,
+        x: &mut DVector<N>,
+        mut observer: impl FnMut(IterationInfo<N::RealField>) -> ControlFlow<()>,
+    ) -> Option<usize>
+    where
+        A: MatVec<N>,
+        P: Preconditioner<N>,
+        S: Storage<N, Dynamic>,
+    {
+        let n = b.len();
+
+        assert!(
+            a.nrows() == n && a.ncols() == n && x.len() == n,
+            "MinRes: dimension mismatch."
+        );
+
+        let mut y = DVector::zeros(n);
+        let mut tmp = DVector::zeros(n);
+
+        let b_norm = preconditioned_norm(&b.clone_owned(), p, &mut tmp)?;
+
+        // r1 = b - a * x
+        a.mul_vec_to(x, &mut tmp);
+        let mut r1 = b - &tmp;
+        let mut r2 = r1.clone();
+        p.apply(&r1, &mut y);
+        let mut beta = r1.dotc(&y).real();
+
+        if beta < N::RealField::zero() {
+            return None;
+        }
+
+        beta = beta.sqrt();
+
+        // The previous Lanczos vectors are `r1` and `r2`, scaled by their norms. The search
+        // directions `w`, `w1` and `w2` are updated with the Givens rotations `(cs, sn)` that
+        // reduce the tridiagonal Lanczos matrix to upper-triangular form.
+        let mut v = DVector::zeros(n);
+        let mut w = DVector::zeros(n);
+        let mut w1 = DVector::zeros(n);
+        let mut w2 = DVector::zeros(n);
+        let mut old_beta = N::RealField::zero();
+        let mut dbar = N::RealField::zero();
+        let mut epsilon = N::RealField::zero();
+        let mut phibar = beta;
+        let mut cs = -N::RealField::one();
+        let mut sn = N::RealField::zero();
+        let mut niter = 0;
+
+        loop {
+            // `phibar` is the norm of the residual computed by the recurrence, which may drift
+            // from the norm of the actual residual.
+            if self.criterion.is_converged(phibar, b_norm) {
+                a.mul_vec_to(x, &mut tmp);
+                let residual = b - &tmp;
+
+                if self
+                    .criterion
+                    .is_converged(preconditioned_norm(&residual, p, &mut tmp)?, b_norm)
+                {
+                    return Some(niter);
+                }
+            }
+
+            if self.criterion.is_exhausted(niter, n) || beta.is_zero() {
+                return None;
+            }
+
+            niter += 1;
+
+            // Lanczos step.
+            v.copy_from(&y);
+            v.unscale_mut(beta);
+            a.mul_vec_to(&v, &mut y);
+
+            if !old_beta.is_zero() {
+                y.axpy(N::from_real(-beta / old_beta), &r1, N::one());
+            }
+
+            let alpha = v.dotc(&y).real();
+            y.axpy(N::from_real(-alpha / beta), &r2, N::one());
+            r1.copy_from(&r2);
+            r2.copy_from(&y);
+            p.apply(&r2, &mut y);
+            old_beta = beta;
+            beta = r2.dotc(&y).real();
+
+            if beta < N::RealField::zero() {
+                return None;
+            }
+
+            beta = beta.sqrt();
+
+            // Apply the previous rotation and compute the next one.
+            let old_epsilon = epsilon;
+            let delta = cs * dbar + sn * alpha;
+            let gbar = sn * dbar - cs * alpha;
+            epsilon = sn * beta;
+            dbar = -cs * beta;
+            let gamma = gbar.hypot(beta).max(N::RealField::default_epsilon());
+            cs = gbar / gamma;
+            sn = beta / gamma;
+            let phi = cs * phibar;
+            phibar *= sn;
+
+            // w = (v - old_epsilon * w1 - delta * w2) / gamma
+            core::mem::swap(&mut w1, &mut w2);
+            core::mem::swap(&mut w2, &mut w);
+            w.copy_from(&v);
+            w.axpy(N::from_real(-old_epsilon), &w1, N::one());
+            w.axpy(N::from_real(-delta), &w2, N::one());
+            w.unscale_mut(gamma);
+
+            x.axpy(N::from_real(phi), &w, N::one());
+
+            let info = IterationInfo {
+                iteration: niter,
+                residual: phibar,
+            };
+
+            if let ControlFlow::Break(()) = observer(info) {
+                return None;
+            }
+        }
+    }
+}
+
+impl<N: ComplexField> Default for MinRes<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The norm `sqrt(r* * p⁻¹ * r)` induced by the preconditioner, or `None` if the preconditioner
+// is not definite-positive.
+fn preconditioned_norm<N: ComplexField, P: Preconditioner<N>>(
+    r: &DVector<N>,
+    p: &P,
+    work: &mut DVector<N>,
+) -> Option<N::RealField> {
+    p.apply(r, work);
+    let norm_squared = r.dotc(work).real();
+
+    if norm_squared < N::RealField::zero() {
+        None
+    } else {
+        Some(norm_squared.sqrt())
+    }
+}
@@ -0,0 +1,22 @@
+//! Iterative solvers for large linear systems.
+//!
+//! Unlike the decompositions of the `linalg` module, these solvers only access the matrix of
+//! the system through matrix-vector products, so they work with any linear operator implementing
+//! the `MatVec` trait, e.g., dense matrices, `BandedMatrix`, `ToeplitzMatrix`, or user-defined
+//! matrix-free operators. Their convergence can be accelerated with a `Preconditioner`.
+
+mod arnoldi;
+mod bicgstab;
+mod conjugate_gradient;
+mod criterion;
+mod minres;
+mod operator;
+mod preconditioner;
+
+pub use self::arnoldi::*;
+pub use self::bicgstab::*;
+pub use self::conjugate_gradient::*;
+pub use self::criterion::*;
+pub use self::minres::*;
+pub use self::operator::*;
+pub use self::preconditioner::*;
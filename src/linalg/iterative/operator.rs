@@ -0,0 +1,114 @@
+use num::{One, Zero};
+use simba::scalar::{ClosedAdd, ClosedMul, RealField};
+
+use crate::base::dimension::Dynamic;
+use crate::base::storage::Storage;
+use crate::base::{DVector, Matrix, Scalar};
+use crate::linalg::{BandedMatrix, Circulant, ToeplitzMatrix};
+
+/// A linear operator that can be multiplied by vectors.
+///
+/// This is the only access to the matrix of a linear system the iterative solvers need, so it can
+/// be implemented for matrix-free operators, e.g., discretized differential operators, without
+/// ever storing their coefficients.
+pub trait MatVec<N: Scalar> {
+    /// The number of rows of this operator.
+    fn nrows(&self) -> usize;
+
+    /// The number of columns of this operator.
+    fn ncols(&self) -> usize;
+
+    /// Computes `out = self * x`.
+    ///
+    /// The vector `x` has `self.ncols()` elements, and `out` has `self.nrows()` elements.
+    fn mul_vec_to(&self, x: &DVector<N>, out: &mut DVector<N>);
+}
+
+impl<'a, N: Scalar, A: MatVec<N> + ?Sized> MatVec<N> for &'a A {
+    #[inline]
+    fn nrows(&self) -> usize {
+        (**self).nrows()
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        (**self).ncols()
+    }
+
+    #[inline]
+    fn mul_vec_to(&self, x: &DVector<N>, out: &mut DVector<N>) {
+        (**self).mul_vec_to(x, out)
+    }
+}
+
+impl<N, S> MatVec<N> for Matrix<N, Dynamic, Dynamic, S>
+where
+    N: Scalar + Zero + One + ClosedAdd + ClosedMul,
+    S: Storage<N, Dynamic, Dynamic>,
+{
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.nrows()
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.ncols()
+    }
+
+    #[inline]
+    fn mul_vec_to(&self, x: &DVector<N>, out: &mut DVector<N>) {
+        self.mul_to(x, out)
+    }
+}
+
+impl<N: Scalar + Zero + ClosedAdd + ClosedMul> MatVec<N> for BandedMatrix<N> {
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.nrows()
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.ncols()
+    }
+
+    #[inline]
+    fn mul_vec_to(&self, x: &DVector<N>, out: &mut DVector<N>) {
+        out.copy_from(&self.mul_vector(x))
+    }
+}
+
+impl<N: RealField> MatVec<N> for ToeplitzMatrix<N> {
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.nrows()
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.ncols()
+    }
+
+    #[inline]
+    fn mul_vec_to(&self, x: &DVector<N>, out: &mut DVector<N>) {
+        out.copy_from(&self.mul_vector(x))
+    }
+}
+
+impl<N: RealField> MatVec<N> for Circulant<N> {
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.nrows()
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.ncols()
+    }
+
+    #[inline]
+    fn mul_vec_to(&self, x: &DVector<N>, out: &mut DVector<N>) {
+        out.copy_from(&self.mul_vector(x))
+    }
+}
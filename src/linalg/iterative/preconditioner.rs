@@ -0,0 +1,170 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use num::Zero;
+use simba::scalar::ComplexField;
+
+use crate::base::dimension::Dynamic;
+use crate::base::storage::Storage;
+use crate::base::{DMatrix, DVector, Scalar, SquareMatrix};
+
+/// A preconditioner, i.e., an approximation `m` of the matrix `a` of a linear system such that
+/// the systems `m * z = r` are cheap to solve.
+///
+/// Iterative solvers converge faster on the preconditioned system `m⁻¹ * a * x = m⁻¹ * b` if the
+/// spectrum of `m⁻¹ * a` is more clustered than the spectrum of `a`.
+pub trait Preconditioner<N: Scalar> {
+    /// Computes `out = m⁻¹ * r` where `m` is the matrix approximated by this preconditioner.
+    fn apply(&self, r: &DVector<N>, out: &mut DVector<N>);
+}
+
+/// The identity preconditioner, i.e., no preconditioning at all.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct IdentityPreconditioner;
+
+impl<N: Scalar> Preconditioner<N> for IdentityPreconditioner {
+    #[inline]
+    fn apply(&self, r: &DVector<N>, out: &mut DVector<N>) {
+        out.copy_from(r)
+    }
+}
+
+/// The Jacobi preconditioner, i.e., the diagonal of the matrix of the system.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DVector<N>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DVector<N>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct JacobiPreconditioner<N: ComplexField> {
+    inv_diagonal: DVector<N>,
+}
+
+impl<N: ComplexField> JacobiPreconditioner<N> {
+    /// Creates the Jacobi preconditioner of the square matrix `m`.
+    ///
+    /// Returns `None` if one of the diagonal elements of `m` is zero.
+    pub fn new<S: Storage<N, Dynamic, Dynamic>>(m: &SquareMatrix<N, Dynamic, S>) -> Option<Self> {
+        assert!(
+            m.is_square(),
+            "JacobiPreconditioner: the matrix must be square."
+        );
+
+        Self::from_diagonal(m.diagonal())
+    }
+
+    /// Creates the Jacobi preconditioner of a matrix with the given diagonal.
+    ///
+    /// Returns `None` if one of the diagonal elements is zero.
+    pub fn from_diagonal(diagonal: DVector<N>) -> Option<Self> {
+        if diagonal.iter().any(|e| e.is_zero()) {
+            None
+        } else {
+            Some(JacobiPreconditioner {
+                inv_diagonal: diagonal.map(|e| N::one() / e),
+            })
+        }
+    }
+}
+
+impl<N: ComplexField> Preconditioner<N> for JacobiPreconditioner<N> {
+    #[inline]
+    fn apply(&self, r: &DVector<N>, out: &mut DVector<N>) {
+        out.zip_zip_apply(r, &self.inv_diagonal, |_, r, d| r * d)
+    }
+}
+
+/// The zero fill-in incomplete Cholesky preconditioner IC(0) of a hermitian definite-positive
+/// matrix.
+///
+/// This is the factor `l` of the Cholesky decomposition `l * l*` of the matrix, except that the
+/// elements of `l` are only computed where the lower-triangular part of the matrix is non-zero,
+/// and all the other elements are discarded. Thus, this preconditioner is much cheaper to compute
+/// than the Cholesky decomposition for sparse matrices, while capturing their structure much
+/// better than the Jacobi preconditioner.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DMatrix<N>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DMatrix<N>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct IncompleteCholesky<N: ComplexField> {
+    l: DMatrix<N>,
+}
+
+impl<N: ComplexField> IncompleteCholesky<N> {
+    /// Computes the incomplete Cholesky factorization of the hermitian definite-positive matrix
+    /// `m`.
+    ///
+    /// Only the lower-triangular part (including the diagonal) of `m` is read. Returns `None` if
+    /// the factorization breaks down because a non-positive pivot is encountered, which may
+    /// happen even if `m` is definite-positive, unless `m` is, e.g., an M-matrix.
+    pub fn new<S: Storage<N, Dynamic, Dynamic>>(m: &SquareMatrix<N, Dynamic, S>) -> Option<Self> {
+        assert!(
+            m.is_square(),
+            "IncompleteCholesky: the matrix must be square."
+        );
+
+        let n = m.nrows();
+        let mut l = m.lower_triangle();
+
+        for k in 0..n {
+            let mut pivot = l[(k, k)].real();
+
+            for j in 0..k {
+                pivot -= l[(k, j)].modulus_squared();
+            }
+
+            if pivot <= N::RealField::zero() {
+                return None;
+            }
+
+            let denom = N::from_real(pivot.sqrt());
+            l[(k, k)] = denom;
+
+            for i in k + 1..n {
+                if l[(i, k)].is_zero() {
+                    continue;
+                }
+
+                let mut val = l[(i, k)];
+
+                for j in 0..k {
+                    val -= l[(i, j)] * l[(k, j)].conjugate();
+                }
+
+                l[(i, k)] = val / denom;
+            }
+        }
+
+        Some(IncompleteCholesky { l })
+    }
+
+    /// The lower-triangular incomplete Cholesky factor `l`.
+    #[inline]
+    pub fn l(&self) -> &DMatrix<N> {
+        &self.l
+    }
+
+    /// Retrieves the lower-triangular incomplete Cholesky factor `l`.
+    #[inline]
+    pub fn unpack(self) -> DMatrix<N> {
+        self.l
+    }
+}
+
+impl<N: ComplexField> Preconditioner<N> for IncompleteCholesky<N> {
+    fn apply(&self, r: &DVector<N>, out: &mut DVector<N>) {
+        out.copy_from(r);
+        let _ = self.l.solve_lower_triangular_mut(out);
+        let _ = self.l.ad_solve_lower_triangular_mut(out);
+    }
+}
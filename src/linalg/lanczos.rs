@@ -0,0 +1,199 @@
+//! Partial eigendecomposition of large symmetric matrices using the Lanczos iteration.
+
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+use num::Zero;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::base::dimension::Dynamic;
+use crate::base::storage::Storage;
+use crate::base::{DMatrix, DVector, SquareMatrix};
+
+use crate::linalg::{LinalgError, SymmetricEigen};
+
+/// The `k` largest or smallest eigenpairs of a symmetric matrix, computed with the thick-restart
+/// Lanczos iteration.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DVector<N::RealField>: Serialize,
+         DMatrix<N>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DVector<N::RealField>: Deserialize<'de>,
+         DMatrix<N>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct Lanczos<N: ComplexField> {
+    /// The orthonormal eigenvectors associated to the computed eigenvalues.
+    pub eigenvectors: DMatrix<N>,
+
+    /// The computed eigenvalues, sorted by decreasing order if the largest eigenvalues were
+    /// requested, and by increasing order otherwise.
+    pub eigenvalues: DVector<N::RealField>,
+}
+
+impl<N: ComplexField> Lanczos<N> {
+    /// Computes the `k` largest (if `largest` is `true`) or smallest (otherwise) eigenvalues of
+    /// the symmetric matrix `m`, together with their eigenvectors.
+    ///
+    /// Unlike `SymmetricEigen`, the whole matrix `m` is read so it must actually be symmetric.
+    pub fn new<S: Storage<N, Dynamic, Dynamic>>(
+        m: &SquareMatrix<N, Dynamic, S>,
+        k: usize,
+        largest: bool,
+    ) -> Self {
+        Self::try_new(m, k, largest, N::RealField::default_epsilon().sqrt(), 0).unwrap()
+    }
+
+    /// Attempts to compute the `k` largest (if `largest` is `true`) or smallest (otherwise)
+    /// eigenvalues of the symmetric matrix `m`, together with their eigenvectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance on the norm of the residual `m * v - λ * v` of each eigenpair,
+    /// relative to the magnitude of the largest eigenvalue.
+    /// * `max_niter` − maximum number of restarts performed by the algorithm. If this
+    /// number of iteration is exceeded, `LinalgError::NotConverged` is returned. If `niter == 0`,
+    /// then the algorithm continues indefinitely until convergence.
+    ///
+    /// Returns `LinalgError::DimensionMismatch` if `m` is not square or if `k` is greater than its
+    /// dimension.
+    pub fn try_new<S: Storage<N, Dynamic, Dynamic>>(
+        m: &SquareMatrix<N, Dynamic, S>,
+        k: usize,
+        largest: bool,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Result<Self, LinalgError> {
+        let dim = m.nrows();
+
+        if !m.is_square() || k > dim {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
+        if k == 0 {
+            return Ok(Lanczos {
+                eigenvectors: DMatrix::zeros(dim, 0),
+                eigenvalues: DVector::zeros(0),
+            });
+        }
+
+        // Maximum dimension of the Krylov subspace before restarting.
+        let max_basis = dim.min((2 * k + 1).max(20));
+
+        // The orthonormal basis `v` and the products `av = m * v`. The basis has one extra
+        // column to store the next Lanczos vector computed before a restart.
+        let mut v = DMatrix::zeros(dim, max_basis + 1);
+        let mut av = DMatrix::zeros(dim, max_basis);
+
+        // Deterministic starting vector that is unlikely to be orthogonal to any eigenvector.
+        let mut start = DVector::from_fn(dim, |i, _| {
+            N::from_subset(&(1.0 + ((i * 7919) % 1013) as f64 / 1013.0))
+        });
+        let _ = start.normalize_mut();
+        v.set_column(0, &start);
+
+        let mut nbasis = 1;
+        let mut nproducts = 0;
+        let mut niter = 0;
+
+        loop {
+            // Expand the Krylov subspace.
+            while nproducts < max_basis {
+                let w = m * v.column(nproducts);
+                av.set_column(nproducts, &w);
+                nproducts += 1;
+
+                if nbasis < dim && nbasis == nproducts {
+                    let mut w = w;
+                    if !orthonormalize(&v, nbasis, &mut w) {
+                        // Breakdown: we found an invariant subspace so the next vector
+                        // can be any vector orthogonal to the current basis.
+                        for i in 0..dim {
+                            w.fill(N::zero());
+                            w[i] = N::one();
+
+                            if orthonormalize(&v, nbasis, &mut w) {
+                                break;
+                            }
+                        }
+                    }
+
+                    v.set_column(nbasis, &w);
+                    nbasis += 1;
+                }
+            }
+
+            // Rayleigh-Ritz projection.
+            let basis = v.columns(0, nproducts);
+            let products = av.columns(0, nproducts);
+            let h = basis.adjoint() * products;
+            let eig = SymmetricEigen::new(h).sorted(!largest);
+
+            let s = eig.eigenvectors.columns(0, k);
+            let ritz_vectors = basis * s;
+            let ritz_products = products * s;
+
+            let anorm = eig
+                .eigenvalues
+                .iter()
+                .fold(N::RealField::zero(), |a, e| a.max(e.abs()));
+            let converged = nproducts == dim
+                || (0..k).all(|i| {
+                    let val = N::from_real(eig.eigenvalues[i]);
+                    let residual = ritz_products.column(i) - ritz_vectors.column(i) * val;
+                    residual.norm() <= eps * anorm
+                });
+
+            if converged {
+                return Ok(Lanczos {
+                    eigenvectors: ritz_vectors,
+                    eigenvalues: eig.eigenvalues.rows(0, k).into_owned(),
+                });
+            }
+
+            niter += 1;
+            if niter == max_niter {
+                return Err(LinalgError::NotConverged { iterations: niter });
+            }
+
+            // Thick restart: keep the Ritz vectors and the last Lanczos vector, which is
+            // orthogonal to all of them.
+            let next = v.column(nbasis - 1).into_owned();
+            v.columns_mut(0, k).copy_from(&ritz_vectors);
+            av.columns_mut(0, k).copy_from(&ritz_products);
+            v.set_column(k, &next);
+            nproducts = k;
+            nbasis = k + 1;
+        }
+    }
+}
+
+/// Orthogonalizes `w` with regard to the first `ncols` columns of `basis` and normalizes it.
+///
+/// Returns `false` if `w` is (almost) linearly dependent with the basis.
+fn orthonormalize<N: ComplexField>(basis: &DMatrix<N>, ncols: usize, w: &mut DVector<N>) -> bool {
+    let norm0 = w.norm();
+
+    // Applying the Gram-Schmidt process twice is enough to maintain orthogonality
+    // up to the machine precision, unless `w` is almost linearly dependent with the basis.
+    for _ in 0..2 {
+        for i in 0..ncols {
+            let col = basis.column(i);
+            let coeff = col.dotc(w);
+            w.axpy(-coeff, &col, N::one());
+        }
+    }
+
+    let norm = w.norm();
+    if norm.is_zero() || norm <= norm0 * N::RealField::default_epsilon().sqrt() {
+        false
+    } else {
+        w.unscale_mut(norm);
+        true
+    }
+}
@@ -0,0 +1,371 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use num::Zero;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, SquareMatrix, VectorN};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::dimension::{Dim, U1};
+use crate::storage::{Storage, StorageMut};
+use simba::scalar::{ComplexField, RealField};
+
+use crate::linalg::PermutationSequence;
+
+/// The LDLᴴ decomposition with Bunch–Kaufman pivoting of a symmetric (hermitian) matrix.
+///
+/// The decomposed matrix `M` is written as `P * M * Pᵀ = L * D * Lᴴ` where `P` is a permutation
+/// matrix, `L` is unit lower-triangular, and `D` is block-diagonal with blocks of size 1 or 2.
+/// Unlike the Cholesky decomposition, the decomposed matrix does not have to be
+/// definite-positive.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<N, D> +
+                           Allocator<(usize, usize), D>,
+         MatrixN<N, D>: Serialize,
+         VectorN<N, D>: Serialize,
+         PermutationSequence<D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<N, D, D> +
+                           Allocator<N, D> +
+                           Allocator<(usize, usize), D>,
+         MatrixN<N, D>: Deserialize<'de>,
+         VectorN<N, D>: Deserialize<'de>,
+         PermutationSequence<D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct LDL<N: ComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D>,
+{
+    // The strictly lower-triangular part of `L` and the diagonal of `D`.
+    ldl: MatrixN<N, D>,
+    // The subdiagonal of `D`, non-zero only for the 2x2 blocks.
+    d_subdiag: VectorN<N, D>,
+    p: PermutationSequence<D>,
+}
+
+impl<N: ComplexField, D: Dim> Copy for LDL<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D>,
+    MatrixN<N, D>: Copy,
+    VectorN<N, D>: Copy,
+    PermutationSequence<D>: Copy,
+{
+}
+
+impl<N: ComplexField, D: Dim> LDL<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D>,
+{
+    /// Computes the LDLᴴ decomposition with Bunch–Kaufman pivoting of the symmetric (hermitian)
+    /// matrix `matrix`.
+    ///
+    /// Only the lower-triangular part (including its diagonal) of `matrix` is read.
+    pub fn new(mut matrix: MatrixN<N, D>) -> Self {
+        assert!(
+            matrix.is_square(),
+            "Unable to compute the LDL decomposition of a non-square matrix."
+        );
+
+        let dim = matrix.data.shape().0;
+        let n = dim.value();
+
+        let mut p = PermutationSequence::identity_generic(dim);
+        let mut d_subdiag = MatrixMN::zeros_generic(dim, U1);
+
+        // The symmetric interchanges and updates are performed on the full matrix.
+        for j in 0..n {
+            matrix[(j, j)] = N::from_real(matrix[(j, j)].real());
+
+            for i in j + 1..n {
+                matrix[(j, i)] = matrix[(i, j)].conjugate();
+            }
+        }
+
+        // (1 + sqrt(17)) / 8, which minimizes the bound of the element growth.
+        let alpha: N::RealField = crate::convert(0.6403882032022076);
+        let mut k = 0;
+
+        while k < n {
+            let akk = matrix[(k, k)].modulus();
+
+            // The largest off-diagonal element of the k-th column.
+            let mut r = k;
+            let mut lambda = N::RealField::zero();
+
+            for i in k + 1..n {
+                let norm = matrix[(i, k)].modulus();
+
+                if norm > lambda {
+                    r = i;
+                    lambda = norm;
+                }
+            }
+
+            let mut two_by_two = false;
+
+            if akk < alpha * lambda {
+                // The largest off-diagonal element of the r-th column.
+                let mut sigma = N::RealField::zero();
+
+                for i in (k..n).filter(|i| *i != r) {
+                    sigma = sigma.max(matrix[(i, r)].modulus());
+                }
+
+                if akk * sigma < alpha * lambda * lambda {
+                    if matrix[(r, r)].modulus() >= alpha * sigma {
+                        matrix.swap_rows(k, r);
+                        matrix.swap_columns(k, r);
+                        p.append_permutation(k, r);
+                    } else {
+                        matrix.swap_rows(k + 1, r);
+                        matrix.swap_columns(k + 1, r);
+                        p.append_permutation(k + 1, r);
+                        two_by_two = true;
+                    }
+                }
+            }
+
+            if two_by_two {
+                Self::pivot_2x2(&mut matrix, k);
+                d_subdiag[k] = matrix[(k + 1, k)];
+                matrix[(k + 1, k)] = N::zero();
+                k += 2;
+            } else {
+                Self::pivot_1x1(&mut matrix, k);
+                k += 1;
+            }
+        }
+
+        LDL {
+            ldl: matrix,
+            d_subdiag,
+            p,
+        }
+    }
+
+    // Eliminates the k-th column of `matrix`, using its k-th diagonal element as the pivot.
+    fn pivot_1x1(matrix: &mut MatrixN<N, D>, k: usize) {
+        let n = matrix.nrows();
+        let d = matrix[(k, k)];
+
+        if d.is_zero() {
+            // The column is already zero.
+            return;
+        }
+
+        let mut col = matrix.slice_range_mut(k + 1.., k);
+        col /= d;
+
+        // Since the matrix is hermitian, `matrix[(k, j)]` is the conjugate of the original value
+        // of `matrix[(j, k)]`.
+        for j in k + 1..n {
+            let (col_k, mut col_j) = matrix.columns_range_pair_mut(k, j);
+            let factor = -col_j[k];
+            col_j
+                .rows_range_mut(k + 1..)
+                .axpy(factor, &col_k.rows_range(k + 1..), N::one());
+        }
+    }
+
+    // Eliminates the k-th and (k + 1)-th columns of `matrix`, using the 2x2 diagonal block at
+    // `(k, k)` as the pivot.
+    fn pivot_2x2(matrix: &mut MatrixN<N, D>, k: usize) {
+        let n = matrix.nrows();
+        let e11 = matrix[(k, k)];
+        let e21 = matrix[(k + 1, k)];
+        let e22 = matrix[(k + 1, k + 1)];
+        let det = e11 * e22 - e21 * e21.conjugate();
+
+        for i in k + 2..n {
+            let ci1 = matrix[(i, k)];
+            let ci2 = matrix[(i, k + 1)];
+            matrix[(i, k)] = (ci1 * e22 - ci2 * e21) / det;
+            matrix[(i, k + 1)] = (ci2 * e11 - ci1 * e21.conjugate()) / det;
+        }
+
+        for j in k + 2..n {
+            let (cols, mut col_j) = matrix.columns_range_pair_mut(k..k + 2, j);
+            let factor1 = -col_j[k];
+            let factor2 = -col_j[k + 1];
+            let mut col_j = col_j.rows_range_mut(k + 2..);
+            col_j.axpy(factor1, &cols.slice_range(k + 2.., 0), N::one());
+            col_j.axpy(factor2, &cols.slice_range(k + 2.., 1), N::one());
+        }
+    }
+
+    /// The unit lower-triangular matrix `L` of this decomposition.
+    pub fn l(&self) -> MatrixN<N, D> {
+        let mut l = self.ldl.lower_triangle();
+        l.fill_diagonal(N::one());
+        l
+    }
+
+    /// The block-diagonal matrix `D` of this decomposition.
+    pub fn d(&self) -> MatrixN<N, D> {
+        let n = self.ldl.nrows();
+        let mut d = MatrixN::from_diagonal(&self.ldl.diagonal());
+
+        for i in 0..n.saturating_sub(1) {
+            d[(i + 1, i)] = self.d_subdiag[i];
+            d[(i, i + 1)] = self.d_subdiag[i].conjugate();
+        }
+
+        d
+    }
+
+    /// The symmetric permutations of this decomposition.
+    #[inline]
+    pub fn p(&self) -> &PermutationSequence<D> {
+        &self.p
+    }
+
+    /// The permutations and the two matrices of this decomposition: `(P, L, D)`.
+    pub fn unpack(self) -> (PermutationSequence<D>, MatrixN<N, D>, MatrixN<N, D>) {
+        let l = self.l();
+        let d = self.d();
+
+        (self.p, l, d)
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `None` if the decomposed matrix is not invertible.
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> Option<MatrixMN<N, R2, C2>>
+    where
+        S2: Storage<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
+    /// be overwritten with garbage.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            self.ldl.nrows(),
+            b.nrows(),
+            "LDL solve matrix dimension mismatch."
+        );
+
+        let n = self.ldl.nrows();
+
+        self.p.permute_rows(b);
+        let _ = self.ldl.solve_lower_triangular_with_diag_mut(b, N::one());
+
+        let mut k = 0;
+        while k < n {
+            if k + 1 < n && !self.d_subdiag[k].is_zero() {
+                let e11 = self.ldl[(k, k)];
+                let e21 = self.d_subdiag[k];
+                let e22 = self.ldl[(k + 1, k + 1)];
+                let det = e11 * e22 - e21 * e21.conjugate();
+
+                if det.is_zero() {
+                    return false;
+                }
+
+                for j in 0..b.ncols() {
+                    let b1 = b[(k, j)];
+                    let b2 = b[(k + 1, j)];
+                    b[(k, j)] = (b1 * e22 - b2 * e21.conjugate()) / det;
+                    b[(k + 1, j)] = (b2 * e11 - b1 * e21) / det;
+                }
+
+                k += 2;
+            } else {
+                let d = self.ldl[(k, k)];
+
+                if d.is_zero() {
+                    return false;
+                }
+
+                for j in 0..b.ncols() {
+                    b[(k, j)] /= d;
+                }
+
+                k += 1;
+            }
+        }
+
+        for j in 0..b.ncols() {
+            for i in (0..n).rev() {
+                let dot = self
+                    .ldl
+                    .slice_range(i + 1.., i)
+                    .dotc(&b.slice_range(i + 1.., j));
+                b[(i, j)] -= dot;
+            }
+        }
+
+        self.p.inv_permute_rows(b);
+        true
+    }
+
+    /// Computes the inverse of the decomposed matrix.
+    ///
+    /// Returns `None` if the decomposed matrix is not invertible.
+    pub fn try_inverse(&self) -> Option<MatrixN<N, D>> {
+        let (nrows, ncols) = self.ldl.data.shape();
+        let mut res = MatrixN::identity_generic(nrows, ncols);
+
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    pub fn determinant(&self) -> N {
+        let n = self.ldl.nrows();
+        let mut res = N::one();
+        let mut k = 0;
+
+        while k < n {
+            if k + 1 < n && !self.d_subdiag[k].is_zero() {
+                let e21 = self.d_subdiag[k];
+                res *= self.ldl[(k, k)] * self.ldl[(k + 1, k + 1)] - e21 * e21.conjugate();
+                k += 2;
+            } else {
+                res *= self.ldl[(k, k)];
+                k += 1;
+            }
+        }
+
+        res
+    }
+}
+
+impl<N: ComplexField, D: Dim, S: Storage<N, D, D>> SquareMatrix<N, D, S>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D>,
+{
+    /// Computes the LDLᴴ decomposition with Bunch–Kaufman pivoting of this symmetric (hermitian)
+    /// matrix.
+    ///
+    /// Only the lower-triangular part (including its diagonal) of this matrix is read.
+    pub fn ldl(self) -> LDL<N, D> {
+        LDL::new(self.into_owned())
+    }
+}
@@ -0,0 +1,88 @@
+//! This module provides the matrix logarithm (log) function to square matrices.
+
+use approx::AbsDiffEq;
+use num::One;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, MatrixN};
+use crate::dimension::{DimDiff, DimSub, U1};
+use crate::storage::Storage;
+use simba::scalar::ComplexField;
+
+use crate::linalg::sqrtm::denman_beavers;
+use crate::linalg::Schur;
+
+/// Maximum number of square roots taken before giving up.
+const MAX_NSQRT: usize = 64;
+
+/// Nodes and weights of the 8-points Gauss–Legendre quadrature on `[0, 1]`.
+const GAUSS_LEGENDRE_8: [(f64, f64); 8] = [
+    (0.019855071751231884, 0.05061426814518813),
+    (0.10166676129318664, 0.11119051722668724),
+    (0.2372337950418355, 0.15685332293894363),
+    (0.4082826787521751, 0.181341891689181),
+    (0.591717321247825, 0.181341891689181),
+    (0.7627662049581645, 0.15685332293894363),
+    (0.8983332387068134, 0.11119051722668724),
+    (0.9801449282487681, 0.05061426814518813),
+];
+
+impl<N: ComplexField, D: DimSub<U1>> MatrixN<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, D>
+        + Allocator<N, D, DimDiff<D, U1>>
+        + Allocator<N, DimDiff<D, U1>>,
+{
+    /// Computes the principal logarithm of this matrix, i.e., the matrix `X` such that
+    /// `X.exp() == self` and whose eigenvalues have an imaginary part in `]-π, π[`.
+    ///
+    /// The inverse scaling-and-squaring method is applied to the Schur form of this matrix.
+    /// Returns `None` if this matrix is singular or has eigenvalues on the negative real axis.
+    /// For rotation matrices, `Rotation2::log` and `Rotation3::log` are faster and more
+    /// accurate.
+    pub fn log(&self) -> Option<Self> {
+        assert!(
+            self.is_square(),
+            "Unable to compute the logarithm of a non-square matrix."
+        );
+
+        let (nrows, ncols) = self.data.shape();
+        let eps = N::RealField::default_epsilon();
+        let (q, mut t) = Schur::new(self.clone()).unpack();
+        let id = MatrixN::identity_generic(nrows, ncols);
+
+        // Take square roots until `t` is close enough to the identity:
+        // log(m) = 2^k * log(m^(1 / 2^k)).
+        let threshold: N::RealField = crate::convert(0.25);
+        let mut nsqrt = 0;
+
+        while (&t - &id).norm() > threshold {
+            if nsqrt == MAX_NSQRT {
+                return None;
+            }
+
+            t = denman_beavers(t, eps)?;
+            nsqrt += 1;
+        }
+
+        // log(I + x) = ∫₀¹ x * (I + s * x)⁻¹ ds, evaluated with a Gauss–Legendre quadrature
+        // (which is equivalent to a diagonal Padé approximant).
+        let x = t - &id;
+        let mut res = MatrixN::zeros_generic(nrows, ncols);
+
+        for (node, weight) in GAUSS_LEGENDRE_8.iter() {
+            let node: N = crate::convert(*node);
+            let weight: N = crate::convert(*weight);
+            let denom = &x * node + &id;
+            let term = denom.try_inverse()? * &x;
+
+            res += term * weight;
+        }
+
+        let two: N::RealField = crate::convert(2.0);
+        let scale = (0..nsqrt).fold(N::RealField::one(), |acc, _| acc * two);
+
+        Some((&q * res * q.adjoint()).scale(scale))
+    }
+}
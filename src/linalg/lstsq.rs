@@ -0,0 +1,191 @@
+//! Dense linear least-squares solvers.
+
+use num::Zero;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, Vector2, VectorN};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::dimension::{Dim, DimDiff, DimMin, DimMinimum, DimSub, U1};
+use crate::storage::Storage;
+use simba::scalar::ComplexField;
+
+use crate::linalg::givens::GivensRotation;
+use crate::linalg::{ColPivQR, QR, SVD};
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S>
+where
+    DimMinimum<R, C>: DimSub<U1>, // for Bidiagonal.
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, C>
+        + Allocator<N, R>
+        + Allocator<N, DimDiff<DimMinimum<R, C>, U1>>
+        + Allocator<N, DimMinimum<R, C>, C>
+        + Allocator<N, R, DimMinimum<R, C>>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<N::RealField, DimMinimum<R, C>>
+        + Allocator<N::RealField, DimDiff<DimMinimum<R, C>, U1>>
+        + Allocator<(usize, usize), DimMinimum<R, C>>,
+{
+    /// Computes the minimum-norm least-squares solution `x` of the linear system
+    /// `self * x = b`.
+    ///
+    /// The QR decomposition with column pivoting of `self` is computed first. If it reveals
+    /// that `self` has full column rank, the solution is unique and is computed from this
+    /// decomposition. Otherwise, the solution of minimum norm is computed from the SVD of
+    /// `self`.
+    ///
+    /// Returns `(x, residual_norm, rank)` where `residual_norm` is the norm of `b - self * x`
+    /// and `rank` is the estimated rank of `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `b`   − the right-hand-side of the linear system.
+    /// * `eps` − tolerance used to estimate the rank of `self`. The diagonal elements of `R`,
+    /// and the singular values, smaller than `eps` are considered equal to 0.
+    pub fn lstsq<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+        eps: N::RealField,
+    ) -> (MatrixMN<N, C, C2>, N::RealField, usize)
+    where
+        S2: Storage<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, R> + SameNumberOfRows<R, R2>,
+        DefaultAllocator:
+            Allocator<N, R2, C2> + Allocator<N, C, C2> + Allocator<N, DimMinimum<R, C>, C2>,
+    {
+        assert_eq!(
+            self.nrows(),
+            b.nrows(),
+            "Least-squares solve matrix dimension mismatch."
+        );
+        assert!(
+            eps >= N::RealField::zero(),
+            "Least-squares solve: the epsilon must be non-negative."
+        );
+
+        let ncols = self.data.shape().1;
+
+        if self.is_empty() {
+            let x = MatrixMN::zeros_generic(ncols, b.data.shape().1);
+            return (x, b.norm(), 0);
+        }
+
+        let qr = ColPivQR::new(self.clone_owned());
+        let mut rank = qr.rank(eps);
+
+        let x = if rank == ncols.value() {
+            qr.solve(b, eps)
+        } else {
+            let svd = SVD::new(self.clone_owned(), true, true);
+            rank = svd.rank(eps);
+            svd.solve(b, eps).unwrap()
+        };
+
+        let mut residual = b.clone_owned();
+        residual.gemm(-N::one(), self, &x, N::one());
+
+        (x, residual.norm(), rank)
+    }
+}
+
+impl<N: ComplexField, R: DimMin<C>, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S>
+where
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, R>
+        + Allocator<N, C>
+        + Allocator<N, C, C>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<N, DimMinimum<R, C>, C>,
+{
+    /// Computes the solution `x` of the Tikhonov-regularized (or ridge) least-squares problem
+    /// `min |self * x - b|² + lambda * |x|²`.
+    ///
+    /// This is the solution of `(self^* * self + lambda * I) * x = self^* * b`, but the normal
+    /// equations are not formed explicitly. Instead, this computes the QR decomposition of the
+    /// augmented matrix `[self; sqrt(lambda) * I]`, so the accuracy of the result depends on the
+    /// condition number of this augmented matrix instead of its square.
+    ///
+    /// Returns `None` if `lambda` is zero and the columns of `self` are linearly dependent.
+    ///
+    /// # Arguments
+    ///
+    /// * `b`      − the right-hand-side of the linear system.
+    /// * `lambda` − the non-negative regularization parameter. Larger values yield solutions
+    /// with a smaller norm, at the cost of a larger residual.
+    pub fn solve_regularized<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+        lambda: N::RealField,
+    ) -> Option<MatrixMN<N, C, C2>>
+    where
+        S2: Storage<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, R>,
+        DefaultAllocator: Allocator<N, R2, C2> + Allocator<N, C, C2> + Allocator<N, U1, C2>,
+    {
+        assert_eq!(
+            self.nrows(),
+            b.nrows(),
+            "Regularized solve matrix dimension mismatch."
+        );
+        assert!(
+            lambda >= N::RealField::zero(),
+            "Regularized solve: the regularization parameter must be non-negative."
+        );
+
+        let (nrows, ncols) = self.data.shape();
+        let nb = b.data.shape().1;
+        let n = ncols.value();
+        let min_nrows_ncols = nrows.min(ncols);
+
+        // Start with the QR decomposition of `self`, the rows of the regularization term are
+        // then merged into `R` with Givens rotations.
+        let qr = QR::new(self.clone_owned());
+        let mut qtb = b.clone_owned();
+        qr.q_tr_mul(&mut qtb);
+
+        let mut r = MatrixN::zeros_generic(ncols, ncols);
+        r.rows_generic_mut(0, min_nrows_ncols).copy_from(&qr.r());
+        let mut rhs = MatrixMN::zeros_generic(ncols, nb);
+        rhs.rows_generic_mut(0, min_nrows_ncols)
+            .copy_from(&qtb.rows_generic(0, min_nrows_ncols));
+
+        let sqrt_lambda = N::from_real(lambda.sqrt());
+
+        for i in 0..n {
+            let mut row = VectorN::zeros_generic(ncols, U1);
+            let mut row_rhs = MatrixMN::<N, U1, C2>::zeros_generic(U1, nb);
+            row[i] = sqrt_lambda;
+
+            for k in i..n {
+                let v = Vector2::new(r[(k, k)], row[k]);
+
+                if let Some((rot, norm)) = GivensRotation::cancel_y(&v) {
+                    let c = rot.c();
+                    let s = rot.s();
+                    let rotate = |a: N, b: N| (a.scale(c) - s.conjugate() * b, s * a + b.scale(c));
+
+                    r[(k, k)] = norm;
+                    row[k] = N::zero();
+
+                    for j in k + 1..n {
+                        let (a, b) = rotate(r[(k, j)], row[j]);
+                        r[(k, j)] = a;
+                        row[j] = b;
+                    }
+
+                    for j in 0..nb.value() {
+                        let (a, b) = rotate(rhs[(k, j)], row_rhs[j]);
+                        rhs[(k, j)] = a;
+                        row_rhs[j] = b;
+                    }
+                }
+            }
+        }
+
+        if r.solve_upper_triangular_mut(&mut rhs) {
+            Some(rhs)
+        } else {
+            None
+        }
+    }
+}
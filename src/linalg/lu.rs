@@ -2,13 +2,16 @@
 use serde::{Deserialize, Serialize};
 
 use crate::allocator::{Allocator, Reallocator};
-use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, Scalar};
-use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, MatrixN, Scalar, VectorN};
+use crate::constraint::{AreMultipliable, SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Dim, DimMin, DimMinimum};
 use crate::storage::{Storage, StorageMut};
-use simba::scalar::{ComplexField, Field};
+use approx::AbsDiffEq;
+use num::{One, Zero};
+use simba::scalar::{ComplexField, Field, RealField};
 use std::mem;
 
+use crate::linalg::norm1_estimate::norm1_estimate;
 use crate::linalg::PermutationSequence;
 
 /// LU decomposition with partial (row) pivoting.
@@ -44,6 +47,102 @@ where
 {
 }
 
+/// A LU decomposition computed in-place with `LU::new_in_place`.
+///
+/// This borrows the buffers containing the decomposition, with the same packed layout as `LU`.
+#[derive(Debug)]
+pub struct LUView<'a, N: ComplexField, R: Dim, C: Dim, S: Storage<N, R, C>> {
+    lu: &'a Matrix<N, R, C, S>,
+    pivots: &'a [usize],
+}
+
+impl<'a, N: ComplexField, R: Dim, C: Dim, S: Storage<N, R, C>> LUView<'a, N, R, C, S> {
+    /// The matrix containing the strictly lower-triangular part of `L` and the upper-triangular
+    /// factor `U`.
+    #[inline]
+    pub fn lu_internal(&self) -> &'a Matrix<N, R, C, S> {
+        self.lu
+    }
+
+    /// The row interchanges performed by the decomposition: the row `i` was swapped with the row
+    /// `pivots[i]` at the `i`-th step.
+    #[inline]
+    pub fn pivots(&self) -> &'a [usize] {
+        self.pivots
+    }
+
+    fn permute_rows<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+    {
+        for (i, piv) in self.pivots.iter().enumerate() {
+            if *piv != i {
+                b.swap_rows(i, *piv);
+            }
+        }
+    }
+}
+
+impl<'a, N: ComplexField, D: Dim, S: Storage<N, D, D>> LUView<'a, N, D, D, S> {
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
+    /// be overwritten with garbage.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            self.lu.nrows(),
+            b.nrows(),
+            "LU solve matrix dimension mismatch."
+        );
+        assert!(
+            self.lu.is_square(),
+            "LU solve: unable to solve a non-square system."
+        );
+
+        if self.lu.nrows() == 0 {
+            return true;
+        }
+
+        self.permute_rows(b);
+        let _ = self.lu.solve_lower_triangular_with_diag_mut(b, N::one());
+        self.lu.solve_upper_triangular_mut(b)
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    pub fn determinant(&self) -> N {
+        assert!(
+            self.lu.is_square(),
+            "LU determinant: unable to compute the determinant of a non-square matrix."
+        );
+
+        let mut res = N::one();
+
+        for (i, piv) in self.pivots.iter().enumerate() {
+            res *= self.lu[(i, i)];
+
+            if *piv != i {
+                res = -res;
+            }
+        }
+
+        res
+    }
+
+    /// Indicates if the decomposed matrix is invertible.
+    pub fn is_invertible(&self) -> bool {
+        assert!(
+            self.lu.is_square(),
+            "LU: unable to test the invertibility of a non-square matrix."
+        );
+
+        (0..self.lu.nrows()).all(|i| !self.lu[(i, i)].is_zero())
+    }
+}
+
 /// Performs a LU decomposition to overwrite `out` with the inverse of `matrix`.
 ///
 /// If `matrix` is not invertible, `false` is returned and `out` may contain invalid data.
@@ -84,6 +183,42 @@ where
     matrix.solve_upper_triangular_mut(out)
 }
 
+/// Performs the LU decomposition with partial (row) pivoting of `matrix` in-place.
+///
+/// The row swapped with the row `i` at the `i`-th step of the elimination is reported to
+/// `record_pivot(i, piv)`, including when `piv == i`.
+fn decompose_in_place<N, R, C, S>(
+    matrix: &mut Matrix<N, R, C, S>,
+    mut record_pivot: impl FnMut(usize, usize),
+) where
+    N: ComplexField,
+    R: Dim,
+    C: Dim,
+    S: StorageMut<N, R, C>,
+{
+    let min_nrows_ncols = matrix.nrows().min(matrix.ncols());
+
+    for i in 0..min_nrows_ncols {
+        let piv = matrix.slice_range(i.., i).icamax() + i;
+        let diag = matrix[(piv, i)];
+
+        if diag.is_zero() {
+            // No non-zero entries on this column.
+            record_pivot(i, i);
+            continue;
+        }
+
+        record_pivot(i, piv);
+
+        if piv != i {
+            matrix.columns_range_mut(..i).swap_rows(i, piv);
+            gauss_step_swap(matrix, diag, i, piv);
+        } else {
+            gauss_step(matrix, diag, i);
+        }
+    }
+}
+
 impl<N: ComplexField, R: DimMin<C>, C: Dim> LU<N, R, C>
 where
     DefaultAllocator: Allocator<N, R, C> + Allocator<(usize, usize), DimMinimum<R, C>>,
@@ -95,31 +230,43 @@ where
 
         let mut p = PermutationSequence::identity_generic(min_nrows_ncols);
 
-        if min_nrows_ncols.value() == 0 {
-            return LU { lu: matrix, p };
-        }
-
-        for i in 0..min_nrows_ncols.value() {
-            let piv = matrix.slice_range(i.., i).icamax() + i;
-            let diag = matrix[(piv, i)];
-
-            if diag.is_zero() {
-                // No non-zero entries on this column.
-                continue;
-            }
-
+        decompose_in_place(&mut matrix, |i, piv| {
             if piv != i {
                 p.append_permutation(i, piv);
-                matrix.columns_range_mut(..i).swap_rows(i, piv);
-                gauss_step_swap(&mut matrix, diag, i, piv);
-            } else {
-                gauss_step(&mut matrix, diag, i);
             }
-        }
+        });
 
         LU { lu: matrix, p }
     }
 
+    /// Computes the LU decomposition with partial (row) pivoting of `matrix` in-place, without
+    /// any allocation.
+    ///
+    /// The factors overwrite `matrix` with the same packed layout as `LU::new`: the strictly
+    /// lower-triangular part contains the unit lower-triangular factor `L`, and the upper
+    /// triangular part contains `U`. The `i`-th element of `pivots` is set to the index of the
+    /// row swapped with the row `i` at the `i`-th step of the elimination. `matrix` may be any
+    /// mutable matrix or matrix slice, so that the decomposition can be performed inside of a
+    /// pre-allocated buffer.
+    ///
+    /// Panics if the length of `pivots` is not `min(nrows, ncols)`.
+    pub fn new_in_place<'a, S: StorageMut<N, R, C>>(
+        matrix: &'a mut Matrix<N, R, C, S>,
+        pivots: &'a mut [usize],
+    ) -> LUView<'a, N, R, C, S> {
+        let min_nrows_ncols = matrix.nrows().min(matrix.ncols());
+
+        assert_eq!(
+            pivots.len(),
+            min_nrows_ncols,
+            "LU in-place: the pivot buffer must have min(nrows, ncols) elements."
+        );
+
+        decompose_in_place(matrix, |i, piv| pivots[i] = piv);
+
+        LUView { lu: matrix, pivots }
+    }
+
     #[doc(hidden)]
     pub fn lu_internal(&self) -> &MatrixMN<N, R, C> {
         &self.lu
@@ -254,6 +401,120 @@ where
         self.lu.solve_upper_triangular_mut(b)
     }
 
+    /// Solves the linear system `self * x = b`, and improves the accuracy of the solution with
+    /// iterative refinement.
+    ///
+    /// Each refinement step computes the residual `b - matrix * x` and solves for a correction
+    /// of `x`. The refinement stops once the componentwise relative backward error of `x`
+    /// reaches the machine epsilon, or when it stops decreasing by at least a factor 2. Because
+    /// the residuals are computed in the working precision of `N`, this mostly helps when the
+    /// decomposition is not an accurate decomposition of `matrix`, e.g., when it was computed
+    /// for a nearby matrix and is reused to avoid a new factorization.
+    ///
+    /// Returns `None` if `self` is not invertible.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix`            − the matrix of the linear system. The decomposition only stores
+    /// its factors, so this matrix is needed to compute the residuals.
+    /// * `b`                 − the right-hand-side of the linear system.
+    /// * `max_refine_steps`  − the maximum number of refinement steps.
+    pub fn solve_refined<R2: Dim, C2: Dim, S2, S3>(
+        &self,
+        matrix: &Matrix<N, D, D, S3>,
+        b: &Matrix<N, R2, C2, S2>,
+        max_refine_steps: usize,
+    ) -> Option<MatrixMN<N, R2, C2>>
+    where
+        S2: Storage<N, R2, C2>,
+        S3: Storage<N, D, D>,
+        ShapeConstraint: SameNumberOfRows<R2, D> + AreMultipliable<D, D, R2, C2>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+    {
+        assert_eq!(
+            self.lu.shape(),
+            matrix.shape(),
+            "LU refined solve: mismatched matrix dimensions."
+        );
+
+        let mut x = self.solve(b)?;
+        let n = matrix.nrows();
+        let eps = N::RealField::default_epsilon();
+        let mut prev_berr = None;
+
+        for _ in 0..max_refine_steps {
+            let mut residual = b.clone_owned();
+            residual.gemm(-N::one(), matrix, &x, N::one());
+
+            // The componentwise relative backward error `max(|r| / (|A| * |x| + |b|))`.
+            let mut berr = N::RealField::zero();
+            for j in 0..x.ncols() {
+                for i in 0..n {
+                    let mut denom = b[(i, j)].modulus();
+                    for k in 0..n {
+                        denom += matrix[(i, k)].modulus() * x[(k, j)].modulus();
+                    }
+
+                    if !denom.is_zero() {
+                        berr = berr.max(residual[(i, j)].modulus() / denom);
+                    }
+                }
+            }
+
+            if berr <= eps || prev_berr.map_or(false, |prev| berr * crate::convert(2.0) > prev) {
+                break;
+            }
+
+            let _ = self.solve_mut(&mut residual);
+            x += residual;
+            prev_berr = Some(berr);
+        }
+
+        Some(x)
+    }
+
+    /// Solves the linear system `matrix * x = b`, where `self` is the LU decomposition of the
+    /// equilibrated matrix `diag(row_scale) * matrix * diag(col_scale)`.
+    ///
+    /// The scaling factors are typically computed and applied with `matrix.equilibrate_mut()`
+    /// before computing the decomposition. This applies the row scaling to `b`, solves the
+    /// equilibrated system, and undoes the column scaling on its solution. For badly scaled
+    /// systems, this is more accurate than solving the original system directly.
+    ///
+    /// Returns `None` if `self` is not invertible.
+    pub fn solve_equilibrated<R2: Dim, C2: Dim, S2>(
+        &self,
+        row_scale: &VectorN<N::RealField, D>,
+        col_scale: &VectorN<N::RealField, D>,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> Option<MatrixMN<N, R2, C2>>
+    where
+        S2: Storage<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<N::RealField, D> + Allocator<N, R2, C2>,
+    {
+        assert!(
+            row_scale.len() == self.lu.nrows() && col_scale.len() == self.lu.nrows(),
+            "LU equilibrated solve: mismatched scaling dimensions."
+        );
+
+        let mut res = b.clone_owned();
+
+        for (i, f) in row_scale.iter().enumerate() {
+            res.row_mut(i).apply(|e| e.scale(*f));
+        }
+
+        if !self.solve_mut(&mut res) {
+            return None;
+        }
+
+        for (i, f) in col_scale.iter().enumerate() {
+            res.row_mut(i).apply(|e| e.scale(*f));
+        }
+
+        Some(res)
+    }
+
     /// Computes the inverse of the decomposed matrix.
     ///
     /// Returns `None` if the matrix is not invertible.
@@ -306,6 +567,39 @@ where
         res * self.p.determinant()
     }
 
+    /// Computes the sign and the natural logarithm of the modulus of the determinant of the
+    /// decomposed matrix.
+    ///
+    /// Returns `(sign, logdet)` such that the determinant is equal to `sign * exp(logdet)`. The
+    /// `sign` has a modulus equal to one, or is zero if the decomposed matrix is singular, in which
+    /// case `logdet` is `-inf`. Unlike `.determinant()`, this does not overflow nor underflow for
+    /// large matrices.
+    pub fn logdet(&self) -> (N, N::RealField) {
+        let dim = self.lu.nrows();
+        assert!(
+            self.lu.is_square(),
+            "LU logdet: unable to compute the determinant of a non-square matrix."
+        );
+
+        let mut sign = self.p.determinant::<N>();
+        let mut logdet = N::RealField::zero();
+
+        for i in 0..dim {
+            let diag = unsafe { *self.lu.get_unchecked((i, i)) };
+            let modulus = diag.modulus();
+
+            if modulus.is_zero() {
+                sign = N::zero();
+            } else {
+                sign *= diag.unscale(modulus);
+            }
+
+            logdet += modulus.ln();
+        }
+
+        (sign, logdet)
+    }
+
     /// Indicates if the decomposed matrix is invertible.
     pub fn is_invertible(&self) -> bool {
         assert!(
@@ -321,6 +615,86 @@ where
 
         true
     }
+
+    /// Estimates the reciprocal of the condition number of the decomposed matrix in 1-norm,
+    /// i.e., `1 / (‖A‖₁ * ‖A⁻¹‖₁)`.
+    ///
+    /// Both norms are estimated with the Hager-Higham estimator from products with the factors
+    /// of this decomposition, so this requires only `O(n²)` operations. The result is zero if the
+    /// decomposed matrix is singular, and is close to zero if it is ill-conditioned.
+    pub fn rcond(&self) -> N::RealField
+    where
+        DefaultAllocator: Allocator<N, D>,
+    {
+        assert!(
+            self.lu.is_square(),
+            "LU rcond: unable to compute the condition number of a non-square matrix."
+        );
+
+        let dim = self.lu.data.shape().0;
+        let n = dim.value();
+
+        if n == 0 {
+            return N::RealField::one();
+        }
+
+        if !self.is_invertible() {
+            return N::RealField::zero();
+        }
+
+        // A = P⁻¹ * L * U
+        let norm = norm1_estimate(
+            dim,
+            |x| {
+                for i in 0..n {
+                    x[i] = self.lu.slice_range(i, i..).tr_dot(&x.rows_range(i..));
+                }
+
+                for i in (1..n).rev() {
+                    let dot = self.lu.slice_range(i, ..i).tr_dot(&x.rows_range(..i));
+                    x[i] += dot;
+                }
+
+                self.p.inv_permute_rows(x);
+            },
+            |x| {
+                self.p.permute_rows(x);
+
+                for i in 0..n - 1 {
+                    let dot = self.lu.slice_range(i + 1.., i).dotc(&x.rows_range(i + 1..));
+                    x[i] += dot;
+                }
+
+                for i in (0..n).rev() {
+                    x[i] = self.lu.slice_range(..i + 1, i).dotc(&x.rows_range(..i + 1));
+                }
+            },
+        );
+
+        // A⁻¹ = U⁻¹ * L⁻¹ * P
+        let inv_norm = norm1_estimate(
+            dim,
+            |x| {
+                let _ = self.solve_mut(x);
+            },
+            |x| {
+                let _ = self.lu.ad_solve_upper_triangular_mut(x);
+
+                for i in (0..n - 1).rev() {
+                    let dot = self.lu.slice_range(i + 1.., i).dotc(&x.rows_range(i + 1..));
+                    x[i] -= dot;
+                }
+
+                self.p.inv_permute_rows(x);
+            },
+        );
+
+        if norm.is_zero() {
+            N::RealField::zero()
+        } else {
+            N::RealField::one() / (norm * inv_norm)
+        }
+    }
 }
 
 #[doc(hidden)]
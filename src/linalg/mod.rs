@@ -1,44 +1,115 @@
 //! [Reexported at the root of this crate.] Factorization of real matrices.
 
 pub mod balancing;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod banded;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod banded_cholesky;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod banded_lu;
 mod bidiagonal;
 mod cholesky;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod circulant;
+mod col_piv_qr;
+mod convergence;
 mod convolution;
 mod determinant;
+mod eigen;
+mod equilibrate;
+mod error;
 // FIXME: this should not be needed. However, the exp uses
 // explicit float operations on `f32` and `f64`. We need to
 // get rid of these to allow exp to be used on a no-std context.
 #[cfg(feature = "std")]
 mod exp;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod fft;
 mod full_piv_lu;
+mod generalized_symmetric_eigen;
+mod gershgorin;
 pub mod givens;
 mod hessenberg;
 pub mod householder;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod incremental_svd;
 mod inverse;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod iterative;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod lanczos;
+mod ldl;
+mod log;
+mod lstsq;
 mod lu;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod nnls;
+mod norm1_estimate;
 mod permutation_sequence;
+mod polar;
+#[cfg(feature = "std")]
+mod pow;
+mod power_iteration;
 mod qr;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod riccati;
 mod schur;
 mod solve;
+mod sqrtm;
 mod svd;
+#[cfg(feature = "std")]
+mod svd_randomized;
+mod sylvester;
 mod symmetric_eigen;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod symmetric_eigen_dc;
+mod symmetric_eigen_jacobi;
 mod symmetric_tridiagonal;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod toeplitz;
+mod tridiagonal_solve;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod updatable_qr;
 
-//// FIXME: Not complete enough for publishing.
-//// This handles only cases where each eigenvalue has multiplicity one.
-// mod eigen;
-
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::banded::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::banded_cholesky::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::banded_lu::*;
 pub use self::bidiagonal::*;
 pub use self::cholesky::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::circulant::*;
+pub use self::col_piv_qr::*;
+pub use self::convergence::*;
 pub use self::convolution::*;
+pub use self::eigen::*;
+pub use self::error::*;
 #[cfg(feature = "std")]
 pub use self::exp::*;
 pub use self::full_piv_lu::*;
+pub use self::generalized_symmetric_eigen::*;
 pub use self::hessenberg::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::incremental_svd::*;
+pub use self::ldl::*;
 pub use self::lu::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::nnls::*;
 pub use self::permutation_sequence::*;
+pub use self::polar::*;
+pub use self::power_iteration::*;
 pub use self::qr::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::riccati::*;
 pub use self::schur::*;
 pub use self::svd::*;
+pub use self::sylvester::*;
 pub use self::symmetric_eigen::*;
 pub use self::symmetric_tridiagonal::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::toeplitz::*;
+pub use self::tridiagonal_solve::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::updatable_qr::*;
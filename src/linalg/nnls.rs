@@ -0,0 +1,192 @@
+//! Non-negative least squares.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
+
+use simba::scalar::RealField;
+
+use crate::base::dimension::Dynamic;
+use crate::base::storage::Storage;
+use crate::base::{DMatrix, DVector, Matrix, Vector};
+
+use crate::linalg::{ColPivQR, LinalgError};
+
+/// Computes the vector `x` with non-negative components minimizing `|a * x - b|`.
+///
+/// This uses the active-set method of Lawson and Hanson. See `try_nnls` for details.
+pub fn nnls<N: RealField, S1, S2>(
+    a: &Matrix<N, Dynamic, Dynamic, S1>,
+    b: &Vector<N, Dynamic, S2>,
+) -> DVector<N>
+where
+    S1: Storage<N, Dynamic, Dynamic>,
+    S2: Storage<N, Dynamic>,
+{
+    let eps = N::default_epsilon() * crate::convert(a.nrows().max(a.ncols()).max(1) as f64);
+    try_nnls(a, b, eps, 0).unwrap()
+}
+
+/// Attempts to compute the vector `x` with non-negative components minimizing `|a * x - b|`.
+///
+/// This uses the active-set method of Lawson and Hanson. The components of `x` are split
+/// between an active set, where they are fixed to zero, and a passive set, where they are the
+/// solution of an unconstrained least-squares problem computed with a QR decomposition with
+/// column pivoting. Each iteration moves to the passive set the component along which the
+/// residual decreases the fastest, until the optimality conditions are satisfied.
+///
+/// # Arguments
+///
+/// * `a`         − the matrix of the least-squares problem.
+/// * `b`         − the right-hand-side of the least-squares problem.
+/// * `eps`       − relative tolerance used to determine when the gradient of the objective
+/// function, and the diagonal elements of `R` of the passive subproblems, are equal to 0.
+/// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+/// number of iteration is exceeded, `LinalgError::NotConverged` is returned. If `niter == 0`,
+/// then the algorithm continues indefinitely until convergence.
+///
+/// Returns `LinalgError::DimensionMismatch` if `a` and `b` do not have the same number of rows.
+pub fn try_nnls<N: RealField, S1, S2>(
+    a: &Matrix<N, Dynamic, Dynamic, S1>,
+    b: &Vector<N, Dynamic, S2>,
+    eps: N,
+    max_niter: usize,
+) -> Result<DVector<N>, LinalgError>
+where
+    S1: Storage<N, Dynamic, Dynamic>,
+    S2: Storage<N, Dynamic>,
+{
+    if a.nrows() != b.nrows() {
+        return Err(LinalgError::DimensionMismatch);
+    }
+    assert!(eps >= N::zero(), "NNLS: the epsilon must be non-negative.");
+
+    let (nrows, ncols) = a.shape();
+    let mut x = DVector::zeros(ncols);
+
+    let a_norm = a.norm();
+    let rank_tol = eps * a_norm;
+    let grad_tol = rank_tol * b.norm();
+
+    let mut passive = vec![false; ncols];
+    let mut niter = 0;
+
+    loop {
+        let mut residual = b.clone_owned();
+        residual.gemv(-N::one(), a, &x, N::one());
+        let grad = a.tr_mul(&residual);
+
+        // The components that could not enter the passive set since the last change of `x`.
+        let mut rejected = vec![false; ncols];
+
+        loop {
+            // The optimality conditions are satisfied if the gradient does not point toward
+            // any active component.
+            let mut entering = None;
+            let mut max_grad = grad_tol;
+
+            for j in 0..ncols {
+                if !passive[j] && !rejected[j] && grad[j] > max_grad {
+                    entering = Some(j);
+                    max_grad = grad[j];
+                }
+            }
+
+            let t = match entering {
+                Some(t) => t,
+                None => return Ok(x),
+            };
+
+            if niter == max_niter && max_niter != 0 {
+                return Err(LinalgError::NotConverged { iterations: niter });
+            }
+
+            niter += 1;
+            passive[t] = true;
+
+            let ids: Vec<usize> = (0..ncols).filter(|&j| passive[j]).collect();
+            let a_passive = DMatrix::from_fn(nrows, ids.len(), |i, j| a[(i, ids[j])]);
+            let z = ColPivQR::new(a_passive).solve(b, rank_tol);
+            let it = ids.iter().position(|&j| j == t).unwrap();
+
+            // Because of rounding errors, the entering component may not be positive. Keep it
+            // active to avoid cycling.
+            if z[it] <= N::zero() {
+                passive[t] = false;
+                rejected[t] = true;
+                continue;
+            }
+
+            lawson_hanson_inner_loop(a, b, &mut x, &mut passive, ids, z, rank_tol);
+            break;
+        }
+    }
+}
+
+/// Moves the passive components of `x` toward the unconstrained solution `z` of the passive
+/// subproblem, moving to the active set the components that become zero on the way, until the
+/// solution of the passive subproblem is feasible.
+fn lawson_hanson_inner_loop<N: RealField, S1, S2>(
+    a: &Matrix<N, Dynamic, Dynamic, S1>,
+    b: &Vector<N, Dynamic, S2>,
+    x: &mut DVector<N>,
+    passive: &mut [bool],
+    mut ids: Vec<usize>,
+    mut z: DVector<N>,
+    rank_tol: N,
+) where
+    S1: Storage<N, Dynamic, Dynamic>,
+    S2: Storage<N, Dynamic>,
+{
+    let nrows = a.nrows();
+
+    loop {
+        // Find the largest step toward `z` that keeps `x` feasible.
+        let mut alpha = N::one();
+        let mut limiting = None;
+
+        for (k, &j) in ids.iter().enumerate() {
+            if z[k] <= N::zero() {
+                let step = if x[j].is_zero() {
+                    N::zero()
+                } else {
+                    x[j] / (x[j] - z[k])
+                };
+
+                if limiting.is_none() || step < alpha {
+                    alpha = step;
+                    limiting = Some(j);
+                }
+            }
+        }
+
+        let limiting = match limiting {
+            Some(limiting) => limiting,
+            None => {
+                for (k, &j) in ids.iter().enumerate() {
+                    x[j] = z[k];
+                }
+
+                return;
+            }
+        };
+
+        for (k, &j) in ids.iter().enumerate() {
+            let delta = alpha * (z[k] - x[j]);
+            x[j] += delta;
+        }
+
+        x[limiting] = N::zero();
+
+        // Move to the active set the components that became zero.
+        for &j in &ids {
+            if x[j] <= N::zero() {
+                x[j] = N::zero();
+                passive[j] = false;
+            }
+        }
+
+        ids.retain(|&j| passive[j]);
+        let a_passive = DMatrix::from_fn(nrows, ids.len(), |i, j| a[(i, ids[j])]);
+        z = ColPivQR::new(a_passive).solve(b, rank_tol);
+    }
+}
@@ -0,0 +1,93 @@
+//! Estimation of the 1-norm of a matrix only known through its products with vectors.
+
+use num::Zero;
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, VectorN};
+use crate::dimension::{Dim, U1};
+
+/// Maximum number of iterations of the 1-norm estimator.
+const MAX_NITER: usize = 5;
+
+/// Estimates the 1-norm of a square matrix `A` with `dim` rows, given closures computing
+/// `A * x` and `A^* * x` in-place.
+///
+/// This is the estimator of Hager, with the improvements of Higham (see LAPACK's `xLACN2`).
+/// The estimate is always a lower bound of the actual 1-norm, and is usually exact or within a
+/// factor 3 of it.
+pub(crate) fn norm1_estimate<N: ComplexField, D: Dim>(
+    dim: D,
+    mut mul: impl FnMut(&mut VectorN<N, D>),
+    mut ad_mul: impl FnMut(&mut VectorN<N, D>),
+) -> N::RealField
+where
+    DefaultAllocator: Allocator<N, D>,
+{
+    let n = dim.value();
+
+    if n == 0 {
+        return N::RealField::zero();
+    }
+
+    let norm1 = |v: &VectorN<N, D>| {
+        v.iter()
+            .fold(N::RealField::zero(), |acc, e| acc + e.modulus())
+    };
+    let sign = |e: &N| {
+        let modulus = e.modulus();
+        if modulus.is_zero() {
+            N::one()
+        } else {
+            e.unscale(modulus)
+        }
+    };
+
+    let mut x =
+        VectorN::from_element_generic(dim, U1, N::from_real(crate::convert(1.0 / n as f64)));
+    mul(&mut x);
+    let mut est = norm1(&x);
+
+    if n > 1 {
+        let mut xi = x.map(|e| sign(&e));
+        let mut prev_j = None;
+
+        for _ in 0..MAX_NITER {
+            let mut z = xi.clone();
+            ad_mul(&mut z);
+            let j = z.icamax();
+
+            // Stop if the gradient does not point toward a canonical vector we did not try yet.
+            if prev_j == Some(j) {
+                break;
+            }
+
+            let mut y = VectorN::zeros_generic(dim, U1);
+            y[j] = N::one();
+            mul(&mut y);
+            let new_est = norm1(&y);
+
+            if new_est <= est {
+                break;
+            }
+
+            est = new_est;
+            xi = y.map(|e| sign(&e));
+            prev_j = Some(j);
+        }
+
+        // Alternative estimate, used to catch some matrices the iteration above underestimates.
+        let mut x = VectorN::from_fn_generic(dim, U1, |i, _| {
+            let val: f64 = 1.0 + i as f64 / (n - 1) as f64;
+            N::from_real(crate::convert(if i % 2 == 0 { val } else { -val }))
+        });
+        mul(&mut x);
+        let alt_est = norm1(&x) * crate::convert(2.0 / (3.0 * n as f64));
+
+        if alt_est > est {
+            est = alt_est;
+        }
+    }
+
+    est
+}
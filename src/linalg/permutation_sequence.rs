@@ -139,11 +139,45 @@ where
         }
     }
 
+    /// The inverse of this sequence of permutations.
+    ///
+    /// Applying the inverse with `.permute_rows(...)` is equivalent to applying `self` with
+    /// `.inv_permute_rows(...)`, and similarly for columns.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix3, PermutationSequence, U3};
+    /// let mut p = PermutationSequence::<U3>::identity();
+    /// p.append_permutation(0, 1);
+    /// p.append_permutation(1, 2);
+    ///
+    /// let m = Matrix3::new(1, 2, 3, 4, 5, 6, 7, 8, 9);
+    /// let mut permuted = m;
+    /// p.permute_rows(&mut permuted);
+    /// p.inverse().permute_rows(&mut permuted);
+    ///
+    /// assert_eq!(permuted, m);
+    /// ```
+    pub fn inverse(&self) -> Self {
+        let mut res = self.clone();
+
+        for i in 0..self.len {
+            res.ipiv[i] = self.ipiv[self.len - i - 1];
+        }
+
+        res
+    }
+
     /// The number of non-identity permutations applied by this sequence.
     pub fn len(&self) -> usize {
         self.len
     }
 
+    /// Returns `true` if this sequence does not contain any non-identity permutation.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     /// The determinant of the matrix corresponding to this permutation.
     #[inline]
     pub fn determinant<N: One + ClosedNeg>(&self) -> N {
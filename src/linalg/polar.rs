@@ -0,0 +1,178 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, MatrixN, SquareMatrix};
+use crate::dimension::{Dim, DimDiff, DimMin, DimSub, U1};
+use crate::storage::Storage;
+use simba::scalar::ComplexField;
+
+use crate::linalg::{LinalgError, SVD};
+
+/// Polar decomposition of a square matrix.
+///
+/// The decomposed matrix `M` is written as `M = U * P` where `U` is unitary (orthogonal for
+/// real matrices) and `P` is hermitian (symmetric for real matrices) positive semi-definite.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DefaultAllocator: Allocator<N, D, D>,
+         MatrixN<N, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<N, D, D>,
+         MatrixN<N, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct PolarDecomposition<N: ComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    /// The unitary factor `U` of the decomposition.
+    ///
+    /// For a real matrix with a negative determinant, this is an orthogonal matrix with a
+    /// determinant equal to `-1`, i.e., a rotation composed with a reflection.
+    pub u: MatrixN<N, D>,
+
+    /// The hermitian positive semi-definite factor `P` of the decomposition.
+    pub p: MatrixN<N, D>,
+}
+
+impl<N: ComplexField, D: Dim> Copy for PolarDecomposition<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D>,
+    MatrixN<N, D>: Copy,
+{
+}
+
+impl<N: ComplexField, D: DimMin<D, Output = D>> PolarDecomposition<N, D>
+where
+    D: DimSub<U1>, // For Bidiagonal.
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N::RealField, D>
+        + Allocator<N::RealField, DimDiff<D, U1>>,
+{
+    /// Computes the polar decomposition of the square matrix `m`.
+    pub fn new(m: MatrixN<N, D>) -> Self {
+        Self::try_new(m, N::RealField::default_epsilon(), 0).unwrap()
+    }
+
+    /// Attempts to compute the polar decomposition of the square matrix `m`.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `LinalgError::NotConverged` is returned. If `niter == 0`,
+    /// then the algorithm continues indefinitely until convergence.
+    pub fn try_new(
+        m: MatrixN<N, D>,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Result<Self, LinalgError> {
+        if !m.is_square() {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
+        // With `M = W * Σ * Vᴴ`, we have `U = W * Vᴴ` and `P = V * Σ * Vᴴ`.
+        let svd = SVD::try_new(m, true, true, eps, max_niter)?;
+        let w = svd.u.unwrap();
+        let v_t = svd.v_t.unwrap();
+
+        let mut sigma_v_t = v_t.clone();
+        for i in 0..svd.singular_values.len() {
+            let val = svd.singular_values[i];
+            sigma_v_t.row_mut(i).scale_mut(val);
+        }
+
+        let mut p = v_t.ad_mul(&sigma_v_t);
+
+        // Enforce the exact symmetry of `P`.
+        let dim = p.nrows();
+        for j in 0..dim {
+            p[(j, j)] = N::from_real(p[(j, j)].real());
+
+            for i in j + 1..dim {
+                p[(j, i)] = p[(i, j)].conjugate();
+            }
+        }
+
+        Ok(PolarDecomposition { u: w * v_t, p })
+    }
+}
+
+impl<N: ComplexField, D: Dim> PolarDecomposition<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    /// Retrieves the unitary factor `U` and the hermitian positive semi-definite factor `P`.
+    pub fn unpack(self) -> (MatrixN<N, D>, MatrixN<N, D>) {
+        (self.u, self.p)
+    }
+
+    /// Rebuild the original matrix, i.e., computes `U * P`.
+    pub fn recompose(&self) -> MatrixN<N, D> {
+        &self.u * &self.p
+    }
+}
+
+impl<N: ComplexField, D: DimMin<D, Output = D>, S: Storage<N, D, D>> SquareMatrix<N, D, S>
+where
+    D: DimSub<U1>, // For Bidiagonal.
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N::RealField, D>
+        + Allocator<N::RealField, DimDiff<D, U1>>,
+{
+    /// Computes the polar decomposition of this square matrix.
+    pub fn polar(self) -> PolarDecomposition<N, D> {
+        PolarDecomposition::new(self.into_owned())
+    }
+
+    /// Attempts to compute the polar decomposition of this square matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `LinalgError::NotConverged` is returned. If `niter == 0`,
+    /// then the algorithm continues indefinitely until convergence.
+    pub fn try_polar(
+        self,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Result<PolarDecomposition<N, D>, LinalgError> {
+        PolarDecomposition::try_new(self.into_owned(), eps, max_niter)
+    }
+
+    /// Computes the unitary (orthogonal for real matrices) matrix closest to this square matrix
+    /// in the Frobenius norm.
+    ///
+    /// This is the unitary factor `U` of the polar decomposition, computed without its hermitian
+    /// factor. This is useful to remove the drift of a matrix obtained by accumulating many
+    /// incremental rotations: if the drift is small, the result is a rotation matrix that can be
+    /// given to, e.g., `Rotation3::from_matrix_unchecked`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Rotation3, Vector3};
+    /// let rot = Rotation3::from_axis_angle(&Vector3::z_axis(), 0.7);
+    /// let drifted = rot.matrix() + Matrix3::repeat(1.0e-3);
+    /// let projected = drifted.nearest_orthogonal();
+    ///
+    /// assert!(projected.is_orthogonal(1.0e-7));
+    /// assert_relative_eq!(projected, *rot.matrix(), epsilon = 1.0e-2);
+    /// ```
+    pub fn nearest_orthogonal(self) -> MatrixN<N, D> {
+        let svd = SVD::new(self.into_owned(), true, true);
+        svd.u.unwrap() * svd.v_t.unwrap()
+    }
+}
@@ -0,0 +1,222 @@
+//! This module provides the fractional power (powf) function to square matrices.
+
+use approx::AbsDiffEq;
+use num::Zero;
+
+use crate::allocator::Allocator;
+use crate::base::{DMatrix, DefaultAllocator, MatrixN};
+use crate::dimension::{DimDiff, DimMin, DimMinimum, DimSub, U1};
+use crate::storage::Storage;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::linalg::sqrtm::is_hermitian;
+use crate::linalg::{solve_sylvester, Schur, SymmetricEigen};
+
+/// Eigenvalues closer than this fraction of the spectral radius are put in the same block of the
+/// Schur–Parlett method.
+const CLUSTER_TOL: f64 = 0.1;
+
+impl<N: ComplexField, D> MatrixN<N, D>
+where
+    D: DimMin<D, Output = D> + DimSub<U1>,
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<(usize, usize), DimMinimum<D, D>>
+        + Allocator<N, D>
+        + Allocator<N, D, DimDiff<D, U1>>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N::RealField, D>
+        + Allocator<N::RealField, D, D>
+        + Allocator<N::RealField, DimDiff<D, U1>>,
+{
+    /// Computes the principal power `self^alpha` of this matrix, for a real exponent `alpha`.
+    ///
+    /// If this matrix is symmetric (hermitian), the power is computed by raising its eigenvalues
+    /// to `alpha`, and `None` is returned if it is not positive semi-definite, or if it is
+    /// singular and `alpha` is not positive.
+    ///
+    /// Otherwise, the blocked Schur–Parlett method is applied to the Schur form of this matrix:
+    /// the eigenvalues are gathered into blocks of close eigenvalues, the power of each diagonal
+    /// block is computed directly (or as `(alpha * block.log()).exp()` for blocks of more than one
+    /// eigenvalue), and the off-diagonal blocks are then obtained by solving Sylvester equations.
+    /// `None` is returned if this matrix has an eigenvalue on the negative real axis, or if it
+    /// has a zero eigenvalue and either `alpha` is not positive or the eigenvalue is not isolated.
+    pub fn powf(&self, alpha: N::RealField) -> Option<Self> {
+        assert!(
+            self.is_square(),
+            "Unable to compute the power of a non-square matrix."
+        );
+
+        let dim = self.nrows();
+        let eps = N::RealField::default_epsilon();
+
+        if is_hermitian(self, eps) {
+            let mut eig = SymmetricEigen::new(self.clone());
+            let vmax = eig.eigenvalues.camax();
+            let tol = eps * crate::convert(dim as f64) * vmax;
+
+            for val in eig.eigenvalues.iter_mut() {
+                if *val < -tol || (*val <= tol && alpha <= N::RealField::zero()) {
+                    return None;
+                }
+
+                *val = if *val <= tol {
+                    N::RealField::zero()
+                } else {
+                    val.powf(alpha)
+                };
+            }
+
+            return Some(eig.recompose());
+        }
+
+        let (nrows, ncols) = self.data.shape();
+        let (q, t) = Schur::new(self.clone()).unpack();
+        let f = powf_schur_parlett(&DMatrix::from_iterator(dim, dim, t.iter().cloned()), alpha)?;
+        let f = MatrixN::from_iterator_generic(nrows, ncols, f.iter().cloned());
+
+        Some(&q * f * q.adjoint())
+    }
+}
+
+/// Computes `t^alpha` for an upper-quasitriangular matrix `t` with the blocked Schur–Parlett
+/// method.
+fn powf_schur_parlett<N: ComplexField>(t: &DMatrix<N>, alpha: N::RealField) -> Option<DMatrix<N>> {
+    let dim = t.nrows();
+    let zero = N::RealField::zero();
+
+    // The 1x1 and 2x2 diagonal blocks of `t`, with their eigenvalues as `(re, im)` pairs.
+    let mut blocks = Vec::new();
+    let mut radius = zero;
+    let mut i = 0;
+
+    while i < dim {
+        let (size, eigenvalues) = if i + 1 < dim && !t[(i + 1, i)].is_zero() {
+            let half: N::RealField = crate::convert(0.5);
+            let mean = (t[(i, i)].real() + t[(i + 1, i + 1)].real()) * half;
+            let diff = (t[(i, i)].real() - t[(i + 1, i + 1)].real()) * half;
+            let disc = diff * diff + (t[(i, i + 1)] * t[(i + 1, i)]).real();
+
+            if disc < zero {
+                let im = (-disc).sqrt();
+                (2, [(mean, im), (mean, -im)])
+            } else {
+                let sq = disc.sqrt();
+                (2, [(mean + sq, zero), (mean - sq, zero)])
+            }
+        } else {
+            let val = t[(i, i)];
+            (1, [(val.real(), val.imaginary()); 2])
+        };
+
+        for &(re, im) in eigenvalues.iter() {
+            // The principal power is not defined on the negative real axis.
+            if im.is_zero() && (re < zero || (re.is_zero() && alpha <= zero)) {
+                return None;
+            }
+
+            radius = radius.max(re.hypot(im));
+        }
+
+        blocks.push((i, size, eigenvalues));
+        i += size;
+    }
+
+    // Merge consecutive diagonal blocks until eigenvalues from different clusters are well
+    // separated, which keeps the Sylvester equations well-conditioned.
+    let tol = radius * crate::convert(CLUSTER_TOL);
+    let close = |a: &[(N::RealField, N::RealField); 2], b: &[(N::RealField, N::RealField); 2]| {
+        a.iter()
+            .any(|x| b.iter().any(|y| (x.0 - y.0).hypot(x.1 - y.1) <= tol))
+    };
+
+    let mut clusters = Vec::new();
+    let mut k = 0;
+
+    while k < blocks.len() {
+        let mut last = k;
+        let mut l = k;
+
+        while l <= last {
+            for m in last + 1..blocks.len() {
+                if close(&blocks[l].2, &blocks[m].2) {
+                    last = m;
+                }
+            }
+
+            l += 1;
+        }
+
+        clusters.push((blocks[k].0, blocks[last].0 + blocks[last].1 - blocks[k].0));
+        k = last + 1;
+    }
+
+    let mut f = DMatrix::zeros(dim, dim);
+
+    for (j, &(cj, nj)) in clusters.iter().enumerate() {
+        let fjj = powf_diagonal_block(&t.slice((cj, cj), (nj, nj)).clone_owned(), alpha)?;
+        f.slice_mut((cj, cj), (nj, nj)).copy_from(&fjj);
+
+        // Solve `t_ii * f_ij - f_ij * t_jj = f_ii * t_ij - t_ij * f_jj + ∑ₖ (f_ik * t_kj - t_ik * f_kj)`
+        // from the bottom to the top, where `k` spans the clusters between `i` and `j`.
+        for &(ci, ni) in clusters[..j].iter().rev() {
+            let tij = t.slice((ci, cj), (ni, nj));
+            let mut rhs = f.slice((ci, ci), (ni, ni)) * tij - tij * f.slice((cj, cj), (nj, nj));
+
+            let mid = ci + ni;
+            let nmid = cj - mid;
+            rhs += f.slice((ci, mid), (ni, nmid)) * t.slice((mid, cj), (nmid, nj));
+            rhs -= t.slice((ci, mid), (ni, nmid)) * f.slice((mid, cj), (nmid, nj));
+
+            let fij = solve_sylvester(
+                &t.slice((ci, ci), (ni, ni)),
+                &-t.slice((cj, cj), (nj, nj)),
+                &rhs,
+            )?;
+            f.slice_mut((ci, cj), (ni, nj)).copy_from(&fij);
+        }
+    }
+
+    Some(f)
+}
+
+/// Computes `b^alpha` for a diagonal block `b` of the Schur–Parlett method.
+fn powf_diagonal_block<N: ComplexField>(b: &DMatrix<N>, alpha: N::RealField) -> Option<DMatrix<N>> {
+    let zero = N::RealField::zero();
+
+    if b.nrows() == 1 {
+        let val = b[(0, 0)];
+        let pow = if val.is_zero() {
+            N::zero()
+        } else {
+            val.powf(alpha)
+        };
+
+        return Some(DMatrix::from_element(1, 1, pow));
+    }
+
+    if b.nrows() == 2 && !b[(1, 0)].is_zero() {
+        // A real 2x2 block with the complex conjugate eigenvalues `λ = μ ± iν`. Because
+        // `b^alpha` is a polynomial of `b`, it is equal to `β * b + γ * I` where `β` and `γ` are
+        // real and satisfy `λ^alpha = β * λ + γ`.
+        let half: N::RealField = crate::convert(0.5);
+        let mu = (b[(0, 0)].real() + b[(1, 1)].real()) * half;
+        let diff = (b[(0, 0)].real() - b[(1, 1)].real()) * half;
+        let disc = diff * diff + (b[(0, 1)] * b[(1, 0)]).real();
+
+        if disc < zero {
+            let nu = (-disc).sqrt();
+            let modulus = mu.hypot(nu).powf(alpha);
+            let angle = nu.atan2(mu) * alpha;
+            let beta = modulus * angle.sin() / nu;
+            let gamma = modulus * angle.cos() - beta * mu;
+
+            let mut res = b * N::from_real(beta);
+            res[(0, 0)] += N::from_real(gamma);
+            res[(1, 1)] += N::from_real(gamma);
+
+            return Some(res);
+        }
+    }
+
+    b.log().map(|log| log.scale(alpha).exp())
+}
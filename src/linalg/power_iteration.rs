@@ -0,0 +1,162 @@
+//! Power iteration and inverse iteration for single eigenpairs.
+
+use approx::AbsDiffEq;
+use num::Zero;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, MatrixN, SquareMatrix, VectorN};
+use crate::dimension::{Dim, DimMin, U1};
+use crate::storage::Storage;
+
+use crate::linalg::LU;
+
+/// Computes the eigenvalue of largest modulus of the square matrix `m`, together with a unit
+/// eigenvector, using the power iteration.
+///
+/// Each iteration only requires one matrix-vector product, so this is much cheaper than a full
+/// eigendecomposition when only the dominant eigenpair is needed. The convergence is linear, with
+/// a rate equal to the ratio between the moduli of the second-largest and the largest eigenvalues,
+/// so it is slow if these are close. The eigenvalue is estimated by the Rayleigh quotient of the
+/// current iterate.
+///
+/// # Arguments
+///
+/// * `eps`       − tolerance on the norm of the residual `m * v - λ * v`, relative to the
+/// Frobenius norm of `m`.
+/// * `max_niter` − maximum number of iterations performed by the algorithm. If this number of
+/// iteration is exceeded, `None` is returned. This happens, e.g., if the largest eigenvalues are
+/// a pair of complex-conjugate eigenvalues of a real matrix. If `niter == 0`, then the algorithm
+/// continues indefinitely until convergence.
+pub fn dominant_eigenpair<N, D, S>(
+    m: &SquareMatrix<N, D, S>,
+    eps: N::RealField,
+    max_niter: usize,
+) -> Option<(N, VectorN<N, D>)>
+where
+    N: ComplexField,
+    D: Dim,
+    S: Storage<N, D, D>,
+    DefaultAllocator: Allocator<N, D>,
+{
+    assert!(
+        m.is_square(),
+        "Unable to compute the eigenvalues of a non-square matrix."
+    );
+
+    iterate(m, eps, max_niter, |_, mv| mv)
+}
+
+/// Computes the eigenvalue of the square matrix `m` closest to `shift`, together with a unit
+/// eigenvector, using the inverse iteration.
+///
+/// This performs the power iteration on `(m - shift * I)⁻¹`, so each iteration requires one solve
+/// with the LU decomposition of `m - shift * I`, which is computed only once. The convergence is
+/// fast if `shift` is a good approximation of the eigenvalue, e.g., to refine an eigenvector
+/// associated to an eigenvalue known to a limited accuracy. If `shift` happens to be exactly an
+/// eigenvalue, it is slightly perturbed so that `m - shift * I` becomes invertible.
+///
+/// # Arguments
+///
+/// * `shift`     − the approximation of the eigenvalue to compute.
+/// * `eps`       − tolerance on the norm of the residual `m * v - λ * v`, relative to the
+/// Frobenius norm of `m`.
+/// * `max_niter` − maximum number of iterations performed by the algorithm. If this number of
+/// iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm continues
+/// indefinitely until convergence.
+pub fn inverse_iteration<N, D, S>(
+    m: &SquareMatrix<N, D, S>,
+    shift: N,
+    eps: N::RealField,
+    max_niter: usize,
+) -> Option<(N, VectorN<N, D>)>
+where
+    N: ComplexField,
+    D: DimMin<D, Output = D>,
+    S: Storage<N, D, D>,
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D>,
+{
+    assert!(
+        m.is_square(),
+        "Unable to compute the eigenvalues of a non-square matrix."
+    );
+
+    let shifted = |shift: N| {
+        let mut res: MatrixN<N, D> = m.clone_owned();
+
+        for i in 0..res.nrows() {
+            res[(i, i)] -= shift;
+        }
+
+        LU::new(res)
+    };
+
+    let mut lu = shifted(shift);
+
+    if !lu.is_invertible() {
+        let perturbation = N::RealField::default_epsilon() * (m.norm() + shift.modulus());
+        let perturbation = perturbation.max(N::RealField::default_epsilon());
+        lu = shifted(shift + N::from_real(perturbation));
+    }
+
+    iterate(m, eps, max_niter, |mut res, _| {
+        if !lu.solve_mut(&mut res) {
+            // This may only happen if there is a zero pivot after the perturbation, because of
+            // rounding errors.
+            res.fill(N::zero());
+        }
+
+        res
+    })
+}
+
+// Performs the power iteration where `op(v, m * v)` computes the next iterate from the current
+// one, and returns the Rayleigh quotient of `m` for the converged iterate.
+fn iterate<N, D, S>(
+    m: &SquareMatrix<N, D, S>,
+    eps: N::RealField,
+    max_niter: usize,
+    mut op: impl FnMut(VectorN<N, D>, VectorN<N, D>) -> VectorN<N, D>,
+) -> Option<(N, VectorN<N, D>)>
+where
+    N: ComplexField,
+    D: Dim,
+    S: Storage<N, D, D>,
+    DefaultAllocator: Allocator<N, D>,
+{
+    let dim = m.data.shape().0;
+
+    assert!(
+        dim.value() != 0,
+        "Unable to compute the eigenvalues of an empty matrix."
+    );
+
+    let m_norm = m.norm();
+
+    // Deterministic starting vector that is unlikely to be orthogonal to any eigenvector.
+    let mut v = VectorN::from_fn_generic(dim, U1, |i, _| {
+        N::from_subset(&(1.0 + ((i * 7919) % 1013) as f64 / 1013.0))
+    });
+    let _ = v.normalize_mut();
+    let mut niter = 0;
+
+    loop {
+        let mv = m * &v;
+        let lambda = v.dotc(&mv);
+
+        if (&mv - &v * lambda).norm() <= eps * m_norm {
+            return Some((lambda, v));
+        }
+
+        niter += 1;
+        if niter == max_niter {
+            return None;
+        }
+
+        v = op(v, mv);
+
+        if v.normalize_mut().is_zero() {
+            return None;
+        }
+    }
+}
@@ -55,14 +55,7 @@ where
         let min_nrows_ncols = nrows.min(ncols);
 
         let mut diag = unsafe { MatrixMN::new_uninitialized_generic(min_nrows_ncols, U1) };
-
-        if min_nrows_ncols.value() == 0 {
-            return QR { qr: matrix, diag };
-        }
-
-        for ite in 0..min_nrows_ncols.value() {
-            householder::clear_column_unchecked(&mut matrix, &mut diag[ite], ite, 0, None);
-        }
+        let _ = Self::new_in_place(&mut matrix, diag.as_mut_slice());
 
         QR { qr: matrix, diag }
     }
@@ -95,6 +88,9 @@ where
     }
 
     /// Computes the orthogonal matrix `Q` of this decomposition.
+    ///
+    /// This is the economy-size factor: for a `m x n` matrix, only the `min(m, n)` leading
+    /// columns of `Q` are computed.
     pub fn q(&self) -> MatrixMN<N, R, DimMinimum<R, C>>
     where
         DefaultAllocator: Allocator<N, R, DimMinimum<R, C>>,
@@ -119,6 +115,8 @@ where
     }
 
     /// Unpacks this decomposition into its two matrix factors.
+    ///
+    /// Just like `.q()`, the returned `Q` factor only has `min(nrows, ncols)` columns.
     pub fn unpack(
         self,
     ) -> (
@@ -289,6 +287,155 @@ where
     // }
 }
 
+impl<N: ComplexField, R: DimMin<C>, C: Dim> QR<N, R, C>
+where
+    DefaultAllocator: Allocator<N, R, C> + Allocator<N, DimMinimum<R, C>>,
+{
+    /// Computes the QR decomposition of `matrix` in-place, without any allocation.
+    ///
+    /// The householder reflections and the upper-triangular factor overwrite `matrix` with the
+    /// same packed layout as `QR::new`, and the `min(nrows, ncols)` diagonal elements are written
+    /// to `diag`. `matrix` may be any mutable matrix or matrix slice, so that the decomposition
+    /// can be performed inside of a pre-allocated buffer.
+    ///
+    /// Panics if the length of `diag` is not `min(nrows, ncols)`.
+    pub fn new_in_place<'a, S: StorageMut<N, R, C>>(
+        matrix: &'a mut Matrix<N, R, C, S>,
+        diag: &'a mut [N],
+    ) -> QRView<'a, N, R, C, S> {
+        let min_nrows_ncols = matrix.nrows().min(matrix.ncols());
+
+        assert_eq!(
+            diag.len(),
+            min_nrows_ncols,
+            "QR in-place: the diagonal buffer must have min(nrows, ncols) elements."
+        );
+
+        for ite in 0..min_nrows_ncols {
+            let (mut left, mut right) = matrix.columns_range_pair_mut(ite, ite + 1..);
+            let mut axis = left.rows_range_mut(ite..);
+
+            let (reflection_norm, not_zero) = householder::reflection_axis_mut(&mut axis);
+            diag[ite] = reflection_norm;
+
+            if not_zero {
+                let refl = Reflection::new(Unit::new_unchecked(axis), N::zero());
+                let sign = reflection_norm.signum();
+                refl.reflect_with_sign(&mut right.rows_range_mut(ite..), sign.conjugate());
+            }
+        }
+
+        QRView { qr: matrix, diag }
+    }
+}
+
+/// A QR decomposition computed in-place with `QR::new_in_place`.
+///
+/// This borrows the buffers containing the decomposition, with the same packed layout as `QR`.
+#[derive(Debug)]
+pub struct QRView<'a, N: ComplexField, R: Dim, C: Dim, S: Storage<N, R, C>> {
+    qr: &'a Matrix<N, R, C, S>,
+    diag: &'a [N],
+}
+
+impl<'a, N: ComplexField, R: Dim, C: Dim, S: Storage<N, R, C>> QRView<'a, N, R, C, S> {
+    /// The matrix containing the householder reflections and the strictly upper-triangular part
+    /// of the factor `R`.
+    #[inline]
+    pub fn qr_internal(&self) -> &'a Matrix<N, R, C, S> {
+        self.qr
+    }
+
+    /// The diagonal elements of the upper-triangular factor `R`.
+    #[inline]
+    pub fn r_diagonal(&self) -> impl Iterator<Item = N::RealField> + 'a {
+        self.diag.iter().map(|e| e.modulus())
+    }
+
+    /// The element `(i, j)` of the upper trapezoidal factor `R`, with `i < min(nrows, ncols)`.
+    #[inline]
+    pub fn r_element(&self, i: usize, j: usize) -> N {
+        if i == j {
+            N::from_real(self.diag[i].modulus())
+        } else if i < j {
+            self.qr[(i, j)]
+        } else {
+            N::zero()
+        }
+    }
+
+    /// Multiplies the provided matrix by the transpose of the `Q` matrix of this decomposition.
+    pub fn q_tr_mul<R2: Dim, C2: Dim, S2>(&self, rhs: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+    {
+        for i in 0..self.diag.len() {
+            let axis = self.qr.slice_range(i.., i);
+            let refl = Reflection::new(Unit::new_unchecked(axis), N::zero());
+
+            let mut rhs_rows = rhs.rows_range_mut(i..);
+            refl.reflect_with_sign(&mut rhs_rows, self.diag[i].signum().conjugate());
+        }
+    }
+
+    /// Multiplies the provided matrix by the `Q` matrix of this decomposition.
+    pub fn q_mul<R2: Dim, C2: Dim, S2>(&self, rhs: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+    {
+        for i in (0..self.diag.len()).rev() {
+            let axis = self.qr.slice_range(i.., i);
+            let refl = Reflection::new(Unit::new_unchecked(axis), N::zero());
+
+            let mut rhs_rows = rhs.rows_range_mut(i..);
+            refl.reflect_with_sign(&mut rhs_rows, self.diag[i].signum());
+        }
+    }
+
+    /// Solves the square linear system `self * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` is
+    /// overwritten with garbage.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<N, R2, C2>,
+    {
+        assert_eq!(
+            self.qr.nrows(),
+            b.nrows(),
+            "QR solve matrix dimension mismatch."
+        );
+        assert!(
+            self.qr.is_square(),
+            "QR solve: unable to solve a non-square system."
+        );
+
+        self.q_tr_mul(b);
+
+        let dim = self.qr.nrows();
+
+        for k in 0..b.ncols() {
+            let mut b = b.column_mut(k);
+
+            for i in (0..dim).rev() {
+                let diag = self.diag[i].modulus();
+
+                if diag.is_zero() {
+                    return false;
+                }
+
+                let coeff = b[i].unscale(diag);
+                b[i] = coeff;
+                b.rows_range_mut(..i)
+                    .axpy(-coeff, &self.qr.slice_range(..i, i), N::one());
+            }
+        }
+
+        true
+    }
+}
+
 impl<N: ComplexField, R: DimMin<C>, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S>
 where
     DefaultAllocator: Allocator<N, R, C> + Allocator<N, R> + Allocator<N, DimMinimum<R, C>>,
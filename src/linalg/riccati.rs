@@ -0,0 +1,207 @@
+//! Solution of the continuous algebraic Riccati equation.
+
+use simba::scalar::RealField;
+
+use crate::allocator::Allocator;
+use crate::base::{DMatrix, DefaultAllocator, Matrix, MatrixN, SquareMatrix};
+use crate::dimension::Dim;
+use crate::storage::Storage;
+
+use crate::linalg::solve_sylvester;
+
+/// Computes the stabilizing solution `x` of the continuous algebraic Riccati equation
+/// `a.transpose() * x + x * a - x * b * r⁻¹ * b.transpose() * x + q = 0`.
+///
+/// The matrices `q` and `r` must be symmetric, and `r` must be positive-definite. The solution `x`
+/// is symmetric and such that all the eigenvalues of the closed-loop matrix `a - b * k` have
+/// negative real parts, where `k = r⁻¹ * b.transpose() * x` is the gain of the optimal
+/// linear-quadratic regulator of the system `dx/dt = a * x + b * u` with the cost
+/// `∫ x.transpose() * q * x + u.transpose() * r * u`.
+///
+/// This uses the Schur method: the solution is computed from an orthonormal basis of the stable
+/// invariant subspace of the Hamiltonian matrix `[a, -b * r⁻¹ * b.transpose(); -q, -a.transpose()]`,
+/// obtained by reordering its real Schur form.
+///
+/// Returns `None` if `r` is not positive-definite, or if there is no stabilizing solution, e.g.,
+/// if the Hamiltonian matrix has eigenvalues on the imaginary axis because `(a, b)` is not
+/// stabilizable.
+pub fn solve_care<N, D, C, S1, S2, S3, S4>(
+    a: &SquareMatrix<N, D, S1>,
+    b: &Matrix<N, D, C, S2>,
+    q: &SquareMatrix<N, D, S3>,
+    r: &SquareMatrix<N, C, S4>,
+) -> Option<MatrixN<N, D>>
+where
+    N: RealField,
+    D: Dim,
+    C: Dim,
+    S1: Storage<N, D, D>,
+    S2: Storage<N, D, C>,
+    S3: Storage<N, D, D>,
+    S4: Storage<N, C, C>,
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let n = a.nrows();
+    let m = b.ncols();
+
+    assert!(
+        a.is_square() && q.is_square() && r.is_square(),
+        "Riccati equation: the matrices `a`, `q` and `r` must be square."
+    );
+    assert!(
+        b.nrows() == n && q.nrows() == n && r.nrows() == m,
+        "Riccati equation: dimension mismatch."
+    );
+
+    let (nrows, ncols) = a.data.shape();
+
+    if n == 0 {
+        return Some(MatrixN::zeros_generic(nrows, ncols));
+    }
+
+    let x = do_solve_care(
+        DMatrix::from_fn(n, n, |i, j| a[(i, j)]),
+        DMatrix::from_fn(n, m, |i, j| b[(i, j)]),
+        DMatrix::from_fn(n, n, |i, j| q[(i, j)]),
+        DMatrix::from_fn(m, m, |i, j| r[(i, j)]),
+    )?;
+
+    Some(MatrixN::from_fn_generic(nrows, ncols, |i, j| x[(i, j)]))
+}
+
+fn do_solve_care<N: RealField>(
+    a: DMatrix<N>,
+    b: DMatrix<N>,
+    q: DMatrix<N>,
+    r: DMatrix<N>,
+) -> Option<DMatrix<N>> {
+    let n = a.nrows();
+    let g = &b * r.cholesky()?.solve(&b.transpose());
+
+    let mut h = DMatrix::zeros(2 * n, 2 * n);
+    h.slice_mut((0, 0), (n, n)).copy_from(&a);
+    h.slice_mut((0, n), (n, n)).copy_from(&-g);
+    h.slice_mut((n, 0), (n, n)).copy_from(&-q);
+    h.slice_mut((n, n), (n, n)).copy_from(&-a.transpose());
+
+    let (mut z, mut t) = h.schur().unpack();
+
+    // Move all the diagonal blocks associated to eigenvalues with negative real parts to the
+    // top-left corner of `t`, so that the first `n` columns of `z` span the stable invariant
+    // subspace of the Hamiltonian matrix.
+    let mut nstable = 0;
+    let mut i = 0;
+
+    while i < 2 * n {
+        let size = block_size(&t, i);
+
+        if is_stable_block(&t, i, size) {
+            let mut pos = i;
+
+            while pos > nstable {
+                let prev = if pos > 1 && !t[(pos - 1, pos - 2)].is_zero() {
+                    2
+                } else {
+                    1
+                };
+
+                swap_blocks(&mut t, &mut z, pos - prev, prev, size)?;
+                pos -= prev;
+            }
+
+            nstable += size;
+        }
+
+        i += size;
+    }
+
+    // The Hamiltonian matrix has exactly `n` stable eigenvalues if the stabilizing solution
+    // exists.
+    if nstable != n {
+        return None;
+    }
+
+    // Solve `x * u1 = u2` where `[u1; u2]` is the basis of the stable invariant subspace. If
+    // `u1` is singular, the stable invariant subspace is not the graph of any matrix and there is
+    // no stabilizing solution. Because the basis is only computed up to rounding errors, `u1` is
+    // considered singular as soon as it is too ill-conditioned for `x` to make sense.
+    let u1 = z.slice((0, 0), (n, n));
+    let u2 = z.slice((n, 0), (n, n));
+    let lu = u1.transpose().lu();
+
+    // Since `z` is orthogonal, `‖u1‖₁ ≤ √n`, so `1 / ‖u1⁻¹‖₁ = rcond * ‖u1‖₁` is an absolute
+    // measure of the distance of `u1` to singularity.
+    let norm1 = u1
+        .column_iter()
+        .fold(N::zero(), |norm, col| norm.max(col.lp_norm(1)));
+
+    if lu.rcond() * norm1 <= N::default_epsilon().sqrt() {
+        return None;
+    }
+
+    let xt = lu.solve(&u2.transpose())?;
+
+    // The solution is symmetric up to rounding errors.
+    Some((xt.transpose() + xt) * crate::convert::<_, N>(0.5))
+}
+
+// The size of the diagonal block of the upper-quasitriangular matrix `t` starting at `(i, i)`.
+fn block_size<N: RealField>(t: &DMatrix<N>, i: usize) -> usize {
+    if i + 1 < t.nrows() && !t[(i + 1, i)].is_zero() {
+        2
+    } else {
+        1
+    }
+}
+
+// Tests if the eigenvalues of the diagonal block of `t` starting at `(i, i)` have negative real
+// parts. Both eigenvalues of a 2x2 block have the same real part, i.e., half its trace.
+fn is_stable_block<N: RealField>(t: &DMatrix<N>, i: usize, size: usize) -> bool {
+    let mut trace = N::zero();
+
+    for k in i..i + size {
+        trace += t[(k, k)];
+    }
+
+    trace < N::zero()
+}
+
+// Swaps the adjacent diagonal blocks of sizes `p` and `q` starting at `(i, i)` of the
+// upper-quasitriangular matrix `t` with an orthogonal similarity transformation, which is
+// accumulated into the columns of `z`.
+//
+// If `t11` and `t22` are the two blocks and `t12` the block above `t22`, then the columns of
+// `[-x; I]` where `t11 * x - x * t22 = t12` span the invariant subspace associated to `t22`.
+// Returns `None` if the blocks have a common eigenvalue.
+fn swap_blocks<N: RealField>(
+    t: &mut DMatrix<N>,
+    z: &mut DMatrix<N>,
+    i: usize,
+    p: usize,
+    q: usize,
+) -> Option<()> {
+    let dim = t.nrows();
+    let s = p + q;
+
+    let t11 = t.slice((i, i), (p, p)).into_owned();
+    let t22 = t.slice((i + p, i + p), (q, q)).into_owned();
+    let t12 = t.slice((i, i + p), (p, q)).into_owned();
+    let x = solve_sylvester(&t11, &-t22, &t12)?;
+
+    let mut basis = DMatrix::zeros(s, s);
+    basis.slice_mut((0, 0), (p, q)).copy_from(&-x);
+    basis.slice_mut((p, 0), (q, q)).fill_with_identity();
+    let rot = basis.qr().q();
+
+    let rows = rot.tr_mul(&t.slice((i, 0), (s, dim)));
+    t.slice_mut((i, 0), (s, dim)).copy_from(&rows);
+    let cols = t.slice((0, i), (dim, s)) * &rot;
+    t.slice_mut((0, i), (dim, s)).copy_from(&cols);
+    let cols = z.slice((0, i), (dim, s)) * &rot;
+    z.slice_mut((0, i), (dim, s)).copy_from(&cols);
+
+    // The block below the new diagonal blocks is zero up to rounding errors.
+    t.slice_mut((i + q, i), (p, q)).fill(N::zero());
+
+    Some(())
+}
@@ -12,9 +12,10 @@ use crate::base::storage::Storage;
 use crate::base::{DefaultAllocator, MatrixN, SquareMatrix, Unit, Vector2, Vector3, VectorN};
 
 use crate::geometry::Reflection;
+use crate::linalg::balancing;
 use crate::linalg::givens::GivensRotation;
 use crate::linalg::householder;
-use crate::linalg::Hessenberg;
+use crate::linalg::{Hessenberg, LinalgError};
 
 /// Schur decomposition of a square matrix.
 ///
@@ -68,9 +69,17 @@ where
     ///
     /// * `eps`       − tolerance used to determine when a value converged to 0.
     /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
-    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
-    /// continues indefinitely until convergence.
-    pub fn try_new(m: MatrixN<N, D>, eps: N::RealField, max_niter: usize) -> Option<Self> {
+    /// number of iteration is exceeded, `LinalgError::NotConverged` is returned. If `niter == 0`,
+    /// then the algorithm continues indefinitely until convergence.
+    pub fn try_new(
+        m: MatrixN<N, D>,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Result<Self, LinalgError> {
+        if !m.is_square() {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
         let mut work = unsafe { VectorN::new_uninitialized_generic(m.data.shape().0, U1) };
 
         Self::do_decompose(m, &mut work, eps, max_niter, true)
@@ -83,7 +92,7 @@ where
         eps: N::RealField,
         max_niter: usize,
         compute_q: bool,
-    ) -> Option<(Option<MatrixN<N, D>>, MatrixN<N, D>)> {
+    ) -> Result<(Option<MatrixN<N, D>>, MatrixN<N, D>), LinalgError> {
         assert!(
             m.is_square(),
             "Unable to compute the eigenvectors and eigenvalues of a non-square matrix."
@@ -95,16 +104,16 @@ where
         if dim.value() == 0 {
             let vecs = Some(MatrixN::from_element_generic(dim, dim, N::zero()));
             let vals = MatrixN::from_element_generic(dim, dim, N::zero());
-            return Some((vecs, vals));
+            return Ok((vecs, vals));
         } else if dim.value() == 1 {
             if compute_q {
                 let q = MatrixN::from_element_generic(dim, dim, N::one());
-                return Some((Some(q), m));
+                return Ok((Some(q), m));
             } else {
-                return Some((None, m));
+                return Ok((None, m));
             }
         } else if dim.value() == 2 {
-            return decompose_2x2(m, compute_q);
+            return Ok(decompose_2x2(m, compute_q));
         }
 
         let amax_m = m.camax();
@@ -251,13 +260,13 @@ where
 
             niter += 1;
             if niter == max_niter {
-                return None;
+                return Err(LinalgError::NotConverged { iterations: niter });
             }
         }
 
         t.scale_mut(amax_m);
 
-        Some((q, t))
+        Ok((q, t))
     }
 
     /// Computes the eigenvalues of the decomposed matrix.
@@ -374,6 +383,26 @@ where
         (self.q, self.t)
     }
 
+    /// The unitary matrix `Q` of this decomposition.
+    #[inline]
+    pub fn q(&self) -> &MatrixN<N, D> {
+        &self.q
+    }
+
+    /// The upper-quasitriangular matrix `T` of this decomposition.
+    ///
+    /// For real matrices, each complex-conjugate pair of eigenvalues corresponds to a 2x2 block on
+    /// the diagonal of `T`.
+    #[inline]
+    pub fn t(&self) -> &MatrixN<N, D> {
+        &self.t
+    }
+
+    /// Rebuild the original matrix, i.e., computes `Q * T * Q.adjoint()`.
+    pub fn recompose(&self) -> MatrixN<N, D> {
+        &self.q * &self.t * self.q.adjoint()
+    }
+
     /// Computes the real eigenvalues of the decomposed matrix.
     ///
     /// Return `None` if some eigenvalues are complex.
@@ -401,7 +430,7 @@ where
 fn decompose_2x2<N: ComplexField, D: Dim>(
     mut m: MatrixN<N, D>,
     compute_q: bool,
-) -> Option<(Option<MatrixN<N, D>>, MatrixN<N, D>)>
+) -> (Option<MatrixN<N, D>>, MatrixN<N, D>)
 where
     DefaultAllocator: Allocator<N, D, D>,
 {
@@ -433,7 +462,7 @@ where
         }
     };
 
-    Some((q, m))
+    (q, m)
 }
 
 fn compute_2x2_eigvals<N: ComplexField, S: Storage<N, U2, U2>>(
@@ -510,9 +539,13 @@ where
     ///
     /// * `eps`       − tolerance used to determine when a value converged to 0.
     /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
-    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
-    /// continues indefinitely until convergence.
-    pub fn try_schur(self, eps: N::RealField, max_niter: usize) -> Option<Schur<N, D>> {
+    /// number of iteration is exceeded, `LinalgError::NotConverged` is returned. If
+    /// `niter == 0`, then the algorithm continues indefinitely until convergence.
+    pub fn try_schur(
+        self,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Result<Schur<N, D>, LinalgError> {
         Schur::try_new(self.into_owned(), eps, max_niter)
     }
 
@@ -557,8 +590,10 @@ where
     }
 
     /// Computes the eigenvalues of this matrix.
+    ///
+    /// The matrix is balanced first, see `balancing::balance_parlett_reinsch`, so that the
+    /// eigenvalues of badly scaled matrices are computed accurately.
     pub fn complex_eigenvalues(&self) -> VectorN<NumComplex<N>, D>
-    // FIXME: add balancing?
     where
         N: RealField,
         DefaultAllocator: Allocator<NumComplex<N>, D>,
@@ -566,14 +601,10 @@ where
         let dim = self.data.shape().0;
         let mut work = unsafe { VectorN::new_uninitialized_generic(dim, U1) };
 
-        let schur = Schur::do_decompose(
-            self.clone_owned(),
-            &mut work,
-            N::default_epsilon(),
-            0,
-            false,
-        )
-        .unwrap();
+        let mut m = self.clone_owned();
+        let _ = balancing::balance_parlett_reinsch(&mut m);
+
+        let schur = Schur::do_decompose(m, &mut work, N::default_epsilon(), 0, false).unwrap();
         let mut eig = unsafe { VectorN::new_uninitialized_generic(dim, U1) };
         Schur::do_complex_eigenvalues(&schur.1, &mut eig);
         eig
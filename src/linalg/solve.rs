@@ -2,7 +2,7 @@ use simba::scalar::ComplexField;
 use simba::simd::SimdComplexField;
 
 use crate::base::allocator::Allocator;
-use crate::base::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::base::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
 use crate::base::dimension::{Dim, U1};
 use crate::base::storage::{Storage, StorageMut};
 use crate::base::{DVectorSlice, DefaultAllocator, Matrix, MatrixMN, SquareMatrix, Vector};
@@ -364,6 +364,143 @@ impl<N: ComplexField, D: Dim, S: Storage<N, D, D>> SquareMatrix<N, D, S> {
         true
     }
 
+    /*
+     *
+     * Versions writing the solution into a pre-allocated buffer
+     *
+     */
+    /// Solves the linear system `self . x = b` where `x` is the unknown and only the
+    /// lower-triangular part of `self` (including the diagonal) is considered not-zero.
+    ///
+    /// The solution is written into `out`, which must have the same shape as `b`. Each column
+    /// of `b` is a right-hand-side. Returns `false` if `self` is not invertible, in which case
+    /// the content of `out` is unspecified.
+    #[inline]
+    pub fn solve_lower_triangular_mut_to<R2: Dim, C2: Dim, S2, R3: Dim, C3: Dim, S3>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+        out: &mut Matrix<N, R3, C3, S3>,
+    ) -> bool
+    where
+        S2: Storage<N, R2, C2>,
+        S3: StorageMut<N, R3, C3>,
+        ShapeConstraint:
+            SameNumberOfRows<R3, R2> + SameNumberOfColumns<C3, C2> + SameNumberOfRows<R3, D>,
+    {
+        out.copy_from(b);
+        self.solve_lower_triangular_mut(out)
+    }
+
+    /// Solves the linear system `self . x = b` where `x` is the unknown and only the
+    /// upper-triangular part of `self` (including the diagonal) is considered not-zero.
+    ///
+    /// The solution is written into `out`, which must have the same shape as `b`. Each column
+    /// of `b` is a right-hand-side. Returns `false` if `self` is not invertible, in which case
+    /// the content of `out` is unspecified.
+    #[inline]
+    pub fn solve_upper_triangular_mut_to<R2: Dim, C2: Dim, S2, R3: Dim, C3: Dim, S3>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+        out: &mut Matrix<N, R3, C3, S3>,
+    ) -> bool
+    where
+        S2: Storage<N, R2, C2>,
+        S3: StorageMut<N, R3, C3>,
+        ShapeConstraint:
+            SameNumberOfRows<R3, R2> + SameNumberOfColumns<C3, C2> + SameNumberOfRows<R3, D>,
+    {
+        out.copy_from(b);
+        self.solve_upper_triangular_mut(out)
+    }
+
+    /// Solves the linear system `self.transpose() . x = b` where `x` is the unknown and only the
+    /// lower-triangular part of `self` (including the diagonal) is considered not-zero.
+    ///
+    /// The solution is written into `out`, which must have the same shape as `b`. Each column
+    /// of `b` is a right-hand-side. Returns `false` if `self` is not invertible, in which case
+    /// the content of `out` is unspecified.
+    #[inline]
+    pub fn tr_solve_lower_triangular_mut_to<R2: Dim, C2: Dim, S2, R3: Dim, C3: Dim, S3>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+        out: &mut Matrix<N, R3, C3, S3>,
+    ) -> bool
+    where
+        S2: Storage<N, R2, C2>,
+        S3: StorageMut<N, R3, C3>,
+        ShapeConstraint:
+            SameNumberOfRows<R3, R2> + SameNumberOfColumns<C3, C2> + SameNumberOfRows<R3, D>,
+    {
+        out.copy_from(b);
+        self.tr_solve_lower_triangular_mut(out)
+    }
+
+    /// Solves the linear system `self.transpose() . x = b` where `x` is the unknown and only the
+    /// upper-triangular part of `self` (including the diagonal) is considered not-zero.
+    ///
+    /// The solution is written into `out`, which must have the same shape as `b`. Each column
+    /// of `b` is a right-hand-side. Returns `false` if `self` is not invertible, in which case
+    /// the content of `out` is unspecified.
+    #[inline]
+    pub fn tr_solve_upper_triangular_mut_to<R2: Dim, C2: Dim, S2, R3: Dim, C3: Dim, S3>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+        out: &mut Matrix<N, R3, C3, S3>,
+    ) -> bool
+    where
+        S2: Storage<N, R2, C2>,
+        S3: StorageMut<N, R3, C3>,
+        ShapeConstraint:
+            SameNumberOfRows<R3, R2> + SameNumberOfColumns<C3, C2> + SameNumberOfRows<R3, D>,
+    {
+        out.copy_from(b);
+        self.tr_solve_upper_triangular_mut(out)
+    }
+
+    /// Solves the linear system `self.adjoint() . x = b` where `x` is the unknown and only the
+    /// lower-triangular part of `self` (including the diagonal) is considered not-zero.
+    ///
+    /// The solution is written into `out`, which must have the same shape as `b`. Each column
+    /// of `b` is a right-hand-side. Returns `false` if `self` is not invertible, in which case
+    /// the content of `out` is unspecified.
+    #[inline]
+    pub fn ad_solve_lower_triangular_mut_to<R2: Dim, C2: Dim, S2, R3: Dim, C3: Dim, S3>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+        out: &mut Matrix<N, R3, C3, S3>,
+    ) -> bool
+    where
+        S2: Storage<N, R2, C2>,
+        S3: StorageMut<N, R3, C3>,
+        ShapeConstraint:
+            SameNumberOfRows<R3, R2> + SameNumberOfColumns<C3, C2> + SameNumberOfRows<R3, D>,
+    {
+        out.copy_from(b);
+        self.ad_solve_lower_triangular_mut(out)
+    }
+
+    /// Solves the linear system `self.adjoint() . x = b` where `x` is the unknown and only the
+    /// upper-triangular part of `self` (including the diagonal) is considered not-zero.
+    ///
+    /// The solution is written into `out`, which must have the same shape as `b`. Each column
+    /// of `b` is a right-hand-side. Returns `false` if `self` is not invertible, in which case
+    /// the content of `out` is unspecified.
+    #[inline]
+    pub fn ad_solve_upper_triangular_mut_to<R2: Dim, C2: Dim, S2, R3: Dim, C3: Dim, S3>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+        out: &mut Matrix<N, R3, C3, S3>,
+    ) -> bool
+    where
+        S2: Storage<N, R2, C2>,
+        S3: StorageMut<N, R3, C3>,
+        ShapeConstraint:
+            SameNumberOfRows<R3, R2> + SameNumberOfColumns<C3, C2> + SameNumberOfRows<R3, D>,
+    {
+        out.copy_from(b);
+        self.ad_solve_upper_triangular_mut(out)
+    }
+
     #[inline(always)]
     fn xx_solve_lower_triangular_vector_mut<R2: Dim, S2>(
         &self,
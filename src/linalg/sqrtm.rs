@@ -0,0 +1,142 @@
+//! This module provides the matrix square root (sqrtm) function to square matrices.
+
+use approx::AbsDiffEq;
+use num::Zero;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, MatrixN};
+use crate::dimension::{Dim, DimDiff, DimSub, U1};
+use crate::storage::Storage;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::linalg::{Schur, SymmetricEigen};
+
+/// Maximum number of Denman–Beavers iterations before giving up.
+const MAX_NITER: usize = 100;
+
+impl<N: ComplexField, D: DimSub<U1>> MatrixN<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, D>
+        + Allocator<N, D, DimDiff<D, U1>>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N::RealField, D>
+        + Allocator<N::RealField, DimDiff<D, U1>>,
+{
+    /// Computes the principal square root of this matrix, i.e., the matrix `X` such that
+    /// `X * X = self` and whose eigenvalues have a positive real part.
+    ///
+    /// If this matrix is symmetric (hermitian), its square root is computed from its
+    /// eigendecomposition and `None` is returned if it is not positive semi-definite.
+    /// Otherwise, the Denman–Beavers iteration is applied to its Schur form, in which case
+    /// `None` is returned if this matrix is singular, has eigenvalues on the negative real
+    /// axis, or if the iteration failed to converge.
+    pub fn sqrtm(&self) -> Option<Self> {
+        assert!(
+            self.is_square(),
+            "Unable to compute the square root of a non-square matrix."
+        );
+
+        let dim = self.nrows();
+        let eps = N::RealField::default_epsilon();
+        let amax = self.camax();
+
+        if amax.is_zero() {
+            return Some(self.clone());
+        }
+
+        if is_hermitian(self, eps) {
+            let mut eig = SymmetricEigen::new(self.clone());
+            let vmax = eig.eigenvalues.camax();
+            let tol = eps * crate::convert(dim as f64) * vmax;
+
+            for val in eig.eigenvalues.iter_mut() {
+                if *val < -tol {
+                    return None;
+                }
+
+                *val = val.max(N::RealField::zero()).sqrt();
+            }
+
+            return Some(eig.recompose());
+        }
+
+        sqrtm_denman_beavers(self.clone(), eps)
+    }
+}
+
+/// Tests whether `m` is hermitian, up to `eps` times its largest element.
+pub(crate) fn is_hermitian<N: ComplexField, D: Dim>(m: &MatrixN<N, D>, eps: N::RealField) -> bool
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let dim = m.nrows();
+    let amax = m.camax();
+
+    (0..dim).all(|j| (j..dim).all(|i| (m[(i, j)] - m[(j, i)].conjugate()).modulus() <= eps * amax))
+}
+
+/// Computes the principal square root of `m` with the Denman–Beavers iteration on its Schur form.
+fn sqrtm_denman_beavers<N: ComplexField, D: DimSub<U1>>(
+    m: MatrixN<N, D>,
+    eps: N::RealField,
+) -> Option<MatrixN<N, D>>
+where
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, D>
+        + Allocator<N, D, DimDiff<D, U1>>
+        + Allocator<N, DimDiff<D, U1>>,
+{
+    let (q, t) = Schur::new(m).unpack();
+    let sqrt_t = denman_beavers(t, eps)?;
+
+    Some(&q * sqrt_t * q.adjoint())
+}
+
+/// Computes the principal square root of `m` with the Denman–Beavers iteration.
+///
+/// Returns `None` if the iteration failed to converge.
+pub(crate) fn denman_beavers<N: ComplexField, D: Dim>(
+    m: MatrixN<N, D>,
+    eps: N::RealField,
+) -> Option<MatrixN<N, D>>
+where
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    let (nrows, ncols) = m.data.shape();
+
+    // Normalize the input for faster convergence: sqrt(m) = sqrt(s) * sqrt(m / s).
+    let scale = m.norm();
+    if scale.is_zero() {
+        return Some(m);
+    }
+
+    let half: N::RealField = crate::convert(0.5);
+    let tol = eps * crate::convert(nrows.value() as f64);
+    let mut y = m.unscale(scale);
+    let mut z = MatrixN::identity_generic(nrows, ncols);
+    let mut prev_delta = None;
+
+    for _ in 0..MAX_NITER {
+        let y_inv = y.clone().try_inverse()?;
+        let z_inv = z.clone().try_inverse()?;
+
+        let y_next = (&y + z_inv).scale(half);
+        z = (z + y_inv).scale(half);
+
+        let delta = (&y_next - &y).norm();
+        y = y_next;
+
+        // The iteration converges quadratically, so stop as soon as it stagnates due to the
+        // rounding errors.
+        let stagnated = prev_delta.map_or(false, |prev| delta >= prev * half);
+
+        if delta <= tol * y.norm() || (stagnated && delta <= eps.sqrt() * y.norm()) {
+            return Some(y.scale(scale.sqrt()));
+        }
+
+        prev_delta = Some(delta);
+    }
+
+    None
+}
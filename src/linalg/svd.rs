@@ -3,17 +3,22 @@ use serde::{Deserialize, Serialize};
 
 use approx::AbsDiffEq;
 use num::{One, Zero};
+use std::ops::ControlFlow;
 
 use crate::allocator::Allocator;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::DMatrix;
 use crate::base::{DefaultAllocator, Matrix, Matrix2x3, MatrixMN, Vector2, VectorN};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::dimension::Dynamic;
 use crate::dimension::{Dim, DimDiff, DimMin, DimMinimum, DimSub, U1, U2};
 use crate::storage::Storage;
 use simba::scalar::{ComplexField, RealField};
 
 use crate::linalg::givens::GivensRotation;
 use crate::linalg::symmetric_eigen;
-use crate::linalg::Bidiagonal;
+use crate::linalg::{Bidiagonal, ConvergenceInfo, IterationInfo, LinalgError};
 
 /// Singular Value Decomposition of a general matrix.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -47,8 +52,14 @@ where
         + Allocator<N::RealField, DimMinimum<R, C>>,
 {
     /// The left-singular vectors `U` of this SVD.
+    ///
+    /// This is the economy-size factor: only the `min(nrows, ncols)` left-singular vectors
+    /// associated to the singular values are computed.
     pub u: Option<MatrixMN<N, R, DimMinimum<R, C>>>,
     /// The right-singular vectors `V^t` of this SVD.
+    ///
+    /// This is the economy-size factor: only the `min(nrows, ncols)` right-singular vectors
+    /// associated to the singular values are computed.
     pub v_t: Option<MatrixMN<N, DimMinimum<R, C>, C>>,
     /// The singular values of this SVD.
     pub singular_values: VectorN<N::RealField, DimMinimum<R, C>>,
@@ -98,19 +109,80 @@ where
     /// * `compute_v` − set this to `true` to enable the computation of right-singular vectors.
     /// * `eps`       − tolerance used to determine when a value converged to 0.
     /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
-    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
-    /// continues indefinitely until convergence.
+    /// number of iteration is exceeded, `LinalgError::NotConverged` is returned. If `niter == 0`,
+    /// then the algorithm continues indefinitely until convergence.
     pub fn try_new(
-        mut matrix: MatrixMN<N, R, C>,
+        matrix: MatrixMN<N, R, C>,
         compute_u: bool,
         compute_v: bool,
         eps: N::RealField,
         max_niter: usize,
-    ) -> Option<Self> {
+    ) -> Result<Self, LinalgError> {
         assert!(
             matrix.len() != 0,
             "Cannot compute the SVD of an empty matrix."
         );
+
+        let (svd, info) = Self::do_decompose(matrix, compute_u, compute_v, eps, max_niter, None);
+
+        if info.converged {
+            Ok(svd)
+        } else {
+            Err(LinalgError::NotConverged {
+                iterations: info.iterations,
+            })
+        }
+    }
+
+    /// Attempts to compute the Singular Value Decomposition of `matrix` using implicit shift,
+    /// calling `observer` after each iteration.
+    ///
+    /// The `residual` given to the observer is the norm of the off-diagonal part of the
+    /// bidiagonal matrix being diagonalized, relative to the largest absolute value of the
+    /// components of `matrix`. If the observer returns `ControlFlow::Break(())`, or if the maximum
+    /// number of iterations is reached, the algorithm stops and the partially converged
+    /// decomposition is returned together with a `ConvergenceInfo` telling it did not converge.
+    /// In that case, `u` and `v_t` are still orthonormal (unitary) but the singular values are
+    /// only approximations. Returns `LinalgError::DimensionMismatch` if `matrix` is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `compute_u` − set this to `true` to enable the computation of left-singular vectors.
+    /// * `compute_v` − set this to `true` to enable the computation of right-singular vectors.
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If
+    /// `niter == 0`, then the algorithm continues until convergence, or until it is aborted by the
+    /// observer.
+    pub fn try_new_with_observer(
+        matrix: MatrixMN<N, R, C>,
+        compute_u: bool,
+        compute_v: bool,
+        eps: N::RealField,
+        max_niter: usize,
+        mut observer: impl FnMut(IterationInfo<N::RealField>) -> ControlFlow<()>,
+    ) -> Result<(Self, ConvergenceInfo), LinalgError> {
+        if matrix.len() == 0 {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
+        Ok(Self::do_decompose(
+            matrix,
+            compute_u,
+            compute_v,
+            eps,
+            max_niter,
+            Some(&mut observer),
+        ))
+    }
+
+    fn do_decompose(
+        mut matrix: MatrixMN<N, R, C>,
+        compute_u: bool,
+        compute_v: bool,
+        eps: N::RealField,
+        max_niter: usize,
+        mut observer: Option<&mut dyn FnMut(IterationInfo<N::RealField>) -> ControlFlow<()>>,
+    ) -> (Self, ConvergenceInfo) {
         let (nrows, ncols) = matrix.data.shape();
         let min_nrows_ncols = nrows.min(ncols);
         let dim = min_nrows_ncols.value();
@@ -128,6 +200,7 @@ where
         let mut off_diagonal = b.off_diagonal();
 
         let mut niter = 0;
+        let mut converged = true;
         let (mut start, mut end) = Self::delimit_subproblem(
             &mut diagonal,
             &mut off_diagonal,
@@ -283,8 +356,21 @@ where
             end = sub.1;
 
             niter += 1;
-            if niter == max_niter {
-                return None;
+
+            let aborted = match observer.as_mut() {
+                Some(observer) => matches!(
+                    observer(IterationInfo {
+                        iteration: niter,
+                        residual: off_diagonal.norm(),
+                    }),
+                    ControlFlow::Break(())
+                ),
+                None => false,
+            };
+
+            if (niter == max_niter || aborted) && end != start {
+                converged = false;
+                break;
             }
         }
 
@@ -303,11 +389,17 @@ where
             }
         }
 
-        Some(Self {
+        let svd = Self {
             u,
             v_t,
             singular_values: diagonal,
-        })
+        };
+        let info = ConvergenceInfo {
+            converged,
+            iterations: niter,
+        };
+
+        (svd, info)
     }
 
     /*
@@ -629,15 +721,15 @@ where
     /// * `compute_v` − set this to `true` to enable the computation of right-singular vectors.
     /// * `eps`       − tolerance used to determine when a value converged to 0.
     /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
-    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
-    /// continues indefinitely until convergence.
+    /// number of iteration is exceeded, `LinalgError::NotConverged` is returned. If `niter == 0`,
+    /// then the algorithm continues indefinitely until convergence.
     pub fn try_svd(
         self,
         compute_u: bool,
         compute_v: bool,
         eps: N::RealField,
         max_niter: usize,
-    ) -> Option<SVD<N, R, C>> {
+    ) -> Result<SVD<N, R, C>, LinalgError> {
         SVD::try_new(self.into_owned(), compute_u, compute_v, eps, max_niter)
     }
 
@@ -657,11 +749,111 @@ where
     /// Computes the pseudo-inverse of this matrix.
     ///
     /// All singular values below `eps` are considered equal to 0.
+    /// Returns `Err` if `eps` is negative. The pseudo-inverse of an empty matrix is an empty
+    /// matrix.
     pub fn pseudo_inverse(self, eps: N::RealField) -> Result<MatrixMN<N, C, R>, &'static str>
     where
         DefaultAllocator: Allocator<N, C, R>,
     {
-        SVD::new(self.clone_owned(), true, true).pseudo_inverse(eps)
+        if self.is_empty() {
+            if eps < N::RealField::zero() {
+                return Err("SVD pseudo inverse: the epsilon must be non-negative.");
+            }
+
+            let (nrows, ncols) = self.data.shape();
+            return Ok(MatrixMN::zeros_generic(ncols, nrows));
+        }
+
+        SVD::new(self.into_owned(), true, true).pseudo_inverse(eps)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<N: ComplexField, R: Dim, C: Dim, S: Storage<N, R, C>> Matrix<N, R, C, S> {
+    /// Computes an orthonormal basis of the kernel (the null space) of this matrix, i.e., a
+    /// matrix `K` with orthonormal columns such that `self * K` is zero.
+    ///
+    /// The basis is made of the right-singular vectors of this matrix associated to the singular
+    /// values smaller than or equal to `eps`. The number of columns of `K` is thus
+    /// `self.ncols() - self.rank(eps)`.
+    pub fn kernel(&self, eps: N::RealField) -> MatrixMN<N, C, Dynamic>
+    where
+        DefaultAllocator: Allocator<N, C, Dynamic>,
+    {
+        assert!(
+            eps >= N::RealField::zero(),
+            "Matrix kernel: the epsilon must be non-negative."
+        );
+
+        let (nrows, ncols) = self.data.shape();
+
+        if ncols.value() == 0 {
+            return MatrixMN::zeros_generic(ncols, Dynamic::new(0));
+        }
+
+        // The SVD of a wide matrix does not compute all its right-singular vectors. Padding it
+        // with zero rows does not change its kernel, and makes it square.
+        let m = DMatrix::from_fn(nrows.value().max(ncols.value()), ncols.value(), |i, j| {
+            if i < nrows.value() {
+                self[(i, j)]
+            } else {
+                N::zero()
+            }
+        });
+
+        let svd = SVD::<N, Dynamic, Dynamic>::new(m, false, true);
+        let singular_values = svd.singular_values;
+        let v_t = svd.v_t.unwrap();
+        let null = |i: &usize| singular_values[*i] <= eps;
+        let dim = (0..ncols.value()).filter(null).count();
+
+        let mut res = MatrixMN::zeros_generic(ncols, Dynamic::new(dim));
+        for (j, i) in (0..ncols.value()).filter(null).enumerate() {
+            for k in 0..ncols.value() {
+                res[(k, j)] = v_t[(i, k)].conjugate();
+            }
+        }
+
+        res
+    }
+
+    /// Computes an orthonormal basis of the image (the column space) of this matrix, i.e., a
+    /// matrix with `self.rank(eps)` orthonormal columns spanning the same space as the columns
+    /// of this matrix.
+    ///
+    /// The basis is made of the left-singular vectors of this matrix associated to the singular
+    /// values greater than `eps`.
+    pub fn image(&self, eps: N::RealField) -> MatrixMN<N, R, Dynamic>
+    where
+        DefaultAllocator: Allocator<N, R, Dynamic>,
+    {
+        assert!(
+            eps >= N::RealField::zero(),
+            "Matrix image: the epsilon must be non-negative."
+        );
+
+        let (nrows, ncols) = self.data.shape();
+
+        if self.is_empty() {
+            return MatrixMN::zeros_generic(nrows, Dynamic::new(0));
+        }
+
+        let m = DMatrix::from_fn(nrows.value(), ncols.value(), |i, j| self[(i, j)]);
+
+        let svd = SVD::<N, Dynamic, Dynamic>::new(m, true, false);
+        let singular_values = svd.singular_values;
+        let u = svd.u.unwrap();
+        let nonzero = |i: &usize| singular_values[*i] > eps;
+        let rank = (0..singular_values.len()).filter(nonzero).count();
+
+        let mut res = MatrixMN::zeros_generic(nrows, Dynamic::new(rank));
+        for (j, i) in (0..singular_values.len()).filter(nonzero).enumerate() {
+            for k in 0..nrows.value() {
+                res[(k, j)] = u[(k, i)];
+            }
+        }
+
+        res
     }
 }
 
@@ -684,10 +876,7 @@ fn compute_2x2_uptrig_svd<N: RealField>(
 
     let denom = (m11 + m22).hypot(m12) + (m11 - m22).hypot(m12);
 
-    // NOTE: v1 is the singular value that is the closest to m22.
-    // This prevents cancellation issues when constructing the vector `csv` below. If we chose
-    // otherwise, we would have v1 ~= m11 when m12 is small. This would cause catastrophic
-    // cancellation on `v1 * v1 - m11 * m11` below.
+    // NOTE: v1 is the smallest singular value, computed without cancellation.
     let mut v1 = m11 * m22 * two / denom;
     let mut v2 = half * denom;
 
@@ -695,7 +884,23 @@ fn compute_2x2_uptrig_svd<N: RealField>(
     let mut v_t = None;
 
     if compute_u || compute_v {
-        let (csv, sgn_v) = GivensRotation::new(m11 * m12, v1 * v1 - m11 * m11);
+        // The right singular vector associated to v1 is orthogonal to both rows of
+        // `M^t * M - v1^2 * I`. Use the row with the largest norm to build it, because the other
+        // one may suffer from catastrophic cancellation. For example, when m12 is small, we
+        // have `v1 ~= m11` if `|m11| <= |m22|`, and `v1 ~= m22` otherwise.
+        let v1_sq = v1 * v1;
+        let m11_m12 = m11 * m12;
+        let row1 = (m11_m12, v1_sq - m11 * m11);
+        let row2 = (v1_sq - m12 * m12 - m22 * m22, m11_m12);
+
+        let (cv, sv) = if row1.0 * row1.0 + row1.1 * row1.1 >= row2.0 * row2.0 + row2.1 * row2.1 {
+            row1
+        } else {
+            row2
+        };
+
+        let (csv, norm_v) = GivensRotation::new(cv, sv);
+        let sgn_v = norm_v.signum();
         v1 *= sgn_v;
         v2 *= sgn_v;
 
@@ -704,9 +909,12 @@ fn compute_2x2_uptrig_svd<N: RealField>(
         }
 
         if compute_u {
-            let cu = (m11.scale(csv.c()) + m12 * csv.s()) / v1;
-            let su = (m22 * csv.s()) / v1;
-            let (csu, sgn_u) = GivensRotation::new(cu, su);
+            // The left singular vector associated to v2 is `M * [-sv, cv]^t / v2`. Dividing by
+            // v2 instead of v1 avoids amplifying rounding errors when v1 is tiny.
+            let cu = (m22 * csv.c()) / v2;
+            let su = (m11 * csv.s() - m12 * csv.c()) / v2;
+            let (csu, norm_u) = GivensRotation::new(cu, su);
+            let sgn_u = norm_u.signum();
 
             v1 *= sgn_u;
             v2 *= sgn_u;
@@ -0,0 +1,100 @@
+//! Truncated singular value decomposition of large matrices using random projections.
+
+use rand::Rng;
+use rand_distr::StandardNormal;
+
+use crate::base::dimension::Dynamic;
+use crate::base::storage::Storage;
+use crate::base::{DMatrix, DVector, Matrix};
+use simba::scalar::ComplexField;
+
+use crate::linalg::{QR, SVD};
+
+impl<N: ComplexField, S: Storage<N, Dynamic, Dynamic>> Matrix<N, Dynamic, Dynamic, S> {
+    /// Computes an approximation of the `rank` largest singular values of this matrix, and
+    /// of their singular vectors, using random projections.
+    ///
+    /// This is much faster than a full SVD when `rank` is small compared to the dimensions of
+    /// this matrix. The range of this matrix is first approximated by multiplying it with a
+    /// random gaussian matrix with `rank + oversampling` columns. Then the SVD of this matrix
+    /// projected onto this approximate range is computed.
+    ///
+    /// The singular values of the result are sorted by decreasing order.
+    ///
+    /// # Arguments
+    ///
+    /// * `rank`          − the number of singular values to compute.
+    /// * `oversampling`  − the number of additional random samples of the range of this matrix.
+    /// A small value like `10` is usually enough to get accurate results.
+    /// * `n_power_iters` − the number of power iterations performed to improve the accuracy of
+    /// the result when the singular values of this matrix decrease slowly.
+    pub fn svd_randomized(
+        &self,
+        rank: usize,
+        oversampling: usize,
+        n_power_iters: usize,
+    ) -> SVD<N, Dynamic, Dynamic> {
+        self.svd_randomized_with_rng(rank, oversampling, n_power_iters, &mut rand::thread_rng())
+    }
+
+    /// Computes an approximation of the `rank` largest singular values of this matrix, and
+    /// of their singular vectors, using random projections generated by `rng`.
+    ///
+    /// See `.svd_randomized(...)` for details.
+    pub fn svd_randomized_with_rng<G: Rng + ?Sized>(
+        &self,
+        rank: usize,
+        oversampling: usize,
+        n_power_iters: usize,
+        rng: &mut G,
+    ) -> SVD<N, Dynamic, Dynamic> {
+        let (nrows, ncols) = self.shape();
+        assert!(
+            nrows != 0 && ncols != 0,
+            "Cannot compute the SVD of an empty matrix."
+        );
+
+        let min_nrows_ncols = nrows.min(ncols);
+        let rank = rank.min(min_nrows_ncols);
+        let nsamples = (rank + oversampling).min(min_nrows_ncols);
+
+        if rank == 0 {
+            return SVD {
+                u: Some(DMatrix::zeros(nrows, 0)),
+                v_t: Some(DMatrix::zeros(0, ncols)),
+                singular_values: DVector::zeros(0),
+            };
+        }
+
+        // Find an orthonormal basis `q` approximating the range of `self`.
+        let omega = DMatrix::from_fn(ncols, nsamples, |_, _| {
+            N::from_real(crate::convert(rng.sample::<f64, _>(StandardNormal)))
+        });
+        let mut q = QR::new(self * omega).q();
+
+        for _ in 0..n_power_iters {
+            let z = QR::new(self.ad_mul(&q)).q();
+            q = QR::new(self * z).q();
+        }
+
+        // Compute the SVD of `self` projected onto this basis.
+        let svd = SVD::new(q.ad_mul(self), true, true);
+        let b_u = svd.u.unwrap();
+        let b_v_t = svd.v_t.unwrap();
+        let b_svals = svd.singular_values;
+
+        let mut ids: Vec<usize> = (0..b_svals.len()).collect();
+        ids.sort_by(|&i, &j| b_svals[j].partial_cmp(&b_svals[i]).unwrap());
+        ids.truncate(rank);
+
+        let b_u = DMatrix::from_fn(b_u.nrows(), rank, |i, j| b_u[(i, ids[j])]);
+        let v_t = DMatrix::from_fn(rank, ncols, |i, j| b_v_t[(ids[i], j)]);
+        let singular_values = DVector::from_iterator(rank, ids.iter().map(|&i| b_svals[i]));
+
+        SVD {
+            u: Some(q * b_u),
+            v_t: Some(v_t),
+            singular_values,
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Solution of the Sylvester matrix equation.
+
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, Matrix4, MatrixMN, SquareMatrix, Vector4};
+use crate::dimension::{DimDiff, DimSub, U1};
+use crate::storage::Storage;
+
+use crate::linalg::Schur;
+
+/// Computes the solution `x` of the Sylvester equation `a * x + x * b = c`.
+///
+/// This uses the Bartels-Stewart algorithm: the Schur decompositions `a = u * s * u*` and
+/// `b = v * t * v*` reduce the equation to `s * y + y * t = u* * c * v`, which is solved by
+/// substitution over the 1x1 and 2x2 diagonal blocks of the quasi-triangular factors `s` and `t`.
+/// This requires `O(n³ + m³)` operations where `a` is `n × n` and `b` is `m × m`.
+///
+/// The solution exists and is unique if and only if `a` and `-b` have no common eigenvalue.
+/// Returns `None` if such a common eigenvalue is detected. In particular, the continuous Lyapunov
+/// equation `a * x + x * a* = c` is solved with `solve_sylvester(a, a.adjoint(), c)`.
+pub fn solve_sylvester<N, R, C, S1, S2, S3>(
+    a: &SquareMatrix<N, R, S1>,
+    b: &SquareMatrix<N, C, S2>,
+    c: &Matrix<N, R, C, S3>,
+) -> Option<MatrixMN<N, R, C>>
+where
+    N: ComplexField,
+    R: DimSub<U1>,
+    C: DimSub<U1>,
+    S1: Storage<N, R, R>,
+    S2: Storage<N, C, C>,
+    S3: Storage<N, R, C>,
+    DefaultAllocator: Allocator<N, R, R>
+        + Allocator<N, R, DimDiff<R, U1>>
+        + Allocator<N, DimDiff<R, U1>>
+        + Allocator<N, R>
+        + Allocator<N, C, C>
+        + Allocator<N, C, DimDiff<C, U1>>
+        + Allocator<N, DimDiff<C, U1>>
+        + Allocator<N, C>
+        + Allocator<N, R, C>,
+{
+    assert!(
+        a.is_square() && b.is_square(),
+        "Sylvester equation: the matrices `a` and `b` must be square."
+    );
+    assert_eq!(
+        c.shape(),
+        (a.nrows(), b.nrows()),
+        "Sylvester equation: dimension mismatch."
+    );
+
+    let nrows = a.nrows();
+    let ncols = b.nrows();
+
+    if nrows == 0 || ncols == 0 {
+        return Some(c.clone_owned());
+    }
+
+    let (u, s) = Schur::new(a.clone_owned()).unpack();
+    let (v, t) = Schur::new(b.clone_owned()).unpack();
+
+    // The right-hand-side `u* * c * v` is overwritten by the solution `y` of the reduced
+    // equation. Because `t` is upper-quasitriangular, the column blocks of `y` are computed from
+    // the first to the last. Because `s` is upper-quasitriangular, the row blocks of each column
+    // block are computed from the last to the first.
+    let mut y = u.ad_mul(c) * &v;
+    let mut j = 0;
+
+    while j < ncols {
+        let q = if j + 1 < ncols && !t[(j + 1, j)].is_zero() {
+            2
+        } else {
+            1
+        };
+        let mut end = nrows;
+
+        while end > 0 {
+            let p = if end > 1 && !s[(end - 1, end - 2)].is_zero() {
+                2
+            } else {
+                1
+            };
+            let i = end - p;
+
+            // The small system `s_ii * y_ij + y_ij * t_jj = rhs` is written as a linear system on
+            // the column-major vectorization of `y_ij`, padded with the identity to be 4x4.
+            let mut m = Matrix4::identity();
+            let mut rhs = Vector4::zeros();
+
+            for cj in 0..q {
+                for ri in 0..p {
+                    let mut val = y[(i + ri, j + cj)];
+
+                    for l in end..nrows {
+                        val -= s[(i + ri, l)] * y[(l, j + cj)];
+                    }
+
+                    for k in 0..j {
+                        val -= y[(i + ri, k)] * t[(k, j + cj)];
+                    }
+
+                    let row = ri + p * cj;
+                    rhs[row] = val;
+                    m[(row, row)] = N::zero();
+
+                    for li in 0..p {
+                        m[(row, li + p * cj)] += s[(i + ri, i + li)];
+                    }
+
+                    for kj in 0..q {
+                        m[(row, ri + p * kj)] += t[(j + kj, j + cj)];
+                    }
+                }
+            }
+
+            let sol = m.full_piv_lu().solve(&rhs)?;
+
+            for cj in 0..q {
+                for ri in 0..p {
+                    y[(i + ri, j + cj)] = sol[ri + p * cj];
+                }
+            }
+
+            end = i;
+        }
+
+        j += q;
+    }
+
+    Some(u * y * v.adjoint())
+}
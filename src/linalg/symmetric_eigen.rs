@@ -3,15 +3,16 @@ use serde::{Deserialize, Serialize};
 
 use approx::AbsDiffEq;
 use num::Zero;
+use std::ops::ControlFlow;
 
 use crate::allocator::Allocator;
 use crate::base::{DefaultAllocator, Matrix2, MatrixN, SquareMatrix, Vector2, VectorN};
-use crate::dimension::{Dim, DimDiff, DimSub, U1, U2};
+use crate::dimension::{Dim, DimDiff, DimName, DimSub, Dynamic, U1, U2};
 use crate::storage::Storage;
-use simba::scalar::ComplexField;
+use simba::scalar::{ComplexField, RealField};
 
 use crate::linalg::givens::GivensRotation;
-use crate::linalg::SymmetricTridiagonal;
+use crate::linalg::{ConvergenceInfo, IterationInfo, LinalgError, SymmetricTridiagonal};
 
 /// Eigendecomposition of a symmetric matrix.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -73,33 +74,318 @@ where
     ///
     /// * `eps`       − tolerance used to determine when a value converged to 0.
     /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
-    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
-    /// continues indefinitely until convergence.
-    pub fn try_new(m: MatrixN<N, D>, eps: N::RealField, max_niter: usize) -> Option<Self>
+    /// number of iteration is exceeded, `LinalgError::NotConverged` is returned. If `niter == 0`,
+    /// then the algorithm continues indefinitely until convergence.
+    pub fn try_new(
+        m: MatrixN<N, D>,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Result<Self, LinalgError>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<N, DimDiff<D, U1>> + Allocator<N::RealField, DimDiff<D, U1>>,
+    {
+        let (eig, info) = Self::try_new_partial(m, eps, max_niter)?;
+
+        if info.converged {
+            Ok(eig)
+        } else {
+            Err(LinalgError::NotConverged {
+                iterations: info.iterations,
+            })
+        }
+    }
+
+    /// Computes the eigendecomposition of the given symmetric matrix with user-specified
+    /// convergence parameters, and returns the partially converged decomposition instead of
+    /// an error if the maximum number of iterations is reached.
+    ///
+    /// The returned `ConvergenceInfo` tells whether the algorithm converged and how many
+    /// iterations were performed. If it did not converge, the eigenvectors are still orthonormal
+    /// (unitary), but some of them are only approximations spanning invariant subspaces of `m`,
+    /// and the corresponding eigenvalues are the diagonal elements of the partially reduced
+    /// matrix. The caller can then decide whether the result is accurate enough, e.g., by
+    /// checking the residuals `m * v - λ * v`.
+    ///
+    /// Only the lower-triangular part (including its diagonal) of `m` is read.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If
+    /// `niter == 0`, then the algorithm continues indefinitely until convergence.
+    pub fn try_new_partial(
+        m: MatrixN<N, D>,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Result<(Self, ConvergenceInfo), LinalgError>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<N, DimDiff<D, U1>> + Allocator<N::RealField, DimDiff<D, U1>>,
+    {
+        let (vals, vecs, info) = Self::do_decompose(m, true, eps, max_niter, None)?;
+        let eig = SymmetricEigen {
+            eigenvectors: vecs.unwrap(),
+            eigenvalues: vals,
+        };
+
+        Ok((eig, info))
+    }
+
+    /// Computes the eigendecomposition of the given symmetric matrix with user-specified
+    /// convergence parameters, calling `observer` after each iteration.
+    ///
+    /// The `residual` given to the observer is the norm of the off-diagonal part of the
+    /// tridiagonal matrix being diagonalized, relative to the largest absolute value of the
+    /// components of `m`. If the observer returns `ControlFlow::Break(())`, the algorithm stops
+    /// and the partially converged decomposition is returned, as with
+    /// `SymmetricEigen::try_new_partial`.
+    ///
+    /// Only the lower-triangular part (including its diagonal) of `m` is read.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If
+    /// `niter == 0`, then the algorithm continues until convergence, or until it is aborted by the
+    /// observer.
+    pub fn try_new_with_observer(
+        m: MatrixN<N, D>,
+        eps: N::RealField,
+        max_niter: usize,
+        mut observer: impl FnMut(IterationInfo<N::RealField>) -> ControlFlow<()>,
+    ) -> Result<(Self, ConvergenceInfo), LinalgError>
     where
         D: DimSub<U1>,
         DefaultAllocator: Allocator<N, DimDiff<D, U1>> + Allocator<N::RealField, DimDiff<D, U1>>,
     {
-        Self::do_decompose(m, true, eps, max_niter).map(|(vals, vecs)| SymmetricEigen {
+        let (vals, vecs, info) = Self::do_decompose(m, true, eps, max_niter, Some(&mut observer))?;
+        let eig = SymmetricEigen {
             eigenvectors: vecs.unwrap(),
             eigenvalues: vals,
+        };
+
+        Ok((eig, info))
+    }
+
+    /// Computes the eigendecomposition of a symmetric matrix from its tridiagonalization.
+    ///
+    /// This reuses a reduction `SymmetricTridiagonal::new(m)` that was already computed, e.g., to
+    /// locate the eigenvalues of `m` from the diagonal and off-diagonal elements of the
+    /// tridiagonal matrix, before computing the eigendecomposition of `m`.
+    ///
+    /// The results are the same as with `SymmetricEigen::new(m)` up to rounding errors only:
+    /// `SymmetricEigen::new(m)` scales `m` by its largest element before the reduction, whereas
+    /// this scales the tridiagonal matrix by its own largest element before the QR iterations.
+    pub fn from_tridiagonal(tri: SymmetricTridiagonal<N, D>) -> Self
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<N, DimDiff<D, U1>> + Allocator<N::RealField, DimDiff<D, U1>>,
+    {
+        Self::try_from_tridiagonal(tri, N::RealField::default_epsilon(), 0).unwrap()
+    }
+
+    /// Computes the eigendecomposition of a symmetric matrix from its tridiagonalization with
+    /// user-specified convergence parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `LinalgError::NotConverged` is returned. If `niter == 0`,
+    /// then the algorithm continues indefinitely until convergence.
+    pub fn try_from_tridiagonal(
+        tri: SymmetricTridiagonal<N, D>,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Result<Self, LinalgError>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<N, DimDiff<D, U1>> + Allocator<N::RealField, DimDiff<D, U1>>,
+    {
+        let (mut q, mut diag, mut off_diag) = tri.unpack();
+        let amax = diag.amax().max(off_diag.amax());
+
+        if !amax.is_zero() {
+            diag.unscale_mut(amax);
+            off_diag.unscale_mut(amax);
+        }
+
+        let info = Self::do_iterate(&mut diag, &mut off_diag, Some(&mut q), eps, max_niter, None);
+
+        if !info.converged {
+            return Err(LinalgError::NotConverged {
+                iterations: info.iterations,
+            });
+        }
+
+        diag.scale_mut(amax);
+
+        Ok(SymmetricEigen {
+            eigenvectors: q,
+            eigenvalues: diag,
         })
     }
 
+    /// Computes only the eigenvalues of the given symmetric matrix.
+    ///
+    /// This is faster than `SymmetricEigen::new(m).eigenvalues` because the rotations applied
+    /// to the matrix are not accumulated to compute its eigenvectors.
+    ///
+    /// Only the lower-triangular part (including its diagonal) of `m` is read.
+    pub fn eigenvalues(m: MatrixN<N, D>) -> VectorN<N::RealField, D>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<N, DimDiff<D, U1>> + Allocator<N::RealField, DimDiff<D, U1>>,
+    {
+        Self::try_eigenvalues(m, N::RealField::default_epsilon(), 0).unwrap()
+    }
+
+    /// Computes only the eigenvalues of the given symmetric matrix with user-specified
+    /// convergence parameters.
+    ///
+    /// Only the lower-triangular part (including its diagonal) of `m` is read.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `LinalgError::NotConverged` is returned. If `niter == 0`,
+    /// then the algorithm continues indefinitely until convergence.
+    pub fn try_eigenvalues(
+        m: MatrixN<N, D>,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Result<VectorN<N::RealField, D>, LinalgError>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<N, DimDiff<D, U1>> + Allocator<N::RealField, DimDiff<D, U1>>,
+    {
+        let (vals, _, info) = Self::do_decompose(m, false, eps, max_niter, None)?;
+
+        if info.converged {
+            Ok(vals)
+        } else {
+            Err(LinalgError::NotConverged {
+                iterations: info.iterations,
+            })
+        }
+    }
+
+    /// Computes the eigendecomposition of the given symmetric matrix using the buffers of
+    /// `workspace`, and returns a reference to the decomposition stored in `workspace`.
+    ///
+    /// Once the workspace is created, this does not perform any allocation as long as `m` has the
+    /// same dimension as the workspace, which makes it suitable for decomposing many matrices of
+    /// the same size in a loop. Otherwise, the workspace is resized first. The results are the
+    /// same as `SymmetricEigen::try_new`.
+    ///
+    /// Only the lower-triangular part (including its diagonal) of `m` is read.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `LinalgError::NotConverged` is returned. If `niter == 0`,
+    /// then the algorithm continues indefinitely until convergence.
+    pub fn try_new_with_workspace<'a, S: Storage<N, D, D>>(
+        m: &SquareMatrix<N, D, S>,
+        workspace: &'a mut SymmetricEigenWorkspace<N, D>,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Result<&'a Self, LinalgError>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<N, DimDiff<D, U1>> + Allocator<N::RealField, DimDiff<D, U1>>,
+    {
+        if !m.is_square() {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
+        let dim = m.data.shape().0;
+
+        if workspace.tri.nrows() != dim.value() {
+            *workspace = SymmetricEigenWorkspace::new_generic(dim);
+        }
+
+        if dim.value() == 0 {
+            return Ok(&workspace.eig);
+        }
+
+        let SymmetricEigenWorkspace {
+            tri,
+            off_diagonal,
+            work,
+            off_diag,
+            eig,
+        } = workspace;
+
+        tri.copy_from(m);
+        let m_amax = tri.camax();
+
+        if !m_amax.is_zero() {
+            tri.unscale_mut(m_amax);
+        }
+
+        let view = SymmetricTridiagonal::new_in_place(
+            tri,
+            off_diagonal.as_mut_slice(),
+            work.as_mut_slice(),
+        );
+
+        for (i, e) in view.diagonal().enumerate() {
+            eig.eigenvalues[i] = e;
+        }
+
+        for (i, e) in view.off_diagonal().enumerate() {
+            off_diag[i] = e;
+        }
+
+        eig.eigenvectors.fill_with_identity();
+        view.q_mul(&mut eig.eigenvectors);
+
+        let info = Self::do_iterate(
+            &mut eig.eigenvalues,
+            off_diag,
+            Some(&mut eig.eigenvectors),
+            eps,
+            max_niter,
+            None,
+        );
+
+        if !info.converged {
+            return Err(LinalgError::NotConverged {
+                iterations: info.iterations,
+            });
+        }
+
+        eig.eigenvalues.scale_mut(m_amax);
+
+        Ok(eig)
+    }
+
     fn do_decompose(
         mut m: MatrixN<N, D>,
         eigenvectors: bool,
         eps: N::RealField,
         max_niter: usize,
-    ) -> Option<(VectorN<N::RealField, D>, Option<MatrixN<N, D>>)>
+        observer: Option<&mut dyn FnMut(IterationInfo<N::RealField>) -> ControlFlow<()>>,
+    ) -> Result<
+        (
+            VectorN<N::RealField, D>,
+            Option<MatrixN<N, D>>,
+            ConvergenceInfo,
+        ),
+        LinalgError,
+    >
     where
         D: DimSub<U1>,
         DefaultAllocator: Allocator<N, DimDiff<D, U1>> + Allocator<N::RealField, DimDiff<D, U1>>,
     {
-        assert!(
-            m.is_square(),
-            "Unable to compute the eigendecomposition of a non-square matrix."
-        );
+        if !m.is_square() {
+            return Err(LinalgError::DimensionMismatch);
+        }
+
         let dim = m.nrows();
         let m_amax = m.camax();
 
@@ -121,13 +407,54 @@ where
             off_diag = res.1;
         }
 
-        if dim == 1 {
-            diag.scale_mut(m_amax);
-            return Some((diag, q));
+        let info = if dim == 1 {
+            ConvergenceInfo {
+                converged: true,
+                iterations: 0,
+            }
+        } else {
+            Self::do_iterate(
+                &mut diag,
+                &mut off_diag,
+                q.as_mut(),
+                eps,
+                max_niter,
+                observer,
+            )
+        };
+
+        diag.scale_mut(m_amax);
+
+        Ok((diag, q, info))
+    }
+
+    // Diagonalizes the real symmetric tridiagonal matrix with diagonal `diag` and off-diagonal
+    // `off_diag` with implicit QR iterations, and accumulates the rotations into `q` if provided.
+    // Stops early, leaving the partially diagonalized matrix, if `max_niter` is reached or if the
+    // observer aborts the iteration.
+    fn do_iterate(
+        diag: &mut VectorN<N::RealField, D>,
+        off_diag: &mut VectorN<N::RealField, DimDiff<D, U1>>,
+        mut q: Option<&mut MatrixN<N, D>>,
+        eps: N::RealField,
+        max_niter: usize,
+        mut observer: Option<&mut dyn FnMut(IterationInfo<N::RealField>) -> ControlFlow<()>>,
+    ) -> ConvergenceInfo
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<N::RealField, DimDiff<D, U1>>,
+    {
+        let dim = diag.len();
+
+        if dim <= 1 {
+            return ConvergenceInfo {
+                converged: true,
+                iterations: 0,
+            };
         }
 
         let mut niter = 0;
-        let (mut start, mut end) = Self::delimit_subproblem(&diag, &mut off_diag, dim - 1, eps);
+        let (mut start, mut end) = Self::delimit_subproblem(diag, off_diag, dim - 1, eps);
 
         while end != start {
             let subdim = end - start + 1;
@@ -170,7 +497,7 @@ where
                             off_diag[i + 1] *= rot.c();
                         }
 
-                        if let Some(ref mut q) = q {
+                        if let Some(q) = q.as_mut() {
                             let rot = GivensRotation::new_unchecked(rot.c(), N::from_real(rot.s()));
                             rot.inverse().rotate_rows(&mut q.fixed_columns_mut::<U2>(i));
                         }
@@ -190,12 +517,21 @@ where
                     diag[start + 1],
                 );
                 let eigvals = m.eigenvalues().unwrap();
-                let basis = Vector2::new(eigvals.x - diag[start + 1], off_diag[start]);
+
+                // NOTE: both vectors are colinear to the eigenvector associated to `eigvals.x`.
+                // Choose the one that does not suffer from catastrophic cancellation.
+                let basis = if (eigvals.x - diag[start + 1]).abs() > (eigvals.x - diag[start]).abs()
+                {
+                    Vector2::new(eigvals.x - diag[start + 1], off_diag[start])
+                } else {
+                    Vector2::new(off_diag[start], eigvals.x - diag[start])
+                };
 
                 diag[start] = eigvals[0];
                 diag[start + 1] = eigvals[1];
+                off_diag[start] = N::RealField::zero();
 
-                if let Some(ref mut q) = q {
+                if let Some(q) = q.as_mut() {
                     if let Some((rot, _)) = GivensRotation::try_new(basis.x, basis.y, eps) {
                         let rot = GivensRotation::new_unchecked(rot.c(), N::from_real(rot.s()));
                         rot.rotate_rows(&mut q.fixed_columns_mut::<U2>(start));
@@ -206,20 +542,36 @@ where
             }
 
             // Re-delimit the subproblem in case some decoupling occurred.
-            let sub = Self::delimit_subproblem(&diag, &mut off_diag, end, eps);
+            let sub = Self::delimit_subproblem(diag, off_diag, end, eps);
 
             start = sub.0;
             end = sub.1;
 
             niter += 1;
-            if niter == max_niter {
-                return None;
+
+            let aborted = match observer.as_mut() {
+                Some(observer) => matches!(
+                    observer(IterationInfo {
+                        iteration: niter,
+                        residual: off_diag.norm(),
+                    }),
+                    ControlFlow::Break(())
+                ),
+                None => false,
+            };
+
+            if (niter == max_niter || aborted) && end != start {
+                return ConvergenceInfo {
+                    converged: false,
+                    iterations: niter,
+                };
             }
         }
 
-        diag.scale_mut(m_amax);
-
-        Some((diag, q))
+        ConvergenceInfo {
+            converged: true,
+            iterations: niter,
+        }
     }
 
     fn delimit_subproblem(
@@ -265,6 +617,74 @@ where
         (new_start, n)
     }
 
+    /// Sorts the eigenvalues in ascending (or descending) order, and permutes the columns of the
+    /// eigenvectors matrix accordingly.
+    pub fn sort(&mut self, ascending: bool) {
+        let dim = self.eigenvalues.len();
+
+        for i in 0..dim {
+            let mut best = i;
+
+            for j in i + 1..dim {
+                let is_better = if ascending {
+                    self.eigenvalues[j] < self.eigenvalues[best]
+                } else {
+                    self.eigenvalues[j] > self.eigenvalues[best]
+                };
+
+                if is_better {
+                    best = j;
+                }
+            }
+
+            if best != i {
+                self.eigenvalues.swap_rows(i, best);
+                self.eigenvectors.swap_columns(i, best);
+            }
+        }
+    }
+
+    /// Returns this decomposition with its eigenvalues sorted in ascending (or descending)
+    /// order, and the columns of its eigenvectors matrix permuted accordingly.
+    pub fn sorted(mut self, ascending: bool) -> Self {
+        self.sort(ascending);
+        self
+    }
+
+    /// Multiplies each eigenvector by a scalar of modulus 1 so that its component of largest
+    /// modulus is real and positive.
+    ///
+    /// Eigenvectors are only defined up to such a factor (a sign for real matrices), which depends
+    /// on the order of the operations performed by the algorithm. Normalizing them makes results
+    /// like the principal axes of a PCA reproducible. If several components have the same largest
+    /// modulus, the first one is used.
+    pub fn normalize_signs(&mut self) {
+        for mut col in self.eigenvectors.column_iter_mut() {
+            let mut best = 0;
+            let mut best_modulus = N::RealField::zero();
+
+            for (i, e) in col.iter().enumerate() {
+                let modulus = e.modulus();
+
+                if modulus > best_modulus {
+                    best = i;
+                    best_modulus = modulus;
+                }
+            }
+
+            if !best_modulus.is_zero() {
+                let phase = col[best].unscale(best_modulus);
+                col *= phase.conjugate();
+            }
+        }
+    }
+
+    /// Returns this decomposition with its eigenvectors normalized with `normalize_signs`.
+    pub fn with_normalized_signs(mut self) -> Self {
+        self.normalize_signs();
+        self
+    }
+
     /// Rebuild the original matrix.
     ///
     /// This is useful if some of the eigenvalues have been manually modified.
@@ -279,6 +699,90 @@ where
     }
 }
 
+/// The buffers used by `SymmetricEigen::try_new_with_workspace` to compute the
+/// eigendecomposition of symmetric matrices without any allocation.
+///
+/// A workspace can be reused to decompose any number of matrices of the same dimension. It also
+/// stores the last decomposition computed with it.
+#[derive(Clone, Debug)]
+pub struct SymmetricEigenWorkspace<N: ComplexField, D: DimSub<U1>>
+where
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N::RealField, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N::RealField, DimDiff<D, U1>>,
+{
+    tri: MatrixN<N, D>,
+    off_diagonal: VectorN<N, DimDiff<D, U1>>,
+    work: VectorN<N, DimDiff<D, U1>>,
+    off_diag: VectorN<N::RealField, DimDiff<D, U1>>,
+    eig: SymmetricEigen<N, D>,
+}
+
+impl<N: ComplexField, D: DimSub<U1>> SymmetricEigenWorkspace<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N::RealField, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N::RealField, DimDiff<D, U1>>,
+{
+    /// Creates a workspace for the eigendecomposition of matrices of dimension `dim`.
+    pub fn new_generic(dim: D) -> Self {
+        // `dim.sub(U1)` would underflow for empty matrices.
+        let sub = DimDiff::<D, U1>::from_usize(dim.value().saturating_sub(1));
+
+        Self {
+            tri: MatrixN::zeros_generic(dim, dim),
+            off_diagonal: VectorN::zeros_generic(sub, U1),
+            work: VectorN::zeros_generic(sub, U1),
+            off_diag: VectorN::zeros_generic(sub, U1),
+            eig: SymmetricEigen {
+                eigenvectors: MatrixN::zeros_generic(dim, dim),
+                eigenvalues: VectorN::zeros_generic(dim, U1),
+            },
+        }
+    }
+
+    /// The dimension of the matrices this workspace can decompose without any allocation.
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.tri.nrows()
+    }
+
+    /// The last decomposition computed with this workspace.
+    ///
+    /// This is meaningless if the last call to `SymmetricEigen::try_new_with_workspace` failed,
+    /// or if no decomposition was computed yet.
+    #[inline]
+    pub fn last_decomposition(&self) -> &SymmetricEigen<N, D> {
+        &self.eig
+    }
+}
+
+impl<N: ComplexField, D: DimSub<U1> + DimName> Default for SymmetricEigenWorkspace<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N::RealField, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N::RealField, DimDiff<D, U1>>,
+{
+    /// Creates a workspace for the eigendecomposition of statically-sized matrices.
+    fn default() -> Self {
+        Self::new_generic(D::name())
+    }
+}
+
+impl<N: ComplexField> SymmetricEigenWorkspace<N, Dynamic>
+where
+    DefaultAllocator:
+        Allocator<N, Dynamic, Dynamic> + Allocator<N::RealField, Dynamic> + Allocator<N, Dynamic>,
+{
+    /// Creates a workspace for the eigendecomposition of `n × n` matrices.
+    pub fn new(n: usize) -> Self {
+        Self::new_generic(Dynamic::new(n))
+    }
+}
+
 /// Computes the wilkinson shift, i.e., the 2x2 symmetric matrix eigenvalue to its tailing
 /// component `tnn`.
 ///
@@ -324,13 +828,13 @@ where
     ///
     /// * `eps`       − tolerance used to determine when a value converged to 0.
     /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
-    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
-    /// continues indefinitely until convergence.
+    /// number of iteration is exceeded, `LinalgError::NotConverged` is returned. If `niter == 0`,
+    /// then the algorithm continues indefinitely until convergence.
     pub fn try_symmetric_eigen(
         self,
         eps: N::RealField,
         max_niter: usize,
-    ) -> Option<SymmetricEigen<N, D>> {
+    ) -> Result<SymmetricEigen<N, D>, LinalgError> {
         SymmetricEigen::try_new(self.into_owned(), eps, max_niter)
     }
 
@@ -338,14 +842,7 @@ where
     ///
     /// Only the lower-triangular part of the matrix is read.
     pub fn symmetric_eigenvalues(&self) -> VectorN<N::RealField, D> {
-        SymmetricEigen::do_decompose(
-            self.clone_owned(),
-            false,
-            N::RealField::default_epsilon(),
-            0,
-        )
-        .unwrap()
-        .0
+        SymmetricEigen::eigenvalues(self.clone_owned())
     }
 }
 
@@ -0,0 +1,353 @@
+//! Divide-and-conquer eigensolver for symmetric tridiagonal matrices (Cuppen's method), and
+//! rank-one updates of symmetric eigendecompositions.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use num::Zero;
+use std::cmp::Ordering;
+
+use crate::allocator::Allocator;
+use crate::base::{DMatrix, DefaultAllocator, MatrixN, Vector, VectorN};
+use crate::dimension::{Dim, DimDiff, DimSub, U1};
+use crate::storage::Storage;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::linalg::{SymmetricEigen, SymmetricTridiagonal};
+
+/// Subproblems of at most this dimension are solved with the implicit QR algorithm
+/// instead of being split further.
+const LEAF_SIZE: usize = 25;
+
+impl<N: ComplexField, D: DimSub<U1>> SymmetricEigen<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, DimDiff<D, U1>>
+        + Allocator<N::RealField, D>
+        + Allocator<N::RealField, DimDiff<D, U1>>,
+{
+    /// Computes the eigendecomposition of the given symmetric matrix using the divide-and-conquer
+    /// method on its tridiagonal form.
+    ///
+    /// The tridiagonal matrix is recursively split in two halves whose eigendecompositions are
+    /// merged by solving a secular equation, so most of the work is done by matrix
+    /// multiplications instead of sequences of Givens rotations. The results have the same
+    /// accuracy as with `SymmetricEigen::new`.
+    ///
+    /// Only the lower-triangular part (including its diagonal) of `m` is read.
+    pub fn new_dc(mut m: MatrixN<N, D>) -> Self {
+        assert!(
+            m.is_square(),
+            "Unable to compute the eigendecomposition of a non-square matrix."
+        );
+
+        let dim = m.data.shape().0;
+        let m_amax = m.camax();
+
+        if !m_amax.is_zero() {
+            m.unscale_mut(m_amax);
+        }
+
+        let (q, diag, off_diag) = SymmetricTridiagonal::new(m).unpack();
+        let (vals, vecs) = tridiagonal_dc(diag.as_slice(), off_diag.as_slice());
+
+        let vecs = MatrixN::from_fn_generic(dim, dim, |i, j| N::from_real(vecs[(i, j)]));
+
+        SymmetricEigen {
+            eigenvectors: q * vecs,
+            eigenvalues: VectorN::from_fn_generic(dim, U1, |i, _| vals[i] * m_amax),
+        }
+    }
+}
+
+impl<N: ComplexField, D: Dim> SymmetricEigen<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<N::RealField, D>,
+{
+    /// Updates this eigendecomposition of a symmetric matrix `M` such that it becomes the
+    /// eigendecomposition of `M + sigma * v * vᴴ`.
+    ///
+    /// The updated eigenvalues are computed as the roots of a secular equation in `O(n²)`
+    /// operations. Computing the updated eigenvectors then requires a matrix multiplication.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps` − relative tolerance used to determine when an eigenpair is left unchanged by the
+    /// update, e.g., because `v` is (almost) orthogonal to the associated eigenvector.
+    pub fn rank_one_update<S2: Storage<N, D>>(
+        &mut self,
+        v: &Vector<N, D, S2>,
+        sigma: N::RealField,
+        eps: N::RealField,
+    ) {
+        let (dim, _) = self.eigenvectors.data.shape();
+        let n = dim.value();
+
+        // With `M = V * Λ * Vᴴ`, we have `M + sigma * v * vᴴ = V * (Λ + sigma * z * zᴴ) * Vᴴ` with
+        // `z = Vᴴ * v`. The phases of `z` are factored-out so the inner problem is real.
+        let z = self.eigenvectors.ad_mul(v);
+
+        if sigma.is_zero() || z.iter().all(|e| e.is_zero()) {
+            return;
+        }
+
+        for (mut col, zj) in self.eigenvectors.column_iter_mut().zip(z.iter()) {
+            let (_, phase) = zj.to_exp();
+            col *= phase;
+        }
+
+        let d = self.eigenvalues.iter().cloned().collect();
+        let z = z.iter().map(|e| e.modulus()).collect();
+        let (vals, vecs) = rank_one_update(d, z, sigma, DMatrix::identity(n, n), eps);
+
+        let vecs = MatrixN::from_fn_generic(dim, dim, |i, j| N::from_real(vecs[(i, j)]));
+
+        self.eigenvectors = &self.eigenvectors * vecs;
+        self.eigenvalues = VectorN::from_fn_generic(dim, U1, |i, _| vals[i]);
+    }
+}
+
+/// Computes the eigenvalues and eigenvectors of the symmetric tridiagonal matrix with the
+/// given diagonal and off-diagonal elements.
+fn tridiagonal_dc<N: RealField>(diag: &[N], off_diag: &[N]) -> (Vec<N>, DMatrix<N>) {
+    let dim = diag.len();
+
+    if dim <= LEAF_SIZE {
+        let t = DMatrix::from_fn(dim, dim, |i, j| {
+            if i == j {
+                diag[i]
+            } else if i == j + 1 {
+                off_diag[j]
+            } else if j == i + 1 {
+                off_diag[i]
+            } else {
+                N::zero()
+            }
+        });
+
+        let eig = SymmetricEigen::new(t);
+        return (eig.eigenvalues.iter().cloned().collect(), eig.eigenvectors);
+    }
+
+    // Split the matrix as `T = diag(T1, T2) + rho * v * vᵀ` where `v` has only two non-zero
+    // components equal to 1, at the rows where `T` is split.
+    let mid = dim / 2;
+    let rho = off_diag[mid - 1];
+
+    let mut diag1 = diag[..mid].to_vec();
+    let mut diag2 = diag[mid..].to_vec();
+    diag1[mid - 1] -= rho;
+    diag2[0] -= rho;
+
+    let (vals1, vecs1) = tridiagonal_dc(&diag1, &off_diag[..mid - 1]);
+    let (vals2, vecs2) = tridiagonal_dc(&diag2, &off_diag[mid..]);
+
+    // With `Q = diag(Q1, Q2)`, we have `T = Q * (D + rho * z * zᵀ) * Qᵀ` where `z = Qᵀ * v`.
+    let mut q = DMatrix::zeros(dim, dim);
+    q.slice_mut((0, 0), (mid, mid)).copy_from(&vecs1);
+    q.slice_mut((mid, mid), (dim - mid, dim - mid))
+        .copy_from(&vecs2);
+
+    let d = vals1.iter().chain(vals2.iter()).cloned().collect();
+    let z = vecs1
+        .row(mid - 1)
+        .iter()
+        .chain(vecs2.row(0).iter())
+        .cloned()
+        .collect();
+
+    rank_one_update(d, z, rho, q, N::default_epsilon())
+}
+
+/// Computes the eigendecomposition of `Q * (diag(d) + rho * z * zᵀ) * Qᵀ` where `Q` is
+/// orthogonal and `z` is not zero.
+///
+/// The eigenpairs are deflated when the corresponding contributions are smaller than
+/// `8 * eps * max(|d|, |rho| * |z|²)`.
+fn rank_one_update<N: RealField>(
+    mut d: Vec<N>,
+    mut z: Vec<N>,
+    mut rho: N,
+    q: DMatrix<N>,
+    eps: N,
+) -> (Vec<N>, DMatrix<N>) {
+    let dim = d.len();
+
+    // Work with `rho > 0` by negating the whole problem if needed.
+    let negated = rho < N::zero();
+    if negated {
+        for e in &mut d {
+            *e = -*e;
+        }
+        rho = -rho;
+    }
+
+    let znorm_squared = z.iter().fold(N::zero(), |a, e| a + *e * *e);
+    let znorm = znorm_squared.sqrt();
+    for e in &mut z {
+        *e /= znorm;
+    }
+    rho *= znorm_squared;
+
+    // Sort the diagonal in increasing order.
+    let mut perm: Vec<usize> = (0..dim).collect();
+    perm.sort_unstable_by(|a, b| d[*a].partial_cmp(&d[*b]).unwrap_or(Ordering::Equal));
+
+    let mut d: Vec<N> = perm.iter().map(|i| d[*i]).collect();
+    let mut z: Vec<N> = perm.iter().map(|i| z[*i]).collect();
+    let mut q = DMatrix::from_fn(dim, dim, |i, j| q[(i, perm[j])]);
+
+    // Deflation: the eigenpairs associated to negligible components of `z`, or to (almost)
+    // equal diagonal elements, are already known.
+    let dmax = d.iter().fold(N::zero(), |a, e| a.max(e.abs()));
+    let tol = eps * crate::convert(8.0) * dmax.max(rho);
+    let mut kept: Vec<usize> = Vec::with_capacity(dim);
+
+    for j in 0..dim {
+        if rho * z[j].abs() <= tol {
+            z[j] = N::zero();
+            continue;
+        }
+
+        if let Some(&p) = kept.last() {
+            let r = z[p].hypot(z[j]);
+            let c = z[p] / r;
+            let s = z[j] / r;
+
+            if (c * s * (d[j] - d[p])).abs() <= tol {
+                // Rotate the basis so that the j-th component of `z` becomes zero.
+                for i in 0..dim {
+                    let qp = q[(i, p)];
+                    let qj = q[(i, j)];
+                    q[(i, p)] = c * qp + s * qj;
+                    q[(i, j)] = c * qj - s * qp;
+                }
+
+                let (dp, dj) = (d[p], d[j]);
+                d[p] = c * c * dp + s * s * dj;
+                d[j] = s * s * dp + c * c * dj;
+                z[p] = r;
+                z[j] = N::zero();
+                continue;
+            }
+        }
+
+        kept.push(j);
+    }
+
+    let nkept = kept.len();
+    let dk: Vec<N> = kept.iter().map(|i| d[*i]).collect();
+    let zk: Vec<N> = kept.iter().map(|i| z[*i]).collect();
+
+    // Solve the secular equation `1 + rho * Σ zk[j]² / (dk[j] - λ) = 0`. Each root is
+    // represented as `dk[origin] + tau` where `dk[origin]` is the closest pole, so that the
+    // differences `λ - dk[j]` can be computed accurately.
+    let mut origins = Vec::with_capacity(nkept);
+    let mut taus = Vec::with_capacity(nkept);
+
+    for i in 0..nkept {
+        let (origin, mut lo, mut hi) = if i + 1 < nkept {
+            let half_gap = (dk[i + 1] - dk[i]) * crate::convert(0.5);
+
+            if secular_function(&dk, &zk, rho, i, half_gap) >= N::zero() {
+                (i, N::zero(), half_gap)
+            } else {
+                (i + 1, -half_gap, N::zero())
+            }
+        } else {
+            let znorm_squared = zk.iter().fold(N::zero(), |a, e| a + *e * *e);
+            (i, N::zero(), rho * znorm_squared)
+        };
+
+        // The secular function is increasing on each interval, so we can use a bisection.
+        loop {
+            let tau = (lo + hi) * crate::convert(0.5);
+
+            if tau <= lo || tau >= hi || hi - lo <= eps * lo.abs().max(hi.abs()) {
+                break;
+            }
+
+            if secular_function(&dk, &zk, rho, origin, tau) < N::zero() {
+                lo = tau;
+            } else {
+                hi = tau;
+            }
+        }
+
+        let mut tau = (lo + hi) * crate::convert(0.5);
+        if tau.is_zero() {
+            tau = if origin == i { hi } else { lo };
+        }
+
+        origins.push(origin);
+        taus.push(tau);
+    }
+
+    // `λ_i - dk[j]`.
+    let shifted_root = |i: usize, j: usize| (dk[origins[i]] - dk[j]) + taus[i];
+
+    // Recompute `z` from the computed eigenvalues (Löwner's formula) so that the eigenvectors
+    // are numerically orthogonal.
+    let zhat: Vec<N> = (0..nkept)
+        .map(|j| {
+            let mut prod = shifted_root(j, j) / rho;
+
+            for i in 0..nkept {
+                if i != j {
+                    prod *= shifted_root(i, j) / (dk[i] - dk[j]);
+                }
+            }
+
+            let val = prod.abs().sqrt();
+            if zk[j] < N::zero() {
+                -val
+            } else {
+                val
+            }
+        })
+        .collect();
+
+    let mut v = DMatrix::from_fn(nkept, nkept, |j, i| zhat[j] / -shifted_root(i, j));
+    for mut col in v.column_iter_mut() {
+        let _ = col.normalize_mut();
+    }
+
+    // Assemble the eigendecomposition.
+    let mut eigenvalues = Vec::with_capacity(dim);
+    let mut eigenvectors = DMatrix::zeros(dim, dim);
+    let mut ncols = 0;
+
+    for j in 0..dim {
+        if z[j].is_zero() {
+            eigenvalues.push(d[j]);
+            eigenvectors.set_column(ncols, &q.column(j));
+            ncols += 1;
+        }
+    }
+
+    let qk = DMatrix::from_fn(dim, nkept, |i, j| q[(i, kept[j])]);
+    eigenvectors.columns_mut(ncols, nkept).copy_from(&(qk * v));
+
+    for i in 0..nkept {
+        eigenvalues.push(dk[origins[i]] + taus[i]);
+    }
+
+    if negated {
+        for e in &mut eigenvalues {
+            *e = -*e;
+        }
+    }
+
+    (eigenvalues, eigenvectors)
+}
+
+/// Evaluates the secular function `1 + rho * Σ z[j]² / (d[j] - λ)` at `λ = d[origin] + tau`.
+fn secular_function<N: RealField>(d: &[N], z: &[N], rho: N, origin: usize, tau: N) -> N {
+    let mut sum = N::zero();
+
+    for j in 0..d.len() {
+        sum += z[j] * z[j] / ((d[j] - d[origin]) - tau);
+    }
+
+    N::one() + rho * sum
+}
@@ -0,0 +1,147 @@
+//! Cyclic Jacobi eigensolver for symmetric matrices.
+
+use num::{One, Zero};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, MatrixN};
+use crate::dimension::Dim;
+use crate::storage::Storage;
+use simba::scalar::ComplexField;
+
+use crate::linalg::{LinalgError, SymmetricEigen};
+
+impl<N: ComplexField, D: Dim> SymmetricEigen<N, D>
+where
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N::RealField, D>,
+{
+    /// Computes the eigendecomposition of the given symmetric matrix using the cyclic Jacobi
+    /// method.
+    ///
+    /// This is slower than `SymmetricEigen::new` but the eigenvalues of small magnitude are
+    /// computed with a much better relative accuracy, in particular for graded matrices.
+    ///
+    /// Only the lower-triangular part (including its diagonal) of `m` is read.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps` − the off-diagonal element at `(i, j)` is considered zero when its modulus is
+    /// smaller than `eps * sqrt(|m[(i, i)]| * |m[(j, j)]|)`.
+    ///
+    /// The sweeps continue indefinitely until convergence. Use `try_new_jacobi` to bound their
+    /// number, e.g., when `eps` may be too small to be reached with floating-point arithmetic.
+    pub fn new_jacobi(m: MatrixN<N, D>, eps: N::RealField) -> Self {
+        Self::try_new_jacobi(m, eps, 0).unwrap()
+    }
+
+    /// Attempts to compute the eigendecomposition of the given symmetric matrix using the cyclic
+    /// Jacobi method.
+    ///
+    /// Only the lower-triangular part (including its diagonal) of `m` is read.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps` − the off-diagonal element at `(i, j)` is considered zero when its modulus is
+    /// smaller than `eps * sqrt(|m[(i, i)]| * |m[(j, j)]|)`.
+    /// * `max_niter` − maximum total number of sweeps over the off-diagonal elements, including
+    /// the final sweep checking the convergence. If this number is exceeded,
+    /// `LinalgError::NotConverged` is returned. If `max_niter == 0`, then the algorithm continues
+    /// indefinitely until convergence.
+    pub fn try_new_jacobi(
+        mut m: MatrixN<N, D>,
+        eps: N::RealField,
+        max_niter: usize,
+    ) -> Result<Self, LinalgError> {
+        assert!(
+            m.is_square(),
+            "Unable to compute the eigendecomposition of a non-square matrix."
+        );
+
+        let (nrows, ncols) = m.data.shape();
+        let dim = nrows.value();
+
+        for j in 0..dim {
+            m[(j, j)] = N::from_real(m[(j, j)].real());
+
+            for i in 0..j {
+                m[(i, j)] = m[(j, i)].conjugate();
+            }
+        }
+
+        let mut eigenvectors = MatrixN::identity_generic(nrows, ncols);
+        let two: N::RealField = crate::convert(2.0);
+        let mut niter = 0;
+
+        loop {
+            if niter == max_niter && max_niter != 0 {
+                return Err(LinalgError::NotConverged { iterations: niter });
+            }
+
+            niter += 1;
+            let mut rotated = false;
+
+            for p in 0..dim {
+                for q in p + 1..dim {
+                    let apq = m[(p, q)];
+                    let norm = apq.modulus();
+                    let app = m[(p, p)].real();
+                    let aqq = m[(q, q)].real();
+
+                    if norm <= eps * (app.abs() * aqq.abs()).sqrt() || norm.is_zero() {
+                        continue;
+                    }
+
+                    rotated = true;
+
+                    // `phase * apq` is real, so the real Jacobi rotation can be used afterwards.
+                    let phase = (apq / N::from_real(norm)).conjugate();
+                    let tau = (aqq - app) / (two * norm);
+                    let sign = if tau < N::RealField::zero() {
+                        -N::RealField::one()
+                    } else {
+                        N::RealField::one()
+                    };
+                    let t = sign / (tau.abs() + N::RealField::one().hypot(tau));
+                    let c = N::RealField::one() / N::RealField::one().hypot(t);
+                    let s = N::from_real(t * c);
+                    let c = N::from_real(c);
+
+                    // Apply the rotation `G = [c, s; -s * phase, c * phase]` on the right.
+                    for i in 0..dim {
+                        let mip = m[(i, p)];
+                        let miq = m[(i, q)] * phase;
+                        m[(i, p)] = c * mip - s * miq;
+                        m[(i, q)] = s * mip + c * miq;
+
+                        let vip = eigenvectors[(i, p)];
+                        let viq = eigenvectors[(i, q)] * phase;
+                        eigenvectors[(i, p)] = c * vip - s * viq;
+                        eigenvectors[(i, q)] = s * vip + c * viq;
+                    }
+
+                    // Apply `Gᴴ` on the left.
+                    let phase = phase.conjugate();
+                    for j in 0..dim {
+                        let mpj = m[(p, j)];
+                        let mqj = m[(q, j)] * phase;
+                        m[(p, j)] = c * mpj - s * mqj;
+                        m[(q, j)] = s * mpj + c * mqj;
+                    }
+
+                    m[(p, p)] = N::from_real(app - t * norm);
+                    m[(q, q)] = N::from_real(aqq + t * norm);
+                    m[(p, q)] = N::zero();
+                    m[(q, p)] = N::zero();
+                }
+            }
+
+            if !rotated {
+                break;
+            }
+        }
+
+        Ok(SymmetricEigen {
+            eigenvalues: m.map_diagonal(|e| e.real()),
+            eigenvectors,
+        })
+    }
+}
@@ -2,12 +2,19 @@
 use serde::{Deserialize, Serialize};
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, MatrixMN, MatrixN, SquareMatrix, VectorN};
-use crate::dimension::{DimDiff, DimSub, U1};
-use crate::storage::Storage;
-use simba::scalar::ComplexField;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::DVector;
+use crate::base::{
+    DVectorSliceMut, DefaultAllocator, Matrix, MatrixMN, MatrixN, SquareMatrix, Unit, VectorN,
+};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::dimension::{Dim, DimDiff, DimSub, U1};
+use crate::storage::{Storage, StorageMut};
+use simba::scalar::{ComplexField, RealField};
 
+use crate::geometry::Reflection;
 use crate::linalg::householder;
+use crate::linalg::solve_tridiagonal_mut;
 
 /// Tridiagonalization of a symmetric matrix.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -34,6 +41,60 @@ where
     off_diagonal: VectorN<N, DimDiff<D, U1>>,
 }
 
+/// A tridiagonalization computed in-place with `SymmetricTridiagonal::new_in_place`.
+///
+/// This borrows the buffers containing the decomposition, with the same packed layout as
+/// `SymmetricTridiagonal`.
+#[derive(Debug)]
+pub struct SymmetricTridiagonalView<'a, N: ComplexField, D: Dim, S: Storage<N, D, D>> {
+    tri: &'a Matrix<N, D, D, S>,
+    off_diagonal: &'a [N],
+}
+
+impl<'a, N: ComplexField, D: Dim, S: Storage<N, D, D>> SymmetricTridiagonalView<'a, N, D, S> {
+    /// The diagonal components of this decomposition.
+    pub fn diagonal(&self) -> impl Iterator<Item = N::RealField> + 'a {
+        let tri = self.tri;
+        (0..tri.nrows()).map(move |i| tri[(i, i)].real())
+    }
+
+    /// The off-diagonal components of this decomposition.
+    pub fn off_diagonal(&self) -> impl Iterator<Item = N::RealField> + 'a {
+        self.off_diagonal.iter().map(|e| e.modulus())
+    }
+
+    /// Multiplies the provided matrix by the unitary matrix `Q` of this decomposition.
+    pub fn q_mul<R2: Dim, C2: Dim, S2>(&self, rhs: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+    {
+        for i in (0..self.off_diagonal.len()).rev() {
+            let axis = self.tri.slice_range(i + 1.., i);
+            let refl = Reflection::new(Unit::new_unchecked(axis), N::zero());
+            refl.reflect_with_sign(
+                &mut rhs.rows_range_mut(i + 1..),
+                self.off_diagonal[i].signum(),
+            );
+        }
+    }
+
+    /// Multiplies the provided matrix by the adjoint of the unitary matrix `Q` of this
+    /// decomposition.
+    pub fn q_ad_mul<R2: Dim, C2: Dim, S2>(&self, rhs: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+    {
+        for i in 0..self.off_diagonal.len() {
+            let axis = self.tri.slice_range(i + 1.., i);
+            let refl = Reflection::new(Unit::new_unchecked(axis), N::zero());
+            refl.reflect_with_sign(
+                &mut rhs.rows_range_mut(i + 1..),
+                self.off_diagonal[i].signum().conjugate(),
+            );
+        }
+    }
+}
+
 impl<N: ComplexField, D: DimSub<U1>> Copy for SymmetricTridiagonal<N, D>
 where
     DefaultAllocator: Allocator<N, D, D> + Allocator<N, DimDiff<D, U1>>,
@@ -62,9 +123,50 @@ where
         );
 
         let mut off_diagonal = unsafe { MatrixMN::new_uninitialized_generic(dim.sub(U1), U1) };
-        let mut p = unsafe { MatrixMN::new_uninitialized_generic(dim.sub(U1), U1) };
+        let mut p: VectorN<N, DimDiff<D, U1>> =
+            unsafe { MatrixMN::new_uninitialized_generic(dim.sub(U1), U1) };
+        let _ = Self::new_in_place(&mut m, off_diagonal.as_mut_slice(), p.as_mut_slice());
+
+        Self {
+            tri: m,
+            off_diagonal,
+        }
+    }
+
+    /// Computes the tridiagonalization of the symmetric matrix `m` in-place, without any
+    /// allocation.
+    ///
+    /// The householder reflections and the diagonal of the tridiagonal matrix overwrite the
+    /// lower-triangular part of `m` with the same packed layout as `SymmetricTridiagonal::new`,
+    /// and the `n - 1` off-diagonal elements are written to `off_diagonal`. The buffer `work`
+    /// must also have `n - 1` elements and is used as scratch space. `m` may be any mutable
+    /// matrix or matrix slice, so that the decomposition can be performed inside of a
+    /// pre-allocated buffer.
+    ///
+    /// Only the lower-triangular part (including the diagonal) of `m` is read.
+    pub fn new_in_place<'a, S: StorageMut<N, D, D>>(
+        m: &'a mut Matrix<N, D, D, S>,
+        off_diagonal: &'a mut [N],
+        work: &mut [N],
+    ) -> SymmetricTridiagonalView<'a, N, D, S> {
+        let dim = m.nrows();
 
-        for i in 0..dim.value() - 1 {
+        assert!(
+            m.is_square(),
+            "Unable to compute the symmetric tridiagonal decomposition of a non-square matrix."
+        );
+        assert!(
+            dim != 0,
+            "Unable to compute the symmetric tridiagonal decomposition of an empty matrix."
+        );
+        assert!(
+            off_diagonal.len() == dim - 1 && work.len() == dim - 1,
+            "Symmetric tridiagonal in-place: the buffers must have n - 1 elements."
+        );
+
+        let mut p = DVectorSliceMut::from_slice(work, dim - 1);
+
+        for i in 0..dim - 1 {
             let mut m = m.rows_range_mut(i + 1..);
             let (mut axis, mut m) = m.columns_range_pair_mut(i, i + 1..);
 
@@ -83,7 +185,7 @@ where
             }
         }
 
-        Self {
+        SymmetricTridiagonalView {
             tri: m,
             off_diagonal,
         }
@@ -147,6 +249,37 @@ where
         householder::assemble_q(&self.tri, self.off_diagonal.as_slice())
     }
 
+    /// Multiplies the provided matrix by the unitary matrix `Q` of this decomposition.
+    pub fn q_mul<R2: Dim, C2: Dim, S2>(&self, rhs: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+    {
+        for i in (0..self.off_diagonal.len()).rev() {
+            let axis = self.tri.slice_range(i + 1.., i);
+            let refl = Reflection::new(Unit::new_unchecked(axis), N::zero());
+            refl.reflect_with_sign(
+                &mut rhs.rows_range_mut(i + 1..),
+                self.off_diagonal[i].signum(),
+            );
+        }
+    }
+
+    /// Multiplies the provided matrix by the adjoint of the unitary matrix `Q` of this
+    /// decomposition.
+    pub fn q_ad_mul<R2: Dim, C2: Dim, S2>(&self, rhs: &mut Matrix<N, R2, C2, S2>)
+    where
+        S2: StorageMut<N, R2, C2>,
+    {
+        for i in 0..self.off_diagonal.len() {
+            let axis = self.tri.slice_range(i + 1.., i);
+            let refl = Reflection::new(Unit::new_unchecked(axis), N::zero());
+            refl.reflect_with_sign(
+                &mut rhs.rows_range_mut(i + 1..),
+                self.off_diagonal[i].signum().conjugate(),
+            );
+        }
+    }
+
     /// Recomputes the original symmetric matrix.
     pub fn recompose(mut self) -> MatrixN<N, D> {
         let q = self.q();
@@ -161,6 +294,194 @@ where
 
         &q * self.tri * q.adjoint()
     }
+
+    /// Solves the linear system `m * x = b`, where `m` is the decomposed matrix and `x` the unknown.
+    ///
+    /// Because `m = Q * T * Qᴴ`, this computes `Q * T⁻¹ * Qᴴ * b`, where the system with the
+    /// real tridiagonal matrix `T` is solved in `O(n)` operations with `linalg::solve_tridiagonal`.
+    /// The householder reflections of `Q` are applied directly to `b`, so that the products
+    /// with `Q` require `O(n²)` operations per right-hand-side and `Q` is never assembled.
+    ///
+    /// Returns `None` if a zero pivot is encountered while solving the tridiagonal system. Because
+    /// this system is solved without pivoting, this never happens if `m` is definite-positive,
+    /// but may happen for some invertible indefinite matrices.
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> Option<MatrixMN<N, D, C2>>
+    where
+        S2: Storage<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<D, R2>,
+        DefaultAllocator: Allocator<N, D> + Allocator<N, D, C2>,
+    {
+        let diag = self.tri.map_diagonal(|e| N::from_real(e.real()));
+        let off_diagonal = self.off_diagonal.map(|e| N::from_real(e.modulus()));
+
+        let (nrows, ncols) = (self.tri.data.shape().0, b.data.shape().1);
+        let mut res = MatrixMN::zeros_generic(nrows, ncols);
+        res.copy_from(b);
+        self.q_ad_mul(&mut res);
+
+        if solve_tridiagonal_mut::<N, D, D, C2, _, _, _, _>(
+            &off_diagonal,
+            &diag,
+            &off_diagonal,
+            &mut res,
+        ) {
+            self.q_mul(&mut res);
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Counts the eigenvalues of the decomposed matrix that are strictly smaller than `x`.
+    ///
+    /// This computes the number of negative pivots of the `LDLᵀ` factorization of `T - x * I`,
+    /// where `T` is the real tridiagonal matrix of this decomposition, which is equal to the
+    /// number of eigenvalues smaller than `x` by the Sylvester law of inertia (this is equivalent
+    /// to counting the sign changes of the Sturm sequence of `T`). This requires only `O(n)`
+    /// operations.
+    pub fn count_eigenvalues_below(&self, x: N::RealField) -> usize
+    where
+        DefaultAllocator: Allocator<N::RealField, D> + Allocator<N::RealField, DimDiff<D, U1>>,
+    {
+        let diag = self.diagonal();
+        let off_diag_sq = self.off_diagonal.map(|e| e.modulus_squared());
+        let pivmin = sturm_pivmin(&diag, &off_diag_sq);
+
+        sturm_count(&diag, &off_diag_sq, x, pivmin)
+    }
+
+    /// Counts the eigenvalues `λ` of the decomposed matrix such that `a <= λ < b`.
+    pub fn count_eigenvalues_in_range(&self, a: N::RealField, b: N::RealField) -> usize
+    where
+        DefaultAllocator: Allocator<N::RealField, D> + Allocator<N::RealField, DimDiff<D, U1>>,
+    {
+        assert!(a <= b, "The bounds of the interval must be sorted.");
+        let diag = self.diagonal();
+        let off_diag_sq = self.off_diagonal.map(|e| e.modulus_squared());
+        let pivmin = sturm_pivmin(&diag, &off_diag_sq);
+
+        sturm_count(&diag, &off_diag_sq, b, pivmin) - sturm_count(&diag, &off_diag_sq, a, pivmin)
+    }
+
+    /// Computes the eigenvalues `λ` of the decomposed matrix such that `a <= λ < b`, sorted in
+    /// increasing order.
+    ///
+    /// Each eigenvalue is located by bisection, counting the eigenvalues below the middle of the
+    /// current interval with `count_eigenvalues_below`, until the interval has a width of the order
+    /// of the machine epsilon times the norm of the matrix. Each eigenvalue thus requires
+    /// `O(n)` operations per bit of accuracy, which is much cheaper than computing all the
+    /// eigenvalues with `SymmetricEigen` if only a few of them lie in the interval.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn eigenvalues_in_range(&self, a: N::RealField, b: N::RealField) -> DVector<N::RealField>
+    where
+        DefaultAllocator: Allocator<N::RealField, D> + Allocator<N::RealField, DimDiff<D, U1>>,
+    {
+        assert!(a <= b, "The bounds of the interval must be sorted.");
+        let diag = self.diagonal();
+        let off_diag_sq = self.off_diagonal.map(|e| e.modulus_squared());
+        let pivmin = sturm_pivmin(&diag, &off_diag_sq);
+        let tol = pivmin * crate::convert(2.0);
+
+        let count_a = sturm_count(&diag, &off_diag_sq, a, pivmin);
+        let count_b = sturm_count(&diag, &off_diag_sq, b, pivmin);
+        let mut res = DVector::zeros(count_b - count_a);
+        let mut lo = a;
+
+        for k in count_a..count_b {
+            // Invariant: `count(lo) <= k < count(hi)`, i.e., `lo <= λ_k < hi`. Since the
+            // eigenvalues are found in increasing order, `λ_(k - 1)` is a valid lower bound.
+            let mut hi = b;
+
+            loop {
+                let mid = (lo + hi) * crate::convert(0.5);
+
+                if hi - lo <= tol || mid == lo || mid == hi {
+                    break;
+                }
+
+                if sturm_count(&diag, &off_diag_sq, mid, pivmin) > k {
+                    hi = mid;
+                } else {
+                    lo = mid;
+                }
+            }
+
+            res[k - count_a] = (lo + hi) * crate::convert(0.5);
+        }
+
+        res
+    }
+}
+
+// The minimum absolute value of the pivots of the `LDLᵀ` factorizations computed by
+// `sturm_count`, which is of the order of the machine epsilon times the norm of the matrix.
+fn sturm_pivmin<N: RealField, D: DimSub<U1>>(
+    diag: &VectorN<N, D>,
+    off_diag_sq: &VectorN<N, DimDiff<D, U1>>,
+) -> N
+where
+    DefaultAllocator: Allocator<N, D> + Allocator<N, DimDiff<D, U1>>,
+{
+    let n = diag.len();
+    let mut norm = N::zero();
+
+    // Gershgorin bound of the spectral radius.
+    for i in 0..n {
+        let mut row = diag[i].abs();
+
+        if i > 0 {
+            row += off_diag_sq[i - 1].sqrt();
+        }
+
+        if i + 1 < n {
+            row += off_diag_sq[i].sqrt();
+        }
+
+        norm = norm.max(row);
+    }
+
+    if norm.is_zero() {
+        norm = N::one();
+    }
+
+    N::default_epsilon() * norm
+}
+
+// The number of negative pivots of the `LDLᵀ` factorization of `T - x * I` where `T` is the
+// tridiagonal matrix with diagonal `diag` and squared off-diagonal `off_diag_sq`. Tiny pivots are
+// replaced by `-pivmin` to avoid divisions by zero.
+fn sturm_count<N: RealField, D: DimSub<U1>>(
+    diag: &VectorN<N, D>,
+    off_diag_sq: &VectorN<N, DimDiff<D, U1>>,
+    x: N,
+    pivmin: N,
+) -> usize
+where
+    DefaultAllocator: Allocator<N, D> + Allocator<N, DimDiff<D, U1>>,
+{
+    let mut count = 0;
+    let mut pivot = N::one();
+
+    for i in 0..diag.len() {
+        pivot = if i == 0 {
+            diag[0] - x
+        } else {
+            diag[i] - x - off_diag_sq[i - 1] / pivot
+        };
+
+        if pivot.abs() < pivmin {
+            pivot = -pivmin;
+        }
+
+        if pivot < N::zero() {
+            count += 1;
+        }
+    }
+
+    count
 }
 
 impl<N: ComplexField, D: DimSub<U1>, S: Storage<N, D, D>> SquareMatrix<N, D, S>
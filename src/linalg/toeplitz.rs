@@ -0,0 +1,262 @@
+//! Square matrices with constant diagonals.
+
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use num::Zero;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::allocator::Allocator;
+use crate::base::dimension::Dim;
+use crate::base::storage::{Storage, StorageMut};
+use crate::base::{DMatrix, DVector, DefaultAllocator, Matrix, MatrixMN, Scalar, Vector};
+
+use crate::linalg::Circulant;
+
+/// A square Toeplitz matrix, i.e., a matrix where each diagonal is constant.
+///
+/// The element `(i, j)` only depends on `i - j`, so the whole matrix is determined by its first
+/// column and its first row, and only these are stored. Toeplitz matrices arise, e.g., as the
+/// autocorrelation matrices of stationary signals, or from the discretization of convolutions.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DVector<N>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DVector<N>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToeplitzMatrix<N: Scalar> {
+    first_column: DVector<N>,
+    first_row: DVector<N>,
+}
+
+impl<N: Scalar> ToeplitzMatrix<N> {
+    /// Creates a Toeplitz matrix from its first column and its first row.
+    ///
+    /// Both must have the same length and the same first element, i.e., the diagonal element of
+    /// the matrix.
+    pub fn new(first_column: DVector<N>, first_row: DVector<N>) -> Self {
+        assert_eq!(
+            first_column.len(),
+            first_row.len(),
+            "ToeplitzMatrix: the first column and the first row must have the same length."
+        );
+        assert!(
+            first_column.is_empty() || first_column[0] == first_row[0],
+            "ToeplitzMatrix: the first column and the first row must start with the same element."
+        );
+
+        ToeplitzMatrix {
+            first_column,
+            first_row,
+        }
+    }
+
+    /// Creates a symmetric Toeplitz matrix from its first column.
+    pub fn new_symmetric(first_column: DVector<N>) -> Self {
+        ToeplitzMatrix {
+            first_row: first_column.clone(),
+            first_column,
+        }
+    }
+
+    /// The number of rows of this matrix.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.first_column.len()
+    }
+
+    /// The number of columns of this matrix.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.first_row.len()
+    }
+
+    /// The first column of this matrix.
+    #[inline]
+    pub fn first_column(&self) -> &DVector<N> {
+        &self.first_column
+    }
+
+    /// The first row of this matrix, stored as a column vector.
+    #[inline]
+    pub fn first_row(&self) -> &DVector<N> {
+        &self.first_row
+    }
+
+    /// A reference to the element `(i, j)` of this matrix.
+    #[inline]
+    pub fn get(&self, i: usize, j: usize) -> Option<&N> {
+        if i >= self.nrows() || j >= self.ncols() {
+            None
+        } else if i >= j {
+            Some(&self.first_column[i - j])
+        } else {
+            Some(&self.first_row[j - i])
+        }
+    }
+}
+
+impl<N: Scalar + Zero> ToeplitzMatrix<N> {
+    /// Converts this Toeplitz matrix to a dense matrix.
+    pub fn to_dense(&self) -> DMatrix<N> {
+        DMatrix::from_fn(self.nrows(), self.ncols(), |i, j| {
+            self.get(i, j).unwrap().inlined_clone()
+        })
+    }
+}
+
+impl<N: RealField> ToeplitzMatrix<N> {
+    /// Computes the product of this matrix with the vector `x`.
+    ///
+    /// This matrix is embedded in a circulant matrix of dimension at least `2n - 1`, so the product
+    /// is computed in `O(n log(n))` operations with fast Fourier transforms, and the dense matrix
+    /// is never formed.
+    pub fn mul_vector<R: Dim, S: Storage<N, R>>(&self, x: &Vector<N, R, S>) -> DVector<N> {
+        let n = self.ncols();
+        assert_eq!(x.nrows(), n, "ToeplitzMatrix product: dimension mismatch.");
+
+        if n == 0 {
+            return DVector::zeros(0);
+        }
+
+        // The first column of the circulant embedding is `[c; 0; ...; 0; r[n - 1]; ...; r[1]]`
+        // where `c` and `r` are the first column and the first row of this matrix.
+        let m = (2 * n - 1).next_power_of_two();
+        let embedding = Circulant::new(DVector::from_fn(m, |i, _| {
+            if i < n {
+                self.first_column[i]
+            } else if i > m - n {
+                self.first_row[m - i]
+            } else {
+                N::zero()
+            }
+        }));
+        let padded = DVector::from_fn(m, |i, _| if i < n { x[i] } else { N::zero() });
+
+        embedding.mul_vector(&padded).rows(0, n).into_owned()
+    }
+}
+
+impl<N: ComplexField> ToeplitzMatrix<N> {
+    // The element `(i, j)` for `i - j = k`.
+    #[inline]
+    fn diagonal_element(&self, k: isize) -> N {
+        if k >= 0 {
+            self.first_column[k as usize]
+        } else {
+            self.first_row[(-k) as usize]
+        }
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `None` if the Levinson recursion breaks down. See `solve_mut` for details.
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> Option<MatrixMN<N, R2, C2>>
+    where
+        S2: Storage<N, R2, C2>,
+        DefaultAllocator: Allocator<N, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// This uses the Levinson recursion, which requires `O(n²)` operations instead of the `O(n³)`
+    /// operations of a dense decomposition, and only `O(n)` additional memory. Each column of `b`
+    /// is a right-hand-side, and is overwritten by the corresponding solution.
+    ///
+    /// The recursion successively solves the systems of all the leading principal submatrices
+    /// of `self`, so it breaks down if one of them is singular, even if `self` is invertible.
+    /// This never happens for definite-positive matrices, e.g., the autocorrelation matrices
+    /// of the Yule-Walker equations of autoregressive models. Returns `false`, and `b` may be
+    /// overwritten with garbage, if the recursion breaks down.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<N, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<N, R2, C2>,
+    {
+        let n = self.nrows();
+
+        assert_eq!(
+            n,
+            b.nrows(),
+            "ToeplitzMatrix solve matrix dimension mismatch."
+        );
+
+        if n == 0 {
+            return true;
+        }
+
+        let t0 = self.first_column[0];
+
+        if t0.is_zero() {
+            return false;
+        }
+
+        // The forward and backward vectors are the first and the last columns of the inverse of
+        // the leading principal submatrix of dimension `k`.
+        let mut forward = DVector::zeros(n);
+        let mut backward = DVector::zeros(n);
+        forward[0] = N::one() / t0;
+        backward[0] = forward[0];
+
+        for k in 0..b.ncols() {
+            b[(0, k)] /= t0;
+        }
+
+        for k in 1..n {
+            let mut err_f = N::zero();
+            let mut err_b = N::zero();
+
+            for i in 0..k {
+                err_f += self.diagonal_element((k - i) as isize) * forward[i];
+                err_b += self.diagonal_element(-(i as isize + 1)) * backward[i];
+            }
+
+            let denom = N::one() - err_f * err_b;
+
+            if denom.is_zero() {
+                return false;
+            }
+
+            // The new backward vector is `([0; b] - err_b * [f; 0]) / denom`, and the new forward
+            // vector is `([f; 0] - err_f * [0; b]) / denom`. Both are updated in-place, from the
+            // last to the first element.
+            for i in (0..=k).rev() {
+                let f = forward[i];
+                let b_shifted = if i > 0 { backward[i - 1] } else { N::zero() };
+
+                forward[i] = (f - err_f * b_shifted) / denom;
+                backward[i] = (b_shifted - err_b * f) / denom;
+            }
+
+            for c in 0..b.ncols() {
+                let mut err_x = N::zero();
+
+                for i in 0..k {
+                    err_x += self.diagonal_element((k - i) as isize) * b[(i, c)];
+                }
+
+                let coeff = b[(k, c)] - err_x;
+                b[(k, c)] = N::zero();
+
+                for i in 0..=k {
+                    b[(i, c)] += backward[i] * coeff;
+                }
+            }
+        }
+
+        true
+    }
+}
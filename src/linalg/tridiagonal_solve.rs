@@ -0,0 +1,121 @@
+//! Linear systems with a tridiagonal matrix.
+
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, MatrixMN, Vector, VectorN};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::dimension::{Dim, DimDiff, DimSub, U1};
+use crate::storage::{Storage, StorageMut};
+
+/// Computes the solution `x` of the linear system `a * x = b` where `a` is the tridiagonal matrix
+/// with the diagonal `diag`, the subdiagonal `lower` and the superdiagonal `upper`.
+///
+/// Returns `None` if a zero pivot is encountered. See `solve_tridiagonal_mut` for details.
+pub fn solve_tridiagonal<N, D, R2, C2, S1, S2, S3, S4>(
+    lower: &Vector<N, DimDiff<D, U1>, S1>,
+    diag: &Vector<N, D, S2>,
+    upper: &Vector<N, DimDiff<D, U1>, S3>,
+    b: &Matrix<N, R2, C2, S4>,
+) -> Option<MatrixMN<N, R2, C2>>
+where
+    N: ComplexField,
+    D: DimSub<U1>,
+    R2: Dim,
+    C2: Dim,
+    S1: Storage<N, DimDiff<D, U1>>,
+    S2: Storage<N, D>,
+    S3: Storage<N, DimDiff<D, U1>>,
+    S4: Storage<N, R2, C2>,
+    ShapeConstraint: SameNumberOfRows<R2, D>,
+    DefaultAllocator: Allocator<N, D> + Allocator<N, DimDiff<D, U1>> + Allocator<N, R2, C2>,
+{
+    let mut res = b.clone_owned();
+    if solve_tridiagonal_mut(lower, diag, upper, &mut res) {
+        Some(res)
+    } else {
+        None
+    }
+}
+
+/// Solves the linear system `a * x = b` where `a` is the tridiagonal matrix with the diagonal
+/// `diag`, the subdiagonal `lower` and the superdiagonal `upper`.
+///
+/// This uses the Thomas algorithm, i.e., a Gaussian elimination without pivoting, so it requires
+/// only `O(n)` operations per right-hand-side. Each column of `b` is a right-hand-side, and is
+/// overwritten by the corresponding solution.
+///
+/// Because no pivoting is performed, this is only guaranteed to be numerically stable if `a` is
+/// diagonally dominant or definite-positive, which is the case for, e.g., the systems arising from
+/// cubic spline interpolation or from finite-difference discretizations of the heat equation.
+/// Returns `false` and leaves `b` unchanged if a zero pivot is encountered.
+pub fn solve_tridiagonal_mut<N, D, R2, C2, S1, S2, S3, S4>(
+    lower: &Vector<N, DimDiff<D, U1>, S1>,
+    diag: &Vector<N, D, S2>,
+    upper: &Vector<N, DimDiff<D, U1>, S3>,
+    b: &mut Matrix<N, R2, C2, S4>,
+) -> bool
+where
+    N: ComplexField,
+    D: DimSub<U1>,
+    R2: Dim,
+    C2: Dim,
+    S1: Storage<N, DimDiff<D, U1>>,
+    S2: Storage<N, D>,
+    S3: Storage<N, DimDiff<D, U1>>,
+    S4: StorageMut<N, R2, C2>,
+    ShapeConstraint: SameNumberOfRows<R2, D>,
+    DefaultAllocator: Allocator<N, D> + Allocator<N, DimDiff<D, U1>>,
+{
+    let dim = diag.data.shape().0;
+    let n = dim.value();
+
+    assert_eq!(b.nrows(), n, "Tridiagonal solve matrix dimension mismatch.");
+
+    if n == 0 {
+        return true;
+    }
+
+    assert!(
+        lower.len() == n - 1 && upper.len() == n - 1,
+        "Tridiagonal solve: the off-diagonals must have one element less than the diagonal."
+    );
+
+    // Eliminate the subdiagonal once for all the right-hand-sides. After the elimination, the
+    // i-th equation reads `x[i] + factors[i] * x[i + 1] = y[i]`, where `y` is obtained from `b` by
+    // forward substitution.
+    let mut pivots: VectorN<N, D> = diag.clone_owned();
+    let mut factors: VectorN<N, DimDiff<D, U1>> = upper.clone_owned();
+
+    for i in 0..n {
+        if i > 0 {
+            pivots[i] -= lower[i - 1] * factors[i - 1];
+        }
+
+        if pivots[i].is_zero() {
+            return false;
+        }
+
+        if i < n - 1 {
+            factors[i] /= pivots[i];
+        }
+    }
+
+    for k in 0..b.ncols() {
+        let mut x = b.column_mut(k);
+
+        x[0] /= pivots[0];
+
+        for i in 1..n {
+            let x_prev = x[i - 1];
+            x[i] = (x[i] - lower[i - 1] * x_prev) / pivots[i];
+        }
+
+        for i in (0..n - 1).rev() {
+            let x_next = x[i + 1];
+            x[i] -= factors[i] * x_next;
+        }
+    }
+
+    true
+}
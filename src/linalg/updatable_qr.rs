@@ -0,0 +1,249 @@
+//! QR decomposition with explicit factors that can be updated when rows or columns are inserted
+//! or removed.
+
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
+use crate::base::dimension::{Dim, Dynamic, U1, U2};
+use crate::base::storage::Storage;
+use crate::base::{
+    DMatrix, DVector, DefaultAllocator, Matrix, MatrixMN, RowVector, Vector, Vector2,
+};
+
+use crate::linalg::givens::GivensRotation;
+
+/// The QR decomposition of a general matrix, with an explicit square unitary factor `Q`.
+///
+/// Unlike `QR`, this decomposition can be updated in `O(m * (m + n))` operations when a row or a
+/// column is inserted into, or removed from, the decomposed `m x n` matrix. This is useful for
+/// recursive least squares, or to solve least-squares problems on sliding windows.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(serialize = "DMatrix<N>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(bound(deserialize = "DMatrix<N>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct UpdatableQR<N: ComplexField> {
+    q: DMatrix<N>,
+    r: DMatrix<N>,
+}
+
+impl<N: ComplexField> UpdatableQR<N> {
+    /// Computes the QR decomposition of `matrix`.
+    pub fn new(matrix: DMatrix<N>) -> Self {
+        let (nrows, ncols) = matrix.shape();
+        let qr = matrix.qr();
+
+        let mut q = DMatrix::identity(nrows, nrows);
+        qr.q_tr_mul(&mut q);
+        let q = q.adjoint();
+
+        let mut r = DMatrix::zeros(nrows, ncols);
+        let r_min = qr.r();
+        r.rows_mut(0, r_min.nrows()).copy_from(&r_min);
+
+        UpdatableQR { q, r }
+    }
+
+    /// The square unitary matrix `Q` of this decomposition.
+    #[inline]
+    pub fn q(&self) -> &DMatrix<N> {
+        &self.q
+    }
+
+    /// The upper trapezoidal matrix `R` of this decomposition, with the same dimensions as the
+    /// decomposed matrix.
+    #[inline]
+    pub fn r(&self) -> &DMatrix<N> {
+        &self.r
+    }
+
+    /// Unpacks this decomposition into its two matrix factors `(Q, R)`.
+    #[inline]
+    pub fn unpack(self) -> (DMatrix<N>, DMatrix<N>) {
+        (self.q, self.r)
+    }
+
+    /// Rebuild the decomposed matrix, i.e., computes `Q * R`.
+    pub fn recompose(&self) -> DMatrix<N> {
+        &self.q * &self.r
+    }
+
+    /// Updates this decomposition such that it becomes the decomposition of the decomposed
+    /// matrix with `col` inserted as its `j`-th column.
+    pub fn insert_column<R2: Dim, S2>(self, j: usize, col: &Vector<N, R2, S2>) -> Self
+    where
+        S2: Storage<N, R2, U1>,
+        ShapeConstraint: SameNumberOfRows<Dynamic, R2>,
+    {
+        let (nrows, ncols) = self.r.shape();
+        assert_eq!(
+            col.len(),
+            nrows,
+            "The new column must have the same number of rows as the decomposed matrix."
+        );
+        assert!(
+            j <= ncols,
+            "j needs to be within the bound of the new matrix."
+        );
+
+        let UpdatableQR { mut q, r } = self;
+        let mut r = r.insert_column(j, N::zero());
+        let qtc: DVector<N> = q.ad_mul(col);
+        r.column_mut(j).copy_from::<Dynamic, U1, _>(&qtc);
+
+        // Cancel the new column below the diagonal, from the bottom.
+        for i in (j + 1..nrows).rev() {
+            let v = r.fixed_slice::<U2, U1>(i - 1, j).into_owned();
+
+            if let Some((rot, norm)) = GivensRotation::cancel_y(&v) {
+                rot.rotate(&mut r.fixed_rows_mut::<U2>(i - 1).columns_range_mut(j + 1..));
+                rot.inverse()
+                    .rotate_rows(&mut q.fixed_columns_mut::<U2>(i - 1));
+                r[(i - 1, j)] = norm;
+                r[(i, j)] = N::zero();
+            }
+        }
+
+        UpdatableQR { q, r }
+    }
+
+    /// Updates this decomposition such that it becomes the decomposition of the decomposed
+    /// matrix with its `j`-th column removed.
+    pub fn remove_column(self, j: usize) -> Self {
+        let (nrows, ncols) = self.r.shape();
+        assert!(j < ncols, "j needs to be within the bound of the matrix.");
+
+        let UpdatableQR { mut q, r } = self;
+        let mut r = r.remove_column(j);
+
+        // The columns after `j` have one non-zero subdiagonal element.
+        for i in j..(ncols - 1).min(nrows.saturating_sub(1)) {
+            let v = r.fixed_slice::<U2, U1>(i, i).into_owned();
+
+            if let Some((rot, norm)) = GivensRotation::cancel_y(&v) {
+                rot.rotate(&mut r.fixed_rows_mut::<U2>(i).columns_range_mut(i + 1..));
+                rot.inverse().rotate_rows(&mut q.fixed_columns_mut::<U2>(i));
+                r[(i, i)] = norm;
+                r[(i + 1, i)] = N::zero();
+            }
+        }
+
+        UpdatableQR { q, r }
+    }
+
+    /// Updates this decomposition such that it becomes the decomposition of the decomposed
+    /// matrix with `row` inserted as its `i`-th row.
+    pub fn insert_row<C2: Dim, S2>(self, i: usize, row: &RowVector<N, C2, S2>) -> Self
+    where
+        S2: Storage<N, U1, C2>,
+        ShapeConstraint: SameNumberOfColumns<Dynamic, C2>,
+    {
+        let (nrows, ncols) = self.r.shape();
+        assert_eq!(
+            row.len(),
+            ncols,
+            "The new row must have the same number of columns as the decomposed matrix."
+        );
+        assert!(
+            i <= nrows,
+            "i needs to be within the bound of the new matrix."
+        );
+
+        // With the new row on top of `R`, the matrix `diag(1, Q)`, whose first row is moved to
+        // the `i`-th position, is the unitary factor.
+        let UpdatableQR { q, r } = self;
+        let mut q = q.insert_row(i, N::zero()).insert_column(0, N::zero());
+        q[(i, 0)] = N::one();
+
+        let mut r = r.insert_row(0, N::zero());
+        r.row_mut(0).copy_from(row);
+
+        for k in 0..ncols.min(nrows) {
+            let v = r.fixed_slice::<U2, U1>(k, k).into_owned();
+
+            if let Some((rot, norm)) = GivensRotation::cancel_y(&v) {
+                rot.rotate(&mut r.fixed_rows_mut::<U2>(k).columns_range_mut(k + 1..));
+                rot.inverse().rotate_rows(&mut q.fixed_columns_mut::<U2>(k));
+                r[(k, k)] = norm;
+                r[(k + 1, k)] = N::zero();
+            }
+        }
+
+        UpdatableQR { q, r }
+    }
+
+    /// Updates this decomposition such that it becomes the decomposition of the decomposed
+    /// matrix with its `i`-th row removed.
+    pub fn remove_row(self, i: usize) -> Self {
+        let nrows = self.r.nrows();
+        assert!(i < nrows, "i needs to be within the bound of the matrix.");
+
+        let UpdatableQR { mut q, mut r } = self;
+
+        // Cancel the `i`-th row of `Q` except its first element. Because `Q` is unitary, its
+        // first column then becomes a multiple of the `i`-th canonical vector.
+        for k in (1..nrows).rev() {
+            let v = Vector2::new(q[(i, k - 1)].conjugate(), q[(i, k)].conjugate());
+
+            if let Some((rot, _)) = GivensRotation::cancel_y(&v) {
+                rot.rotate(&mut r.fixed_rows_mut::<U2>(k - 1));
+                rot.inverse()
+                    .rotate_rows(&mut q.fixed_columns_mut::<U2>(k - 1));
+                q[(i, k)] = N::zero();
+            }
+        }
+
+        let q = q.remove_row(i).remove_column(0);
+        let r = r.remove_row(0);
+
+        UpdatableQR { q, r }
+    }
+
+    /// Computes the least-squares solution of the linear system `self * x = b`, where `x` is the
+    /// unknown to be determined.
+    ///
+    /// Returns `None` if the decomposed matrix has more columns than rows, or if its columns
+    /// are linearly dependent.
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<N, R2, C2, S2>,
+    ) -> Option<MatrixMN<N, Dynamic, C2>>
+    where
+        S2: Storage<N, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<Dynamic, R2>,
+        DefaultAllocator: Allocator<N, Dynamic, C2>,
+    {
+        let (nrows, ncols) = self.r.shape();
+        assert_eq!(
+            nrows,
+            b.nrows(),
+            "UpdatableQR solve matrix dimension mismatch."
+        );
+
+        if ncols > nrows {
+            return None;
+        }
+
+        let qtb = self.q.ad_mul(b);
+        let mut res = qtb.rows(0, ncols).into_owned();
+
+        if self
+            .r
+            .slice((0, 0), (ncols, ncols))
+            .solve_upper_triangular_mut(&mut res)
+        {
+            Some(res)
+        } else {
+            None
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use na::{geometry::Quaternion, Matrix2, Vector3};
+use na::{geometry::Quaternion, DMatrix, Matrix2, Matrix2x3, Vector2, Vector3};
 use num_traits::{One, Zero};
 
 #[test]
@@ -21,6 +21,123 @@ fn gemm_noncommutative() {
     assert_eq!(res, Matrix2::zero());
 }
 
+#[test]
+fn ger_accumulate() {
+    let x = Vector2::new(1.0, 2.0);
+    let y = Vector3::new(3.0, 4.0, 5.0);
+
+    let mut a = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    a.ger(2.0, &x, &y, 0.5);
+    assert_eq!(a, Matrix2x3::new(6.5, 9.0, 11.5, 14.0, 18.5, 23.0));
+
+    // With a zero `beta`, the initial content of the matrix must not be read.
+    let mut a = Matrix2x3::repeat(std::f64::NAN);
+    a.ger(2.0, &x, &y, 0.0);
+    assert_eq!(a, x * y.transpose() * 2.0);
+}
+
+#[test]
+fn gemm_converted() {
+    let a = DMatrix::from_fn(3, 150, |i, j| ((i * 7 + j * 3) % 251) as u8);
+    let b = DMatrix::from_fn(150, 2, |i, j| ((i * 5 + j * 11) % 253) as u8);
+    let expected = a.map(|e| e as f32) * b.map(|e| e as f32);
+
+    // The inner dimension spans several conversion blocks.
+    let mut res = DMatrix::repeat(3, 2, 1.0f32);
+    res.gemm_converted(2.0, &a, &b, 3.0);
+    assert_eq!(res, &expected * 2.0 + DMatrix::repeat(3, 2, 3.0));
+
+    // With a zero `beta`, the initial content of the matrix must not be read.
+    let mut res = DMatrix::repeat(3, 2, std::f32::NAN);
+    res.gemm_converted(1.0, &a, &b, 0.0);
+    assert_eq!(res, expected);
+
+    let a = Matrix2x3::new(1.5f32, 2.0, -3.0, 4.0, 0.25, 6.0);
+    let b = Matrix2x3::new(1.0f32, -2.0, 3.0, 0.5, 5.0, 6.0).transpose();
+    let mut res = Matrix2::identity();
+    res.gemm_converted(1.0f64, &a, &b, 1.0);
+    assert_eq!(
+        res,
+        a.map(f64::from) * b.map(f64::from) + Matrix2::identity()
+    );
+
+    // An empty inner dimension only scales the matrix.
+    let mut res = Matrix2::new(1.0f64, 2.0, 3.0, 4.0);
+    res.gemm_converted(
+        1.0,
+        &DMatrix::<f32>::zeros(2, 0),
+        &DMatrix::zeros(0, 2),
+        2.0,
+    );
+    assert_eq!(res, Matrix2::new(2.0, 4.0, 6.0, 8.0));
+}
+
+#[test]
+fn gemv_strided_operands() {
+    let big = DMatrix::from_fn(6, 6, |i, j| (i * 6 + j) as f64);
+
+    // Every other row of `big`, so both the columns of `a` and `x` have a row stride of 2.
+    let a = big.slice_with_steps((0, 0), (3, 3), (1, 1));
+    let column = big.column(1);
+    let x = column.rows_step_by(0, 2);
+
+    let mut res = Vector3::new(1.0, 2.0, 3.0);
+    res.gemv(2.0, &a, &x, 0.5);
+    assert_eq!(
+        res,
+        a.clone_owned() * x.clone_owned() * 2.0 + Vector3::new(0.5, 1.0, 1.5)
+    );
+
+    let mut res = Vector3::new(1.0, 2.0, 3.0);
+    res.axcpy(2.0, &x, 3.0, 0.0);
+    assert_eq!(res, x.clone_owned() * 6.0);
+}
+
+#[test]
+fn half_precision_storage() {
+    use half::{bf16, f16};
+
+    let m = DMatrix::from_fn(3, 4, |i, j| f16::from_f32((i * 4 + j) as f32 * 0.5));
+    assert_eq!(m[(2, 3)], f16::from_f32(5.5));
+    assert_eq!(m.transpose()[(3, 2)], m[(2, 3)]);
+    assert_eq!(
+        m.map(f32::from),
+        DMatrix::from_fn(3, 4, |i, j| (i * 4 + j) as f32 * 0.5)
+    );
+
+    // The conversion back to half-precision rounds to the nearest representable value, e.g.,
+    // `171 / 512` for `1 / 3`.
+    let m = Matrix2::new(1.0f32, 1.0 / 3.0, -2.5, 65504.0).map(bf16::from_f32);
+    assert_eq!(
+        m.map(f32::from),
+        Matrix2::new(1.0, 171.0 / 512.0, -2.5, 65536.0)
+    );
+}
+
+#[test]
+fn gemm_converted_half() {
+    use half::{bf16, f16};
+
+    let a = DMatrix::from_fn(4, 100, |i, j| f16::from_f32(((i + 2 * j) % 7) as f32 - 3.0));
+    let b = DMatrix::from_fn(100, 3, |i, j| {
+        f16::from_f32(((3 * i + j) % 5) as f32 * 0.25)
+    });
+    let expected = a.map(f32::from) * b.map(f32::from);
+
+    let mut res = DMatrix::zeros(4, 3);
+    res.gemm_converted(1.0f32, &a, &b, 0.0);
+    assert_eq!(res, expected);
+
+    let a = a.map(|e| bf16::from_f32(e.to_f32()));
+    let b = b.map(|e| bf16::from_f32(e.to_f32()));
+    let mut res = DMatrix::repeat(4, 3, 1.0f64);
+    res.gemm_converted(0.5, &a, &b, 2.0);
+    assert_eq!(
+        res,
+        expected.map(f64::from) * 0.5 + DMatrix::repeat(4, 3, 2.0)
+    );
+}
+
 #[cfg(feature = "arbitrary")]
 mod blas_quickcheck {
     use na::{DMatrix, DVector};
@@ -96,6 +213,63 @@ mod blas_quickcheck {
             relative_eq!(a1.lower_triangle(), a2)
         }
 
+        fn symmetric_rank_k_update(n: usize, k: usize, alpha: f64, beta: f64) -> bool {
+            let n = cmp::max(1, cmp::min(n, 50));
+            let k = cmp::min(k, 20);
+            let a = DMatrix::<f64>::new_random(n, k);
+            let c = DMatrix::<f64>::new_random(n, n);
+            let mut c1 = &c * c.transpose();
+            let mut c2 = c1.lower_triangle();
+
+            c1 = &a * a.transpose() * alpha + c1 * beta;
+            c2.symmetric_rank_k_update(alpha, &a, beta);
+
+            if !relative_eq!(c1.lower_triangle(), c2, epsilon = 1.0e-7) {
+                return false;
+            }
+
+            c1 = &a * a.transpose() * alpha;
+            c2.symmetric_rank_k_update(alpha, &a, 0.0);
+
+            relative_eq!(c1.lower_triangle(), c2, epsilon = 1.0e-7)
+        }
+
+        fn triangular_mul(n: usize, k: usize) -> bool {
+            let n = cmp::max(1, cmp::min(n, 50));
+            let k = cmp::min(k, 20);
+            let a = DMatrix::<f64>::new_random(n, n);
+            let b = DMatrix::<f64>::new_random(n, k);
+
+            relative_eq!(a.lower_triangular_mul(&b), a.lower_triangle() * &b, epsilon = 1.0e-7)
+                && relative_eq!(a.upper_triangular_mul(&b), a.upper_triangle() * &b, epsilon = 1.0e-7)
+        }
+
+        fn gemm_with_transpositions(n: usize, alpha: f64, beta: f64) -> bool {
+            let n = cmp::max(1, cmp::min(n, 50));
+            let a = DMatrix::<f64>::new_random(n, 4);
+            let b = DMatrix::<f64>::new_random(4, 3);
+            let c = DMatrix::<f64>::new_random(n, 3);
+
+            let expected = &a * &b * alpha + &c * beta;
+            let (at, bt) = (a.transpose(), b.transpose());
+
+            for (a, b, transpose_a, transpose_b) in vec![
+                (&a, &b, false, false),
+                (&at, &b, true, false),
+                (&a, &bt, false, true),
+                (&at, &bt, true, true),
+            ] {
+                let mut res = c.clone();
+                res.gemm_with_transpositions(alpha, a, b, beta, transpose_a, transpose_b);
+
+                if !relative_eq!(res, expected, epsilon = 1.0e-7) {
+                    return false;
+                }
+            }
+
+            true
+        }
+
         fn quadform(n: usize, alpha: f64, beta: f64) -> bool {
             let n       = cmp::max(1, cmp::min(n, 50));
             let rhs     = DMatrix::<f64>::new_random(6, n);
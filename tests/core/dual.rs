@@ -0,0 +1,105 @@
+use std::cmp::Ordering;
+
+use na::{
+    dual, ComplexField, DMatrix, DVector, Dual, Matrix2, Matrix3, Point2, RealField, Rotation2,
+    Vector1, Vector2, Vector3,
+};
+
+#[test]
+fn dual_elementary_functions() {
+    let x = Dual::variable(0.3f64);
+    let d = |f: fn(Dual<f64>) -> Dual<f64>| f(x).eps;
+
+    assert_relative_eq!(d(|x| x.sin()), 0.3f64.cos(), epsilon = 1.0e-14);
+    assert_relative_eq!(
+        d(|x| x.tan()),
+        1.0 / 0.3f64.cos().powi(2),
+        epsilon = 1.0e-14
+    );
+    assert_relative_eq!(
+        d(|x| x.asin()),
+        1.0 / (1.0 - 0.09f64).sqrt(),
+        epsilon = 1.0e-14
+    );
+    assert_relative_eq!(d(|x| x.atanh()), 1.0 / (1.0 - 0.09), epsilon = 1.0e-14);
+    assert_relative_eq!(
+        d(|x| x.exp() * x.ln()),
+        0.3f64.exp() * (0.3f64.ln() + 1.0 / 0.3)
+    );
+    assert_relative_eq!(d(|x| x.powi(3)), 3.0 * 0.09, epsilon = 1.0e-14);
+    assert_relative_eq!(d(|x| x.powf(x)), 0.3f64.powf(0.3) * (0.3f64.ln() + 1.0));
+    assert_relative_eq!(d(|x| x.sqrt().recip()), -0.5 * 0.3f64.powf(-1.5));
+    assert_relative_eq!(
+        d(|x| x.atan2(Dual::constant(2.0))),
+        2.0 / (0.09 + 4.0),
+        epsilon = 1.0e-14
+    );
+
+    // Constant exponents do not differentiate `ln(0)`.
+    assert_eq!(Dual::variable(0.0f64).powf(Dual::constant(2.0)).eps, 0.0);
+    assert_eq!(Dual::variable(-2.0f64).abs().eps, -1.0);
+    assert_eq!(Dual::<f64>::pi().re, f64::pi());
+}
+
+#[test]
+fn dual_comparisons_ignore_derivatives() {
+    let a = Dual::new(1.0f64, 2.0);
+    let b = Dual::new(1.0f64, -3.0);
+
+    assert_eq!(a, b);
+    assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+    assert_relative_eq!(a, b);
+    assert_eq!(a.to_string(), "1 + 2ε");
+    assert_eq!(b.to_string(), "1 - 3ε");
+}
+
+#[test]
+fn dual_jacobian_of_linear_algebra() {
+    let a = Matrix3::new(4.0, 1.0, 0.5, 1.0, 3.0, -1.0, 0.5, -1.0, 5.0);
+    let x = Vector3::new(1.0, -2.0, 0.5);
+
+    // The Jacobian of `x -> a⁻¹ x` computed through an LU decomposition is `a⁻¹`.
+    let ad = a.map(Dual::constant);
+    let j = dual::jacobian(|x: &Vector3<Dual<f64>>| ad.lu().solve(x).unwrap(), &x);
+    assert_relative_eq!(j, a.try_inverse().unwrap(), epsilon = 1.0e-12);
+
+    // The derivative of the determinant is given by Jacobi's formula.
+    let b = Matrix3::new(1.0, 0.0, 2.0, -1.0, 1.0, 0.0, 0.0, 3.0, 1.0);
+    let t = Dual::variable(0.0);
+    let det = (ad + b.map(Dual::constant) * t).determinant();
+    let expected = a.determinant() * (a.try_inverse().unwrap() * b).trace();
+    assert_relative_eq!(det.eps, expected, epsilon = 1.0e-10);
+
+    // Dynamically-sized vectors.
+    let x = DVector::from_vec(vec![1.0, 2.0, 3.0]);
+    let j = dual::jacobian(
+        |x: &DVector<Dual<f64>>| DVector::from_element(1, x.norm()),
+        &x,
+    );
+    assert_relative_eq!(j, DMatrix::from_row_slice(1, 3, x.normalize().as_slice()));
+}
+
+#[test]
+fn dual_jacobian_of_geometry() {
+    let p = Point2::new(2.0, 1.0);
+    let angle = 0.7;
+
+    let j = dual::jacobian(
+        |angle: &Vector1<Dual<f64>>| {
+            let rot = Rotation2::new(angle[0]);
+            (rot * p.map(Dual::constant)).coords
+        },
+        &Vector1::new(angle),
+    );
+
+    let (sin, cos) = f64::sin_cos(angle);
+    let expected = Vector2::new(-sin * p.x - cos * p.y, cos * p.x - sin * p.y);
+    assert_relative_eq!(j.column(0).into_owned(), expected, epsilon = 1.0e-14);
+
+    let m = Matrix2::new(1.0, 2.0, 3.0, 4.0);
+    let j = dual::jacobian(
+        |x: &Vector2<Dual<f64>>| m.map(Dual::constant) * x,
+        &Vector2::zeros(),
+    );
+    assert_eq!(j, m);
+}
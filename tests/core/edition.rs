@@ -1,6 +1,6 @@
 use na::{
-    DMatrix, Matrix, Matrix3, Matrix3x4, Matrix3x5, Matrix4, Matrix4x3, Matrix4x5, Matrix5,
-    Matrix5x3, Matrix5x4,
+    DMatrix, Matrix, Matrix2x3, Matrix3, Matrix3x2, Matrix3x4, Matrix3x5, Matrix4, Matrix4x3,
+    Matrix4x5, Matrix5, Matrix5x3, Matrix5x4,
 };
 use na::{Dynamic, U2, U3, U5};
 
@@ -577,6 +577,65 @@ fn insert_rows_to_empty_matrix() {
     assert_eq!(expected2, m2.insert_rows(0, 3, 42));
 }
 
+#[test]
+#[rustfmt::skip]
+fn insert_columns_from() {
+    let m = Matrix3::new(
+        11, 14, 15,
+        21, 24, 25,
+        31, 34, 35);
+    let columns = Matrix3x2::new(
+        12, 13,
+        22, 23,
+        32, 33);
+
+    let expected = Matrix3x5::new(
+        11, 12, 13, 14, 15,
+        21, 22, 23, 24, 25,
+        31, 32, 33, 34, 35);
+
+    assert_eq!(m.insert_columns_from(1, &columns), expected);
+
+    let computed: Matrix<_, U3, Dynamic, _> =
+        m.insert_columns_from(1, &DMatrix::from_column_slice(3, 2, columns.as_slice()));
+    assert!(computed.eq(&expected));
+
+    let computed = DMatrix::from_column_slice(3, 3, m.as_slice()).insert_columns_from(3, &columns);
+    assert_eq!(computed.columns(3, 2), columns);
+}
+
+#[test]
+#[rustfmt::skip]
+fn insert_rows_from() {
+    let m = Matrix3::new(
+        11, 12, 13,
+        41, 42, 43,
+        51, 52, 53);
+    let rows = Matrix2x3::new(
+        21, 22, 23,
+        31, 32, 33);
+
+    let expected = Matrix5x3::new(
+        11, 12, 13,
+        21, 22, 23,
+        31, 32, 33,
+        41, 42, 43,
+        51, 52, 53);
+
+    assert_eq!(m.insert_rows_from(1, &rows), expected);
+
+    let computed = DMatrix::from_column_slice(3, 3, m.as_slice()).insert_rows_from(0, &rows);
+    assert_eq!(computed.rows(0, 2), rows);
+    assert_eq!(computed.rows(2, 3), m);
+}
+
+#[test]
+#[should_panic]
+fn insert_columns_from_wrong_dimensions() {
+    let m = DMatrix::repeat(3, 2, 0);
+    let _ = m.insert_columns_from(1, &DMatrix::repeat(2, 2, 1));
+}
+
 #[test]
 #[rustfmt::skip]
 fn resize() {
@@ -680,3 +739,26 @@ fn resize_empty_matrix() {
     assert_eq!(m1, m6.resize(0, 0, 42));
     assert_eq!(m1, m7.resize(0, 0, 42));
 }
+
+#[test]
+fn into_vector_from_vector() {
+    let m = DMatrix::from_row_slice(2, 3, &[11, 12, 13, 21, 22, 23]);
+    let v = m.clone().into_vector();
+
+    assert_eq!(v.as_slice(), m.as_slice());
+    assert_eq!(DMatrix::from_vector(v.clone(), 2, 3), m);
+    assert_eq!(
+        DMatrix::from_vector(v, 3, 2),
+        m.reshape_generic(Dynamic::new(3), Dynamic::new(2))
+    );
+
+    let m = Matrix::<_, U2, Dynamic, _>::from_row_slice(&[1, 2, 3, 4]);
+    assert_eq!(m.into_vector().as_slice(), &[1, 3, 2, 4]);
+}
+
+#[test]
+#[should_panic]
+fn from_vector_wrong_dimensions() {
+    let v = na::DVector::from_row_slice(&[1, 2, 3, 4, 5, 6]);
+    let _ = DMatrix::from_vector(v, 4, 2);
+}
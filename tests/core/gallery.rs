@@ -0,0 +1,80 @@
+use na::{gallery, DMatrix, DVector};
+
+#[test]
+fn hilbert_is_ill_conditioned() {
+    let h = gallery::hilbert::<f64>(6);
+    let svals = h.singular_values();
+
+    assert_eq!(h, h.transpose());
+    assert_relative_eq!(h[(5, 5)], 1.0 / 11.0);
+    assert!(svals.max() / svals.min() > 1.0e7);
+}
+
+#[test]
+fn vandermonde_evaluates_polynomials() {
+    let points = DVector::from_column_slice(&[-1.0, 0.5, 2.0, 3.0]);
+    let coeffs = DVector::from_column_slice(&[1.0, -2.0, 3.0]);
+    let v = gallery::vandermonde(&points, 3);
+
+    assert_eq!(v.shape(), (4, 3));
+    assert_relative_eq!(
+        v * coeffs,
+        points.map(|x| 1.0 - 2.0 * x + 3.0 * x * x),
+        epsilon = 1.0e-12
+    );
+}
+
+#[test]
+fn pascal_and_frank_have_unit_determinant() {
+    let p = gallery::pascal::<f64>(6);
+    assert_eq!(p[(5, 5)], 252.0);
+    assert_relative_eq!(p.determinant(), 1.0, epsilon = 1.0e-6);
+
+    let f = gallery::frank::<f64>(6);
+    assert_eq!(f[(5, 3)], 0.0);
+    assert_eq!(f[(5, 4)], 1.0);
+    assert_eq!(f[(0, 5)], 1.0);
+    assert_relative_eq!(f.determinant(), 1.0, epsilon = 1.0e-9);
+}
+
+#[test]
+fn wilkinson_has_close_eigenvalues() {
+    let w = gallery::wilkinson::<f64>(21);
+    let mut eigenvalues: Vec<f64> = w.symmetric_eigenvalues().iter().cloned().collect();
+    eigenvalues.sort_by(|a, b| b.partial_cmp(a).unwrap());
+
+    assert_eq!(w[(0, 0)], 10.0);
+    assert_eq!(w[(10, 10)], 0.0);
+    assert_relative_eq!(eigenvalues[0], 10.746194182903393, epsilon = 1.0e-10);
+    assert!(eigenvalues[0] - eigenvalues[1] < 1.0e-10);
+}
+
+#[test]
+fn random_matrices() {
+    let mut rng = rand::thread_rng();
+
+    let q = gallery::random_orthogonal::<f64, _>(5, &mut rng);
+    assert!(q.is_orthogonal(1.0e-7));
+
+    for n in 1..6 {
+        let rot = gallery::random_rotation::<f64, _>(n, &mut rng);
+        assert!(rot.is_orthogonal(1.0e-7));
+        assert_relative_eq!(rot.determinant(), 1.0, epsilon = 1.0e-7);
+    }
+
+    let a = gallery::random_spd::<f64, _>(5, 1.0e4, &mut rng);
+    let eigenvalues = a.clone().symmetric_eigenvalues();
+    assert_eq!(a, a.transpose());
+    assert_relative_eq!(eigenvalues.max(), 1.0, epsilon = 1.0e-7);
+    assert_relative_eq!(eigenvalues.min(), 1.0e-4, epsilon = 1.0e-7);
+
+    let c = gallery::random_correlation::<f64, _>(5, &mut rng);
+    assert_eq!(c, c.transpose());
+    assert_eq!(c.diagonal(), DVector::repeat(5, 1.0));
+    assert!(c.cholesky().is_some());
+
+    assert_eq!(
+        gallery::random_orthogonal::<f64, _>(0, &mut rng),
+        DMatrix::zeros(0, 0)
+    );
+}
@@ -0,0 +1,72 @@
+use na::interval;
+use na::{DMatrix, DVector, Interval, Matrix2, Matrix3, Vector3};
+use num::{One, Zero};
+
+#[test]
+fn interval_arithmetic_rounds_outward() {
+    let third = Interval::one() / Interval::point(3.0f64);
+    assert!(third.lo() < 1.0 / 3.0 && 1.0 / 3.0 < third.hi());
+    // The bounds are adjacent floats, and `next_up` is not an inherent method before Rust 1.86.
+    let next_up = interval::IntervalBound::next_up;
+    assert_eq!(next_up(next_up(third.lo())), third.hi());
+
+    let x = Interval::new(-1.0f64, 2.0);
+    let y = Interval::new(3.0, 4.0);
+
+    assert!(Interval::new(2.0, 6.0).is_subset(&(x + y)));
+    assert!(Interval::new(-5.0, -1.0).is_subset(&(x - y)));
+    assert!(Interval::new(-4.0, 8.0).is_subset(&(x * y)));
+    assert!((x * y).width() < 12.0 + 1.0e-14);
+    assert_eq!(-x, Interval::new(-2.0, 1.0));
+    assert_eq!(y / x, Interval::entire());
+
+    // Exact zeros remain exact.
+    assert_eq!(x * Interval::zero(), Interval::zero());
+    assert_eq!(x.hull(&y), Interval::new(-1.0, 4.0));
+    assert_eq!(x.intersection(&y), None);
+    assert_eq!(x.mag(), 2.0);
+    assert_eq!(x.to_string(), "[-1, 2]");
+}
+
+#[test]
+fn interval_matrix_operations() {
+    let a = Matrix2::new(0.1f64, 0.2, 0.3, 0.4).map(Interval::point);
+    let b = Matrix2::new(1.0, -1.0, 0.5, 2.0).map(Interval::point);
+
+    let prod = a * b;
+    let expected = Matrix2::new(0.1f64, 0.2, 0.3, 0.4) * Matrix2::new(1.0, -1.0, 0.5, 2.0);
+
+    for (e, x) in prod.iter().zip(expected.iter()) {
+        assert!(e.contains(*x) && e.width() < 1.0e-15);
+    }
+
+    let sum = DMatrix::from_element(3, 3, Interval::point(0.1f32)).row_sum();
+    assert!(sum[0].contains(0.1 + 0.1 + 0.1));
+}
+
+#[test]
+fn interval_verified_solve() {
+    let a = Matrix3::new(2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0);
+    let x = interval::verified_solve(&a, &Vector3::new(1.0, 0.0, 1.0)).unwrap();
+
+    // The exact solution is `(1, 1, 1)`.
+    for e in x.iter() {
+        assert!(e.contains(1.0) && e.width() < 1.0e-14);
+    }
+
+    // The Hilbert matrix of order 8 is ill-conditioned but still verifiable in `f64`.
+    let n = 8;
+    let h = DMatrix::from_fn(n, n, |i, j| 1.0 / (i + j + 1) as f64);
+    let b = DVector::from_element(n, 1.0);
+    let x = interval::verified_solve(&h, &b).unwrap();
+    let approx = h.clone().lu().solve(&b).unwrap();
+
+    for (e, x) in x.iter().zip(approx.iter()) {
+        assert!(e.contains(*x));
+    }
+
+    // Too ill-conditioned to be verified.
+    let n = 14;
+    let h = DMatrix::from_fn(n, n, |i, j| 1.0 / (i + j + 1) as f64);
+    assert!(interval::verified_solve(&h, &DVector::from_element(n, 1.0)).is_none());
+}
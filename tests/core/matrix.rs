@@ -1,7 +1,7 @@
 use num::{One, Zero};
 use std::cmp::Ordering;
 
-use na::dimension::{U15, U2, U4, U8};
+use na::dimension::{U15, U2, U4, U6, U8};
 use na::{
     self, DMatrix, DVector, Matrix2, Matrix2x3, Matrix2x4, Matrix3, Matrix3x2, Matrix3x4, Matrix4,
     Matrix4x3, Matrix4x5, Matrix5, Matrix6, MatrixMN, RowVector3, RowVector4, RowVector5, Vector1,
@@ -176,6 +176,227 @@ fn from_rows() {
     assert_eq!(a, expected);
 }
 
+#[test]
+fn checked_constructors() {
+    let expected = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    let mismatch = |expected, found| na::ElementCountMismatch { expected, found };
+
+    assert_eq!(
+        Matrix2x3::try_from_row_slice(&[1, 2, 3, 4, 5, 6]),
+        Ok(expected)
+    );
+    assert_eq!(
+        Matrix2x3::try_from_column_slice(&[1, 4, 2, 5, 3, 6]),
+        Ok(expected)
+    );
+    assert_eq!(
+        Matrix2x3::try_from_vec(vec![1, 4, 2, 5, 3, 6]),
+        Ok(expected)
+    );
+    assert_eq!(
+        Matrix2x3::try_from_iterator(vec![1, 4, 2, 5, 3, 6]),
+        Ok(expected)
+    );
+
+    assert_eq!(
+        Matrix2x3::try_from_row_slice(&[1, 2, 3, 4, 5]),
+        Err(mismatch(6, 5))
+    );
+    assert_eq!(Matrix2x3::try_from_iterator(0..7), Err(mismatch(6, 7)));
+    assert_eq!(
+        DMatrix::try_from_vec(2, 3, vec![1, 2, 3, 4]),
+        Err(mismatch(6, 4))
+    );
+    assert_eq!(
+        DMatrix::try_from_iterator(3, 2, 0..6),
+        Ok(DMatrix::from_iterator(3, 2, 0..6))
+    );
+
+    // The dynamic dimension is deduced from the number of components.
+    assert_eq!(
+        MatrixMN::<i32, U2, na::Dynamic>::try_from_row_slice(&[1, 2, 3, 4, 5, 6]),
+        Ok(MatrixMN::<i32, U2, na::Dynamic>::from_row_slice(&[
+            1, 2, 3, 4, 5, 6
+        ]))
+    );
+    assert_eq!(
+        MatrixMN::<i32, U2, na::Dynamic>::try_from_row_slice(&[1, 2, 3, 4, 5]),
+        Err(mismatch(4, 5))
+    );
+    assert_eq!(
+        DVector::<i32>::try_from_vec(vec![1, 2, 3]).map(|v| v.len()),
+        Ok(3)
+    );
+}
+
+#[test]
+fn const_constructors() {
+    const ROWS: Matrix2x3<i32> = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    const COLUMN: Vector3<f64> = Vector3::new(1.0, 2.0, 3.0);
+    static TABLE: [Matrix2<u8>; 2] = [Matrix2::new(1, 0, 0, 1), Matrix2::new(0, 1, 1, 0)];
+
+    assert_eq!(ROWS, Matrix2x3::from_row_slice(&[1, 2, 3, 4, 5, 6]));
+    assert_eq!(COLUMN, Vector3::from_column_slice(&[1.0, 2.0, 3.0]));
+    assert_eq!(TABLE[1] * TABLE[1], TABLE[0]);
+}
+
+#[test]
+fn const_identity() {
+    const I3: Matrix3<f64> = Matrix3::IDENTITY;
+    const I2X3: Matrix2x3<i32> = Matrix2x3::IDENTITY;
+
+    assert_eq!(I3, Matrix3::identity());
+    assert_eq!(I2X3, Matrix2x3::identity());
+    assert_eq!(Vector3::<u8>::IDENTITY, Vector3::x());
+}
+
+#[test]
+#[rustfmt::skip]
+fn row_major_matrices() {
+    use na::{DRowMajorMatrix, RowMajorMatrixMN};
+
+    let expected = Matrix2x3::new(1.0, 2.0, 3.0,
+                                  4.0, 5.0, 6.0);
+    let mut m = DRowMajorMatrix::from_row_major_vec(2, 3, vec![1.0, 2.0, 3.0,
+                                                          4.0, 5.0, 6.0]);
+    assert_eq!(m, expected);
+    assert_eq!(m[(1, 0)], 4.0);
+    assert_eq!(m.column(2), Vector2::new(3.0, 6.0));
+    assert_eq!(m.transpose(), expected.transpose());
+
+    // The operations with column-major matrices take the strides into account.
+    assert_eq!(&m + expected, expected * 2.0);
+    assert_eq!(&m * Vector3::new(1.0, 0.0, -1.0), Vector2::new(-2.0, -2.0));
+    assert_eq!(&m * expected.transpose(), expected * expected.transpose());
+
+    m.row_mut(1).fill(0.0);
+    m[(0, 1)] = -2.0;
+    assert_eq!(m.as_row_major_slice(), &[1.0, -2.0, 3.0, 0.0, 0.0, 0.0]);
+
+    // Conversions between the row-major and column-major storages.
+    let column_major: DMatrix<f64> = m.clone_owned();
+    assert_eq!(column_major.as_slice(), &[1.0, 0.0, -2.0, 0.0, 3.0, 0.0]);
+    assert_eq!(column_major.to_row_major(), m);
+    assert_eq!(m.into_row_major_vec(), vec![1.0, -2.0, 3.0, 0.0, 0.0, 0.0]);
+
+    let s = RowMajorMatrixMN::<_, U2, U4>::from_row_major_vec((0..8).collect());
+    assert_eq!(s.into_owned(), Matrix2x4::new(0, 1, 2, 3, 4, 5, 6, 7));
+    let t = Matrix3x2::new(1, 2, 3, 4, 5, 6).to_row_major();
+    assert_eq!(t.as_row_major_slice(), &[1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+#[should_panic]
+fn row_major_matrix_wrong_length() {
+    let _ = na::DRowMajorMatrix::from_row_major_vec(2, 3, vec![0.0; 5]);
+}
+
+#[test]
+fn const_generic_aliases() {
+    let m: na::SMatrix<f64, 3, 4> = Matrix3x4::from_fn(|i, j| (i * 4 + j) as f64);
+    let v: na::SVector<f64, 4> = Vector4::new(1.0, 0.0, 0.0, 0.0);
+    assert_eq!(m * v, Vector3::new(0.0, 4.0, 8.0));
+
+    // Dimensions beyond the named ones work as well.
+    let big = na::SVector::<u32, 200>::from_element(2);
+    assert_eq!(big.len(), 200);
+    assert_eq!(big.dot(&big), 800);
+
+    let wide = na::SMatrix::<u32, 2, 200>::from_element(1);
+    assert_eq!(wide * big, Vector2::new(400, 400));
+}
+
+#[test]
+fn format_adaptors() {
+    let m = DMatrix::from_row_slice(3, 2, &[1.5, -20.0, 300.0, 4.0, 0.0, 0.25]);
+
+    assert_eq!(m.format().to_string(), "1.5   -20\n300     4\n  0  0.25\n");
+    assert_eq!(
+        format!("{:.1}", m.format()),
+        "  1.5  -20.0\n300.0    4.0\n  0.0    0.2\n"
+    );
+    // The precision of the adaptor takes precedence over the one of the format string.
+    assert_eq!(
+        format!("{:.1}", m.format().precision(0).csv()),
+        "2,-20\n300,4\n0,0\n"
+    );
+    assert_eq!(
+        m.format().precision(2).latex().to_string(),
+        "\\begin{pmatrix}\n  1.50 & -20.00 \\\\\n  300.00 & 4.00 \\\\\n  0.00 & 0.25\n\\end{pmatrix}\n"
+    );
+
+    let empty = DMatrix::<f64>::zeros(0, 3);
+    assert_eq!(empty.format().to_string(), "");
+    assert_eq!(empty.format().csv().to_string(), "");
+    assert_eq!(
+        empty.format().latex().to_string(),
+        "\\begin{pmatrix}\n\\end{pmatrix}\n"
+    );
+}
+
+#[test]
+fn parse_matrices() {
+    use na::ParseMatrixError;
+
+    let expected = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, -5.0, 6.5]);
+
+    let layouts = [
+        "[1 2 3; 4 -5 6.5]",
+        "1, 2, 3; 4, -5, 6.5;",
+        "1 2 3\n4 -5 6.5\n",
+        "  1,2,3\r\n\r\n4,-5,6.5\r\n",
+        "[1.0 2.0 3.0\n 4.0 -5.0 6.5]",
+    ];
+
+    for layout in layouts.iter() {
+        assert_eq!(DMatrix::<f64>::from_str_rows(layout), Ok(expected.clone()));
+        assert_eq!(
+            layout.parse::<Matrix2x3<f64>>(),
+            Ok(Matrix2x3::new(1.0, 2.0, 3.0, 4.0, -5.0, 6.5))
+        );
+    }
+
+    assert_eq!("[]".parse::<DMatrix<f64>>(), Ok(DMatrix::zeros(0, 0)));
+    assert_eq!(
+        "1 2 3".parse::<RowVector3<i32>>(),
+        Ok(RowVector3::new(1, 2, 3))
+    );
+    assert_eq!(
+        "1; 2; 3".parse::<DVector<i32>>(),
+        Ok(DVector::from_row_slice(&[1, 2, 3]))
+    );
+
+    let complex = "[1+2i 0; 0 3-1i]"
+        .parse::<Matrix2<na::Complex<f64>>>()
+        .unwrap();
+    assert_eq!(complex[(0, 0)], na::Complex::new(1.0, 2.0));
+
+    assert_eq!(
+        "1 2; 3 x".parse::<DMatrix<i32>>(),
+        Err(ParseMatrixError::InvalidComponent {
+            row: 1,
+            column: 1,
+            text: "x".to_string()
+        })
+    );
+    assert_eq!(
+        "1 2\n3 4 5".parse::<DMatrix<i32>>(),
+        Err(ParseMatrixError::RaggedRow {
+            row: 1,
+            expected: 2,
+            found: 3
+        })
+    );
+    assert_eq!(
+        "1 2; 3 4".parse::<Matrix2x3<i32>>(),
+        Err(ParseMatrixError::ShapeMismatch { nrows: 2, ncols: 2 })
+    );
+    assert_eq!(
+        "1 2; 3 4".parse::<DVector<i32>>().unwrap_err().to_string(),
+        "the parsed 2x2 matrix does not have the dimensions of the matrix type"
+    );
+}
+
 #[test]
 fn from_columns() {
     let columns = &[
@@ -208,6 +429,57 @@ fn from_columns_dynamic() {
     assert_eq!(a, expected);
 }
 
+#[test]
+fn from_blocks() {
+    let a = Matrix2x3::new(11, 12, 13, 21, 22, 23);
+    let b = Matrix2x3::new(14, 15, 16, 24, 25, 26);
+    let c = Matrix2x3::new(31, 32, 33, 41, 42, 43);
+    let d = Matrix2x3::new(34, 35, 36, 44, 45, 46);
+
+    let expected = DMatrix::from_row_slice(
+        4,
+        6,
+        &[
+            11, 12, 13, 14, 15, 16, 21, 22, 23, 24, 25, 26, 31, 32, 33, 34, 35, 36, 41, 42, 43, 44,
+            45, 46,
+        ],
+    );
+
+    let m = MatrixMN::<_, U4, U6>::from_blocks(&[[&a, &b], [&c, &d]]);
+    assert_eq!(m, expected);
+
+    let m = DMatrix::from_blocks(&[[&a, &b], [&c, &d]]);
+    assert_eq!(m, expected);
+
+    let rows: Vec<Vec<_>> = vec![vec![&a], vec![&b], vec![&c]];
+    let m = DMatrix::from_blocks(&rows);
+    assert_eq!(m.shape(), (6, 3));
+    assert_eq!(m.rows(2, 2), b);
+}
+
+#[test]
+#[should_panic]
+fn from_blocks_incompatible_rows() {
+    let a = DMatrix::from_element(2, 2, 0);
+    let b = DMatrix::from_element(3, 2, 0);
+    let _ = DMatrix::from_blocks(&[[&a, &b]]);
+}
+
+#[test]
+#[should_panic]
+fn from_blocks_incompatible_columns() {
+    let a = DMatrix::from_element(2, 2, 0);
+    let b = DMatrix::from_element(2, 3, 0);
+    let _ = DMatrix::from_blocks(&[[&a], [&b]]);
+}
+
+#[test]
+#[should_panic]
+fn from_blocks_wrong_static_dimensions() {
+    let a = Matrix2::new(1, 2, 3, 4);
+    let _ = Matrix4::from_blocks(&[[&a, &a]]);
+}
+
 #[test]
 #[should_panic]
 fn from_too_many_rows() {
@@ -382,6 +654,30 @@ fn simple_product() {
     assert_eq!(a * b * c, vec![a, b, c].into_iter().product());
 }
 
+#[test]
+#[rustfmt::skip]
+fn pow_integer() {
+    // Adjacency matrix of the path graph 0 - 1 - 2.
+    let adj = Matrix3::new(0u32, 1, 0,
+                           1,    0, 1,
+                           0,    1, 0);
+
+    assert_eq!(adj.pow(0), Matrix3::identity());
+    assert_eq!(adj.pow(1), adj);
+    assert_eq!(adj.pow(4), Matrix3::new(2, 0, 2,
+                                        0, 4, 0,
+                                        2, 0, 2));
+    assert_eq!(adj.pow(7), adj * adj * adj * adj * adj * adj * adj);
+}
+
+#[test]
+fn pow_dynamic() {
+    let m = DMatrix::from_row_slice(3, 3, &[1.0, 2.0, 0.0, -1.0, 0.5, 1.0, 0.0, 3.0, -2.0]);
+
+    assert_relative_eq!(m.pow(5), &m * &m * &m * &m * &m, epsilon = 1.0e-7);
+    assert_eq!(DMatrix::<f64>::zeros(0, 0).pow(3), DMatrix::zeros(0, 0));
+}
+
 #[test]
 fn cross_product_vector_and_row_vector() {
     let v1 = Vector3::new(1.0, 2.0, 3.0);
@@ -402,6 +698,20 @@ fn cross_product_vector_and_row_vector() {
     );
 }
 
+#[test]
+fn cross_matrix_and_vee() {
+    let v1 = Vector3::new(1.0, 2.0, 3.0);
+    let v2 = Vector3::new(1.0, 5.0, 7.0);
+    let hat = v1.cross_matrix();
+
+    assert_eq!(hat * v2, v1.cross(&v2));
+    assert_eq!(hat.transpose(), -hat);
+    assert_eq!(hat.vee(), v1);
+
+    // The symmetric part is ignored.
+    assert_eq!((hat + Matrix3::repeat(4.0)).vee(), v1);
+}
+
 #[test]
 fn simple_scalar_conversion() {
     let a = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
@@ -708,6 +1018,520 @@ fn kronecker() {
     assert_eq!(a.kronecker(&b), expected);
 }
 
+#[test]
+fn component_functions() {
+    let m = DMatrix::from_fn(3, 4, |i, j| 0.1 + (i * 4 + j) as f64 / 20.0);
+
+    assert_eq!(m.component_exp(), m.map(|e| e.exp()));
+    assert_eq!(m.component_ln(), m.map(|e| e.ln()));
+    assert_eq!(m.component_sqrt(), m.map(|e| e.sqrt()));
+    assert_eq!(m.component_tanh(), m.map(|e| e.tanh()));
+    assert_eq!(m.component_asin(), m.map(|e| e.asin()));
+    assert_eq!(m.component_powi(3), m.map(|e| e.powi(3)));
+    assert_eq!(m.component_powf(1.5), m.map(|e| e.powf(1.5)));
+    assert_eq!(m.component_clamp(0.2, 0.5), m.map(|e| e.max(0.2).min(0.5)));
+
+    let mut m2 = m.clone();
+    m2.component_cos_mut();
+    assert_eq!(m2, m.map(|e| e.cos()));
+
+    let c = na::Vector2::new(na::Complex::new(0.0, 1.0), na::Complex::new(1.0, -1.0));
+    assert_eq!(c.component_exp(), c.map(|e| e.exp()));
+    assert_eq!(c.component_powf(2.0), c.map(|e| e.powf(2.0)));
+}
+
+#[test]
+fn complex_core_operations() {
+    use na::Complex;
+
+    let i = Complex::new(0.0, 1.0);
+    let u = Vector2::new(Complex::new(1.0, 1.0), Complex::new(2.0, 0.0));
+    let v = Vector2::new(i, Complex::new(1.0, -1.0));
+
+    // `dot` does not conjugate, `dotc` conjugates its left-hand side.
+    assert_eq!(u.dot(&v), Complex::new(1.0, -1.0));
+    assert_eq!(u.dotc(&v), Complex::new(3.0, -1.0));
+    assert_eq!(u.dotc(&u), Complex::new(u.norm_squared(), 0.0));
+    assert_relative_eq!(u.norm(), 6.0f64.sqrt(), epsilon = 1.0e-12);
+
+    let m = Matrix2::new(Complex::new(1.0, 2.0), i, Complex::new(3.0, 0.0), -i);
+    let adjoint = Matrix2::new(Complex::new(1.0, -2.0), Complex::new(3.0, 0.0), -i, i);
+    assert_eq!(m.adjoint(), adjoint);
+    assert_eq!((m * u).dotc(&v), u.dotc(&(m.adjoint() * v)));
+}
+
+#[test]
+fn adjoint_multiplications() {
+    use na::Complex;
+
+    let a = DMatrix::from_fn(3, 4, |i, j| Complex::new(i as f64 - 1.0, (i * j) as f64));
+    let b = DMatrix::from_fn(2, 4, |i, j| Complex::new(j as f64, 1.0 - (i + j) as f64));
+
+    assert_eq!(a.mul_ad(&b), &a * b.adjoint());
+    assert_eq!(a.ad_mul(&a), a.adjoint() * &a);
+
+    let mut out = DMatrix::from_element(4, 4, Complex::new(f64::NAN, 0.0));
+    a.mul_ad_to(&b, &mut out.slice_mut((1, 1), (3, 2)));
+    assert_eq!(out.slice((1, 1), (3, 2)), a.mul_ad(&b));
+    assert!(out[(0, 0)].re.is_nan());
+
+    // The inner dimension is zero.
+    let empty = DMatrix::<Complex<f64>>::zeros(3, 0);
+    assert_eq!(empty.mul_ad(&DMatrix::zeros(2, 0)), DMatrix::zeros(3, 2));
+}
+
+#[test]
+fn overflow_checked_arithmetic() {
+    let a = Matrix2x3::new(1u8, 2, 3, 4, 5, 6);
+    let b = Matrix2x3::new(250u8, 0, 1, 2, 3, 4);
+
+    assert_eq!(a.checked_add(&a), Some(a + a));
+    assert_eq!(a.checked_add(&b), Some(a + b));
+    assert_eq!(b.checked_add(&b), None);
+    assert_eq!(b.checked_sub(&a), None);
+    assert_eq!(a.checked_sub(&a), Some(Matrix2x3::zeros()));
+
+    let m = DMatrix::from_row_slice(2, 2, &[i64::MAX / 2, 1, 1, 1]);
+    let v = DVector::from_vec(vec![2, 2]);
+    assert_eq!(m.checked_mul(&v), None);
+    assert_eq!(
+        m.checked_mul(&DVector::from_vec(vec![2, -1])),
+        Some(&m * DVector::from_vec(vec![2, -1]))
+    );
+
+    // The inner dimension is zero.
+    let empty = DMatrix::<u8>::zeros(3, 0);
+    assert_eq!(
+        empty.checked_mul(&DMatrix::zeros(0, 2)),
+        Some(DMatrix::zeros(3, 2))
+    );
+}
+
+#[test]
+fn saturating_and_wrapping_arithmetic() {
+    let a = DMatrix::from_row_slice(2, 2, &[100i8, -100, 50, 0]);
+    let b = DMatrix::from_row_slice(2, 2, &[100i8, 100, -100, 127]);
+
+    assert_eq!(
+        a.saturating_add(&b),
+        DMatrix::from_row_slice(2, 2, &[127, 0, -50, 127])
+    );
+    assert_eq!(
+        a.saturating_sub(&b),
+        DMatrix::from_row_slice(2, 2, &[0, -128, 127, -127])
+    );
+    // The partial sums saturate: 100 * 100 - 100 * 127 = 127 - 128.
+    assert_eq!(
+        a.saturating_mul(&b),
+        DMatrix::from_row_slice(2, 2, &[127, -1, 127, 127])
+    );
+
+    // Wrapping arithmetic computes the exact results modulo 256.
+    let exact = a.map(i32::from) * b.map(i32::from);
+    assert_eq!(a.wrapping_mul(&b), exact.map(|e| e as i8));
+    assert_eq!(
+        a.wrapping_add(&b),
+        (a.map(i32::from) + b.map(i32::from)).map(|e| e as i8)
+    );
+    assert_eq!(a.wrapping_sub(&b).wrapping_add(&b), a);
+
+    // Without overflow, these match the usual operations.
+    let m = Matrix2x3::new(1u16, 2, 3, 4, 5, 6);
+    let n = Matrix3x2::new(1u16, 2, 3, 4, 5, 6);
+    assert_eq!(m.saturating_mul(&n), m * n);
+    assert_eq!(m.wrapping_mul(&n), m * n);
+    assert_eq!(m.saturating_add(&m), m + m);
+}
+
+#[test]
+fn total_order_and_hash() {
+    use na::OrderedMatrix;
+    use std::collections::{BTreeSet, HashMap};
+
+    let nan = std::f64::NAN;
+    let inf = std::f64::INFINITY;
+    let mut values = vec![nan, inf, 1.0, 0.0, -0.0, -1.0, -inf, -nan];
+    values.sort_by(|a, b| Vector1::new(*a).total_cmp(&Vector1::new(*b)));
+    let bits: Vec<_> = values.iter().map(|e| e.to_bits()).collect();
+    let expected: Vec<_> = [-nan, -inf, -1.0, -0.0, 0.0, 1.0, inf, nan]
+        .iter()
+        .map(|e| e.to_bits())
+        .collect();
+    assert_eq!(bits, expected);
+
+    // Matrices are ordered by shape first.
+    let small = DMatrix::from_element(1, 3, 5);
+    let large = DMatrix::from_element(2, 1, 0);
+    assert_eq!(small.total_cmp(&large), Ordering::Less);
+    assert_eq!(
+        Matrix2::new(1, 2, 3, 4).total_cmp(&Matrix2::new(1, 2, 3, 5)),
+        Ordering::Less
+    );
+
+    let mut cache = HashMap::new();
+    let _ = cache.insert(OrderedMatrix(Vector2::new(nan, 1.0)), 1);
+    let _ = cache.insert(OrderedMatrix(Vector2::new(0.0, 1.0)), 2);
+    let _ = cache.insert(OrderedMatrix(Vector2::new(-0.0, 1.0)), 3);
+    assert_eq!(cache.len(), 3);
+    assert_eq!(cache[&OrderedMatrix(Vector2::new(nan, 1.0))], 1);
+    assert_eq!(cache[&Vector2::new(-0.0, 1.0).into()], 3);
+
+    let set: BTreeSet<_> = vec![
+        OrderedMatrix(DMatrix::from_element(2, 2, 1.0f32)),
+        OrderedMatrix(DMatrix::from_element(1, 2, 3.0)),
+        OrderedMatrix(DMatrix::from_element(2, 2, 1.0)),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(set.len(), 2);
+    assert_eq!(set.iter().next().unwrap().nrows(), 1);
+}
+
+#[test]
+fn double_ended_row_and_column_iterators() {
+    let m = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+
+    let mut columns = m.column_iter();
+    assert_eq!(columns.len(), 3);
+    assert_eq!(columns.next_back().unwrap(), m.column(2));
+    assert_eq!(columns.next().unwrap(), m.column(0));
+    assert_eq!(columns.len(), 1);
+    assert_eq!(columns.next_back().unwrap(), m.column(1));
+    assert!(columns.next().is_none() && columns.next_back().is_none());
+
+    let rows: Vec<_> = m.row_iter().rev().map(|r| r.into_owned()).collect();
+    assert_eq!(rows, vec![m.row(1).into_owned(), m.row(0).into_owned()]);
+
+    let mut m2 = m;
+    {
+        let mut columns = m2.column_iter_mut();
+        let mut last = columns.next_back().unwrap();
+        let mut first = columns.next().unwrap();
+        first *= 10;
+        last *= 100;
+        assert_eq!(columns.len(), 1);
+    }
+    assert_eq!(m2, Matrix2x3::new(10, 2, 300, 40, 5, 600));
+
+    for (i, mut row) in m2.row_iter_mut().rev().enumerate() {
+        row.fill(i as i32);
+    }
+    assert_eq!(m2, Matrix2x3::new(1, 1, 1, 0, 0, 0));
+}
+
+#[test]
+fn columns_chunks_and_windows() {
+    let m = DMatrix::from_fn(3, 7, |i, j| i * 10 + j);
+
+    // Mini-batches of columns.
+    let chunks: Vec<_> = m.columns_chunks(3).collect();
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks[0], m.columns(0, 3));
+    assert_eq!(chunks[1], m.columns(3, 3));
+    assert_eq!(chunks[2], m.columns(6, 1));
+    assert_eq!(m.columns_chunks(7).len(), 1);
+    assert_eq!(m.columns_chunks(100).next().unwrap(), m);
+    assert_eq!(m.columns_chunks(2).next_back().unwrap(), m.columns(6, 1));
+    assert_eq!(DMatrix::<f64>::zeros(3, 0).columns_chunks(2).count(), 0);
+
+    // The chunks are slices of the original storage.
+    assert_eq!(chunks[1].as_ptr(), m.column(3).as_ptr());
+
+    let windows: Vec<_> = m.columns_windows(5).collect();
+    assert_eq!(windows.len(), 3);
+    for (i, w) in windows.iter().enumerate() {
+        assert_eq!(*w, m.columns(i, 5));
+    }
+    assert_eq!(m.columns_windows(1).len(), 7);
+    assert_eq!(m.columns_windows(8).count(), 0);
+    assert_eq!(m.columns_windows(7).rev().next().unwrap(), m);
+
+    // Works with slices of matrices as well.
+    let s = m.slice((1, 1), (2, 4));
+    let windows: Vec<_> = s.columns_windows(2).map(|w| w.sum()).collect();
+    assert_eq!(
+        windows,
+        (1..4)
+            .map(|j| m.slice((1, j), (2, 2)).sum())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn broadcast() {
+    let m = DMatrix::from_row_slice(3, 2, &[1.0, 10.0, 2.0, 20.0, 6.0, 30.0]);
+
+    let centered = m.sub_row_broadcast(&m.row_mean());
+    assert_eq!(centered.row_mean(), na::RowDVector::zeros(2));
+    assert_eq!(centered.add_row_broadcast(&m.row_mean()), m);
+
+    let centered = m.sub_column_broadcast(&m.column_mean());
+    assert_eq!(centered.column_mean(), DVector::zeros(3));
+    assert_eq!(centered.add_column_broadcast(&m.column_mean()), m);
+
+    let row = na::RowVector2::new(1.0, 2.0);
+    let column = Vector3::new(1.0, 2.0, 3.0);
+
+    let mut m2 = m.clone();
+    m2.axpy_row_broadcast(2.0, &row, 3.0);
+    assert_eq!(
+        m2,
+        DMatrix::from_fn(3, 2, |i, j| 2.0 * row[j] + 3.0 * m[(i, j)])
+    );
+
+    m2.axpy_column_broadcast(2.0, &column, 0.0);
+    assert_eq!(m2, DMatrix::from_fn(3, 2, |i, _| 2.0 * column[i]));
+
+    let mut m3 = Matrix3x2::from_column_slice(m.as_slice());
+    m3.add_column_broadcast_mut(&column);
+    m3.sub_row_broadcast_mut(&row);
+    assert_eq!(
+        m3,
+        Matrix3x2::from_fn(|i, j| m[(i, j)] + column[i] - row[j])
+    );
+}
+
+#[test]
+#[should_panic]
+fn broadcast_wrong_dimensions() {
+    let m = DMatrix::from_element(3, 2, 1.0);
+    let _ = m.add_column_broadcast(&DVector::from_element(2, 1.0));
+}
+
+#[test]
+fn masks() {
+    let a = DMatrix::from_row_slice(2, 3, &[1, 5, 3, 7, 2, 6]);
+    let b = DMatrix::from_row_slice(2, 3, &[4, 5, 2, 7, 9, 0]);
+
+    let lt = a.component_lt(&b);
+    assert_eq!(
+        lt,
+        DMatrix::from_row_slice(2, 3, &[true, false, false, false, true, false])
+    );
+    assert_eq!(a.component_ge(&b), lt.map(|m| !m));
+    assert_eq!(a.component_le(&b), b.component_ge(&a));
+    assert_eq!(a.component_gt(&b), b.component_lt(&a));
+    assert_eq!(
+        a.component_eq(&b),
+        DMatrix::from_row_slice(2, 3, &[false, true, false, true, false, false])
+    );
+    assert_eq!(a.component_ne(&b), a.component_eq(&b).map(|m| !m));
+
+    // The component-wise minimum.
+    assert_eq!(a.select(&lt, &b), a.inf(&b));
+
+    let mut c = a.clone();
+    c.copy_from_masked(&lt, &b);
+    assert_eq!(c, a.sup(&b));
+
+    c.fill_masked(&c.component_gt(&DMatrix::from_element(2, 3, 5)), 5);
+    assert_eq!(c, DMatrix::from_row_slice(2, 3, &[4, 5, 3, 5, 5, 5]));
+}
+
+#[test]
+#[should_panic]
+fn masks_wrong_dimensions() {
+    let mut a = DMatrix::from_element(2, 3, 0);
+    let mask = DMatrix::from_element(3, 2, true);
+    a.fill_masked(&mask, 1);
+}
+
+#[test]
+fn cumsum_diff() {
+    let m = DMatrix::from_fn(4, 3, |i, j| (i * i + 3 * j) as i64 - 5);
+
+    let cumsum = m.cumsum_rows();
+    assert_eq!(cumsum.row(3), m.row_sum());
+    assert_eq!(cumsum.diff_rows(), m.rows(1, 3));
+
+    let cumsum = m.cumsum_columns();
+    assert_eq!(cumsum.column(2), m.column_sum());
+    assert_eq!(cumsum.diff_columns(), m.columns(1, 2));
+
+    let diff: Matrix3x2<_> = Matrix4x3::from_fn(|i, j| i * j).diff_rows().diff_columns();
+    assert_eq!(diff, Matrix3x2::repeat(1));
+
+    let empty = DMatrix::<f64>::zeros(1, 0);
+    assert_eq!(empty.diff_rows().shape(), (0, 0));
+    assert_eq!(empty.cumsum_columns(), empty);
+}
+
+#[test]
+#[should_panic]
+fn diff_rows_empty() {
+    let _ = DMatrix::<f64>::zeros(0, 3).diff_rows();
+}
+
+#[test]
+fn zip_apply_variants() {
+    let a = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    let b = DMatrix::from_row_slice(2, 3, &[10, 20, 30, 40, 50, 60]);
+    let c = Matrix2x3::new(2, 2, 2, 3, 3, 3);
+
+    let expected = Matrix2x3::new(21, 42, 63, 124, 155, 186);
+    let b_static = Matrix2x3::from_column_slice(b.as_slice());
+    assert_eq!(a.zip_zip_map(&b_static, &c, |a, b, c| a + b * c), expected);
+    assert_eq!(a.zip_zip_apply_into(&b, &c, |a, b, c| a + b * c), expected);
+
+    let mut m = a;
+    m.zip_zip_apply(&b, &c, |a, b, c| a + b * c);
+    assert_eq!(m, expected);
+
+    assert_eq!(
+        a.zip_apply_into(&b, |a, b| a * b),
+        a.component_mul(&b_static)
+    );
+}
+
+#[test]
+fn extrema_indices() {
+    let m = DMatrix::from_row_slice(3, 4, &[4, 9, 2, 9, -3, 0, 7, 1, 5, -3, 7, 8]);
+
+    assert_eq!(m.imax_full(), (0, 1));
+    assert_eq!(m.imin_full(), (1, 0));
+    assert_eq!(m[m.imax_full()], m.max());
+    assert_eq!(m[m.imin_full()], m.min());
+
+    assert_eq!(m.row_imax(), RowVector4::new(2, 0, 1, 0));
+    assert_eq!(m.row_imin(), RowVector4::new(1, 2, 0, 1));
+    assert_eq!(m.column_imax(), Vector3::new(1, 2, 3));
+    assert_eq!(m.column_imin(), Vector3::new(2, 0, 1));
+
+    for (j, i) in m.row_imax().iter().enumerate() {
+        assert_eq!(m[(*i, j)], m.row_max()[j]);
+    }
+}
+
+#[test]
+fn operator_norms() {
+    let m = DMatrix::from_row_slice(3, 2, &[1.0, -7.0, -2.0, 0.5, 3.0, 1.0f64]);
+
+    assert_eq!(m.norm_one(), 8.5);
+    assert_eq!(m.norm_infinity_operator(), 8.0);
+    assert_eq!(m.transpose().norm_one(), 8.0);
+    assert_eq!(DMatrix::<f64>::zeros(0, 3).norm_infinity_operator(), 0.0);
+
+    let spectral = m.singular_values().max();
+    let estimate = m.spectral_norm_estimate(50);
+    assert!(estimate <= spectral * (1.0 + 1.0e-12));
+    assert_relative_eq!(estimate, spectral, epsilon = 1.0e-7);
+
+    assert_eq!(DMatrix::<f64>::zeros(3, 2).spectral_norm_estimate(10), 0.0);
+    assert_eq!(DMatrix::<f64>::zeros(0, 2).spectral_norm_estimate(10), 0.0);
+}
+
+#[test]
+fn orthonormalize_columns() {
+    let mut m = DMatrix::from_row_slice(
+        3,
+        4,
+        &[1.0, 2.0, 0.0, 1.0, 1.0, 2.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0],
+    );
+    let original = m.clone();
+
+    assert_eq!(m.orthonormalize_columns(1.0e-7), 3);
+    assert_relative_eq!(
+        m.columns(0, 3).tr_mul(&m.columns(0, 3)),
+        DMatrix::identity(3, 3),
+        epsilon = 1.0e-7
+    );
+    assert_relative_eq!(
+        m.column(0).into_owned(),
+        original.column(0).normalize(),
+        epsilon = 1.0e-7
+    );
+    assert_eq!(m.column(3), DVector::zeros(3));
+
+    // The second column is dependent on the first one, so it is moved to the end.
+    let mut m = original.columns(0, 3).into_owned();
+    assert_eq!(m.orthonormalize_columns(1.0e-7), 2);
+    assert_relative_eq!(m.column(0).dot(&m.column(1)), 0.0, epsilon = 1.0e-7);
+    assert_relative_eq!(
+        m.column(1).into_owned(),
+        DVector::from_column_slice(&[-0.5, 0.5, 1.0]) / 1.5f64.sqrt(),
+        epsilon = 1.0e-7
+    );
+    assert_eq!(m.column(2), DVector::zeros(3));
+}
+
+#[test]
+fn complete_orthonormal_basis() {
+    let vs = DMatrix::from_row_slice(
+        5,
+        3,
+        &[
+            1.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+        ],
+    );
+    let (basis, rank) = vs.complete_orthonormal_basis(1.0e-7);
+
+    assert_eq!(rank, 2);
+    assert_relative_eq!(
+        basis.tr_mul(&basis),
+        DMatrix::identity(5, 5),
+        epsilon = 1.0e-7
+    );
+
+    for j in 0..3 {
+        // The columns of `vs` are in the span of the first `rank` basis vectors.
+        let coords = basis.columns(0, rank).tr_mul(&vs.column(j));
+        assert_relative_eq!(
+            basis.columns(0, rank) * coords,
+            vs.column(j).into_owned(),
+            epsilon = 1.0e-7
+        );
+    }
+
+    let (basis, rank) = DMatrix::<f64>::zeros(4, 0).complete_orthonormal_basis(1.0e-7);
+    assert_eq!(rank, 0);
+    assert_eq!(basis, DMatrix::identity(4, 4));
+}
+
+#[test]
+fn orthonormal_subspace_basis_non_orthogonal_family() {
+    let vs = [
+        Vector4::new(1.0, 0.0, 0.0, 0.0),
+        Vector4::new(1.0, 1.0, 0.0, 0.0),
+    ];
+    let mut basis = Vec::new();
+    Vector4::orthonormal_subspace_basis(&vs, |e| {
+        basis.push(*e);
+        true
+    });
+
+    assert_eq!(basis.len(), 2);
+
+    for b in &basis {
+        assert_relative_eq!(b.norm(), 1.0, epsilon = 1.0e-7);
+
+        for v in vs.iter().chain(basis.iter().filter(|e| *e != b)) {
+            assert_relative_eq!(v.dot(b), 0.0, epsilon = 1.0e-7);
+        }
+    }
+}
+
+#[test]
+fn kronecker_to() {
+    let a = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    let b = Vector2::new(10, 20);
+
+    let mut static_out = Matrix4x3::zeros();
+    a.kronecker_to(&b, &mut static_out);
+    assert_eq!(static_out, a.kronecker(&b));
+
+    let mut dynamic_out = DMatrix::from_element(4, 3, 0);
+    a.kronecker_to(&b, &mut dynamic_out);
+    assert_eq!(dynamic_out, a.kronecker(&b));
+}
+
+#[test]
+#[should_panic]
+fn kronecker_to_wrong_dimensions() {
+    let a = Matrix2::new(1, 2, 3, 4);
+    let mut out = Matrix3::zeros();
+    a.kronecker_to(&a, &mut out);
+}
+
 #[test]
 fn set_row_column() {
     let a = Matrix4x5::new(
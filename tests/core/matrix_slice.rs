@@ -1,12 +1,13 @@
 #![allow(non_snake_case)]
 
 use na::{
-    DMatrix, DMatrixSlice, DMatrixSliceMut, Matrix2, Matrix2x3, Matrix2x4, Matrix2x6, Matrix3,
-    Matrix3x2, Matrix3x4, Matrix4x2, Matrix6x2, MatrixSlice2, MatrixSlice2x3, MatrixSlice2xX,
-    MatrixSlice3, MatrixSlice3x2, MatrixSliceMut2, MatrixSliceMut2x3, MatrixSliceMut2xX,
-    MatrixSliceMut3, MatrixSliceMut3x2, MatrixSliceMutXx3, MatrixSliceXx3, RowVector4, Vector3,
+    DMatrix, DMatrixSlice, DMatrixSliceMut, DRowMajorMatrixSlice, DRowMajorMatrixSliceMut, Matrix2,
+    Matrix2x3, Matrix2x4, Matrix2x6, Matrix3, Matrix3x2, Matrix3x4, Matrix4x2, Matrix6x2,
+    MatrixSlice2, MatrixSlice2x3, MatrixSlice2xX, MatrixSlice3, MatrixSlice3x2, MatrixSliceMut2,
+    MatrixSliceMut2x3, MatrixSliceMut2xX, MatrixSliceMut3, MatrixSliceMut3x2, MatrixSliceMutXx3,
+    MatrixSliceXx3, RowMajorMatrixSliceMN, RowMajorMatrixSliceMutMN, RowVector4, Vector2, Vector3,
 };
-use na::{U2, U3, U4};
+use na::{Dynamic, U2, U3, U4};
 
 #[test]
 #[rustfmt::skip]
@@ -165,6 +166,40 @@ fn col_slice_mut() {
     assert_eq!(expected_a, a.clone_owned());
 }
 
+#[test]
+#[rustfmt::skip]
+fn rows_and_columns_step_by() {
+    let mut a = Matrix2x6::new(11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+                               21.0, 22.0, 23.0, 24.0, 25.0, 26.0);
+
+    let expected = Matrix2x3::new(12.0, 14.0, 16.0,
+                                  22.0, 24.0, 26.0);
+    assert_eq!(a.columns_step_by(1, 2), expected);
+    assert_eq!(a.columns_step_by(0, 4), Matrix2::new(11.0, 15.0, 21.0, 25.0));
+    assert_eq!(a.columns_step_by(6, 3).ncols(), 0);
+    assert_eq!(a.rows_step_by(1, 5), a.row(1));
+
+    a.columns_step_by_mut(2, 3).fill(0.0);
+
+    let expected_a = Matrix2x6::new(11.0, 12.0, 0.0, 14.0, 15.0, 0.0,
+                                    21.0, 22.0, 0.0, 24.0, 25.0, 0.0);
+    assert_eq!(a, expected_a);
+
+    let mut t = expected_a.transpose();
+    t.rows_step_by_mut(0, 2).fill(1.0);
+    assert_eq!(t.column(0).as_slice(), &[1.0, 12.0, 1.0, 14.0, 1.0, 0.0]);
+}
+
+#[test]
+fn rows_and_columns_step_by_large_step() {
+    let a = DMatrix::from_fn(4, 3, |i, j| (i * 3 + j) as f64);
+
+    assert_eq!(a.rows_step_by(0, usize::MAX), a.rows(0, 1));
+    assert_eq!(a.rows_step_by(2, 5), a.rows(2, 1));
+    assert_eq!(a.columns_step_by(1, usize::MAX), a.columns(1, 1));
+    assert_eq!(a.columns_step_by(3, usize::MAX).ncols(), 0);
+}
+
 #[test]
 #[rustfmt::skip]
 fn rows_range_pair() {
@@ -281,6 +316,39 @@ fn new_slice_mut() {
     assert!(data_mut == expected2x3);
 }
 
+#[test]
+#[rustfmt::skip]
+fn row_major_slices() {
+    let data = [ 1.0, 2.0, 3.0,
+                 4.0, 5.0, 6.0 ];
+    let expected = Matrix2x3::new(1.0, 2.0, 3.0,
+                                  4.0, 5.0, 6.0);
+
+    let m = RowMajorMatrixSliceMN::<_, U2, U3>::from_row_major_slice(&data);
+    let m_dyn = DRowMajorMatrixSlice::from_row_major_slice(&data, 2, 3);
+    assert_eq!(m, expected);
+    assert_eq!(m_dyn, expected);
+    assert_eq!(m.transpose(), Matrix3x2::from_column_slice(&data));
+    assert_eq!(m * Vector3::new(1.0, 0.0, -1.0), Vector2::new(-2.0, -2.0));
+
+    // Copying a column-major matrix into a row-major buffer.
+    let mut buffer = [0.0; 6];
+    DRowMajorMatrixSliceMut::from_row_major_slice(&mut buffer, 2, 3).copy_from(&expected);
+    assert_eq!(buffer, data);
+
+    RowMajorMatrixSliceMutMN::<_, U2, Dynamic>::from_row_major_slice(&mut buffer, 3)
+        .row_mut(1)
+        .fill(0.0);
+    assert_eq!(buffer, [1.0, 2.0, 3.0, 0.0, 0.0, 0.0]);
+}
+
+#[test]
+#[should_panic]
+fn row_major_slice_buffer_too_small() {
+    let data = [0.0; 5];
+    let _ = DRowMajorMatrixSlice::from_row_major_slice(&data, 2, 3);
+}
+
 #[test]
 #[should_panic]
 fn row_out_of_bounds() {
@@ -302,6 +370,20 @@ fn rows_with_step_out_of_bounds() {
     a.rows_with_step(1, 2, 1);
 }
 
+#[test]
+#[should_panic]
+fn rows_step_by_zero_step() {
+    let a = Matrix3x4::<f32>::zeros();
+    a.rows_step_by(0, 0);
+}
+
+#[test]
+#[should_panic]
+fn columns_step_by_out_of_bounds() {
+    let a = Matrix3x4::<f32>::zeros();
+    a.columns_step_by(5, 10);
+}
+
 #[test]
 #[should_panic]
 fn column_out_of_bounds() {
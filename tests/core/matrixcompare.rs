@@ -4,9 +4,10 @@
 //! The tests here only check that the necessary trait implementations are correctly implemented,
 //! in addition to some sanity checks with example input.
 
-use nalgebra::{DMatrix, MatrixMN, U4, U5};
+use nalgebra::{DMatrix, Matrix2, Matrix2x3, MatrixMN, U4, U5};
 
-use matrixcompare::{assert_matrix_eq, DenseAccess};
+use matrixcompare::comparators::{AbsoluteElementwiseComparator, UlpElementwiseComparator};
+use matrixcompare::{assert_matrix_eq, compare_matrices, DenseAccess, MatrixComparisonFailure};
 
 #[cfg(feature = "arbitrary")]
 quickcheck! {
@@ -83,3 +84,45 @@ fn assert_matrix_eq_dense_negative_comparison() {
 
     assert_matrix_eq!(a, b);
 }
+
+#[test]
+fn compare_matrices_reports_mismatched_elements() {
+    let a = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    let mut b = a;
+    b[(0, 2)] += 1.0e-3;
+    b[(1, 0)] = f64::NAN;
+
+    match compare_matrices(&a, &b, &AbsoluteElementwiseComparator { tol: 1.0e-2 }) {
+        Err(MatrixComparisonFailure::MismatchedElements(failure)) => {
+            // NaN never matches.
+            assert_eq!(failure.mismatches.len(), 1);
+            let mismatch = &failure.mismatches[0];
+            assert_eq!((mismatch.row, mismatch.col), (1, 0));
+            assert_eq!(mismatch.left, 4.0);
+            assert!(mismatch.right.is_nan());
+        }
+        other => panic!("unexpected comparison result: {:?}", other),
+    }
+
+    let next = a.map(|e| f64::from_bits(e.to_bits() + 1));
+    assert!(compare_matrices(&a, &next, &UlpElementwiseComparator { tol: 1 }).is_ok());
+    assert!(compare_matrices(&a, &next, &UlpElementwiseComparator { tol: 0 }).is_err());
+
+    let zeros = DMatrix::zeros(2, 2);
+    match compare_matrices(&a, &zeros, &AbsoluteElementwiseComparator { tol: 1.0 }) {
+        Err(MatrixComparisonFailure::MismatchedDimensions(failure)) => {
+            assert_eq!(failure.dim_left, (2, 3));
+            assert_eq!(failure.dim_right, (2, 2));
+        }
+        other => panic!("unexpected comparison result: {:?}", other),
+    }
+}
+
+#[test]
+#[should_panic(expected = "(0, 1): x = 2, y = 2.5.")]
+fn assert_matrix_eq_reports_mismatched_elements() {
+    let a = Matrix2::new(1.0, 2.0, 3.0, 4.0);
+    let b = Matrix2::new(1.0, 2.5, 3.0, 4.0);
+
+    assert_matrix_eq!(a, b, comp = abs, tol = 1.0e-3);
+}
@@ -3,13 +3,20 @@ mod abomonation;
 mod blas;
 mod cg;
 mod conversion;
+#[cfg(feature = "dual")]
+mod dual;
 mod edition;
 mod empty;
+mod gallery;
+#[cfg(feature = "interval")]
+mod interval;
 mod matrix;
 mod matrix_slice;
 #[cfg(feature = "mint")]
 mod mint;
 mod serde;
+mod stack;
+mod uninit;
 
 #[cfg(feature = "compare")]
 mod matrixcompare;
@@ -0,0 +1,70 @@
+use na::{
+    DMatrix, DVector, Matrix2, Matrix2x3, Matrix3x2, MatrixMN, RowVector2, RowVector3, Vector2,
+    Vector3,
+};
+use na::{Dynamic, U2};
+
+#[test]
+fn hstack() {
+    let a = Vector2::new(1, 2);
+    let b = Vector2::new(3, 4);
+    let c = Vector2::new(5, 6);
+
+    let stacked: MatrixMN<_, U2, Dynamic> = na::hstack(&[a, b, c]);
+    assert_eq!(stacked, Matrix2x3::new(1, 3, 5, 2, 4, 6));
+
+    let a = DMatrix::from_row_slice(2, 1, &[1, 2]);
+    let b = DMatrix::from_row_slice(2, 2, &[3, 4, 5, 6]);
+    let expected = DMatrix::from_row_slice(2, 3, &[1, 3, 4, 2, 5, 6]);
+    assert_eq!(na::hstack(&[a, b]), expected);
+}
+
+#[test]
+fn vstack() {
+    let a = RowVector2::new(1, 2);
+    let b = RowVector2::new(3, 4);
+    let c = RowVector2::new(5, 6);
+
+    let stacked: MatrixMN<_, Dynamic, U2> = na::vstack(&[a, b, c]);
+    assert_eq!(stacked, Matrix3x2::new(1, 2, 3, 4, 5, 6));
+
+    let a = DVector::from_row_slice(&[1, 2]);
+    let b = DVector::from_row_slice(&[3]);
+    assert_eq!(na::vstack(&[a, b]), DVector::from_row_slice(&[1, 2, 3]));
+}
+
+#[test]
+#[should_panic]
+fn hstack_wrong_dimensions() {
+    let a = DMatrix::from_element(2, 2, 0);
+    let b = DMatrix::from_element(3, 2, 0);
+    let _ = na::hstack(&[a, b]);
+}
+
+#[test]
+#[should_panic]
+fn vstack_empty() {
+    let _ = na::vstack::<i32, U2, U2, _>(&[] as &[Matrix2<i32>]);
+}
+
+#[test]
+fn stack_blocks() {
+    let a = Matrix2::new(1, 2, 4, 5);
+    let b = DVector::from_row_slice(&[3, 6]);
+    let c = RowVector3::new(7, 8, 9);
+
+    let expected = DMatrix::from_row_slice(3, 3, &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(na::stack![a, b; c], expected);
+    assert_eq!(na::stack![a, b,; c;], expected);
+
+    let v = Vector3::new(1, 2, 3);
+    assert_eq!(na::stack![v * 2], DMatrix::from_row_slice(3, 1, &[2, 4, 6]));
+}
+
+#[test]
+#[should_panic]
+fn stack_blocks_wrong_dimensions() {
+    let a = Matrix2::new(1, 2, 3, 4);
+    let b = RowVector3::new(5, 6, 7);
+    let _ = na::stack![a; b];
+}
@@ -0,0 +1,109 @@
+use std::mem::MaybeUninit;
+use std::rc::Rc;
+
+use na::{DMatrix, Matrix3, Matrix3x2, MatrixMN, Vector3, U3};
+
+#[test]
+fn uninit_push_columns() {
+    let mut m = Matrix3x2::new_uninit();
+    assert!(!m.is_init());
+
+    m.push_column(&Vector3::new(1, 2, 3));
+    m.push_column_from_fn(|i| 4 + i);
+    assert!(m.is_init());
+
+    assert_eq!(m.into_init(), Some(Matrix3x2::new(1, 4, 2, 5, 3, 6)));
+}
+
+#[test]
+fn uninit_dynamic() {
+    let mut m = DMatrix::new_uninit(2, 3);
+    assert_eq!((m.nrows(), m.ncols()), (2, 3));
+
+    m.push_column(&DMatrix::from_element(2, 1, 1.0).column(0));
+    assert!(m.into_init().is_none());
+
+    let mut m = MatrixMN::<f64, U3, na::Dynamic>::new_uninit(2);
+    m.push_column_from_fn(|i| i as f64);
+
+    for (k, c) in m.uninit_slice_mut().iter_mut().enumerate() {
+        *c = MaybeUninit::new(10.0 + k as f64);
+    }
+
+    let m = unsafe { m.assume_init() };
+    assert_eq!(
+        m,
+        MatrixMN::<f64, U3, na::Dynamic>::from_column_slice(&[0.0, 1.0, 2.0, 10.0, 11.0, 12.0])
+    );
+}
+
+#[test]
+#[should_panic]
+fn uninit_push_too_many_columns() {
+    let mut m = Matrix3::new_uninit();
+
+    for _ in 0..4 {
+        m.push_column(&Vector3::new(1, 2, 3));
+    }
+}
+
+// A non-`Copy` scalar type that keeps track of its live instances.
+#[derive(Clone, PartialEq, Debug)]
+struct Counted(Rc<()>);
+
+impl na::Scalar for Counted {}
+
+#[test]
+fn uninit_drop_partially_initialized() {
+    let elt = Counted(Rc::new(()));
+
+    {
+        let mut m = DMatrix::<Counted>::new_uninit(3, 4);
+        m.push_column_from_fn(|_| elt.clone());
+        m.push_column_from_fn(|_| elt.clone());
+        assert_eq!(Rc::strong_count(&elt.0), 7);
+    }
+
+    // Only the pushed components are dropped.
+    assert_eq!(Rc::strong_count(&elt.0), 1);
+
+    let mut m = Matrix3::<Counted>::new_uninit();
+    for _ in 0..3 {
+        m.push_column_from_fn(|_| elt.clone());
+    }
+    let m = m.into_init().unwrap();
+    assert_eq!(Rc::strong_count(&elt.0), 10);
+
+    drop(m);
+    assert_eq!(Rc::strong_count(&elt.0), 1);
+}
+
+#[test]
+fn uninit_bool() {
+    let mut m = Matrix3x2::<bool>::new_uninit();
+    m.push_column(&Vector3::new(true, false, true));
+    assert!(m.into_init().is_none());
+
+    let mut m = DMatrix::<bool>::new_uninit(2, 2);
+    m.push_column_from_fn(|i| i == 0);
+    m.push_column_from_fn(|i| i == 1);
+    assert_eq!(
+        m.into_init(),
+        Some(DMatrix::from_row_slice(2, 2, &[true, false, false, true]))
+    );
+}
+
+#[test]
+fn uninit_drop_empty() {
+    let elt = Counted(Rc::new(()));
+
+    let mut m = DMatrix::<Counted>::new_uninit(0, 3);
+    for _ in 0..3 {
+        m.push_column_from_fn(|_| elt.clone());
+    }
+    assert!(m.is_init());
+    assert_eq!(m.into_init().map(|m| m.shape()), Some((0, 3)));
+
+    drop(DMatrix::<Counted>::new_uninit(4, 5));
+    assert_eq!(Rc::strong_count(&elt.0), 1);
+}
@@ -93,6 +93,68 @@ fn to_homogeneous() {
     assert_eq!(a.to_homogeneous(), expected);
 }
 
+#[test]
+fn lexicographic_comparisons() {
+    use na::{DVector, Point2};
+    use std::cmp::Ordering;
+
+    let mut points = vec![
+        Point3::new(1, 2, 3),
+        Point3::new(0, 5, 5),
+        Point3::new(1, 2, -1),
+        Point3::new(1, -4, 0),
+    ];
+    points.sort_by(|a, b| a.lexicographic_cmp(b));
+    assert_eq!(
+        points,
+        vec![
+            Point3::new(0, 5, 5),
+            Point3::new(1, -4, 0),
+            Point3::new(1, 2, -1),
+            Point3::new(1, 2, 3),
+        ]
+    );
+
+    let a = Point2::new(0.0, std::f64::NAN);
+    assert_eq!(
+        a.lexicographic_partial_cmp(&Point2::new(1.0, 0.0)),
+        Some(Ordering::Less)
+    );
+    assert_eq!(a.lexicographic_partial_cmp(&Point2::new(0.0, 0.0)), None);
+
+    // A vector that is a prefix of another is smaller.
+    let short = DVector::from_vec(vec![1, 2]);
+    let long = DVector::from_vec(vec![1, 2, 0]);
+    assert_eq!(short.lexicographic_cmp(&long), Ordering::Less);
+    assert_eq!(
+        long.lexicographic_cmp(&DVector::from_vec(vec![1, 3])),
+        Ordering::Less
+    );
+    assert_eq!(
+        Vector3::new(1, 2, 3).lexicographic_cmp(&Vector3::new(1, 2, 3)),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn ordered_points_as_btree_map_keys() {
+    use na::{OrderedMatrix, Point2};
+    use std::collections::BTreeMap;
+
+    let mut map = BTreeMap::new();
+    let _ = map.insert(OrderedMatrix::from(Point2::new(1.0, -2.0)), "c");
+    let _ = map.insert(OrderedMatrix::from(Point2::new(-1.0, 3.0)), "a");
+    let _ = map.insert(OrderedMatrix::from(Point2::new(1.0, -3.0)), "b");
+    let _ = map.insert(OrderedMatrix::from(Point2::new(std::f64::NAN, 0.0)), "d");
+
+    let values: Vec<_> = map.values().cloned().collect();
+    assert_eq!(values, vec!["a", "b", "c", "d"]);
+    assert_eq!(map[&OrderedMatrix::from(Point2::new(1.0, -3.0))], "b");
+
+    let first = map.keys().next().unwrap();
+    assert_eq!(Point2::from(first.into_inner()), Point2::new(-1.0, 3.0));
+}
+
 #[cfg(feature = "arbitrary")]
 quickcheck!(
     fn point_sub(pt1: Point3<f64>, pt2: Point3<f64>) -> bool {
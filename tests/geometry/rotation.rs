@@ -1,4 +1,31 @@
-use na::{Quaternion, RealField, UnitQuaternion, Vector2, Vector3};
+use na::{
+    Matrix2, Point2, Quaternion, RealField, Rotation2, Translation2, UnitQuaternion, Vector2,
+    Vector3,
+};
+
+#[test]
+fn const_rotation_table() {
+    // The rotations by multiples of a quarter turn.
+    const QUARTER_TURNS: [Rotation2<i32>; 4] = [
+        Rotation2::from_matrix_unchecked(Matrix2::new(1, 0, 0, 1)),
+        Rotation2::from_matrix_unchecked(Matrix2::new(0, -1, 1, 0)),
+        Rotation2::from_matrix_unchecked(Matrix2::new(-1, 0, 0, -1)),
+        Rotation2::from_matrix_unchecked(Matrix2::new(0, 1, -1, 0)),
+    ];
+    const ORIGIN: Point2<i32> = Point2::new(1, 0);
+    const SHIFT: Translation2<i32> = Translation2::new(0, 2);
+    const UNIT_W: UnitQuaternion<f64> =
+        UnitQuaternion::new_unchecked(Quaternion::new(1.0, 0.0, 0.0, 0.0));
+
+    let expected = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+    for (rot, &(x, y)) in QUARTER_TURNS.iter().zip(expected.iter()) {
+        assert_eq!(rot.matrix() * ORIGIN.coords, Vector2::new(x, y));
+    }
+
+    assert_eq!(SHIFT.vector + ORIGIN.coords, Vector2::new(1, 2));
+    assert_eq!(UNIT_W, UnitQuaternion::identity());
+}
 
 #[test]
 fn angle_2() {
@@ -2,6 +2,7 @@
 extern crate abomonation;
 #[macro_use]
 extern crate approx;
+extern crate half;
 #[cfg(feature = "mint")]
 extern crate mint;
 extern crate nalgebra as na;
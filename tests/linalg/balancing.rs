@@ -3,7 +3,8 @@
 use std::cmp;
 
 use na::balancing;
-use na::{DMatrix, Matrix4};
+use na::{DMatrix, Matrix3, Matrix4, Vector3};
+use num_complex::Complex;
 
 quickcheck! {
     fn balancing_parlett_reinsch(n: usize) -> bool {
@@ -24,3 +25,56 @@ quickcheck! {
         balanced == m
     }
 }
+
+#[test]
+#[rustfmt::skip]
+fn balance_badly_scaled() {
+    let m = Matrix3::new(1.0,    1.0e-8, 3.0e-8,
+                         2.0e8,  2.0,    1.0,
+                         5.0e7,  1.0,    4.0);
+    let mut balanced = m;
+    let d = balancing::balance_parlett_reinsch(&mut balanced);
+
+    // The scaling factors are powers of two, so the balancing is exact.
+    for f in d.iter() {
+        assert_eq!(f64::log2(*f).fract(), 0.0);
+    }
+
+    assert!(balanced.norm() < 1.0e-6 * m.norm());
+    assert_eq!(balanced, Matrix3::from_fn(|i, j| m[(i, j)] * d[j] / d[i]));
+
+    let mut unbalanced = balanced;
+    balancing::unbalance(&mut unbalanced, &d);
+    assert_eq!(unbalanced, m);
+}
+
+#[test]
+#[rustfmt::skip]
+fn balance_unbalance_eigenvectors() {
+    let m = Matrix3::new(1.0,    1.0e-6, 0.0,
+                         1.0e6,  2.0,    1.0e4,
+                         0.0,    1.0e-4, 3.0);
+    let mut balanced = m;
+    let d = balancing::balance_parlett_reinsch(&mut balanced);
+
+    let eig = balanced.eigen();
+    let mut eigenvectors = eig.eigenvectors;
+    balancing::unbalance_eigenvectors(&mut eigenvectors, &d);
+    let m = m.map(|e| Complex::new(e, 0.0));
+
+    for i in 0..3 {
+        let v = eigenvectors.column(i);
+        assert_relative_eq!(m * v, v * eig.eigenvalues[i], epsilon = 1.0e-9 * v.norm());
+    }
+}
+
+#[test]
+fn balance_diagonal_and_triangular() {
+    // Matrices without any off-diagonal element in a row or column are left untouched.
+    let mut m = Matrix3::new(1.0e10, 1.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0);
+    let orig = m;
+    let d = balancing::balance_parlett_reinsch(&mut m);
+
+    assert_eq!(d, Vector3::new(1.0, 1.0, 1.0));
+    assert_eq!(m, orig);
+}
@@ -0,0 +1,126 @@
+use na::{BandedMatrix, DMatrix, DVector};
+
+/// The matrix of the finite-difference discretization of `-u''` on `n` points.
+fn laplacian_1d(n: usize) -> BandedMatrix<f64> {
+    let off = vec![-1.0; n - 1];
+    let diag = vec![2.0; n];
+    BandedMatrix::from_diagonals(1, 1, &[&off, &diag, &off])
+}
+
+#[test]
+#[rustfmt::skip]
+fn banded_from_matrix_to_dense() {
+    let m = DMatrix::from_row_slice(4, 4, &[
+        1.0,  2.0,  3.0,  4.0,
+        5.0,  6.0,  7.0,  8.0,
+        9.0,  10.0, 11.0, 12.0,
+        13.0, 14.0, 15.0, 16.0]);
+    let banded = BandedMatrix::from_matrix(&m, 1, 2);
+    let expected = DMatrix::from_row_slice(4, 4, &[
+        1.0, 2.0,  3.0,  0.0,
+        5.0, 6.0,  7.0,  8.0,
+        0.0, 10.0, 11.0, 12.0,
+        0.0, 0.0,  15.0, 16.0]);
+
+    assert_eq!(banded.to_dense(), expected);
+    assert_eq!(banded.get(0, 2), Some(&3.0));
+    assert_eq!(banded.get(0, 3), None);
+    assert_eq!(banded.get(2, 0), None);
+    assert_eq!(banded.get(4, 4), None);
+
+    let x = DVector::from_row_slice(&[1.0, -2.0, 3.0, -4.0]);
+    assert_eq!(banded.mul_vector(&x), expected * x);
+}
+
+#[test]
+fn banded_lu_solve() {
+    for &(n, kl, ku) in &[(1, 0, 0), (10, 0, 3), (10, 2, 0), (20, 3, 2), (20, 1, 5)] {
+        let m = BandedMatrix::from_matrix(&DMatrix::<f64>::new_random(n, n), kl, ku);
+        let dense = m.to_dense();
+        let b = DMatrix::<f64>::new_random(n, 3);
+
+        let lu = m.lu();
+        let x = lu.solve(&b).unwrap();
+
+        assert_relative_eq!(&dense * x, b, epsilon = 1.0e-7);
+        assert_relative_eq!(
+            lu.determinant(),
+            dense.determinant(),
+            epsilon = 1.0e-7,
+            max_relative = 1.0e-7
+        );
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn banded_lu_pivoting() {
+    // The first diagonal element is zero, so the rows have to be interchanged.
+    let m = DMatrix::from_row_slice(3, 3, &[
+        0.0, 1.0, 0.0,
+        2.0, 1.0, 3.0,
+        0.0, 4.0, 1.0]);
+    let lu = BandedMatrix::from_matrix(&m, 1, 1).lu();
+    let b = DVector::from_row_slice(&[1.0, 2.0, 3.0]);
+    let x = lu.solve(&b).unwrap();
+
+    assert_relative_eq!(m * x, b, epsilon = 1.0e-10);
+    assert_relative_eq!(lu.determinant(), -2.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn banded_lu_singular() {
+    // A lower-triangular matrix with a zero on its diagonal.
+    let m = BandedMatrix::from_diagonals(1, 0, &[&[1.0, 1.0, 0.0, 1.0], &[1.0, 1.0, 1.0]]);
+    let lu = m.lu();
+
+    assert!(!lu.is_invertible());
+    assert!(lu.solve(&DVector::from_element(4, 1.0)).is_none());
+    assert_eq!(lu.determinant(), 0.0);
+}
+
+#[test]
+fn banded_cholesky_laplacian() {
+    let m = laplacian_1d(50);
+    let dense = m.to_dense();
+    let chol = m.cholesky().unwrap();
+
+    let l = chol.l();
+    assert_eq!(l.lower_bandwidth(), 1);
+    assert_eq!(l.upper_bandwidth(), 0);
+    assert_relative_eq!(
+        l.to_dense() * l.to_dense().transpose(),
+        dense,
+        epsilon = 1.0e-10
+    );
+
+    let b = DMatrix::<f64>::new_random(50, 2);
+    assert_relative_eq!(&dense * chol.solve(&b), b, epsilon = 1.0e-7);
+
+    // The determinant of the discrete Laplacian of dimension `n` is `n + 1`.
+    assert_relative_eq!(chol.determinant(), 51.0, epsilon = 1.0e-7);
+}
+
+#[test]
+fn banded_cholesky_wide_band() {
+    let n = 15;
+    let a = DMatrix::<f64>::new_random(n, n);
+    let spd = BandedMatrix::from_matrix(&(&a * a.transpose()), 4, 4).to_dense()
+        + DMatrix::identity(n, n) * (n as f64);
+    let chol = BandedMatrix::from_matrix(&spd, 4, 0).cholesky().unwrap();
+
+    let b = DVector::<f64>::new_random(n);
+    assert_relative_eq!(&spd * chol.solve(&b), b, epsilon = 1.0e-7);
+    assert_relative_eq!(
+        chol.determinant(),
+        spd.determinant(),
+        epsilon = 1.0e-7,
+        max_relative = 1.0e-7
+    );
+}
+
+#[test]
+fn banded_cholesky_not_definite() {
+    let m = BandedMatrix::from_diagonals(1, 1, &[&[2.0], &[1.0, 1.0], &[2.0]]);
+    assert!(m.cholesky().is_none());
+}
@@ -5,7 +5,7 @@ macro_rules! gen_tests(
         mod $module {
             use na::debug::RandomSDP;
             use na::dimension::{U4, Dynamic};
-            use na::{DMatrix, DVector, Matrix4x3, Vector4};
+            use na::{Cholesky, DMatrix, DVector, Matrix4x3, Vector4};
             use rand::random;
             #[allow(unused_imports)]
             use crate::core::helper::{RandScalar, RandComplex};
@@ -18,6 +18,27 @@ macro_rules! gen_tests(
                     relative_eq!(m, &l * l.adjoint(), epsilon = 1.0e-7)
                 }
 
+                fn cholesky_in_place(n: usize) -> bool {
+                    let n = n.max(1).min(30);
+                    let m = RandomSDP::new(Dynamic::new(n), || random::<$scalar>().0).unwrap();
+                    let l = m.clone().cholesky().unwrap().unpack();
+                    let b = DMatrix::from_fn(n, 2, |_, _| random::<$scalar>().0);
+
+                    // Factor a submatrix of a larger buffer, which is left untouched elsewhere.
+                    let mut buffer = DMatrix::from_element(n + 1, n + 1, na::zero());
+                    buffer.slice_mut((1, 1), (n, n)).copy_from(&m);
+                    let reference = buffer.clone();
+                    let mut block = buffer.slice_mut((1, 1), (n, n));
+                    let view = na::Cholesky::new_in_place(&mut block).unwrap();
+                    let mut x = b.clone();
+                    view.solve_mut(&mut x);
+
+                    relative_eq!(view.l().clone_owned(), l, epsilon = 1.0e-7) &&
+                    relative_eq!(&m * x, b, epsilon = 1.0e-6) &&
+                    buffer.row(0) == reference.row(0) &&
+                    buffer.column(0) == reference.column(0)
+                }
+
                 fn cholesky_static(_m: RandomSDP<f64, U4>) -> bool {
                     let m = RandomSDP::new(U4, || random::<$scalar>().0).unwrap();
                     let chol = m.cholesky().unwrap();
@@ -99,6 +120,20 @@ macro_rules! gen_tests(
                     relative_eq!(m, m_chol_updated, epsilon = 1.0e-7)
                 }
 
+                fn cholesky_rank_one_downdate(_n: usize) -> bool {
+                    let m = RandomSDP::new(U4, || random::<$scalar>().0).unwrap();
+                    let x = Vector4::<$scalar>::new_random().map(|e| e.0);
+                    let sigma = random::<f64>(); // needs to be a real
+
+                    // the downdate cancels the update
+                    let mut chol = m.clone().cholesky().unwrap();
+                    chol.rank_one_update(&x, sigma);
+                    chol.rank_one_update(&x, -sigma);
+                    let m_chol_updated = chol.l() * chol.l().adjoint();
+
+                    relative_eq!(m, m_chol_updated, epsilon = 1.0e-7)
+                }
+
                 fn cholesky_insert_column(n: usize) -> bool {
                     let n = n.max(1).min(10);
                     let j = random::<usize>() % n;
@@ -129,10 +164,96 @@ macro_rules! gen_tests(
 
                     relative_eq!(m_updated, m_chol_updated, epsilon = 1.0e-7)
                 }
+
+                fn cholesky_pivoted(n: usize) -> bool {
+                    let n = n.max(1).min(50);
+                    let m = RandomSDP::new(Dynamic::new(n), || random::<$scalar>().0).unwrap();
+                    let (chol, p, rank) = Cholesky::new_pivoted(m.clone(), 1.0e-10).unwrap();
+                    let l = chol.unpack();
+                    let mut llh = &l * l.adjoint();
+                    p.inv_permute_rows(&mut llh);
+                    p.inv_permute_columns(&mut llh);
+
+                    rank == n && relative_eq!(m, llh, epsilon = 1.0e-7)
+                }
+
+                fn cholesky_rcond(n: usize) -> bool {
+                    let m = RandomSDP::new(Dynamic::new(n.max(1).min(15)), || random::<$scalar>().0).unwrap();
+                    let m1 = m.clone().try_inverse().unwrap();
+
+                    // The estimated norms are lower bounds of the actual norms.
+                    let rcond = 1.0 / (m.norm_one() * m1.norm_one());
+                    let est = m.cholesky().unwrap().rcond();
+                    est >= rcond * (1.0 - 1.0e-7) && est <= 1.0 + 1.0e-7
+                }
+
+                fn cholesky_pivoted_semidefinite(n: usize, k: usize) -> bool {
+                    let n = n.max(1).min(50);
+                    let k = k.min(n);
+                    let a = DMatrix::<$scalar>::new_random(n, k).map(|e| e.0);
+                    let m = &a * a.adjoint();
+                    let (chol, p, rank) = Cholesky::new_pivoted(m.clone(), 1.0e-11).unwrap();
+                    let l = chol.unpack();
+                    let mut llh = &l * l.adjoint();
+                    p.inv_permute_rows(&mut llh);
+                    p.inv_permute_columns(&mut llh);
+
+                    rank == k && relative_eq!(m, llh, epsilon = 1.0e-6)
+                }
             }
         }
     }
 );
 
+#[test]
+#[rustfmt::skip]
+fn cholesky_rcond() {
+    let m = na::Matrix3::new(
+        2.0, -1.0,  0.0,
+       -1.0,  2.0, -1.0,
+        0.0, -1.0,  2.0);
+
+    // ‖m‖₁ = 4 and ‖m⁻¹‖₁ = 2.
+    assert_relative_eq!(m.cholesky().unwrap().rcond(), 0.125, epsilon = 1.0e-10);
+
+    let ill_conditioned = na::Matrix2::new(1.0, 1.0, 1.0, 1.0 + 1.0e-10);
+    assert!(ill_conditioned.cholesky().unwrap().rcond() < 1.0e-9);
+}
+
+#[test]
+#[rustfmt::skip]
+fn cholesky_logdet() {
+    let m = na::Matrix3::new(
+        2.0, -1.0,  0.0,
+       -1.0,  2.0, -1.0,
+        0.0, -1.0,  2.0);
+    let (sign, logdet) = m.cholesky().unwrap().logdet();
+    assert_eq!(sign, 1.0);
+    assert_relative_eq!(logdet, 4.0f64.ln(), epsilon = 1.0e-10);
+
+    // The determinant of this matrix underflows.
+    let small = na::DMatrix::<f64>::from_diagonal_element(100, 100, 1.0e-10);
+    let (sign, logdet) = small.cholesky().unwrap().logdet();
+    assert_eq!(sign, 1.0);
+    assert_relative_eq!(logdet, 100.0 * 1.0e-10f64.ln(), epsilon = 1.0e-9);
+}
+
+#[test]
+fn cholesky_pivoted_scale_invariant_rank() {
+    let a = na::Matrix3x2::new(1.0, 2.0, 0.5, -1.0, 3.0, 1.0);
+
+    for scale in [1.0e-20, 1.0, 1.0e20].iter() {
+        let m = a * a.transpose() * *scale;
+        let (_, _, rank) = na::Cholesky::new_pivoted(m, 1.0e-10).unwrap();
+        assert_eq!(rank, 2);
+    }
+}
+
+#[test]
+fn cholesky_pivoted_indefinite() {
+    let m = na::Matrix2::new(1.0, 2.0, 2.0, 1.0);
+    assert!(na::Cholesky::new_pivoted(m, 1.0e-10).is_none());
+}
+
 gen_tests!(complex, RandComplex<f64>);
 gen_tests!(f64, RandScalar<f64>);
@@ -0,0 +1,73 @@
+use na::{Circulant, Complex, DMatrix, DVector};
+
+#[test]
+#[rustfmt::skip]
+fn circulant_to_dense() {
+    let c = Circulant::new(DVector::from_row_slice(&[1.0, 2.0, 3.0]));
+    let expected = DMatrix::from_row_slice(3, 3, &[
+        1.0, 3.0, 2.0,
+        2.0, 1.0, 3.0,
+        3.0, 2.0, 1.0]);
+
+    assert_eq!(c.to_dense(), expected);
+    assert_eq!(c.get(0, 1), Some(&3.0));
+    assert_eq!(c.get(3, 0), None);
+}
+
+#[test]
+fn circulant_mul_vector() {
+    // Powers of two use the radix-2 transform, the other lengths use the Bluestein algorithm.
+    for &n in &[1, 2, 7, 8, 12, 64, 100] {
+        let c = Circulant::new(DVector::<f64>::new_random(n));
+        let x = DVector::<f64>::new_random(n);
+
+        assert_relative_eq!(c.mul_vector(&x), c.to_dense() * x, epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn circulant_eigenvalues() {
+    for &n in &[5, 16] {
+        let c = Circulant::new(DVector::<f64>::new_random(n));
+        let dense = c.to_dense().map(|e| Complex::new(e, 0.0));
+        let eigenvalues = c.eigenvalues();
+
+        for k in 0..n {
+            let v = DVector::from_fn(n, |j, _| {
+                let angle = 2.0 * std::f64::consts::PI * (j * k) as f64 / n as f64;
+                Complex::new(angle.cos(), angle.sin())
+            });
+
+            assert_relative_eq!(&dense * &v, v * eigenvalues[k], epsilon = 1.0e-10);
+        }
+    }
+}
+
+#[test]
+fn circulant_solve_and_inverse() {
+    for &n in &[6, 32] {
+        let mut first_column = DVector::<f64>::new_random(n);
+        first_column[0] += n as f64;
+        let c = Circulant::new(first_column);
+        let b = DVector::<f64>::new_random(n);
+
+        let x = c.solve(&b).unwrap();
+        assert_relative_eq!(c.to_dense() * x, b, epsilon = 1.0e-10);
+
+        let inv = c.try_inverse().unwrap();
+        assert_relative_eq!(
+            c.mul_circulant(&inv).to_dense(),
+            DMatrix::identity(n, n),
+            epsilon = 1.0e-10
+        );
+    }
+}
+
+#[test]
+fn circulant_singular() {
+    // All the eigenvalues except the first one are zero.
+    let c = Circulant::new(DVector::from_element(7, 1.0));
+
+    assert!(c.solve(&DVector::from_element(7, 1.0)).is_none());
+    assert!(c.try_inverse().is_none());
+}
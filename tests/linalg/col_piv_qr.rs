@@ -0,0 +1,104 @@
+use na::{Matrix4x3, Vector4};
+
+#[test]
+#[rustfmt::skip]
+fn col_piv_qr_rank_deficient_solve() {
+    // The third column is the sum of the first two.
+    let m = Matrix4x3::new(
+        1.0, 2.0,  3.0,
+        4.0, 5.0,  9.0,
+        7.0, 8.0, 15.0,
+        1.0, 0.0,  1.0);
+    let b = Vector4::new(1.0, 2.0, 4.0, 8.0);
+
+    let qr = m.col_piv_qr();
+    assert_eq!(qr.rank(1.0e-10), 2);
+
+    // The residual of a least-squares solution is orthogonal to the columns of `m`.
+    let x = qr.solve(&b, 1.0e-10);
+    let residual = m * x - b;
+    assert_relative_eq!(m.transpose() * residual, na::zero(), epsilon = 1.0e-10);
+    assert_relative_eq!(m * x, m * m.pseudo_inverse(1.0e-10).unwrap() * b, epsilon = 1.0e-10);
+}
+
+#[cfg(feature = "arbitrary")]
+mod quickcheck_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: ty) => {
+            mod $module {
+                use na::{ComplexField, DMatrix, DVector, Matrix3x5, Matrix4, Matrix5x3};
+                use std::cmp;
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+
+                quickcheck! {
+                    fn col_piv_qr(m: DMatrix<$scalar>) -> bool {
+                        let m = m.map(|e| e.0);
+                        let qr = m.clone().col_piv_qr();
+                        let (q, r, p) = qr.unpack();
+                        let mut qr = &q * &r;
+                        p.inv_permute_columns(&mut qr);
+
+                        relative_eq!(m, qr, epsilon = 1.0e-7) &&
+                        q.is_orthogonal(1.0e-7) &&
+                        (1..r.nrows()).all(|i| r[(i, i)].modulus() <= r[(i - 1, i - 1)].modulus() + 1.0e-7)
+                    }
+
+                    fn col_piv_qr_static_5_3(m: Matrix5x3<$scalar>) -> bool {
+                        let m = m.map(|e| e.0);
+                        let (q, r, p) = m.col_piv_qr().unpack();
+                        let mut qr = q * r;
+                        p.inv_permute_columns(&mut qr);
+
+                        relative_eq!(m, qr, epsilon = 1.0e-7) &&
+                        q.is_orthogonal(1.0e-7)
+                    }
+
+                    fn col_piv_qr_static_3_5(m: Matrix3x5<$scalar>) -> bool {
+                        let m = m.map(|e| e.0);
+                        let (q, r, p) = m.col_piv_qr().unpack();
+                        let mut qr = q * r;
+                        p.inv_permute_columns(&mut qr);
+
+                        relative_eq!(m, qr, epsilon = 1.0e-7) &&
+                        q.is_orthogonal(1.0e-7)
+                    }
+
+                    fn col_piv_qr_solve(n: usize, nb: usize) -> bool {
+                        if n != 0 && nb != 0 {
+                            let n  = cmp::min(n, 50);  // To avoid slowing down the test too much.
+                            let nb = cmp::min(nb, 50); // To avoid slowing down the test too much.
+                            let m  = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+
+                            let qr = m.clone().col_piv_qr();
+                            let b1 = DVector::<$scalar>::new_random(n).map(|e| e.0);
+                            let b2 = DMatrix::<$scalar>::new_random(n, nb).map(|e| e.0);
+
+                            if qr.rank(1.0e-7) == n {
+                                let sol1 = qr.solve(&b1, 1.0e-7);
+                                let sol2 = qr.solve(&b2, 1.0e-7);
+
+                                return relative_eq!(&m * sol1, b1, epsilon = 1.0e-6) &&
+                                    relative_eq!(&m * sol2, b2, epsilon = 1.0e-6)
+                            }
+                        }
+
+                        return true;
+                    }
+
+                    fn col_piv_qr_rank(m: Matrix4<$scalar>) -> bool {
+                        let m = m.map(|e| e.0);
+                        let mut m = m;
+                        let c = m.column(0) + m.column(1);
+                        m.set_column(3, &c);
+
+                        m.col_piv_qr().rank(1.0e-7) <= 3
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, RandComplex<f64>);
+    gen_tests!(f64, RandScalar<f64>);
+}
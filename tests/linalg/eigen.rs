@@ -1,11 +1,18 @@
-use na::DMatrix;
+use na::allocator::Allocator;
+use na::linalg::LinalgError;
+use na::{
+    DMatrix, DefaultAllocator, Dim, Eigen, GeneralizedSymmetricEigen, Matrix2, Matrix3, MatrixN,
+    SymmetricEigen, SymmetricEigenWorkspace, SymmetricTridiagonal, Vector3,
+};
+use num_complex::Complex;
+use std::ops::ControlFlow;
 
 #[cfg(feature = "arbitrary")]
 mod quickcheck_tests {
     macro_rules! gen_tests(
         ($module: ident, $scalar: ty) => {
             mod $module {
-                use na::{DMatrix, Matrix2, Matrix3, Matrix4};
+                use na::{ComplexField, DMatrix, DVector, Matrix2, Matrix3, Matrix4, SymmetricEigen};
                 #[allow(unused_imports)]
                 use crate::core::helper::{RandScalar, RandComplex};
                 use std::cmp;
@@ -20,6 +27,83 @@ mod quickcheck_tests {
                         relative_eq!(m.lower_triangle(), recomp.lower_triangle(), epsilon = 1.0e-5)
                     }
 
+                    fn symmetric_eigen_eigenvalues_only(n: usize) -> bool {
+                        let n      = cmp::max(1, cmp::min(n, 10));
+                        let m      = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let vals   = SymmetricEigen::eigenvalues(m.clone());
+                        let eig    = m.symmetric_eigen();
+
+                        relative_eq!(vals, eig.eigenvalues, epsilon = 1.0e-7)
+                    }
+
+                    fn symmetric_eigen_sorted(n: usize) -> bool {
+                        let n      = cmp::max(1, cmp::min(n, 10));
+                        let m      = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let asc    = m.clone().symmetric_eigen().sorted(true);
+                        let desc   = m.clone().symmetric_eigen().sorted(false);
+
+                        for i in 1..n {
+                            if asc.eigenvalues[i - 1] > asc.eigenvalues[i] ||
+                               desc.eigenvalues[i - 1] < desc.eigenvalues[i] {
+                                return false;
+                            }
+                        }
+
+                        relative_eq!(m.lower_triangle(), asc.recompose().lower_triangle(), epsilon = 1.0e-5) &&
+                        relative_eq!(m.lower_triangle(), desc.recompose().lower_triangle(), epsilon = 1.0e-5)
+                    }
+
+                    fn symmetric_eigen_dc(n: usize) -> bool {
+                        let n      = cmp::max(1, cmp::min(n, 60));
+                        let m      = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let eig    = SymmetricEigen::new_dc(m.clone());
+                        let recomp = eig.recompose();
+                        let id     = DMatrix::identity(n, n);
+
+                        relative_eq!(m.lower_triangle(), recomp.lower_triangle(), epsilon = 1.0e-5) &&
+                        relative_eq!(eig.eigenvectors.adjoint() * &eig.eigenvectors, id, epsilon = 1.0e-7)
+                    }
+
+                    fn symmetric_eigen_rank_one_update(n: usize) -> bool {
+                        let n      = cmp::max(1, cmp::min(n, 30));
+                        let m      = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let v      = DVector::<$scalar>::new_random(n).map(|e| e.0);
+                        let sigma  = rand::random::<f64>() * 2.0 - 1.0;
+                        let mut eig = m.clone().symmetric_eigen();
+                        eig.rank_one_update(&v, sigma, 1.0e-15);
+
+                        let mut updated = m;
+                        updated.gerc(ComplexField::from_real(sigma), &v, &v, ComplexField::from_real(1.0));
+                        let recomp = eig.recompose();
+                        let id     = DMatrix::identity(n, n);
+
+                        relative_eq!(updated.lower_triangle(), recomp.lower_triangle(), epsilon = 1.0e-5) &&
+                        relative_eq!(eig.eigenvectors.adjoint() * &eig.eigenvectors, id, epsilon = 1.0e-7)
+                    }
+
+                    fn symmetric_eigen_jacobi(n: usize) -> bool {
+                        let n      = cmp::max(1, cmp::min(n, 10));
+                        let m      = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let eig    = SymmetricEigen::new_jacobi(m.clone(), 1.0e-15);
+                        let recomp = eig.recompose();
+
+                        relative_eq!(m.lower_triangle(), recomp.lower_triangle(), epsilon = 1.0e-5)
+                    }
+
+                    fn symmetric_eigen_gershgorin_discs(n: usize) -> bool {
+                        let n      = cmp::max(1, cmp::min(n, 10));
+                        let m      = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let discs  = m.gershgorin_discs();
+                        let eig    = m.symmetric_eigen();
+
+                        eig.eigenvalues.iter().all(|val| {
+                            discs.iter().any(|(center, radius)| {
+                                // The centers are real since `m` is hermitian.
+                                (center.real() - *val).abs() <= *radius + 1.0e-7
+                            })
+                        })
+                    }
+
                     fn symmetric_eigen_singular(n: usize) -> bool {
                         let n      = cmp::max(1, cmp::min(n, 10));
                         let mut m  = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0).hermitian_part();
@@ -63,6 +147,163 @@ mod quickcheck_tests {
     gen_tests!(f64, RandScalar<f64>);
 }
 
+#[cfg(feature = "arbitrary")]
+mod generalized_quickcheck_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: ty) => {
+            mod $module {
+                use na::{DMatrix, Matrix3, Matrix4};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use std::cmp;
+
+                quickcheck! {
+                    fn generalized_symmetric_eigen(n: usize) -> bool {
+                        let n = cmp::max(1, cmp::min(n, 10));
+                        let a = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let b = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                        let b = &b * b.adjoint() + DMatrix::identity(n, n);
+                        let eig = a.clone().generalized_symmetric_eigen(b.clone()).unwrap();
+
+                        let mut xl = eig.eigenvectors.clone();
+                        for i in 0..n {
+                            xl.column_mut(i).scale_mut(eig.eigenvalues[i]);
+                        }
+                        let bxl = &b * xl;
+
+                        relative_eq!(&a * &eig.eigenvectors, bxl, epsilon = 1.0e-5) &&
+                        relative_eq!(eig.eigenvectors.adjoint() * &b * &eig.eigenvectors, DMatrix::identity(n, n), epsilon = 1.0e-5) &&
+                        relative_eq!(eig.recompose(&b).lower_triangle(), a.lower_triangle(), epsilon = 1.0e-5)
+                    }
+
+                    fn generalized_symmetric_eigen_static_square_4x4(a: Matrix4<$scalar>) -> bool {
+                        let a = a.map(|e| e.0).hermitian_part();
+                        let b = Matrix4::<$scalar>::new_random().map(|e| e.0);
+                        let b = b * b.adjoint() + Matrix4::identity();
+                        let eig = a.generalized_symmetric_eigen(b).unwrap();
+
+                        relative_eq!(eig.recompose(&b).lower_triangle(), a.lower_triangle(), epsilon = 1.0e-5)
+                    }
+
+                    fn generalized_symmetric_eigen_static_square_3x3(a: Matrix3<$scalar>) -> bool {
+                        let a = a.map(|e| e.0).hermitian_part();
+                        let b = Matrix3::<$scalar>::new_random().map(|e| e.0);
+                        let b = b * b.adjoint() + Matrix3::identity();
+                        let eig = a.generalized_symmetric_eigen(b).unwrap();
+
+                        relative_eq!(eig.recompose(&b).lower_triangle(), a.lower_triangle(), epsilon = 1.0e-5)
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, RandComplex<f64>);
+    gen_tests!(f64, RandScalar<f64>);
+}
+
+#[test]
+fn generalized_symmetric_eigen_not_definite_positive() {
+    let a = Matrix2::new(2.0, 1.0, 1.0, 3.0);
+    let b = Matrix2::new(1.0, 0.0, 0.0, -1.0);
+    assert!(a.generalized_symmetric_eigen(b).is_none());
+    assert_eq!(
+        GeneralizedSymmetricEigen::try_new(a, b, 1.0e-12, 0).err(),
+        Some(LinalgError::NotPositiveDefinite)
+    );
+}
+
+#[test]
+fn eigen_errors() {
+    let m = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(
+        Eigen::try_new(m.clone(), 1.0e-12, 0).err(),
+        Some(LinalgError::DimensionMismatch)
+    );
+    assert_eq!(
+        SymmetricEigen::try_new(m, 1.0e-12, 0).err(),
+        Some(LinalgError::DimensionMismatch)
+    );
+
+    let m = Matrix3::new(0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0);
+    assert_eq!(
+        Eigen::try_new(m, 1.0e-12, 1).err(),
+        Some(LinalgError::NotConverged { iterations: 1 })
+    );
+}
+
+#[test]
+fn symmetric_eigen_dc_repeated_eigenvalues() {
+    // Large enough to be split, with many repeated eigenvalues triggering deflation.
+    let n = 80;
+    let q = DMatrix::<f64>::new_random(n, n).qr().q();
+    let d = DMatrix::from_fn(n, n, |i, j| if i == j { (i % 3) as f64 } else { 0.0 });
+    let m = &q * d * q.transpose();
+
+    let eig = SymmetricEigen::new_dc(m.clone());
+    let mut vals: Vec<f64> = eig.eigenvalues.iter().cloned().collect();
+    vals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    for (i, val) in vals.iter().enumerate() {
+        assert_relative_eq!(*val, (i * 3 / n) as f64, epsilon = 1.0e-10);
+    }
+
+    assert_relative_eq!(m, eig.recompose(), epsilon = 1.0e-10);
+    assert_relative_eq!(
+        eig.eigenvectors.transpose() * &eig.eigenvectors,
+        DMatrix::identity(n, n),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn symmetric_eigen_rank_one_update_deflated() {
+    // The update only affects the first eigenvalue, which becomes equal to the second one.
+    let m = Matrix3::new(1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0);
+    let mut eig = m.symmetric_eigen();
+    eig.rank_one_update(&Vector3::x(), 1.0, 1.0e-15);
+
+    let mut vals: Vec<f64> = eig.eigenvalues.iter().cloned().collect();
+    vals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_relative_eq!(vals.as_slice(), [2.0, 2.0, 3.0].as_ref(), epsilon = 1.0e-10);
+    assert_relative_eq!(
+        eig.recompose(),
+        Matrix3::new(2.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn symmetric_eigen_jacobi_graded() {
+    // The smallest eigenvalue is 0.9800000000002000003 but the implicit QR algorithm
+    // cannot compute it with any relative accuracy.
+    let m = Matrix3::new(
+        1.0e40, 1.0e19, 1.0e19, 1.0e19, 1.0e20, 1.0e9, 1.0e19, 1.0e9, 1.0,
+    );
+    let eig = SymmetricEigen::new_jacobi(m, 1.0e-16).sorted(true);
+
+    assert_relative_eq!(eig.eigenvalues[0], 0.9800000000002, max_relative = 1.0e-12);
+    assert_relative_eq!(eig.eigenvalues[1], 1.0e20, max_relative = 1.0e-12);
+    assert_relative_eq!(eig.eigenvalues[2], 1.0e40, max_relative = 1.0e-12);
+}
+
+#[test]
+fn symmetric_eigen_jacobi_max_niter() {
+    let m = Matrix3::new(4.0, 1.0, 2.0, 1.0, 3.0, 0.5, 2.0, 0.5, 1.0);
+
+    assert_eq!(
+        SymmetricEigen::try_new_jacobi(m, 1.0e-15, 1).err(),
+        Some(LinalgError::NotConverged { iterations: 1 })
+    );
+
+    let eig = SymmetricEigen::try_new_jacobi(m, 1.0e-15, 100).unwrap();
+    assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-12);
+
+    // A diagonal matrix only needs the sweep checking the convergence.
+    let d = Matrix3::from_diagonal(&Vector3::new(1.0, 2.0, 3.0));
+    assert!(SymmetricEigen::try_new_jacobi(d, 0.0, 1).is_ok());
+}
+
 // Test proposed on the issue #176 of rulinalg.
 #[test]
 #[rustfmt::skip]
@@ -108,88 +349,319 @@ fn symmetric_eigen_singular_24x24() {
     );
 }
 
-//  #[cfg(feature = "arbitrary")]
-//  quickcheck! {
-// FIXME: full eigendecomposition is not implemented yet because of its complexity when some
-// eigenvalues have multiplicity > 1.
-//
-//    /*
-//     * NOTE: for the following tests, we use only upper-triangular matrices.
-//     * Thes ensures the schur decomposition will work, and allows use to test the eigenvector
-//     * computation.
-//     */
-//    fn eigen(n: usize) -> bool {
-//        let n = cmp::max(1, cmp::min(n, 10));
-//        let m = DMatrix::<f64>::new_random(n, n).upper_triangle();
-//
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_with_adjascent_duplicate_diagonals(n: usize) -> bool {
-//        let n = cmp::max(1, cmp::min(n, 10));
-//        let mut m = DMatrix::<f64>::new_random(n, n).upper_triangle();
-//
-//        // Suplicate some adjascent diagonal elements.
-//        for i in 0 .. n / 2 {
-//            m[(i * 2 + 1, i * 2 + 1)] = m[(i * 2, i * 2)];
-//        }
-//
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_with_nonadjascent_duplicate_diagonals(n: usize) -> bool {
-//        let n = cmp::max(3, cmp::min(n, 10));
-//        let mut m = DMatrix::<f64>::new_random(n, n).upper_triangle();
-//
-//        // Suplicate some diagonal elements.
-//        for i in n / 2 .. n {
-//            m[(i, i)] = m[(i - n / 2, i - n / 2)];
-//        }
-//
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_static_square_4x4(m: Matrix4<f64>) -> bool {
-//        let m = m.upper_triangle();
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_static_square_3x3(m: Matrix3<f64>) -> bool {
-//        let m = m.upper_triangle();
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//
-//    fn eigen_static_square_2x2(m: Matrix2<f64>) -> bool {
-//        let m = m.upper_triangle();
-//        println!("{}", m);
-//        let eig = RealEigen::new(m.clone()).unwrap();
-//        verify_eigenvectors(m, eig)
-//    }
-//  }
-//
-// fn verify_eigenvectors<D: Dim>(m: MatrixN<f64, D>, mut eig: RealEigen<f64, D>) -> bool
-//     where DefaultAllocator: Allocator<f64, D, D>   +
-//                             Allocator<f64, D>      +
-//                             Allocator<usize, D, D> +
-//                             Allocator<usize, D>,
-//           MatrixN<f64, D>: Display,
-//           VectorN<f64, D>: Display {
-//     let mv = &m * &eig.eigenvectors;
-//
-//     println!("eigenvalues: {}eigenvectors: {}", eig.eigenvalues, eig.eigenvectors);
-//
-//     let dim = m.nrows();
-//     for i in 0 .. dim {
-//         let mut col = eig.eigenvectors.column_mut(i);
-//         col *= eig.eigenvalues[i];
-//     }
-//
-//     println!("{}{:.5}{:.5}", m, mv, eig.eigenvectors);
-//
-//     relative_eq!(eig.eigenvectors, mv, epsilon = 1.0e-5)
-// }
+#[test]
+fn symmetric_eigen_partial() {
+    let m = DMatrix::<f64>::new_random(10, 10);
+    let m = &m + m.transpose();
+
+    let (eig, info) = SymmetricEigen::try_new_partial(m.clone(), 1.0e-12, 2).unwrap();
+    assert!(!info.converged);
+    assert_eq!(info.iterations, 2);
+    assert_eq!(
+        SymmetricEigen::try_new(m.clone(), 1.0e-12, 2).err(),
+        Some(LinalgError::NotConverged { iterations: 2 })
+    );
+
+    // The partial eigenvectors are still orthonormal, and yield the partially reduced matrix.
+    let id = DMatrix::<f64>::identity(10, 10);
+    assert_relative_eq!(
+        eig.eigenvectors.tr_mul(&eig.eigenvectors),
+        id,
+        epsilon = 1.0e-10
+    );
+
+    let (eig, info) = SymmetricEigen::try_new_partial(m.clone(), 1.0e-12, 0).unwrap();
+    assert!(info.converged);
+    assert!(info.iterations > 2);
+    assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-10);
+}
+
+#[test]
+fn symmetric_eigen_with_observer() {
+    let m = DMatrix::<f64>::new_random(10, 10);
+    let m = &m + m.transpose();
+    let mut residuals = Vec::new();
+
+    let (eig, info) = SymmetricEigen::try_new_with_observer(m.clone(), 1.0e-12, 0, |info| {
+        residuals.push(info.residual);
+        ControlFlow::Continue(())
+    })
+    .unwrap();
+    assert!(info.converged);
+    assert_eq!(info.iterations, residuals.len());
+    assert!(*residuals.last().unwrap() <= 1.0e-10);
+    assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-10);
+
+    // Custom stopping criterion.
+    let (_, info) = SymmetricEigen::try_new_with_observer(m, 1.0e-12, 0, |info| {
+        if info.iteration == 3 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .unwrap();
+    assert!(!info.converged);
+    assert_eq!(info.iterations, 3);
+}
+
+#[test]
+fn symmetric_eigen_normalized_signs() {
+    let m = Matrix3::new(2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0);
+    let eig = SymmetricEigen::new(m).with_normalized_signs();
+    let mut flipped = eig.clone();
+    flipped.eigenvectors.neg_mut();
+    flipped.normalize_signs();
+
+    assert_eq!(flipped.eigenvectors, eig.eigenvectors);
+    assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-10);
+
+    for col in eig.eigenvectors.column_iter() {
+        assert!(col[col.iamax()] > 0.0);
+    }
+}
+
+#[test]
+fn symmetric_eigen_normalized_signs_complex() {
+    let m = DMatrix::from_fn(6, 6, |i, j| {
+        Complex::new((i + 2 * j) as f64 % 5.0, ((3 * i + j) % 7) as f64 - 3.0)
+    });
+    let m = &m + m.adjoint();
+    let eig = SymmetricEigen::new(m.clone()).with_normalized_signs();
+    let mut rotated = eig.clone();
+    rotated.eigenvectors *= Complex::new(0.6, 0.8);
+    rotated.normalize_signs();
+
+    assert_relative_eq!(rotated.eigenvectors, eig.eigenvectors, epsilon = 1.0e-12);
+    assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-10);
+
+    for col in eig.eigenvectors.column_iter() {
+        let best = col.iter().fold(Complex::new(0.0, 0.0), |best, e| {
+            if e.norm() > best.norm() {
+                *e
+            } else {
+                best
+            }
+        });
+        assert!(best.im.abs() <= 1.0e-15 && best.re > 0.0);
+    }
+}
+
+#[test]
+fn symmetric_eigen_from_tridiagonal() {
+    let m = DMatrix::<f64>::new_random(9, 9);
+    let m = &m + m.transpose();
+    let tri = SymmetricTridiagonal::new(m.clone());
+
+    // The diagonal and off-diagonal are available before the eigendecomposition.
+    let trace: f64 = tri.diagonal().iter().sum();
+    let eig = SymmetricEigen::from_tridiagonal(tri);
+
+    assert_relative_eq!(eig.eigenvalues.sum(), trace, epsilon = 1.0e-10);
+    assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-10);
+    assert_relative_eq!(
+        eig.sorted(true).eigenvalues,
+        SymmetricEigen::new(m).sorted(true).eigenvalues,
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn symmetric_eigen_with_workspace() {
+    let mut workspace = SymmetricEigenWorkspace::new(6);
+
+    for k in 0..10 {
+        let m = DMatrix::from_fn(6, 6, |i, j| ((i * 6 + j + k) as f64 * 0.37).sin());
+        let m = m.hermitian_part();
+        let expected = SymmetricEigen::try_new(m.clone(), 1.0e-12, 0).unwrap();
+        let eig = SymmetricEigen::try_new_with_workspace(&m, &mut workspace, 1.0e-12, 0).unwrap();
+
+        assert_relative_eq!(eig.eigenvalues, expected.eigenvalues, epsilon = 1.0e-10);
+        assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-10);
+    }
+
+    // A matrix with another dimension resizes the workspace.
+    let m = DMatrix::from_fn(3, 3, |i, j| 1.0 / (i + j + 1) as f64);
+    let eig = SymmetricEigen::try_new_with_workspace(&m, &mut workspace, 1.0e-12, 0).unwrap();
+    assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-10);
+    assert_eq!(workspace.dimension(), 3);
+    assert_relative_eq!(
+        workspace.last_decomposition().recompose(),
+        m,
+        epsilon = 1.0e-10
+    );
+
+    // Empty and 1x1 matrices.
+    let mut workspace = SymmetricEigenWorkspace::new(0);
+    let empty = DMatrix::<f64>::zeros(0, 0);
+    let eig = SymmetricEigen::try_new_with_workspace(&empty, &mut workspace, 1.0e-12, 0).unwrap();
+    assert!(eig.eigenvalues.is_empty() && eig.eigenvectors.is_empty());
+
+    let m = DMatrix::from_element(1, 1, -2.0);
+    let eig = SymmetricEigen::try_new_with_workspace(&m, &mut workspace, 1.0e-12, 0).unwrap();
+    assert_eq!(eig.eigenvalues[0], -2.0);
+    assert_eq!(workspace.dimension(), 1);
+
+    let eig = SymmetricEigen::try_new_with_workspace(&empty, &mut workspace, 1.0e-12, 0).unwrap();
+    assert!(eig.eigenvalues.is_empty());
+}
+
+#[test]
+#[rustfmt::skip]
+fn symmetric_eigen_with_workspace_static_complex() {
+    let m = Matrix3::new(
+        Complex::new(2.0, 0.0),  Complex::new(1.0, -1.0), Complex::new(0.0, 0.5),
+        Complex::new(1.0, 1.0),  Complex::new(3.0, 0.0),  Complex::new(-1.0, 0.0),
+        Complex::new(0.0, -0.5), Complex::new(-1.0, 0.0), Complex::new(1.0, 0.0));
+    let mut workspace = SymmetricEigenWorkspace::default();
+    let eig = SymmetricEigen::try_new_with_workspace(&m, &mut workspace, 1.0e-12, 0).unwrap();
+
+    assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-10);
+    assert_eq!(
+        SymmetricEigen::try_new_with_workspace(&m, &mut workspace, 1.0e-12, 1).err(),
+        Some(LinalgError::NotConverged { iterations: 1 })
+    );
+}
+
+#[test]
+fn symmetric_eigen_2x2_tiny_off_diagonal() {
+    // Both eigenvectors of these matrices are close to the canonical basis vectors, so one of
+    // the two formulas for the eigenvectors suffers from catastrophic cancellation.
+    for m in &[
+        Matrix2::new(1.0, 1.0e-9, 1.0e-9, 2.0),
+        Matrix2::new(-4.0, 1.0e-7, 1.0e-7, 3.0),
+    ] {
+        let eig = m.symmetric_eigen();
+        assert_relative_eq!(eig.recompose(), *m, epsilon = 1.0e-14);
+        assert!(eig.eigenvectors.is_orthogonal(1.0e-14));
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn eigen_rotation_mat3() {
+    // Rotation around the `z` axis followed by a scaling along it.
+    let m = Matrix3::new(0.0, -1.0, 0.0,
+                         1.0,  0.0, 0.0,
+                         0.0,  0.0, 2.0);
+
+    let eig = m.eigen();
+    let mut eigenvalues: Vec<_> = eig.eigenvalues.iter().cloned().collect();
+    eigenvalues.sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+
+    assert_relative_eq!(eigenvalues[0], Complex::new(0.0, -1.0), epsilon = 1.0e-7);
+    assert_relative_eq!(eigenvalues[1], Complex::new(2.0, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(eigenvalues[2], Complex::new(0.0, 1.0), epsilon = 1.0e-7);
+    assert!(verify_eigenvectors(&m, &eig));
+}
+
+#[test]
+#[rustfmt::skip]
+fn eigen_defective_mat2() {
+    // Jordan block: the eigenvalue 3 has only one eigenvector.
+    let m = Matrix2::new(3.0, 1.0,
+                         0.0, 3.0);
+
+    let eig = m.eigen();
+    assert_relative_eq!(eig.eigenvalues[0], Complex::new(3.0, 0.0), epsilon = 1.0e-7);
+    assert_relative_eq!(eig.eigenvalues[1], Complex::new(3.0, 0.0), epsilon = 1.0e-7);
+    assert!(verify_eigenvectors(&m, &eig));
+}
+
+#[test]
+#[rustfmt::skip]
+fn eigen_badly_scaled_mat3() {
+    // `d * m * d⁻¹` has the eigenvalues 1, 2 and 4 of the upper-triangular matrix `m`, but its
+    // entries span 24 orders of magnitude.
+    let m = Matrix3::new(1.0, 1.0, 1.0,
+                         0.0, 2.0, 1.0,
+                         0.0, 0.0, 4.0);
+    let d = Vector3::new(1.0, 1.0e12, 1.0e-12);
+    let scaled = Matrix3::from_fn(|i, j| m[(i, j)] * d[i] / d[j]);
+
+    let eig = scaled.eigen();
+    let mut eigenvalues: Vec<_> = eig.eigenvalues.iter().map(|e| e.re).collect();
+    eigenvalues.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    assert_relative_eq!(eigenvalues[0], 1.0, epsilon = 1.0e-10);
+    assert_relative_eq!(eigenvalues[1], 2.0, epsilon = 1.0e-10);
+    assert_relative_eq!(eigenvalues[2], 4.0, epsilon = 1.0e-10);
+
+    for (i, e) in eig.eigenvalues.iter().enumerate() {
+        let v = eig.eigenvectors.column(i);
+        let mv = scaled.map(|e| Complex::new(e, 0.0)) * v;
+
+        for k in 0..3 {
+            // Componentwise check, since the components of the eigenvectors are badly scaled too.
+            assert_relative_eq!(mv[k], v[k] * e,
+                                epsilon = 1.0e-8 * (v[k].norm() + 1.0e-30), max_relative = 1.0e-8);
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+mod eigen_quickcheck_tests {
+    use super::verify_eigenvectors;
+    use na::{DMatrix, Matrix2, Matrix3, Matrix4};
+    use std::cmp;
+
+    quickcheck! {
+        fn eigen(n: usize) -> bool {
+            let n = cmp::max(1, cmp::min(n, 10));
+            let m = DMatrix::<f64>::new_random(n, n);
+            let eig = m.clone().eigen();
+
+            verify_eigenvectors(&m, &eig)
+        }
+
+        fn eigen_with_duplicate_diagonals(n: usize) -> bool {
+            let n = cmp::max(1, cmp::min(n, 10));
+            let mut m = DMatrix::<f64>::new_random(n, n).upper_triangle();
+
+            // Duplicate some adjacent diagonal elements.
+            for i in 0 .. n / 2 {
+                m[(i * 2 + 1, i * 2 + 1)] = m[(i * 2, i * 2)];
+            }
+
+            let eig = m.clone().eigen();
+            verify_eigenvectors(&m, &eig)
+        }
+
+        fn eigen_static_square_4x4(m: Matrix4<f64>) -> bool {
+            let eig = m.eigen();
+            verify_eigenvectors(&m, &eig)
+        }
+
+        fn eigen_static_square_3x3(m: Matrix3<f64>) -> bool {
+            let eig = m.eigen();
+            verify_eigenvectors(&m, &eig)
+        }
+
+        fn eigen_static_square_2x2(m: Matrix2<f64>) -> bool {
+            let eig = m.eigen();
+            verify_eigenvectors(&m, &eig)
+        }
+    }
+}
+
+fn verify_eigenvectors<D: Dim>(m: &MatrixN<f64, D>, eig: &Eigen<f64, D>) -> bool
+where
+    DefaultAllocator:
+        Allocator<f64, D, D> + Allocator<Complex<f64>, D, D> + Allocator<Complex<f64>, D>,
+{
+    let m = m.map(|e| Complex::new(e, 0.0));
+    let mv = &m * &eig.eigenvectors;
+    let mut vl = eig.eigenvectors.clone();
+
+    for i in 0..m.nrows() {
+        let mut col = vl.column_mut(i);
+        col *= eig.eigenvalues[i];
+    }
+
+    let scale = m.camax().max(1.0);
+    relative_eq!(
+        mv / Complex::new(scale, 0.0),
+        vl / Complex::new(scale, 0.0),
+        epsilon = 1.0e-7
+    )
+}
@@ -99,6 +99,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn exp_closed_forms() {
+        use nalgebra::{Matrix2, Matrix3, Rotation2, Rotation3, Vector3};
+
+        {
+            // Generator of the 2D rotations: the eigenvalues are complex.
+            let m = Matrix2::new(0.0, -0.7, 0.7, 0.0);
+            let expected = Rotation2::new(0.7);
+
+            assert!(relative_eq!(m.exp(), expected.matrix(), epsilon = 1.0e-7));
+        }
+
+        {
+            // Complex eigenvalues with a non-zero real part.
+            let m = Matrix2::new(1.0, -2.0, 2.0, 1.0);
+            let expected = Rotation2::new(2.0).matrix() * 1.0_f64.exp();
+
+            assert!(relative_eq!(m.exp(), expected, epsilon = 1.0e-7));
+        }
+
+        {
+            // Defective matrix.
+            let m = Matrix2::new(2.0, 1.0, 0.0, 2.0);
+            let expected = Matrix2::new(1.0, 1.0, 0.0, 1.0) * 2.0_f64.exp();
+
+            assert!(relative_eq!(m.exp(), expected, epsilon = 1.0e-7));
+        }
+
+        {
+            let axisangle = Vector3::new(0.1, -0.4, 1.3);
+            let m = axisangle.cross_matrix();
+            let expected = Rotation3::new(axisangle);
+
+            assert!(relative_eq!(m.exp(), expected.matrix(), epsilon = 1.0e-7));
+            assert_eq!(Matrix3::<f64>::zeros().exp(), Matrix3::identity());
+        }
+    }
+
     #[test]
     fn exp_dynamic() {
         use nalgebra::DMatrix;
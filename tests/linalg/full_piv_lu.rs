@@ -1,4 +1,4 @@
-use na::Matrix3;
+use na::{Matrix3, Matrix3x4};
 
 #[test]
 #[rustfmt::skip]
@@ -39,6 +39,33 @@ fn full_piv_lu_simple_with_pivot() {
     assert!(relative_eq!(m, lu, epsilon = 1.0e-7));
 }
 
+#[test]
+#[rustfmt::skip]
+fn full_piv_lu_rank_kernel_image() {
+    // The third column is the sum of the first two, and the last one is twice the first one.
+    let m = Matrix3x4::new(
+        1.0, 2.0, 3.0, 2.0,
+        4.0, 5.0, 9.0, 8.0,
+        7.0, 8.0, 15.0, 14.0);
+
+    let lu = m.full_piv_lu();
+    assert_eq!(lu.rank(1.0e-10), 2);
+
+    let kernel = lu.kernel(1.0e-10);
+    assert_eq!(kernel.ncols(), 2);
+    assert_eq!(kernel.clone().full_piv_lu().rank(1.0e-10), 2);
+    assert!((m * &kernel).iter().all(|e: &f64| e.abs() < 1.0e-10));
+
+    let image = lu.image(1.0e-10);
+    assert_eq!(image.ncols(), 2);
+    assert_eq!(image.clone().full_piv_lu().rank(1.0e-10), 2);
+
+    // The columns of `m` are linear combinations of the columns of the image.
+    let mut image_and_m = image.insert_columns(2, 4, 0.0);
+    image_and_m.columns_range_mut(2..).copy_from(&m);
+    assert_eq!(image_and_m.full_piv_lu().rank(1.0e-10), 2);
+}
+
 #[cfg(feature = "arbitrary")]
 mod quickcheck_tests {
     macro_rules! gen_tests(
@@ -132,6 +159,26 @@ mod quickcheck_tests {
                                 (sol2.is_none() || relative_eq!(&m * sol2.unwrap(), b2, epsilon = 1.0e-6))
                     }
 
+                    fn full_piv_lu_kernel_image(n: usize, k: usize) -> bool {
+                        let n = cmp::max(1, cmp::min(n, 15)); // To avoid slowing down the test too much.
+                        let k = cmp::min(k, n);
+
+                        // A matrix of rank at most `k`.
+                        let a = DMatrix::<$scalar>::new_random(n, k).map(|e| e.0);
+                        let b = DMatrix::<$scalar>::new_random(k, n).map(|e| e.0);
+                        let m = a * b;
+
+                        let lu = m.clone().full_piv_lu();
+                        let rank = lu.rank(1.0e-7);
+                        let kernel = lu.kernel(1.0e-7);
+                        let image = lu.image(1.0e-7);
+
+                        rank <= k &&
+                        kernel.ncols() == n - rank &&
+                        image.ncols() == rank &&
+                        relative_eq!(&m * kernel, DMatrix::zeros(n, n - rank), epsilon = 1.0e-6)
+                    }
+
                     fn full_piv_lu_inverse(n: usize) -> bool {
                         let n = cmp::max(1, cmp::min(n, 15)); // To avoid slowing down the test too much.
                         let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
@@ -0,0 +1,118 @@
+use na::linalg::givens::GivensRotation;
+use na::{Complex, DMatrix, Matrix2, Matrix2x3, Matrix3x2, Vector2};
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex::new(re, im)
+}
+
+#[test]
+fn givens_cancel_y_complex() {
+    let v = Vector2::new(c(1.0, -2.0), c(0.5, 3.0));
+    let (rot, r) = GivensRotation::cancel_y(&v).unwrap();
+    let m = rot.to_rotation_matrix();
+
+    assert_relative_eq!(m * v, Vector2::new(r, c(0.0, 0.0)), epsilon = 1.0e-12);
+    assert_relative_eq!(r.norm(), v.norm(), epsilon = 1.0e-12);
+    assert_relative_eq!(m.adjoint() * m, Matrix2::identity(), epsilon = 1.0e-12);
+}
+
+#[test]
+fn givens_cancel_x_complex() {
+    let v = Vector2::new(c(-0.25, 1.5), c(2.0, 0.0));
+    let (rot, r) = GivensRotation::cancel_x(&v).unwrap();
+    let m = rot.to_rotation_matrix();
+
+    assert_relative_eq!(m * v, Vector2::new(c(0.0, 0.0), r), epsilon = 1.0e-12);
+    assert_relative_eq!(r.norm(), v.norm(), epsilon = 1.0e-12);
+}
+
+#[test]
+fn givens_cancel_zero_pivot_complex() {
+    // The pivot is zero, so the phase of the result is arbitrary.
+    let v = Vector2::new(c(0.0, 0.0), c(0.0, -2.0));
+    let (rot, r) = GivensRotation::cancel_y(&v).unwrap();
+
+    assert_relative_eq!(
+        rot.to_rotation_matrix() * v,
+        Vector2::new(r, c(0.0, 0.0)),
+        epsilon = 1.0e-12
+    );
+    assert!(GivensRotation::cancel_y(&Vector2::new(c(1.0, 1.0), c(0.0, 0.0))).is_none());
+}
+
+#[test]
+fn givens_try_new_complex() {
+    let (rot, norm) = GivensRotation::try_new(c(3.0, 4.0), c(0.0, 12.0), 0.0).unwrap();
+
+    assert_relative_eq!(norm.norm(), 13.0, epsilon = 1.0e-12);
+    assert_relative_eq!(
+        Complex::from(rot.c()) * norm,
+        c(3.0, 4.0),
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(rot.s() * norm, c(0.0, 12.0), epsilon = 1.0e-12);
+    assert!(GivensRotation::try_new(c(0.0, 0.0), c(0.0, 0.0), 1.0e-10).is_none());
+}
+
+#[test]
+fn givens_rotate_complex() {
+    let (rot, _) = GivensRotation::cancel_y(&Vector2::new(c(1.0, 1.0), c(-2.0, 0.5))).unwrap();
+    let m = rot.to_rotation_matrix();
+    let a = Matrix2x3::new(
+        c(1.0, 0.0),
+        c(0.0, 2.0),
+        c(-1.0, 1.0),
+        c(3.0, -1.0),
+        c(0.5, 0.5),
+        c(0.0, -4.0),
+    );
+
+    let mut left = a;
+    rot.rotate(&mut left);
+    assert_relative_eq!(left, m * a, epsilon = 1.0e-12);
+
+    let b: Matrix3x2<Complex<f64>> = a.transpose();
+    let mut right = b;
+    rot.rotate_rows(&mut right);
+    assert_relative_eq!(right, b * m, epsilon = 1.0e-12);
+
+    rot.inverse().rotate(&mut left);
+    assert_relative_eq!(left, a, epsilon = 1.0e-12);
+}
+
+#[test]
+fn givens_triangularize_complex() {
+    // Complex QR decomposition with Givens rotations acting on non-adjacent rows.
+    let a = DMatrix::from_fn(4, 3, |i, j| {
+        c(((i * 3 + j) as f64).sin(), ((i + 2 * j) as f64 * 0.5).cos())
+    });
+    let mut r = a.clone();
+    let mut q = DMatrix::identity(4, 4);
+
+    for j in 0..3 {
+        for i in j + 1..4 {
+            let v = Vector2::new(r[(j, j)], r[(i, j)]);
+
+            if let Some((rot, _)) = GivensRotation::cancel_y(&v) {
+                rot.rotate_pair(&mut r, j, i);
+                rot.inverse().rotate_rows_pair(&mut q, j, i);
+            }
+        }
+    }
+
+    for j in 0..3 {
+        for i in j + 1..4 {
+            assert_relative_eq!(r[(i, j)], c(0.0, 0.0), epsilon = 1.0e-12);
+        }
+    }
+
+    assert_relative_eq!(&q * r, a, epsilon = 1.0e-12);
+    assert_relative_eq!(q.adjoint() * &q, DMatrix::identity(4, 4), epsilon = 1.0e-12);
+}
+
+#[test]
+#[should_panic]
+fn givens_rotate_pair_same_row() {
+    let mut m = DMatrix::<f64>::identity(3, 3);
+    GivensRotation::identity().rotate_pair(&mut m, 1, 1);
+}
@@ -0,0 +1,85 @@
+use na::linalg::householder::Reflection;
+use na::{Complex, DMatrix, DVector, Matrix3, Matrix3x4, Unit, Vector3};
+
+#[test]
+fn householder_annihilating() {
+    let x = Vector3::new(3.0f64, 1.0, -2.0);
+    let (refl, beta) = Reflection::annihilating(&x).unwrap();
+
+    let mut hx = x;
+    refl.apply_left(&mut hx);
+
+    assert_relative_eq!(beta.abs(), x.norm(), epsilon = 1.0e-12);
+    assert_relative_eq!(hx, Vector3::new(beta, 0.0, 0.0), epsilon = 1.0e-12);
+}
+
+#[test]
+fn householder_annihilating_complex() {
+    let x = DVector::from_vec(vec![
+        Complex::new(1.0, 2.0),
+        Complex::new(-0.5, 0.25),
+        Complex::new(0.0, -3.0),
+    ]);
+    let (refl, beta) = Reflection::annihilating(&x).unwrap();
+    let h = refl.to_matrix();
+
+    let mut expected = DVector::zeros(3);
+    expected[0] = beta;
+
+    assert_relative_eq!(beta.norm(), x.norm(), epsilon = 1.0e-12);
+    assert_relative_eq!(&h * &x, expected, epsilon = 1.0e-12);
+    assert_relative_eq!(h.adjoint(), h, epsilon = 1.0e-12);
+    assert_relative_eq!(&h * &h, DMatrix::identity(3, 3), epsilon = 1.0e-12);
+}
+
+#[test]
+fn householder_annihilating_zero() {
+    assert!(Reflection::annihilating(&Vector3::<f64>::zeros()).is_none());
+    assert!(Reflection::annihilating(&DVector::<f64>::zeros(0)).is_none());
+}
+
+#[test]
+#[rustfmt::skip]
+fn householder_apply_left_right() {
+    let refl = Reflection::new(Unit::new_normalize(Vector3::new(1.0, -2.0, 0.5)));
+    let h = refl.to_matrix();
+    let m = Matrix3x4::new(1.0, 2.0, 3.0, 4.0,
+                           0.5, -1.0, 0.0, 2.0,
+                           -3.0, 1.0, 1.5, 0.0);
+
+    let mut left = m;
+    refl.apply_left(&mut left);
+    assert_relative_eq!(left, h * m, epsilon = 1.0e-12);
+
+    let mt = m.transpose();
+    let mut right = mt;
+    refl.apply_right(&mut right);
+    assert_relative_eq!(right, mt * h, epsilon = 1.0e-12);
+    assert_relative_eq!(h * h, Matrix3::identity(), epsilon = 1.0e-12);
+}
+
+#[test]
+fn householder_apply_to_slices() {
+    // Reduce a matrix to upper-triangular form, one column at a time, by applying reflections to
+    // the trailing submatrices.
+    let m = DMatrix::from_fn(5, 4, |i, j| {
+        ((i * 4 + j) as f64 * 0.7).sin() + 0.1 * i as f64
+    });
+    let mut r = m.clone();
+    let mut q = DMatrix::identity(5, 5);
+
+    for k in 0..4 {
+        if let Some((refl, _)) = Reflection::annihilating(&r.slice_range(k.., k)) {
+            refl.apply_left(&mut r.slice_range_mut(k.., k..));
+            refl.apply_right(&mut q.slice_range_mut(.., k..));
+        }
+    }
+
+    for j in 0..4 {
+        for i in j + 1..5 {
+            assert_relative_eq!(r[(i, j)], 0.0, epsilon = 1.0e-12);
+        }
+    }
+
+    assert_relative_eq!(q * r, m, epsilon = 1.0e-12);
+}
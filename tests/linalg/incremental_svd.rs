@@ -0,0 +1,119 @@
+#![cfg(feature = "arbitrary")]
+
+use na::{ComplexField, DMatrix, DVector, IncrementalSVD};
+
+/// The singular values of `m`, sorted by decreasing order.
+fn sorted_singular_values<N: ComplexField>(m: DMatrix<N>) -> Vec<N::RealField> {
+    let mut svals: Vec<_> = m.singular_values().iter().cloned().collect();
+    svals.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    svals
+}
+
+#[test]
+fn incremental_svd_rank_deficient() {
+    // The third column is the sum of the first two.
+    let m = DMatrix::from_row_slice(
+        4,
+        3,
+        &[
+            1.0, 0.0, 1.0, //
+            2.0, 1.0, 3.0, //
+            0.0, 3.0, 3.0, //
+            1.0, 1.0, 2.0,
+        ],
+    );
+
+    let mut svd = IncrementalSVD::new(4, 3);
+    svd.push_columns(&m);
+
+    assert_eq!(svd.ncols(), 3);
+    assert_eq!(svd.rank(), 2);
+
+    let expected = sorted_singular_values(m.clone());
+    assert_relative_eq!(svd.singular_values()[0], expected[0], epsilon = 1.0e-10);
+    assert_relative_eq!(svd.singular_values()[1], expected[1], epsilon = 1.0e-10);
+
+    let u = svd.u();
+    assert_relative_eq!(u * u.ad_mul(&m), m, epsilon = 1.0e-10);
+}
+
+#[test]
+fn incremental_svd_zero_column() {
+    let mut svd = IncrementalSVD::<f64>::new(3, 2);
+    svd.push_column(&DVector::zeros(3));
+
+    assert_eq!(svd.ncols(), 1);
+    assert_eq!(svd.rank(), 0);
+}
+
+macro_rules! gen_tests(
+    ($module: ident, $scalar: ty) => {
+        mod $module {
+            use na::{DMatrix, IncrementalSVD};
+            use super::sorted_singular_values;
+            use std::cmp;
+            #[allow(unused_imports)]
+            use crate::core::helper::{RandScalar, RandComplex};
+
+            quickcheck! {
+                fn incremental_svd(nrows: usize, ncols: usize) -> bool {
+                    let nrows = cmp::max(1, cmp::min(nrows, 20));
+                    let ncols = cmp::max(1, cmp::min(ncols, 20));
+                    let m = DMatrix::<$scalar>::new_random(nrows, ncols).map(|e| e.0);
+
+                    let mut svd = IncrementalSVD::new(nrows, cmp::min(nrows, ncols));
+                    svd.push_columns(&m);
+
+                    let u = svd.u();
+                    let expected = sorted_singular_values(m.clone());
+
+                    svd.ncols() == ncols &&
+                    svd.rank() == expected.len() &&
+                    u.is_orthogonal(1.0e-7) &&
+                    svd.singular_values().iter().zip(expected.iter()).all(|(a, b)| relative_eq!(a, b, epsilon = 1.0e-7)) &&
+                    relative_eq!(u * u.ad_mul(&m), m, epsilon = 1.0e-7)
+                }
+
+                fn incremental_svd_low_rank(nrows: usize, ncols: usize, rank: usize) -> bool {
+                    let nrows = cmp::max(1, cmp::min(nrows, 20));
+                    let ncols = cmp::max(1, cmp::min(ncols, 20));
+                    let rank = cmp::max(1, cmp::min(rank, 4));
+                    let a = DMatrix::<$scalar>::new_random(nrows, rank).map(|e| e.0);
+                    let b = DMatrix::<$scalar>::new_random(rank, ncols).map(|e| e.0);
+                    let m = a * b;
+
+                    let mut svd = IncrementalSVD::new(nrows, rank);
+                    svd.push_columns(&m);
+
+                    let u = svd.u();
+                    let expected = sorted_singular_values(m.clone());
+
+                    svd.rank() <= rank &&
+                    u.is_orthogonal(1.0e-7) &&
+                    svd.singular_values().iter().zip(expected.iter()).all(|(a, b)| relative_eq!(a, b, epsilon = 1.0e-7)) &&
+                    relative_eq!(u * u.ad_mul(&m), m, epsilon = 1.0e-7)
+                }
+
+                fn incremental_svd_truncated(nrows: usize, ncols: usize, max_rank: usize) -> bool {
+                    let nrows = cmp::max(1, cmp::min(nrows, 20));
+                    let ncols = cmp::max(1, cmp::min(ncols, 20));
+                    let max_rank = cmp::min(max_rank, 5);
+                    let m = DMatrix::<$scalar>::new_random(nrows, ncols).map(|e| e.0);
+
+                    let mut svd = IncrementalSVD::new(nrows, max_rank);
+                    svd.push_columns(&m);
+
+                    let svals = svd.singular_values();
+
+                    svd.rank() <= max_rank &&
+                    svd.u().ncols() == svd.rank() &&
+                    svd.u().is_orthogonal(1.0e-7) &&
+                    (1..svals.len()).all(|i| svals[i] <= svals[i - 1])
+                }
+            }
+        }
+    }
+);
+
+gen_tests!(complex, RandComplex<f64>);
+gen_tests!(f64, RandScalar<f64>);
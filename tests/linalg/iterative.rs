@@ -0,0 +1,430 @@
+use na::iterative::{
+    Arnoldi, BiCGStab, ConjugateGradient, IdentityPreconditioner, IncompleteCholesky,
+    JacobiPreconditioner, MatVec, MinRes, Preconditioner, StoppingCriterion,
+};
+use na::linalg::IterationInfo;
+use na::{BandedMatrix, Complex, DMatrix, DVector, ToeplitzMatrix};
+use std::ops::ControlFlow;
+
+/// The matrix-free finite-difference discretization of `-u''` on `n` points.
+struct Laplacian1D(usize);
+
+impl MatVec<f64> for Laplacian1D {
+    fn nrows(&self) -> usize {
+        self.0
+    }
+
+    fn ncols(&self) -> usize {
+        self.0
+    }
+
+    fn mul_vec_to(&self, x: &DVector<f64>, out: &mut DVector<f64>) {
+        let n = self.0;
+
+        for i in 0..n {
+            out[i] = 2.0 * x[i];
+
+            if i > 0 {
+                out[i] -= x[i - 1];
+            }
+
+            if i + 1 < n {
+                out[i] -= x[i + 1];
+            }
+        }
+    }
+}
+
+/// The finite-difference discretization of `-Δu` on a `n × n` grid.
+fn laplacian_2d(n: usize) -> DMatrix<f64> {
+    DMatrix::from_fn(n * n, n * n, |i, j| {
+        let (xi, yi) = (i % n, i / n);
+        let (xj, yj) = (j % n, j / n);
+
+        if i == j {
+            4.0
+        } else if (xi == xj && (yi as isize - yj as isize).abs() == 1)
+            || (yi == yj && (xi as isize - xj as isize).abs() == 1)
+        {
+            -1.0
+        } else {
+            0.0
+        }
+    })
+}
+
+fn tight() -> StoppingCriterion<f64> {
+    StoppingCriterion::new(1.0e-10, 1000)
+}
+
+#[test]
+fn cg_matrix_free() {
+    let n = 50;
+    let a = Laplacian1D(n);
+    let b = DVector::<f64>::new_random(n);
+    let x = ConjugateGradient::with_criterion(tight())
+        .solve(&a, &b)
+        .unwrap();
+
+    let mut ax = DVector::zeros(n);
+    a.mul_vec_to(&x, &mut ax);
+    assert!((ax - &b).norm() <= 1.0e-10 * b.norm());
+}
+
+#[test]
+fn cg_dense_and_banded() {
+    let n = 30;
+    let off = vec![-1.0; n - 1];
+    let banded = BandedMatrix::from_diagonals(1, 1, &[&off, &vec![2.5; n], &off]);
+    let dense = banded.to_dense();
+    let b = DVector::<f64>::new_random(n);
+    let cg = ConjugateGradient::with_criterion(tight());
+
+    let x1 = cg.solve(&banded, &b).unwrap();
+    let x2 = cg.solve(&dense, &b).unwrap();
+    let jacobi = JacobiPreconditioner::new(&dense).unwrap();
+    let x3 = cg.solve_preconditioned(&dense, &b, &jacobi).unwrap();
+
+    assert_relative_eq!(&dense * &x1, b, epsilon = 1.0e-8);
+    assert_relative_eq!(x1, x2, epsilon = 1.0e-8);
+    assert_relative_eq!(x1, x3, epsilon = 1.0e-8);
+}
+
+#[test]
+fn cg_incomplete_cholesky() {
+    let a = laplacian_2d(10);
+    let b = DVector::<f64>::from_element(100, 1.0);
+    let cg = ConjugateGradient::with_criterion(tight());
+
+    let mut x = DVector::zeros(100);
+    let niter = cg
+        .solve_mut(&a, &b, &na::iterative::IdentityPreconditioner, &mut x)
+        .unwrap();
+    assert_relative_eq!(&a * &x, b, epsilon = 1.0e-8);
+
+    let ic = IncompleteCholesky::new(&a).unwrap();
+    let mut x = DVector::zeros(100);
+    let niter_ic = cg.solve_mut(&a, &b, &ic, &mut x).unwrap();
+    assert_relative_eq!(&a * &x, b, epsilon = 1.0e-8);
+    assert!(niter_ic < niter);
+
+    // The incomplete factor has the sparsity pattern of the lower-triangular part of `a`.
+    assert!(ic
+        .l()
+        .iter()
+        .zip(a.lower_triangle().iter())
+        .all(|(l, a)| *a != 0.0 || *l == 0.0));
+}
+
+#[test]
+fn cg_incomplete_cholesky_tridiagonal_is_exact() {
+    // There is no fill-in in the Cholesky factor of a tridiagonal matrix.
+    let n = 20;
+    let off = vec![-1.0; n - 1];
+    let a = BandedMatrix::from_diagonals(1, 1, &[&off, &vec![2.0; n], &off]).to_dense();
+    let ic = IncompleteCholesky::new(&a).unwrap();
+    assert_relative_eq!(
+        ic.unpack(),
+        a.cholesky().unwrap().unpack(),
+        epsilon = 1.0e-12
+    );
+}
+
+#[test]
+fn cg_complex_hermitian() {
+    let n = 12;
+    let m = DMatrix::<Complex<f64>>::from_fn(n, n, |i, j| {
+        Complex::new(((i * 7 + j * 3) % 5) as f64, ((i + 2 * j) % 3) as f64 - 1.0)
+    });
+    let a = &m * m.adjoint() + DMatrix::identity(n, n);
+    let b = DVector::from_fn(n, |i, _| Complex::new(i as f64, 1.0));
+
+    let x = ConjugateGradient::with_criterion(tight())
+        .solve(&a, &b)
+        .unwrap();
+    assert_relative_eq!(&a * x, b, epsilon = 1.0e-7);
+}
+
+#[test]
+fn cg_failures() {
+    let b = DVector::from_element(5, 1.0);
+    let cg = ConjugateGradient::with_criterion(tight());
+
+    // Not definite-positive.
+    assert!(cg.solve(&-DMatrix::<f64>::identity(5, 5), &b).is_none());
+
+    // Not enough iterations.
+    let cg = ConjugateGradient::with_criterion(StoppingCriterion::new(1.0e-10, 2));
+    assert!(cg.solve(&Laplacian1D(5), &b).is_none());
+
+    // Zero diagonal.
+    assert!(JacobiPreconditioner::new(&DMatrix::<f64>::zeros(2, 2)).is_none());
+    assert!(IncompleteCholesky::new(&-DMatrix::<f64>::identity(2, 2)).is_none());
+}
+
+#[test]
+fn stopping_criterion_max_niter() {
+    let default = StoppingCriterion::<f64>::default();
+    assert_eq!(default.max_niter_for(50), Some(500));
+    assert!(default.is_exhausted(500, 50));
+    assert!(!default.is_exhausted(499, 50));
+
+    assert_eq!(
+        StoppingCriterion::new(1.0e-10, 7).max_niter_for(50),
+        Some(7)
+    );
+    assert_eq!(StoppingCriterion::new(1.0e-10, 0).max_niter_for(50), None);
+    assert!(!StoppingCriterion::new(1.0e-10, 0).is_exhausted(usize::MAX, 50));
+}
+
+#[test]
+fn cg_observer() {
+    let n = 50;
+    let b = DVector::<f64>::new_random(n);
+    let cg = ConjugateGradient::with_criterion(tight());
+    let p = IdentityPreconditioner;
+    let mut x = DVector::zeros(n);
+    let mut residuals = Vec::new();
+    let observer = |info: IterationInfo<f64>| {
+        assert_eq!(info.iteration, residuals.len() + 1);
+        residuals.push(info.residual);
+        ControlFlow::Continue(())
+    };
+
+    let niter = cg
+        .solve_mut_with_observer(&Laplacian1D(n), &b, &p, &mut x, observer)
+        .unwrap();
+
+    assert_eq!(niter, residuals.len());
+    assert!(*residuals.last().unwrap() <= 1.0e-10 * b.norm());
+
+    // Abort after three iterations.
+    let mut x = DVector::zeros(n);
+    let mut count = 0;
+    let res = cg.solve_mut_with_observer(&Laplacian1D(n), &b, &p, &mut x, |_| {
+        count += 1;
+        if count == 3 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+
+    assert!(res.is_none());
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn bicgstab_convection_diffusion() {
+    // The upwind discretization of `-u'' + 20 * u'` is not symmetric.
+    let n = 40;
+    let h = 1.0 / (n + 1) as f64;
+    let lower = vec![-1.0 - 20.0 * h; n - 1];
+    let upper = vec![-1.0; n - 1];
+    let diag = vec![2.0 + 20.0 * h; n];
+    let a = BandedMatrix::from_diagonals(1, 1, &[&upper, &diag, &lower]);
+    let b = DVector::<f64>::new_random(n);
+    let solver = BiCGStab::with_criterion(tight());
+
+    let x1 = solver.solve(&a, &b).unwrap();
+    let jacobi = JacobiPreconditioner::from_diagonal(DVector::from_vec(diag)).unwrap();
+    let x2 = solver.solve_preconditioned(&a, &b, &jacobi).unwrap();
+
+    assert_relative_eq!(a.to_dense() * &x1, b, epsilon = 1.0e-8);
+    assert_relative_eq!(x1, x2, epsilon = 1.0e-8);
+}
+
+#[test]
+fn bicgstab_toeplitz() {
+    let n = 25;
+    let first_column = DVector::from_fn(n, |i, _| if i == 0 { 4.0 } else { 1.0 / (i * i) as f64 });
+    let first_row = DVector::from_fn(n, |i, _| if i == 0 { 4.0 } else { -1.0 / i as f64 });
+    let a = ToeplitzMatrix::new(first_column, first_row);
+    let b = DVector::<f64>::new_random(n);
+
+    let x = BiCGStab::with_criterion(tight()).solve(&a, &b).unwrap();
+    assert_relative_eq!(x, a.solve(&b).unwrap(), epsilon = 1.0e-8);
+}
+
+#[test]
+fn bicgstab_complex() {
+    let n = 15;
+    let a = DMatrix::<Complex<f64>>::from_fn(n, n, |i, j| {
+        if i == j {
+            Complex::new(n as f64, 1.0)
+        } else {
+            Complex::new(((i * 5 + j) % 4) as f64 - 1.5, ((3 * i + j) % 3) as f64)
+        }
+    });
+    let b = DVector::from_fn(n, |i, _| Complex::new(1.0, i as f64));
+
+    let mut x = DVector::zeros(n);
+    BiCGStab::with_criterion(tight())
+        .solve_mut(&a, &b, &na::iterative::IdentityPreconditioner, &mut x)
+        .unwrap();
+    assert_relative_eq!(&a * x, b, epsilon = 1.0e-7);
+}
+
+#[test]
+fn bicgstab_failures() {
+    let b = DVector::from_element(10, 1.0);
+
+    // Not enough iterations.
+    let solver = BiCGStab::with_criterion(StoppingCriterion::new(1.0e-10, 1));
+    assert!(solver.solve(&Laplacian1D(10), &b).is_none());
+
+    // Singular matrix.
+    let solver = BiCGStab::with_criterion(StoppingCriterion::new(1.0e-10, 100));
+    assert!(solver.solve(&DMatrix::<f64>::zeros(10, 10), &b).is_none());
+}
+
+/// The saddle-point matrix `[a, b*; b, 0]` of an equality-constrained quadratic program.
+fn saddle_point(n: usize, m: usize) -> DMatrix<f64> {
+    let a = laplacian_2d(n);
+    let b = DMatrix::from_fn(m, n * n, |i, j| ((i * 3 + j * 7) % 5) as f64 - 2.0);
+    let mut kkt = DMatrix::zeros(n * n + m, n * n + m);
+    kkt.slice_mut((0, 0), (n * n, n * n)).copy_from(&a);
+    kkt.slice_mut((n * n, 0), (m, n * n)).copy_from(&b);
+    kkt.slice_mut((0, n * n), (n * n, m))
+        .copy_from(&b.transpose());
+    kkt
+}
+
+/// A block-diagonal preconditioner for saddle-point matrices: the diagonal of the first block,
+/// and the identity for the second.
+struct BlockJacobi(DVector<f64>);
+
+impl Preconditioner<f64> for BlockJacobi {
+    fn apply(&self, r: &DVector<f64>, out: &mut DVector<f64>) {
+        for i in 0..r.len() {
+            out[i] = r[i] / self.0.get(i).cloned().unwrap_or(1.0);
+        }
+    }
+}
+
+#[test]
+fn minres_saddle_point() {
+    let kkt = saddle_point(5, 3);
+    let b = DVector::<f64>::new_random(28);
+    let solver = MinRes::with_criterion(tight());
+
+    let x1 = solver.solve(&kkt, &b).unwrap();
+    assert_relative_eq!(&kkt * &x1, b, epsilon = 1.0e-8);
+
+    let precond = BlockJacobi(DVector::from_element(25, 4.0));
+    let x2 = solver.solve_preconditioned(&kkt, &b, &precond).unwrap();
+    assert_relative_eq!(x1, x2, epsilon = 1.0e-8);
+}
+
+#[test]
+fn minres_complex_hermitian_indefinite() {
+    let n = 10;
+    let a = DMatrix::<Complex<f64>>::from_fn(n, n, |i, j| {
+        if i == j {
+            Complex::new(i as f64 - 4.5, 0.0)
+        } else if i < j {
+            Complex::new(0.1, 0.2 * (i + j) as f64 / n as f64)
+        } else {
+            Complex::new(0.1, -0.2 * (i + j) as f64 / n as f64)
+        }
+    });
+    let b = DVector::from_fn(n, |i, _| Complex::new(1.0, i as f64));
+
+    let mut x = DVector::from_element(n, Complex::new(1.0, 0.0));
+    MinRes::with_criterion(tight())
+        .solve_mut(&a, &b, &na::iterative::IdentityPreconditioner, &mut x)
+        .unwrap();
+    assert_relative_eq!(&a * x, b, epsilon = 1.0e-7);
+}
+
+#[test]
+fn minres_failures() {
+    // The system `diag(1, 0) * x = (1, 1)` has no solution.
+    let a = DMatrix::from_diagonal(&DVector::from_row_slice(&[1.0, 0.0]));
+    let b = DVector::from_element(2, 1.0);
+    assert!(MinRes::with_criterion(tight()).solve(&a, &b).is_none());
+
+    // Not enough iterations.
+    let solver = MinRes::with_criterion(StoppingCriterion::new(1.0e-10, 2));
+    assert!(solver
+        .solve(&saddle_point(3, 2), &DVector::from_element(11, 1.0))
+        .is_none());
+}
+
+#[test]
+fn arnoldi_relation() {
+    let n = 20;
+    let a = DMatrix::<f64>::new_random(n, n);
+    let mut arnoldi = Arnoldi::new(&DVector::from_element(n, 1.0), 8);
+
+    while arnoldi.step(&a) {}
+
+    assert_eq!(arnoldi.nsteps(), 8);
+    assert!(!arnoldi.is_invariant());
+
+    let v = arnoldi.basis();
+    let h = arnoldi.hessenberg();
+    assert_eq!(v.shape(), (n, 9));
+    assert_eq!(h.shape(), (9, 8));
+    assert_relative_eq!(v.adjoint() * v, DMatrix::identity(9, 9), epsilon = 1.0e-10);
+    assert_relative_eq!(&a * v.columns(0, 8), v * h, epsilon = 1.0e-10);
+    assert_relative_eq!(
+        v.columns(0, 8).adjoint() * &a * v.columns(0, 8),
+        arnoldi.square_hessenberg().into_owned(),
+        epsilon = 1.0e-10
+    );
+    assert!((0..9).all(|i| (0..8).all(|j| i <= j + 1 || h[(i, j)] == 0.0)));
+}
+
+#[test]
+fn arnoldi_invariant_subspace() {
+    // The starting vector lies in the invariant subspace spanned by the first two axes.
+    let a = DMatrix::from_diagonal(&DVector::from_row_slice(&[1.0, 2.0, 3.0, 4.0]));
+    let mut arnoldi = Arnoldi::new(&DVector::from_row_slice(&[1.0, 1.0, 0.0, 0.0]), 4);
+
+    assert!(arnoldi.step(&a));
+    assert!(arnoldi.step(&a));
+    assert!(!arnoldi.step(&a));
+    assert!(arnoldi.is_invariant());
+    assert_eq!(arnoldi.nsteps(), 2);
+    assert_eq!(arnoldi.basis().ncols(), 2);
+
+    let mut ritz: Vec<_> = arnoldi.ritz_values().iter().map(|e| e.re).collect();
+    ritz.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_relative_eq!(ritz[0], 1.0, epsilon = 1.0e-10);
+    assert_relative_eq!(ritz[1], 2.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn arnoldi_ritz_values() {
+    // The largest eigenvalue is well separated, so it is quickly approximated by a Ritz value.
+    let n = 100;
+    let a = DMatrix::from_fn(n, n, |i, j| {
+        if i == j {
+            if i == 0 {
+                100.0
+            } else {
+                1.0 + i as f64 / n as f64
+            }
+        } else if j == i + 1 {
+            0.5
+        } else {
+            0.0
+        }
+    });
+    let mut arnoldi = Arnoldi::new(&DVector::from_element(n, 1.0), 15);
+
+    while arnoldi.step(&a) {}
+
+    let largest = arnoldi
+        .ritz_values()
+        .iter()
+        .map(|e| e.re)
+        .fold(0.0, f64::max);
+    let expected = a
+        .complex_eigenvalues()
+        .iter()
+        .map(|e| e.re)
+        .fold(0.0, f64::max);
+    assert_relative_eq!(largest, expected, epsilon = 1.0e-8);
+}
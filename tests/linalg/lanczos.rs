@@ -0,0 +1,71 @@
+use na::linalg::lanczos::Lanczos;
+use na::linalg::LinalgError;
+use na::DMatrix;
+
+#[test]
+fn lanczos_diagonal() {
+    let m = DMatrix::from_diagonal(&na::DVector::from_fn(100, |i, _| i as f64));
+    let largest = Lanczos::new(&m, 3, true);
+    let smallest = Lanczos::new(&m, 3, false);
+
+    assert_relative_eq!(
+        largest.eigenvalues,
+        na::DVector::from_row_slice(&[99.0, 98.0, 97.0]),
+        epsilon = 1.0e-7
+    );
+    assert_relative_eq!(
+        smallest.eigenvalues,
+        na::DVector::from_row_slice(&[0.0, 1.0, 2.0]),
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+fn lanczos_max_niter() {
+    let m = DMatrix::from_diagonal(&na::DVector::from_fn(100, |i, _| i as f64));
+    assert_eq!(
+        Lanczos::try_new(&m, 3, true, 1.0e-12, 1).err(),
+        Some(LinalgError::NotConverged { iterations: 1 })
+    );
+}
+
+#[cfg(feature = "arbitrary")]
+mod quickcheck_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: ty) => {
+            mod $module {
+                use na::DMatrix;
+                use na::linalg::lanczos::Lanczos;
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+                use std::cmp;
+
+                quickcheck! {
+                    fn lanczos(n: usize, k: usize, largest: bool) -> bool {
+                        let n = cmp::max(1, cmp::min(n, 40));
+                        let k = cmp::min(k, n);
+                        let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0).hermitian_part();
+
+                        let lanczos = Lanczos::new(&m, k, largest);
+                        let all = m.clone().symmetric_eigen().sorted(!largest);
+
+                        for i in 0..k {
+                            let val = lanczos.eigenvalues[i];
+                            let vec = lanczos.eigenvectors.column(i);
+
+                            if !relative_eq!(lanczos.eigenvalues[i], all.eigenvalues[i], epsilon = 1.0e-7) ||
+                               !relative_eq!(&m * vec, vec.map(|e| e * val), epsilon = 1.0e-5) {
+                                return false;
+                            }
+                        }
+
+                        true
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, RandComplex<f64>);
+    gen_tests!(f64, RandScalar<f64>);
+}
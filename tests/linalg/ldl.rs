@@ -0,0 +1,97 @@
+use na::{Matrix4, Vector4};
+
+#[test]
+#[rustfmt::skip]
+fn ldl_saddle_point() {
+    // A KKT matrix `[H, Aᵀ; A, 0]` with a zero diagonal, which requires 2x2 pivots.
+    let m = Matrix4::new(
+        0.0, 1.0, 1.0, 2.0,
+        1.0, 0.0, 1.0, 0.0,
+        1.0, 1.0, 0.0, 0.0,
+        2.0, 0.0, 0.0, 0.0);
+    let b = Vector4::new(1.0, 2.0, 3.0, 4.0);
+
+    assert!(m.cholesky().is_none());
+
+    let ldl = m.ldl();
+    let x = ldl.solve(&b).unwrap();
+    assert_relative_eq!(m * x, b, epsilon = 1.0e-10);
+    assert_relative_eq!(ldl.determinant(), m.determinant(), epsilon = 1.0e-10);
+
+    let (p, l, d) = ldl.unpack();
+    assert!(d[(1, 0)] != 0.0);
+
+    let mut ldlt = l * d * l.transpose();
+    p.inv_permute_rows(&mut ldlt);
+    p.inv_permute_columns(&mut ldlt);
+    assert_relative_eq!(ldlt, m, epsilon = 1.0e-10);
+}
+
+#[test]
+fn ldl_singular() {
+    let m = Matrix4::<f64>::zeros();
+    let ldl = m.ldl();
+    assert!(ldl.solve(&Vector4::x()).is_none());
+    assert_eq!(ldl.determinant(), 0.0);
+}
+
+#[cfg(feature = "arbitrary")]
+mod quickcheck_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: ty) => {
+            mod $module {
+                use na::{DMatrix, DVector, Matrix4};
+                use std::cmp;
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+
+                quickcheck! {
+                    fn ldl(n: usize) -> bool {
+                        let n = cmp::max(1, cmp::min(n, 50));
+                        let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                        let m = &m + m.adjoint();
+
+                        let (p, l, d) = m.clone().ldl().unpack();
+                        let mut ldlh = &l * d * l.adjoint();
+                        p.inv_permute_rows(&mut ldlh);
+                        p.inv_permute_columns(&mut ldlh);
+
+                        relative_eq!(ldlh, m, epsilon = 1.0e-7)
+                    }
+
+                    fn ldl_static(m: Matrix4<$scalar>) -> bool {
+                        let m = m.map(|e| e.0);
+                        let m = m + m.adjoint();
+
+                        let (p, l, d) = m.ldl().unpack();
+                        let mut ldlh = l * d * l.adjoint();
+                        p.inv_permute_rows(&mut ldlh);
+                        p.inv_permute_columns(&mut ldlh);
+
+                        relative_eq!(ldlh, m, epsilon = 1.0e-7)
+                    }
+
+                    fn ldl_solve(n: usize, nb: usize) -> bool {
+                        let n  = cmp::max(1, cmp::min(n, 50));  // To avoid slowing down the test too much.
+                        let nb = cmp::max(1, cmp::min(nb, 50)); // To avoid slowing down the test too much.
+                        let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                        let m = &m + m.adjoint();
+
+                        let ldl = m.clone().ldl();
+                        let b1 = DVector::<$scalar>::new_random(n).map(|e| e.0);
+                        let b2 = DMatrix::<$scalar>::new_random(n, nb).map(|e| e.0);
+
+                        let sol1 = ldl.solve(&b1);
+                        let sol2 = ldl.solve(&b2);
+
+                        (sol1.is_none() || relative_eq!(&m * sol1.unwrap(), b1, epsilon = 1.0e-6)) &&
+                        (sol2.is_none() || relative_eq!(&m * sol2.unwrap(), b2, epsilon = 1.0e-6))
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, RandComplex<f64>);
+    gen_tests!(f64, RandScalar<f64>);
+}
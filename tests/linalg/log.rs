@@ -0,0 +1,71 @@
+use na::{Matrix2, Matrix3, Rotation2, Rotation3, Vector3};
+
+#[test]
+#[rustfmt::skip]
+fn log_upper_triangular_mat2() {
+    let m = Matrix2::new(1.0e6, 1.0,
+                         0.0,   1.0e-6);
+    let l12 = (1.0e6f64.ln() - 1.0e-6f64.ln()) / (1.0e6 - 1.0e-6);
+    let expected = Matrix2::new(1.0e6f64.ln(), l12,
+                                0.0,           1.0e-6f64.ln());
+
+    assert_relative_eq!(m.log().unwrap(), expected, epsilon = 1.0e-7);
+}
+
+#[test]
+#[rustfmt::skip]
+fn log_no_real_logarithm() {
+    // Has a negative real eigenvalue.
+    assert!(Matrix2::new(-1.0, 0.0, 0.0, 1.0).log().is_none());
+    // Singular.
+    assert!(Matrix2::new(0.0, 1.0, 0.0, 0.0).log().is_none());
+}
+
+#[test]
+fn log_rotation() {
+    let rot2 = Rotation2::new(2.5);
+    assert_relative_eq!(rot2.matrix().log().unwrap(), rot2.log(), epsilon = 1.0e-7);
+
+    let rot3 = Rotation3::new(Vector3::new(0.3, 1.0, -2.0));
+    assert_relative_eq!(rot3.matrix().log().unwrap(), rot3.log(), epsilon = 1.0e-7);
+    assert_relative_eq!(rot3.log().exp(), *rot3.matrix(), epsilon = 1.0e-7);
+    assert_eq!(Rotation3::<f64>::identity().log(), Matrix3::zeros());
+}
+
+#[cfg(feature = "arbitrary")]
+mod quickcheck_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: ty) => {
+            mod $module {
+                use na::{ComplexField, DMatrix};
+                use std::cmp;
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+
+                quickcheck! {
+                    fn log_exp(n: usize) -> bool {
+                        // All the eigenvalues of `m` have a positive real part.
+                        let n     = cmp::max(1, cmp::min(n, 10));
+                        let mut m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                        m += DMatrix::from_diagonal_element(n, n, ComplexField::from_real(2.0 * n as f64));
+                        let log   = m.log().unwrap();
+
+                        relative_eq!(log.exp(), m, epsilon = 1.0e-7)
+                    }
+
+                    fn exp_log(n: usize) -> bool {
+                        // The eigenvalues of `m` have an imaginary part in `]-π, π[`.
+                        let n = cmp::max(1, cmp::min(n, 10));
+                        let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                        let m = m.unscale(m.norm());
+
+                        relative_eq!(m.exp().log().unwrap(), m, epsilon = 1.0e-7)
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, RandComplex<f64>);
+    gen_tests!(f64, RandScalar<f64>);
+}
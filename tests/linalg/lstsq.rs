@@ -0,0 +1,162 @@
+use na::{DMatrix, DVector, Matrix2, Matrix3x2, Vector2, Vector3};
+
+#[test]
+#[rustfmt::skip]
+fn lstsq_full_rank() {
+    let m = Matrix3x2::new(
+        1.0, 0.0,
+        1.0, 1.0,
+        1.0, 2.0);
+    let b = Vector3::new(6.0, 0.0, 0.0);
+
+    // Fit of a line through (0, 6), (1, 0) and (2, 0).
+    let (x, residual_norm, rank) = m.lstsq(&b, 1.0e-10);
+
+    assert_eq!(rank, 2);
+    assert_relative_eq!(x, Vector2::new(5.0, -3.0), epsilon = 1.0e-10);
+    assert_relative_eq!(residual_norm, 6.0f64.sqrt(), epsilon = 1.0e-10);
+}
+
+#[test]
+#[rustfmt::skip]
+fn lstsq_rank_deficient() {
+    // The last column is the sum of the first two.
+    let m = DMatrix::from_row_slice(4, 3, &[
+        1.0, 0.0, 1.0,
+        2.0, 1.0, 3.0,
+        0.0, 3.0, 3.0,
+        1.0, 1.0, 2.0]);
+    let b = DVector::from_row_slice(&[1.0, 2.0, 3.0, 4.0]);
+
+    let (x, residual_norm, rank) = m.lstsq(&b, 1.0e-10);
+    let expected = m.clone().pseudo_inverse(1.0e-10).unwrap() * &b;
+
+    assert_eq!(rank, 2);
+    assert_relative_eq!(x, expected, epsilon = 1.0e-10);
+    assert_relative_eq!(residual_norm, (&b - &m * &x).norm(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn lstsq_underdetermined() {
+    let m = DMatrix::from_row_slice(1, 2, &[3.0, 4.0]);
+    let b = DVector::from_element(1, 5.0);
+
+    let (x, residual_norm, rank) = m.lstsq(&b, 1.0e-10);
+
+    assert_eq!(rank, 1);
+    assert_relative_eq!(x, DVector::from_row_slice(&[0.6, 0.8]), epsilon = 1.0e-10);
+    assert_relative_eq!(residual_norm, 0.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn lstsq_empty() {
+    let m = DMatrix::<f64>::zeros(0, 3);
+    let b = DMatrix::<f64>::zeros(0, 2);
+
+    let (x, residual_norm, rank) = m.lstsq(&b, 1.0e-10);
+
+    assert_eq!(x, DMatrix::zeros(3, 2));
+    assert_eq!(residual_norm, 0.0);
+    assert_eq!(rank, 0);
+}
+
+#[test]
+#[rustfmt::skip]
+fn solve_regularized() {
+    let m = Matrix3x2::new(
+        1.0, 0.0,
+        1.0, 1.0,
+        1.0, 2.0);
+    let b = Vector3::new(6.0, 0.0, 0.0);
+
+    let x = m.solve_regularized(&b, 2.0).unwrap();
+    let normal = m.transpose() * m + Matrix2::identity() * 2.0;
+    let expected = normal.try_inverse().unwrap() * m.transpose() * b;
+    assert_relative_eq!(x, expected, epsilon = 1.0e-10);
+
+    // Without regularization, this is the least-squares solution.
+    let x = m.solve_regularized(&b, 0.0).unwrap();
+    assert_relative_eq!(x, Vector2::new(5.0, -3.0), epsilon = 1.0e-10);
+}
+
+#[test]
+fn solve_regularized_rank_deficient() {
+    let m = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 2.0, 4.0, 6.0]);
+    let b = DVector::from_row_slice(&[1.0, 2.0]);
+
+    assert!(m.solve_regularized(&b, 0.0).is_none());
+
+    // With `m = u * v^t`, where `b = u`, the solution is `v * |u|² / (|u|² * |v|² + lambda)`.
+    // Solving the normal equations directly would lose about 5 digits here.
+    let x = m.solve_regularized(&b, 1.0e-3).unwrap();
+    let expected = DVector::from_row_slice(&[1.0, 2.0, 3.0]) * (5.0 / 70.001);
+    assert_relative_eq!(x, expected, epsilon = 1.0e-14);
+}
+
+#[cfg(feature = "arbitrary")]
+mod quickcheck_tests {
+    use na::{ComplexField, DMatrix};
+
+    /// The matrix `m^* * m + lambda * I` of the normal equations of a regularized problem.
+    pub fn regularized_normal<N: ComplexField<RealField = f64>>(
+        m: &DMatrix<N>,
+        lambda: f64,
+    ) -> DMatrix<N> {
+        let mut normal = m.ad_mul(m);
+        for i in 0..m.ncols() {
+            normal[(i, i)] += N::from_real(lambda);
+        }
+        normal
+    }
+
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: ty) => {
+            mod $module {
+                use na::DMatrix;
+                use std::cmp;
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+
+                quickcheck! {
+                    fn lstsq(nrows: usize, ncols: usize, rank: usize, nb: usize) -> bool {
+                        let nrows = cmp::max(1, cmp::min(nrows, 15));
+                        let ncols = cmp::max(1, cmp::min(ncols, 15));
+                        let rank  = cmp::max(1, cmp::min(rank, cmp::min(nrows, ncols)));
+                        let nb    = cmp::max(1, cmp::min(nb, 5));
+
+                        let a = DMatrix::<$scalar>::new_random(nrows, rank).map(|e| e.0);
+                        let b = DMatrix::<$scalar>::new_random(rank, ncols).map(|e| e.0);
+                        let m = a * b;
+                        let rhs = DMatrix::<$scalar>::new_random(nrows, nb).map(|e| e.0);
+
+                        let (x, residual_norm, est_rank) = m.lstsq(&rhs, 1.0e-10);
+                        let expected = m.clone().pseudo_inverse(1.0e-10).unwrap() * &rhs;
+
+                        // The solution can be large if `m` is ill-conditioned.
+                        est_rank == rank &&
+                        (&x - &expected).norm() <= 1.0e-6 * expected.norm().max(1.0) &&
+                        relative_eq!(residual_norm, (&rhs - &m * &x).norm(), epsilon = 1.0e-6)
+                    }
+
+                    fn solve_regularized(nrows: usize, ncols: usize, nb: usize, lambda: f64) -> bool {
+                        let nrows  = cmp::max(1, cmp::min(nrows, 15));
+                        let ncols  = cmp::max(1, cmp::min(ncols, 15));
+                        let nb     = cmp::max(1, cmp::min(nb, 5));
+                        let lambda = lambda.abs().min(10.0) + 1.0e-2;
+
+                        let m = DMatrix::<$scalar>::new_random(nrows, ncols).map(|e| e.0);
+                        let b = DMatrix::<$scalar>::new_random(nrows, nb).map(|e| e.0);
+
+                        let x = m.solve_regularized(&b, lambda).unwrap();
+                        let expected = super::regularized_normal(&m, lambda).cholesky().unwrap().solve(&m.ad_mul(&b));
+
+                        relative_eq!(x, expected, epsilon = 1.0e-7)
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, RandComplex<f64>);
+    gen_tests!(f64, RandScalar<f64>);
+}
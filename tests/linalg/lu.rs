@@ -1,4 +1,4 @@
-use na::Matrix3;
+use na::{Matrix3, Vector3};
 
 #[test]
 #[rustfmt::skip]
@@ -38,6 +38,212 @@ fn lu_simple_with_pivot() {
     assert!(relative_eq!(m, lu, epsilon = 1.0e-7));
 }
 
+#[test]
+fn lu_qr_solve_complex() {
+    use na::{Complex, Matrix2, Vector2};
+
+    let i = Complex::new(0.0, 1.0);
+    let m = Matrix2::new(
+        Complex::new(2.0, 1.0),
+        i,
+        Complex::new(1.0, 0.0),
+        Complex::new(0.0, -3.0),
+    );
+    let x = Vector2::new(Complex::new(1.0, -1.0), Complex::new(2.0, 0.5));
+    let b = m * x;
+
+    let lu = m.lu();
+    assert_relative_eq!(lu.solve(&b).unwrap(), x, epsilon = 1.0e-12);
+    assert_relative_eq!(
+        lu.determinant(),
+        m.m11 * m.m22 - m.m12 * m.m21,
+        epsilon = 1.0e-12
+    );
+
+    let qr = m.qr();
+    assert_relative_eq!(qr.solve(&b).unwrap(), x, epsilon = 1.0e-12);
+    assert_relative_eq!(qr.q() * qr.r(), m, epsilon = 1.0e-12);
+    assert_relative_eq!(
+        qr.q().adjoint() * qr.q(),
+        Matrix2::identity(),
+        epsilon = 1.0e-12
+    );
+}
+
+#[test]
+#[rustfmt::skip]
+fn lu_permutation_inverse() {
+    let m = Matrix3::new(
+        0.0, -1.0,  2.0,
+       -1.0,  2.0, -1.0,
+        2.0, -1.0,  0.0);
+
+    let (p, l, u) = m.lu().unpack();
+    assert!(!p.is_empty());
+
+    let mut lu = l * u;
+    p.inverse().permute_rows(&mut lu);
+    assert!(relative_eq!(m, lu, epsilon = 1.0e-7));
+
+    // Column permutations of the transpose.
+    let mut lu_t = (l * u).transpose();
+    p.inverse().permute_columns(&mut lu_t);
+    assert!(relative_eq!(m.transpose(), lu_t, epsilon = 1.0e-7));
+
+    let mut permuted = m;
+    p.permute_rows(&mut permuted);
+    p.inverse().inverse().inv_permute_rows(&mut permuted);
+    assert_eq!(permuted, m);
+}
+
+#[test]
+#[rustfmt::skip]
+fn lu_rcond() {
+    let m = Matrix3::new(
+        2.0, -1.0,  0.0,
+       -1.0,  2.0, -1.0,
+        0.0, -1.0,  2.0);
+
+    // ‖m‖₁ = 4 and ‖m⁻¹‖₁ = 2.
+    assert_relative_eq!(m.lu().rcond(), 0.125, epsilon = 1.0e-10);
+
+    let singular = Matrix3::new(
+        1.0, 2.0, 3.0,
+        2.0, 4.0, 6.0,
+        0.0, 1.0, 1.0);
+    assert_eq!(singular.lu().rcond(), 0.0);
+}
+
+#[test]
+#[rustfmt::skip]
+fn lu_logdet() {
+    let m = Matrix3::new(
+        0.0, -1.0,  2.0,
+       -1.0,  2.0, -1.0,
+        2.0, -1.0,  0.0);
+    let (sign, logdet) = m.lu().logdet();
+    assert_eq!(sign, -1.0);
+    assert_relative_eq!(logdet, 4.0f64.ln(), epsilon = 1.0e-10);
+
+    // The determinant of this matrix overflows.
+    let large = na::DMatrix::<f64>::from_diagonal_element(200, 200, -1.0e10);
+    assert_eq!(large.clone().lu().determinant(), std::f64::INFINITY);
+    let (sign, logdet) = large.lu().logdet();
+    assert_eq!(sign, 1.0);
+    assert_relative_eq!(logdet, 200.0 * 1.0e10f64.ln(), epsilon = 1.0e-9);
+
+    let singular = Matrix3::new(
+        1.0, 2.0, 3.0,
+        2.0, 4.0, 6.0,
+        0.0, 1.0, 1.0);
+    let (sign, logdet) = singular.lu().logdet();
+    assert_eq!(sign, 0.0);
+    assert_eq!(logdet, std::f64::NEG_INFINITY);
+}
+
+#[test]
+#[rustfmt::skip]
+fn lu_solve_refined() {
+    let m = Matrix3::new(
+        2.0, -1.0,  0.0,
+       -1.0,  2.0, -1.0,
+        0.0, -1.0,  2.0);
+    let perturbed = m + Matrix3::new(
+        1.0e-3,  0.0,    2.0e-3,
+        0.0,    -1.0e-3, 0.0,
+        3.0e-3,  0.0,    1.0e-3);
+    let b = Vector3::new(1.0, 2.0, 3.0);
+
+    // Reuse the decomposition of `m` to solve the perturbed system.
+    let lu = m.lu();
+    let x = lu.solve(&b).unwrap();
+    let refined = lu.solve_refined(&perturbed, &b, 10).unwrap();
+
+    assert!(!relative_eq!(perturbed * x, b, epsilon = 1.0e-7));
+    assert_relative_eq!(perturbed * refined, b, epsilon = 1.0e-12);
+    assert_eq!(lu.solve_refined(&m, &b, 0), lu.solve(&b));
+}
+
+#[test]
+#[rustfmt::skip]
+fn lu_in_place_static() {
+    let m = Matrix3::new(
+        0.0, 2.0, 1.0,
+        1.0, 1.0, 0.0,
+        4.0, 0.0, 2.0);
+    let b = Vector3::new(1.0, 2.0, 3.0);
+    let mut buffer = m;
+    let mut pivots = [0; 3];
+    let view = na::LU::new_in_place(&mut buffer, &mut pivots);
+    let mut x = b;
+
+    assert!(view.solve_mut(&mut x));
+    assert_eq!(view.pivots(), &[2, 2, 2]);
+    assert_relative_eq!(view.determinant(), m.determinant(), epsilon = 1.0e-12);
+    assert_relative_eq!(m * x, b, epsilon = 1.0e-12);
+}
+
+#[test]
+#[rustfmt::skip]
+fn lu_equilibrate() {
+    let m = na::Matrix3x4::new(
+        1.0e10, 2.0e10,  0.0,    3.0,
+        1.0e-5, 0.0,    -4.0e-5, 0.0,
+        0.0,    0.0,     0.0,    0.0);
+    let mut equilibrated = m;
+    let (r, c) = equilibrated.equilibrate_mut();
+
+    assert_eq!((r, c), m.equilibrate());
+    assert_eq!(r[2], 1.0);
+
+    for f in r.iter().chain(c.iter()) {
+        assert_eq!(f64::log2(*f).fract(), 0.0);
+    }
+
+    for i in 0..3 {
+        for j in 0..4 {
+            assert_eq!(equilibrated[(i, j)], m[(i, j)] * r[i] * c[j]);
+        }
+    }
+
+    for i in 0..2 {
+        let max = equilibrated.row(i).amax();
+        assert!(max > 0.1 && max < 2.0f64.sqrt());
+    }
+
+    for j in 0..4 {
+        let max = equilibrated.column(j).amax();
+        assert!(max >= 0.5f64.sqrt() && max < 2.0f64.sqrt());
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn lu_solve_equilibrated() {
+    // A well-conditioned matrix with badly scaled rows and columns.
+    let base = Matrix3::new(
+        4.0, 1.0, 2.0,
+        1.0, 3.0, 0.5,
+        2.0, 0.5, 5.0);
+    let rows = Vector3::new(1.0e-12, 3.0, 7.0e9);
+    let cols = Vector3::new(5.0e8, 1.0, 2.0e-7);
+    let m = Matrix3::from_fn(|i, j| base[(i, j)] * rows[i] * cols[j]);
+    let x = Vector3::new(1.0e-8, 2.0, -3.0e6);
+    let b = m * x;
+
+    let mut equilibrated = m;
+    let (r, c) = equilibrated.equilibrate_mut();
+    let sol = equilibrated.lu().solve_equilibrated(&r, &c, &b).unwrap();
+
+    for i in 0..3 {
+        assert_relative_eq!(sol[i], x[i], max_relative = 1.0e-12);
+    }
+
+    let singular = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 0.0, 1.0);
+    let (r, c) = singular.equilibrate();
+    assert!(singular.lu().solve_equilibrated(&r, &c, &b).is_none());
+}
+
 #[cfg(feature = "arbitrary")]
 mod quickcheck_tests {
     #[allow(unused_imports)]
@@ -52,6 +258,25 @@ mod quickcheck_tests {
                 use crate::core::helper::{RandScalar, RandComplex};
 
                 quickcheck! {
+                    fn lu_in_place(n: usize, nb: usize) -> bool {
+                        let n  = cmp::max(1, cmp::min(n, 30));
+                        let nb = cmp::min(nb, 10);
+                        let m  = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                        let b  = DMatrix::<$scalar>::new_random(n, nb).map(|e| e.0);
+                        let lu = m.clone().lu();
+
+                        let mut buffer = m.clone();
+                        let mut pivots = vec![0; n];
+                        let view = na::LU::new_in_place(&mut buffer, &mut pivots);
+                        let mut x = b.clone();
+
+                        view.is_invertible() &&
+                        view.solve_mut(&mut x) &&
+                        view.lu_internal() == lu.lu_internal() &&
+                        relative_eq!(view.determinant(), lu.determinant(), epsilon = 1.0e-7) &&
+                        relative_eq!(&m * x, b, epsilon = 1.0e-6)
+                    }
+
                     fn lu(m: DMatrix<$scalar>) -> bool {
                         let mut m = m;
                         if m.len() == 0 {
@@ -131,6 +356,26 @@ mod quickcheck_tests {
                                 (sol2.is_none() || relative_eq!(&m * sol2.unwrap(), b2, epsilon = 1.0e-6))
                     }
 
+                    fn lu_solve_refined(n: usize, nb: usize) -> bool {
+                        let n  = cmp::max(1, cmp::min(n, 15));
+                        let nb = cmp::max(1, cmp::min(nb, 15));
+                        let m  = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+
+                        // Ensure the matrix is well conditioned.
+                        let mut l = m.lower_triangle();
+                        let mut u = m.upper_triangle();
+                        l.fill_diagonal(na::one());
+                        u.fill_diagonal(na::one());
+                        let m = l * u;
+
+                        let delta = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0 * 1.0e-4);
+                        let perturbed = &m + delta;
+                        let b = DMatrix::<$scalar>::new_random(n, nb).map(|e| e.0);
+
+                        let x = m.lu().solve_refined(&perturbed, &b, 20).unwrap();
+                        relative_eq!(&perturbed * x, b, epsilon = 1.0e-7)
+                    }
+
                     fn lu_inverse(n: usize) -> bool {
                         let n = cmp::max(1, cmp::min(n, 15)); // To avoid slowing down the test too much.
                         let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
@@ -150,6 +395,21 @@ mod quickcheck_tests {
                         return id1.is_identity(1.0e-5) && id2.is_identity(1.0e-5);
                     }
 
+                    fn lu_rcond(n: usize) -> bool {
+                        let n = cmp::max(1, cmp::min(n, 15));
+                        let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+
+                        match m.clone().try_inverse() {
+                            Some(m1) => {
+                                // The estimated norms are lower bounds of the actual norms.
+                                let rcond = 1.0 / (m.norm_one() * m1.norm_one());
+                                let est = m.lu().rcond();
+                                est >= rcond * (1.0 - 1.0e-7) && est <= 1.0 + 1.0e-7
+                            }
+                            None => true,
+                        }
+                    }
+
                     fn lu_inverse_static(m: Matrix4<$scalar>) -> bool {
                         let m = m.map(|e| e.0);
                         let lu  = m.lu();
@@ -1,15 +1,35 @@
 mod balancing;
+mod banded;
 mod bidiagonal;
 mod cholesky;
+mod circulant;
+mod col_piv_qr;
 mod convolution;
 mod eigen;
 mod exp;
 mod full_piv_lu;
+mod givens;
 mod hessenberg;
+mod householder;
+mod incremental_svd;
 mod inverse;
+mod iterative;
+mod lanczos;
+mod ldl;
+mod log;
+mod lstsq;
 mod lu;
+mod nnls;
+mod polar;
+mod pow;
+mod power_iteration;
 mod qr;
+mod riccati;
 mod schur;
 mod solve;
+mod sqrtm;
 mod svd;
+mod sylvester;
+mod toeplitz;
 mod tridiagonal;
+mod updatable_qr;
@@ -0,0 +1,70 @@
+use na::linalg::LinalgError;
+use na::{DMatrix, DVector};
+
+/// Checks the optimality conditions of the non-negative least-squares problem `min |a * x - b|`.
+fn is_nnls_solution(a: &DMatrix<f64>, b: &DVector<f64>, x: &DVector<f64>, eps: f64) -> bool {
+    let grad = a.tr_mul(&(b - a * x));
+
+    x.iter().all(|e| *e >= 0.0)
+        && x.iter()
+            .zip(grad.iter())
+            .all(|(e, g)| *g <= eps && (*e == 0.0 || g.abs() <= eps))
+}
+
+#[test]
+fn nnls_identity() {
+    let a = DMatrix::identity(3, 3);
+    let b = DVector::from_row_slice(&[1.0, -2.0, 3.0]);
+    let x = na::linalg::nnls(&a, &b);
+
+    assert_relative_eq!(
+        x,
+        DVector::from_row_slice(&[1.0, 0.0, 3.0]),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+#[rustfmt::skip]
+fn nnls_active_constraint() {
+    let a = DMatrix::from_row_slice(4, 2, &[
+        1.0, 0.0,
+        1.0, 1.0,
+        1.0, 2.0,
+        1.0, 3.0]);
+    // The unconstrained least-squares fit of a line through these points has a negative slope.
+    let b = DVector::from_row_slice(&[4.0, 3.0, 2.0, 3.0]);
+    let x = na::linalg::nnls(&a, &b);
+
+    assert_relative_eq!(x, DVector::from_row_slice(&[3.0, 0.0]), epsilon = 1.0e-10);
+    assert!(is_nnls_solution(&a, &b, &x, 1.0e-10));
+}
+
+#[test]
+fn nnls_max_niter() {
+    let a = DMatrix::identity(3, 3);
+    let b = DVector::from_row_slice(&[1.0, 2.0, 3.0]);
+
+    assert_eq!(
+        na::linalg::try_nnls(&a, &b, 1.0e-10, 2).err(),
+        Some(LinalgError::NotConverged { iterations: 2 })
+    );
+    assert!(na::linalg::try_nnls(&a, &b, 1.0e-10, 3).is_ok());
+}
+
+#[cfg(feature = "arbitrary")]
+mod quickcheck_tests {
+    use na::{DMatrix, DVector};
+
+    quickcheck! {
+        fn nnls(nrows: usize, ncols: usize) -> bool {
+            let nrows = nrows.clamp(1, 20);
+            let ncols = ncols.clamp(1, 20);
+            let a = DMatrix::<f64>::new_random(nrows, ncols).map(|e| e - 0.5);
+            let b = DVector::<f64>::new_random(nrows).map(|e| e - 0.5);
+
+            let x = na::linalg::nnls(&a, &b);
+            super::is_nnls_solution(&a, &b, &x, 1.0e-7)
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use na::{Matrix3, Rotation3};
+
+#[test]
+#[rustfmt::skip]
+fn polar_reflection_mat3() {
+    // A stretch composed with a reflection: `U` must include the reflection.
+    let m = Matrix3::new(0.0, 2.0, 0.0,
+                         1.0, 0.0, 0.0,
+                         0.0, 0.0, 3.0);
+
+    let polar = m.polar();
+    let expected_u = Matrix3::new(0.0, 1.0, 0.0,
+                                  1.0, 0.0, 0.0,
+                                  0.0, 0.0, 1.0);
+    let expected_p = Matrix3::new(1.0, 0.0, 0.0,
+                                  0.0, 2.0, 0.0,
+                                  0.0, 0.0, 3.0);
+
+    assert!(relative_eq!(polar.u, expected_u, epsilon = 1.0e-7));
+    assert!(relative_eq!(polar.p, expected_p, epsilon = 1.0e-7));
+}
+
+#[test]
+#[rustfmt::skip]
+fn nearest_orthogonal_mat3() {
+    let m = Matrix3::new(0.0, 2.0, 0.0,
+                         1.0, 0.0, 0.0,
+                         0.0, 0.0, 3.0);
+
+    assert!(relative_eq!(m.nearest_orthogonal(), m.polar().u, epsilon = 1.0e-7));
+
+    let rot = Rotation3::from_euler_angles(0.1, -0.4, 1.2);
+    let mut drifted = *rot.matrix();
+    for _ in 0..1000 {
+        // Accumulate rounding-like perturbations.
+        drifted *= Matrix3::new(1.0,    1.0e-6, 0.0,
+                                0.0,    1.0,    0.0,
+                                1.0e-6, 0.0,    1.0);
+    }
+
+    assert!(!drifted.is_orthogonal(1.0e-7));
+    let projected = Rotation3::from_matrix_unchecked(drifted.nearest_orthogonal());
+    assert!(projected.matrix().is_orthogonal(1.0e-7));
+    assert!(relative_eq!(projected.matrix().determinant(), 1.0, epsilon = 1.0e-7));
+    assert!(relative_eq!(projected, rot, epsilon = 1.0e-2));
+}
+
+#[cfg(feature = "arbitrary")]
+mod quickcheck_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: ty) => {
+            mod $module {
+                use na::{DMatrix, Matrix2, Matrix3, Matrix4};
+                use std::cmp;
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+
+                quickcheck! {
+                    fn polar(n: usize) -> bool {
+                        let n     = cmp::max(1, cmp::min(n, 10));
+                        let m     = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                        let polar = m.clone().polar();
+                        let eigvals = polar.p.clone().symmetric_eigenvalues();
+
+                        relative_eq!(m, polar.recompose(), epsilon = 1.0e-7) &&
+                        polar.u.is_orthogonal(1.0e-7) &&
+                        relative_eq!(polar.p, polar.p.adjoint(), epsilon = 1.0e-7) &&
+                        eigvals.iter().all(|e| *e >= -1.0e-7)
+                    }
+
+                    fn polar_static_square_4x4(m: Matrix4<$scalar>) -> bool {
+                        let m     = m.map(|e| e.0);
+                        let polar = m.polar();
+
+                        relative_eq!(m, polar.recompose(), epsilon = 1.0e-7) &&
+                        polar.u.is_orthogonal(1.0e-7)
+                    }
+
+                    fn polar_static_square_3x3(m: Matrix3<$scalar>) -> bool {
+                        let m     = m.map(|e| e.0);
+                        let polar = m.polar();
+
+                        relative_eq!(m, polar.recompose(), epsilon = 1.0e-7) &&
+                        polar.u.is_orthogonal(1.0e-7)
+                    }
+
+                    fn polar_static_square_2x2(m: Matrix2<$scalar>) -> bool {
+                        let m     = m.map(|e| e.0);
+                        let polar = m.polar();
+
+                        relative_eq!(m, polar.recompose(), epsilon = 1.0e-7) &&
+                        polar.u.is_orthogonal(1.0e-7)
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, RandComplex<f64>);
+    gen_tests!(f64, RandScalar<f64>);
+}
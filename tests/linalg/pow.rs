@@ -0,0 +1,120 @@
+use na::{DMatrix, Matrix2, Matrix3};
+use num_complex::Complex;
+
+#[test]
+#[rustfmt::skip]
+fn powf_symmetric_mat3() {
+    let m = Matrix3::new(4.0, 1.0, 0.0,
+                         1.0, 5.0, 2.0,
+                         0.0, 2.0, 6.0);
+
+    assert_relative_eq!(m.powf(1.0).unwrap(), m, epsilon = 1.0e-7);
+    assert_relative_eq!(m.powf(2.0).unwrap(), m * m, epsilon = 1.0e-7);
+    assert_relative_eq!(m.powf(0.5).unwrap(), m.sqrtm().unwrap(), epsilon = 1.0e-7);
+    assert_relative_eq!(m.powf(-0.5).unwrap(), m.sqrtm().unwrap().try_inverse().unwrap(), epsilon = 1.0e-7);
+    assert_relative_eq!(m.powf(0.0).unwrap(), Matrix3::identity(), epsilon = 1.0e-7);
+}
+
+#[test]
+#[rustfmt::skip]
+fn powf_general_mat2() {
+    let m = Matrix2::new(2.0, 1.0,
+                         -1.0, 3.0);
+
+    assert_relative_eq!(m.powf(3.0).unwrap(), m * m * m, epsilon = 1.0e-7);
+    assert_relative_eq!(m.powf(-1.0).unwrap(), m.try_inverse().unwrap(), epsilon = 1.0e-7);
+
+    let cbrt = m.powf(1.0 / 3.0).unwrap();
+    assert_relative_eq!(cbrt * cbrt * cbrt, m, epsilon = 1.0e-7);
+}
+
+#[test]
+#[rustfmt::skip]
+fn powf_singular() {
+    let m = Matrix2::new(1.0, 1.0,
+                         1.0, 1.0);
+
+    assert_relative_eq!(m.powf(0.5).unwrap(), m / 2.0f64.sqrt(), epsilon = 1.0e-7);
+    assert!(m.powf(-0.5).is_none());
+    assert!(Matrix2::new(-1.0, 0.0, 0.0, 1.0).powf(0.5).is_none());
+}
+
+#[test]
+#[rustfmt::skip]
+fn powf_complex_eigenvalues_mat3() {
+    // The real Schur form has a 2x2 block for the eigenvalues `1 ± 2i`, and the real eigenvalue
+    // `3` is well separated from them.
+    let m = Matrix3::new(1.0, -2.0, 0.5,
+                         2.0,  1.0, 1.0,
+                         0.0,  0.0, 3.0);
+
+    assert_relative_eq!(m.powf(2.0).unwrap(), m * m, epsilon = 1.0e-7);
+    assert_relative_eq!(m.powf(-1.0).unwrap(), m.try_inverse().unwrap(), epsilon = 1.0e-7);
+    assert_relative_eq!(m.powf(0.5).unwrap(), m.sqrtm().unwrap(), epsilon = 1.0e-7);
+
+    let p = m.powf(0.25).unwrap();
+    assert_relative_eq!(p * p * p * p, m, epsilon = 1.0e-7);
+}
+
+#[test]
+#[rustfmt::skip]
+fn powf_clustered_eigenvalues() {
+    // The eigenvalues `2` and `2.01` are put in the same block of the Schur–Parlett method,
+    // and `10` is well separated from them.
+    let m = Matrix3::new(2.0, 1.0,  3.0,
+                         0.0, 2.01, 1.0,
+                         0.0, 0.0, 10.0);
+
+    assert_relative_eq!(m.powf(3.0).unwrap(), m * m * m, epsilon = 1.0e-6);
+    assert_relative_eq!(m.powf(0.5).unwrap(), m.sqrtm().unwrap(), epsilon = 1.0e-7);
+
+    // A defective eigenvalue.
+    let jordan = Matrix2::new(4.0, 1.0,
+                              0.0, 4.0);
+    assert_relative_eq!(jordan.powf(0.5).unwrap(), Matrix2::new(2.0, 0.25, 0.0, 2.0), epsilon = 1.0e-7);
+}
+
+#[test]
+fn powf_singular_nonsymmetric() {
+    // The isolated zero eigenvalue is allowed for positive exponents.
+    let m = Matrix2::new(0.0, 1.0, 0.0, 4.0);
+
+    assert_relative_eq!(
+        m.powf(0.5).unwrap(),
+        Matrix2::new(0.0, 0.5, 0.0, 2.0),
+        epsilon = 1.0e-7
+    );
+    assert_relative_eq!(m.powf(2.0).unwrap(), m * m, epsilon = 1.0e-7);
+    assert!(m.powf(-1.0).is_none());
+    assert!(Matrix2::new(-1.0, 1.0, 0.0, 2.0).powf(0.5).is_none());
+}
+
+#[test]
+fn powf_complex() {
+    let m = Matrix2::new(
+        Complex::new(2.0, 1.0),
+        Complex::new(1.0, -1.0),
+        Complex::new(0.5, 0.0),
+        Complex::new(3.0, -0.5),
+    );
+
+    assert_relative_eq!(m.powf(2.0).unwrap(), m * m, epsilon = 1.0e-7);
+
+    let p = m.powf(1.0 / 3.0).unwrap();
+    assert_relative_eq!(p * p * p, m, epsilon = 1.0e-7);
+}
+
+#[test]
+fn powf_dynamic() {
+    let m = DMatrix::from_fn(6, 6, |i, j| {
+        if i == j {
+            (i + 1) as f64
+        } else {
+            ((i * 7 + j * 3) % 5) as f64 * 0.1 - 0.2
+        }
+    });
+
+    let p = m.powf(0.5).unwrap();
+    assert_relative_eq!(&p * &p, m, epsilon = 1.0e-7);
+    assert_relative_eq!(m.powf(3.0).unwrap(), &m * &m * &m, epsilon = 1.0e-7);
+}
@@ -0,0 +1,63 @@
+use na::{DMatrix, Matrix2, Matrix3, Vector3};
+
+#[test]
+#[rustfmt::skip]
+fn dominant_eigenpair_symmetric() {
+    let m = Matrix3::new(4.0, 1.0, 0.5,
+                         1.0, 3.0, 0.2,
+                         0.5, 0.2, 1.0);
+    let (val, vec) = na::dominant_eigenpair(&m, 1.0e-12, 10000).unwrap();
+    let eig = m.symmetric_eigen();
+
+    assert_relative_eq!(val, eig.eigenvalues.max(), epsilon = 1.0e-10);
+    assert_relative_eq!(vec.norm(), 1.0, epsilon = 1.0e-10);
+    assert_relative_eq!(m * vec, vec * val, epsilon = 1.0e-10);
+}
+
+#[test]
+#[rustfmt::skip]
+fn dominant_eigenpair_negative_and_non_symmetric() {
+    let m = Matrix3::from_diagonal(&Vector3::new(-5.0, 2.0, 1.0));
+    let (val, _) = na::dominant_eigenpair(&m, 1.0e-12, 10000).unwrap();
+    assert_relative_eq!(val, -5.0, epsilon = 1.0e-10);
+
+    let m = Matrix2::new(3.0, 1.0,
+                         0.0, 1.0);
+    let (val, vec) = na::dominant_eigenpair(&m, 1.0e-12, 10000).unwrap();
+    assert_relative_eq!(val, 3.0, epsilon = 1.0e-10);
+    assert_relative_eq!(m * vec, vec * val, epsilon = 1.0e-10);
+}
+
+#[test]
+#[rustfmt::skip]
+fn dominant_eigenpair_complex_pair() {
+    // A rotation has two complex-conjugate eigenvalues of modulus 1.
+    let m = Matrix2::new(0.0, -1.0,
+                         1.0,  0.0);
+    assert!(na::dominant_eigenpair(&m, 1.0e-12, 1000).is_none());
+}
+
+#[test]
+fn inverse_iteration_interior_eigenvalue() {
+    let n = 10;
+    let a = DMatrix::<f64>::new_random(n, n);
+    let m = &a + a.transpose();
+    let eig = m.clone().symmetric_eigen().sorted(true);
+
+    // Target the third smallest eigenvalue with a coarse approximation.
+    let target = eig.eigenvalues[2];
+    let shift = target + 0.1 * (eig.eigenvalues[3] - target).min(target - eig.eigenvalues[1]);
+    let (val, vec) = na::inverse_iteration(&m, shift, 1.0e-12, 10000).unwrap();
+
+    assert_relative_eq!(val, target, epsilon = 1.0e-9);
+    assert_relative_eq!(&m * &vec, vec * val, epsilon = 1.0e-9);
+}
+
+#[test]
+fn inverse_iteration_exact_shift() {
+    let m = Matrix3::from_diagonal(&Vector3::new(1.0, 2.0, 3.0));
+    let (val, vec) = na::inverse_iteration(&m, 2.0, 1.0e-12, 100).unwrap();
+
+    assert_relative_eq!(val, 2.0, epsilon = 1.0e-10);
+    assert_relative_eq!(vec.abs(), Vector3::y(), epsilon = 1.0e-10);
+}
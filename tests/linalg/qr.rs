@@ -23,6 +23,41 @@ macro_rules! gen_tests(
                     q.is_orthogonal(1.0e-7)
                 }
 
+                fn qr_in_place(n: usize, nb: usize) -> bool {
+                    let n  = cmp::max(1, cmp::min(n, 30));
+                    let nb = cmp::min(nb, 10);
+                    let m  = DMatrix::<$scalar>::new_random(n + 2, n).map(|e| e.0);
+                    let b  = DMatrix::<$scalar>::new_random(n, nb).map(|e| e.0);
+                    let qr = m.clone().qr();
+
+                    // Factor the leading square block inside of a larger buffer.
+                    let mut buffer = m.clone();
+                    let mut diag = vec![na::zero(); n];
+                    let mut block = buffer.rows_mut(0, n);
+                    let view = na::QR::new_in_place(&mut block, &mut diag);
+                    let sq = m.rows(0, n).into_owned().qr();
+                    let mut x = b.clone();
+                    let mut qx = b.clone();
+
+                    if !view.solve_mut(&mut x) {
+                        return false;
+                    }
+
+                    view.q_mul(&mut qx);
+                    view.q_tr_mul(&mut qx);
+
+                    let mut rect_buffer = m.clone();
+                    let mut rect_diag = vec![na::zero(); n];
+                    let rect = na::QR::new_in_place(&mut rect_buffer, &mut rect_diag);
+                    let r = qr.r();
+
+                    relative_eq!(m.rows(0, n) * &x, b, epsilon = 1.0e-6) &&
+                    relative_eq!(x, sq.solve(&b).unwrap(), epsilon = 1.0e-6) &&
+                    relative_eq!(qx, b, epsilon = 1.0e-7) &&
+                    buffer.rows(n, 2) == m.rows(n, 2) &&
+                    (0..n).all(|i| (0..n).all(|j| relative_eq!(rect.r_element(i, j), r[(i, j)], epsilon = 1.0e-7)))
+                }
+
                 fn qr_static_5_3(m: Matrix5x3<$scalar>) -> bool {
                     let m = m.map(|e| e.0);
                     let qr = m.qr();
@@ -131,3 +166,14 @@ macro_rules! gen_tests(
 
 gen_tests!(complex, RandComplex<f64>);
 gen_tests!(f64, RandScalar<f64>);
+
+#[test]
+fn qr_tall_economy_size() {
+    let m = na::DMatrix::<f64>::new_random(2000, 5);
+    let (q, r) = m.clone().qr().unpack();
+
+    assert_eq!(q.shape(), (2000, 5));
+    assert_eq!(r.shape(), (5, 5));
+    assert!(q.is_orthogonal(1.0e-7));
+    assert_relative_eq!(m, q * r, epsilon = 1.0e-7);
+}
@@ -0,0 +1,71 @@
+use na::{DMatrix, Matrix1, Matrix2, Vector2};
+
+fn care_residual(
+    a: &DMatrix<f64>,
+    b: &DMatrix<f64>,
+    q: &DMatrix<f64>,
+    r: &DMatrix<f64>,
+    x: &DMatrix<f64>,
+) -> f64 {
+    let g = b * r.clone().try_inverse().unwrap() * b.transpose();
+    (a.transpose() * x + x * a - x * g * x + q).norm()
+}
+
+#[test]
+fn care_scalar() {
+    let x = na::solve_care(
+        &Matrix1::new(1.0),
+        &Matrix1::new(1.0),
+        &Matrix1::new(1.0),
+        &Matrix1::new(1.0),
+    )
+    .unwrap();
+
+    assert_relative_eq!(x[0], 1.0 + 2.0f64.sqrt(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn care_double_integrator() {
+    let a = Matrix2::new(0.0, 1.0, 0.0, 0.0);
+    let b = Vector2::new(0.0, 1.0);
+    let x = na::solve_care(&a, &b, &Matrix2::identity(), &Matrix1::new(1.0)).unwrap();
+
+    let s3 = 3.0f64.sqrt();
+    assert_relative_eq!(x, Matrix2::new(s3, 1.0, 1.0, s3), epsilon = 1.0e-10);
+}
+
+#[test]
+fn care_random_stabilizing() {
+    let n = 6;
+    let a = DMatrix::<f64>::new_random(n, n) * 2.0 - DMatrix::from_element(n, n, 1.0);
+    let b = DMatrix::<f64>::new_random(n, 2);
+    let c = DMatrix::<f64>::new_random(n, n);
+    let q = c.transpose() * c + DMatrix::identity(n, n);
+    let r = DMatrix::identity(2, 2);
+
+    let x = na::solve_care(&a, &b, &q, &r).unwrap();
+    assert!(care_residual(&a, &b, &q, &r, &x) < 1.0e-8);
+    assert_relative_eq!(x.transpose(), x, epsilon = 1.0e-10);
+
+    // The closed-loop matrix is stable.
+    let k = r.try_inverse().unwrap() * b.transpose() * &x;
+    let eigenvalues = (a - b * k).complex_eigenvalues();
+    assert!(eigenvalues.iter().all(|e| e.re < 0.0));
+}
+
+#[test]
+fn care_not_stabilizable() {
+    // The unstable matrix `a` can't be stabilized without any input.
+    let a = Matrix2::identity();
+    let b = Vector2::zeros();
+
+    assert!(na::solve_care(&a, &b, &Matrix2::identity(), &Matrix1::new(1.0)).is_none());
+}
+
+#[test]
+fn care_indefinite_r() {
+    let a = Matrix2::new(0.0, 1.0, 0.0, 0.0);
+    let b = Vector2::new(0.0, 1.0);
+
+    assert!(na::solve_care(&a, &b, &Matrix2::identity(), &Matrix1::new(-1.0)).is_none());
+}
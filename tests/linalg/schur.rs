@@ -13,6 +13,24 @@ fn schur_simpl_mat3() {
     assert!(relative_eq!(vecs * vals * vecs.transpose(), m, epsilon = 1.0e-7));
 }
 
+#[test]
+#[rustfmt::skip]
+fn schur_accessors_mat3() {
+    // Rotation around the `z` axis: has a pair of complex-conjugate eigenvalues.
+    let m = Matrix3::new(0.0, -1.0, 0.0,
+                         1.0,  0.0, 0.0,
+                         0.0,  0.0, 2.0);
+
+    let schur = m.schur();
+    assert!(relative_eq!(schur.recompose(), m, epsilon = 1.0e-7));
+    assert!(relative_eq!(schur.q() * schur.q().transpose(), Matrix3::identity(), epsilon = 1.0e-7));
+    assert!(schur.eigenvalues().is_none());
+
+    let (q, t) = schur.clone().unpack();
+    assert_eq!(&q, schur.q());
+    assert_eq!(&t, schur.t());
+}
+
 #[cfg(feature = "arbitrary")]
 mod quickcheck_tests {
     macro_rules! gen_tests(
@@ -4,7 +4,7 @@
 macro_rules! gen_tests(
     ($module: ident, $scalar: ty) => {
         mod $module {
-            use na::{Matrix4, Matrix4x5, ComplexField};
+            use na::{Matrix4, Matrix4x5, Matrix4x6, ComplexField, U5};
             #[allow(unused_imports)]
             use crate::core::helper::{RandScalar, RandComplex};
 
@@ -56,6 +56,40 @@ macro_rules! gen_tests(
 
                     relative_eq!(tri.transpose() * x, b, epsilon = 1.0e-7)
                 }
+
+                fn solve_lower_triangular_mut_to(a: Matrix4<$scalar>, b: Matrix4x5<$scalar>) -> bool {
+                    let b = b.map(|e| e.0);
+                    let mut a = a.map(|e| e.0);
+                    unzero_diagonal(&mut a);
+                    let tri = a.lower_triangle();
+                    let mut x = Matrix4x5::zeros();
+
+                    a.solve_lower_triangular_mut_to(&b, &mut x) &&
+                    relative_eq!(tri * x, b, epsilon = 1.0e-7)
+                }
+
+                fn solve_upper_triangular_mut_to(a: Matrix4<$scalar>, b: Matrix4x5<$scalar>) -> bool {
+                    let b = b.map(|e| e.0);
+                    let mut a = a.map(|e| e.0);
+                    unzero_diagonal(&mut a);
+                    let tri = a.upper_triangle();
+                    // Write the solution into a slice of a larger buffer.
+                    let mut buf = Matrix4x6::zeros();
+
+                    a.solve_upper_triangular_mut_to(&b, &mut buf.columns_mut(1, 5)) &&
+                    relative_eq!(tri * buf.fixed_columns::<U5>(1), b, epsilon = 1.0e-7)
+                }
+
+                fn ad_solve_lower_triangular_mut_to(a: Matrix4<$scalar>, b: Matrix4x5<$scalar>) -> bool {
+                    let b = b.map(|e| e.0);
+                    let mut a = a.map(|e| e.0);
+                    unzero_diagonal(&mut a);
+                    let tri = a.lower_triangle();
+                    let mut x = Matrix4x5::zeros();
+
+                    a.ad_solve_lower_triangular_mut_to(&b, &mut x) &&
+                    relative_eq!(tri.adjoint() * x, b, epsilon = 1.0e-7)
+                }
             }
         }
     }
@@ -0,0 +1,82 @@
+use na::{DMatrix, Matrix2, Matrix3};
+
+#[test]
+#[rustfmt::skip]
+fn sqrtm_rotation_mat2() {
+    // The square root of a rotation by 90 degrees is a rotation by 45 degrees.
+    let m = Matrix2::new(0.0, -1.0,
+                         1.0,  0.0);
+    let expected = Matrix2::new(0.5f64.sqrt(), -(0.5f64.sqrt()),
+                                0.5f64.sqrt(), 0.5f64.sqrt());
+
+    assert_relative_eq!(m.sqrtm().unwrap(), expected, epsilon = 1.0e-7);
+}
+
+#[test]
+#[rustfmt::skip]
+fn sqrtm_symmetric_mat3() {
+    let m = Matrix3::new(4.0, 1.0, 0.0,
+                         1.0, 5.0, 2.0,
+                         0.0, 2.0, 6.0);
+    let sqrt  = m.sqrtm().unwrap();
+
+    assert_relative_eq!(sqrt, sqrt.transpose(), epsilon = 1.0e-7);
+    assert_relative_eq!(sqrt * sqrt, m, epsilon = 1.0e-7);
+}
+
+#[test]
+#[rustfmt::skip]
+fn sqrtm_no_real_square_root() {
+    // Not positive semi-definite.
+    assert!(Matrix2::new(-1.0, 0.0, 0.0, 1.0).sqrtm().is_none());
+    // Has a negative real eigenvalue.
+    assert!(Matrix2::new(-1.0, 0.5, 0.0, 1.0).sqrtm().is_none());
+    // Nilpotent.
+    assert!(Matrix2::new(0.0, 1.0, 0.0, 0.0).sqrtm().is_none());
+}
+
+#[test]
+fn sqrtm_zero() {
+    let m = DMatrix::<f64>::zeros(3, 3);
+    assert_eq!(m.sqrtm(), Some(m));
+}
+
+#[cfg(feature = "arbitrary")]
+mod quickcheck_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: ty) => {
+            mod $module {
+                use na::{ComplexField, DMatrix};
+                use std::cmp;
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+
+                quickcheck! {
+                    fn sqrtm_diagonally_dominant(n: usize) -> bool {
+                        // All the eigenvalues of `m` have a positive real part.
+                        let n     = cmp::max(1, cmp::min(n, 10));
+                        let mut m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                        m += DMatrix::from_diagonal_element(n, n, ComplexField::from_real(2.0 * n as f64));
+                        let sqrt  = m.sqrtm().unwrap();
+
+                        relative_eq!(&sqrt * &sqrt, m, epsilon = 1.0e-7)
+                    }
+
+                    fn sqrtm_hermitian_positive_definite(n: usize) -> bool {
+                        let n     = cmp::max(1, cmp::min(n, 10));
+                        let m     = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                        let mut m = &m * m.adjoint();
+                        m += DMatrix::from_diagonal_element(n, n, ComplexField::from_real(1.0));
+                        let sqrt  = m.sqrtm().unwrap();
+
+                        relative_eq!(sqrt, sqrt.adjoint(), epsilon = 1.0e-7) &&
+                        relative_eq!(&sqrt * &sqrt, m, epsilon = 1.0e-7)
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, RandComplex<f64>);
+    gen_tests!(f64, RandScalar<f64>);
+}
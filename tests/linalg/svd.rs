@@ -1,4 +1,5 @@
-use na::{DMatrix, Matrix6};
+use na::{DMatrix, Matrix2, Matrix2x3, Matrix3x4, Matrix6, SVD};
+use std::ops::ControlFlow;
 
 #[cfg(feature = "arbitrary")]
 mod quickcheck_tests {
@@ -150,6 +151,28 @@ mod quickcheck_tests {
 
                         true
                     }
+
+                    fn svd_randomized(nrows: usize, ncols: usize, rank: usize) -> bool {
+                        let nrows = cmp::max(1, cmp::min(nrows, 30));
+                        let ncols = cmp::max(1, cmp::min(ncols, 30));
+                        let rank = cmp::max(1, cmp::min(rank, 5));
+                        let a = DMatrix::<$scalar>::new_random(nrows, rank).map(|e| e.0);
+                        let b = DMatrix::<$scalar>::new_random(rank, ncols).map(|e| e.0);
+                        let m = a * b;
+
+                        let svd = m.svd_randomized(rank, 5, 1);
+                        let (u, s, v_t) = (svd.u.unwrap(), svd.singular_values, svd.v_t.unwrap());
+                        let ds = DMatrix::from_diagonal(&s.map(|e| ComplexField::from_real(e)));
+
+                        let mut expected: Vec<_> = m.singular_values().iter().cloned().collect();
+                        expected.sort_by(|a, b| b.partial_cmp(a).unwrap());
+
+                        (1..s.len()).all(|i| s[i] <= s[i - 1]) &&
+                        s.iter().zip(expected.iter()).all(|(a, b)| relative_eq!(a, b, epsilon = 1.0e-7)) &&
+                        relative_eq!(m, &u * ds * &v_t, epsilon = 1.0e-7) &&
+                        u.is_orthogonal(1.0e-7) &&
+                        v_t.adjoint().is_orthogonal(1.0e-7)
+                    }
                 }
             }
         }
@@ -350,6 +373,136 @@ fn svd_fail() {
     assert_relative_eq!(m, recomp, epsilon = 1.0e-5);
 }
 
+#[test]
+fn svd_with_observer() {
+    let m = DMatrix::<f64>::new_random(8, 6);
+
+    let (svd, info) = SVD::try_new_with_observer(m.clone(), true, true, 1.0e-12, 0, |info| {
+        assert!(info.residual >= 0.0);
+        ControlFlow::Break(())
+    })
+    .unwrap();
+    assert!(!info.converged);
+    assert_eq!(info.iterations, 1);
+
+    // The singular vectors are still orthonormal.
+    let u = svd.u.unwrap();
+    let v_t = svd.v_t.unwrap();
+    assert_relative_eq!(u.tr_mul(&u), DMatrix::identity(6, 6), epsilon = 1.0e-10);
+    let vvt = &v_t * v_t.transpose();
+    assert_relative_eq!(vvt, DMatrix::identity(6, 6), epsilon = 1.0e-10);
+
+    let mut niter = 0;
+    let (svd, info) = SVD::try_new_with_observer(m.clone(), true, true, 1.0e-12, 0, |info| {
+        niter = info.iteration;
+        ControlFlow::Continue(())
+    })
+    .unwrap();
+    assert!(info.converged);
+    assert_eq!(info.iterations, niter);
+    assert_relative_eq!(svd.recompose().unwrap(), m, epsilon = 1.0e-10);
+
+    let empty = DMatrix::<f64>::zeros(0, 3);
+    let observer = |_| ControlFlow::Continue(());
+    assert!(SVD::try_new_with_observer(empty, true, true, 1.0e-12, 0, observer).is_err());
+}
+
+#[test]
+fn svd_only_u_or_v() {
+    // The 2x2 subproblem of this matrix is solved with only one of its rotations computed.
+    let m = Matrix2x3::new(3.52, 0.0, 4.68, 0.0, 2.14, 1.03);
+    let expected = m.svd(true, true).singular_values;
+
+    assert_relative_eq!(
+        m.svd(true, false).singular_values,
+        expected,
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        m.svd(false, true).singular_values,
+        expected,
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        m.svd(false, false).singular_values,
+        expected,
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        m.transpose().svd(true, false).singular_values,
+        expected,
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        m.transpose().svd(false, true).singular_values,
+        expected,
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+#[rustfmt::skip]
+fn svd_nearly_decoupled_2x2_subproblem() {
+    // The last 2x2 subproblem of the bidiagonal iteration has a tiny off-diagonal element.
+    let m = DMatrix::from_column_slice(7, 7, &[
+        5.1915557035783, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 1.5295534626863427, 0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 1.474498690888783, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0, 1.3244611788923653, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0, 0.0, 1.0417433275932744, 0.0, 0.0,
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.6343951450663003, 0.0,
+        2.2256502812951497, 0.3141492850402764, 0.1372707194739944, 0.1532151105436918,
+        -0.6475315314373181, 0.17272160722207663, 1.13183817684865]);
+    let svd = m.clone().svd(true, true);
+    let recomp = svd.recompose().unwrap();
+    assert_relative_eq!(m, recomp, epsilon = 1.0e-10);
+}
+
+#[test]
+#[rustfmt::skip]
+fn svd_rank_one_2x2() {
+    // After scaling, the last diagonal element of the bidiagonal matrix is slightly larger than
+    // the epsilon, so the 2x2 subproblem has a singular value close to zero.
+    let m = Matrix2::new(
+        0.1315240369998954,  0.1887046984707434,
+        0.11943800271279952, 0.17136420689310647);
+    let svd = m.svd(true, true);
+    let recomp = svd.recompose().unwrap();
+    assert_relative_eq!(m, recomp, epsilon = 1.0e-10);
+}
+
+#[test]
+fn svd_randomized_truncated() {
+    // A matrix of rank 3 with some small noise.
+    let a = DMatrix::<f64>::new_random(40, 3);
+    let b = DMatrix::<f64>::new_random(3, 30);
+    let noise = DMatrix::<f64>::new_random(40, 30) * 1.0e-8;
+    let m = a * b + noise;
+
+    let svd = m.svd_randomized(3, 5, 2);
+    let mut expected: Vec<_> = m.singular_values().iter().cloned().collect();
+    expected.sort_by(|a, b| b.partial_cmp(a).unwrap());
+
+    assert_eq!(svd.singular_values.len(), 3);
+    for i in 0..3 {
+        assert_relative_eq!(svd.singular_values[i], expected[i], epsilon = 1.0e-6);
+    }
+
+    assert_relative_eq!(m, svd.recompose().unwrap(), epsilon = 1.0e-6);
+}
+
+#[test]
+fn svd_tall_economy_size() {
+    let m = DMatrix::<f64>::new_random(2000, 5);
+    let svd = m.clone().svd(true, true);
+    let (u, v_t) = (svd.u.as_ref().unwrap(), svd.v_t.as_ref().unwrap());
+
+    assert_eq!(u.shape(), (2000, 5));
+    assert_eq!(v_t.shape(), (5, 5));
+    assert!(u.is_orthogonal(1.0e-7));
+    assert_relative_eq!(m, svd.recompose().unwrap(), epsilon = 1.0e-7);
+}
+
 #[test]
 fn svd_err() {
     let m = DMatrix::from_element(10, 10, 0.0);
@@ -363,3 +516,71 @@ fn svd_err() {
         svd.clone().pseudo_inverse(-1.0)
     );
 }
+
+#[test]
+#[rustfmt::skip]
+fn svd_pseudo_inverse_rank_deficient() {
+    let m = Matrix3x4::new(
+        1.0, 2.0, 3.0, 4.0,
+        2.0, 4.0, 6.0, 8.0,
+        1.0, 0.0, 1.0, 0.0);
+    let pinv = m.pseudo_inverse(1.0e-10).unwrap();
+
+    // The Moore–Penrose conditions.
+    assert_relative_eq!(m * pinv * m, m, epsilon = 1.0e-7);
+    assert_relative_eq!(pinv * m * pinv, pinv, epsilon = 1.0e-7);
+    assert_relative_eq!((m * pinv).transpose(), m * pinv, epsilon = 1.0e-7);
+    assert_relative_eq!((pinv * m).transpose(), pinv * m, epsilon = 1.0e-7);
+}
+
+#[test]
+fn svd_pseudo_inverse_empty() {
+    let m = DMatrix::<f64>::zeros(0, 3);
+    let pinv = m.pseudo_inverse(1.0e-10).unwrap();
+    assert_eq!(pinv.shape(), (3, 0));
+}
+
+#[test]
+#[rustfmt::skip]
+fn svd_kernel_image_rank_deficient() {
+    // The second row is twice the first one, so `m` has rank 2.
+    let m = Matrix3x4::new(
+        1.0, 2.0, 3.0, 4.0,
+        2.0, 4.0, 6.0, 8.0,
+        1.0, 0.0, 1.0, 0.0);
+    assert_eq!(m.rank(1.0e-10), 2);
+
+    let kernel = m.kernel(1.0e-10);
+    assert_eq!(kernel.ncols(), 2);
+    assert_relative_eq!(kernel.transpose() * &kernel, DMatrix::identity(2, 2), epsilon = 1.0e-10);
+    assert!((m * &kernel).norm() < 1.0e-10);
+
+    let image = m.image(1.0e-10);
+    assert_eq!(image.ncols(), 2);
+    assert_relative_eq!(image.transpose() * &image, DMatrix::identity(2, 2), epsilon = 1.0e-10);
+    // The columns of `m` are left unchanged by the orthogonal projection onto the image.
+    assert_relative_eq!(&image * (image.transpose() * m), m, epsilon = 1.0e-10);
+
+    // The kernel and the image of the adjoint are orthogonal complements.
+    let image_t = m.transpose().image(1.0e-10);
+    assert_relative_eq!(image_t.transpose() * &kernel, DMatrix::zeros(2, 2), epsilon = 1.0e-10);
+}
+
+#[test]
+fn svd_kernel_image_full_rank_and_empty() {
+    let m = Matrix6::<f64>::new_random();
+    assert_eq!(m.kernel(1.0e-10).ncols(), 0);
+    assert_eq!(m.image(1.0e-10).ncols(), 6);
+
+    let zero = DMatrix::<f64>::zeros(2, 3);
+    assert_relative_eq!(
+        zero.kernel(1.0e-10),
+        DMatrix::identity(3, 3),
+        epsilon = 1.0e-10
+    );
+    assert_eq!(zero.image(1.0e-10).shape(), (2, 0));
+
+    let empty = DMatrix::<f64>::zeros(0, 3);
+    assert_eq!(empty.kernel(1.0e-10).shape(), (3, 3));
+    assert_eq!(empty.image(1.0e-10).shape(), (0, 0));
+}
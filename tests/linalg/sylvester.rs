@@ -0,0 +1,95 @@
+use na::{DMatrix, Matrix2, Matrix2x3, Matrix3};
+
+#[test]
+#[rustfmt::skip]
+fn sylvester_complex_eigenvalues() {
+    // Both matrices have a pair of complex-conjugate eigenvalues, i.e., 2x2 blocks on the
+    // diagonal of their real Schur forms.
+    let a = Matrix3::new(1.0, -2.0, 0.5,
+                         2.0,  1.0, 0.0,
+                         0.0,  0.0, 3.0);
+    let b = Matrix2::new(0.0, 1.0,
+                        -4.0, 0.5);
+    let c = Matrix2x3::new(1.0, 2.0, 3.0,
+                           4.0, 5.0, 6.0).transpose();
+
+    let x = na::solve_sylvester(&a, &b, &c).unwrap();
+    assert_relative_eq!(a * x + x * b, c, epsilon = 1.0e-10);
+}
+
+#[test]
+fn sylvester_lyapunov() {
+    // A stable matrix, so the Lyapunov equation has a unique symmetric solution.
+    let n = 8;
+    let a = DMatrix::<f64>::new_random(n, n) - DMatrix::identity(n, n) * (n as f64);
+    let c = -DMatrix::<f64>::identity(n, n);
+
+    let x = na::solve_sylvester(&a, &a.transpose(), &c).unwrap();
+    assert_relative_eq!(&a * &x + &x * a.transpose(), c, epsilon = 1.0e-10);
+    assert_relative_eq!(x.transpose(), x, epsilon = 1.0e-10);
+}
+
+#[test]
+fn sylvester_singular() {
+    // `a` and `-b` share the eigenvalue `1`.
+    let a = Matrix2::new(1.0, 2.0, 0.0, 3.0);
+    let b = -Matrix2::identity();
+
+    assert!(na::solve_sylvester(&a, &b, &Matrix2::identity()).is_none());
+}
+
+#[test]
+fn sylvester_empty() {
+    let a = DMatrix::<f64>::zeros(0, 0);
+    let b = DMatrix::<f64>::new_random(3, 3);
+    let c = DMatrix::<f64>::zeros(0, 3);
+
+    assert_eq!(na::solve_sylvester(&a, &b, &c), Some(c));
+}
+
+#[cfg(feature = "arbitrary")]
+mod quickcheck_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: ty) => {
+            mod $module {
+                use std::cmp;
+                use na::{DMatrix, Matrix2, Matrix2x4, Matrix4};
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+
+                quickcheck! {
+                    fn sylvester(n: usize, m: usize) -> bool {
+                        let n = cmp::max(1, cmp::min(n, 10));
+                        let m = cmp::max(1, cmp::min(m, 10));
+
+                        // The shifts make sure that `a` and `-b` don't share any eigenvalue.
+                        let mut a = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                        let mut b = DMatrix::<$scalar>::new_random(m, m).map(|e| e.0);
+                        a.set_diagonal(&a.diagonal().add_scalar(na::convert(n as f64)));
+                        b.set_diagonal(&b.diagonal().add_scalar(na::convert(m as f64)));
+                        let c = DMatrix::<$scalar>::new_random(n, m).map(|e| e.0);
+
+                        let x = na::solve_sylvester(&a, &b, &c).unwrap();
+                        relative_eq!(&a * &x + &x * &b, c, epsilon = 1.0e-7)
+                    }
+
+                    fn sylvester_static(a: Matrix2<$scalar>, b: Matrix4<$scalar>, c: Matrix2x4<$scalar>) -> bool {
+                        // Both matrices are positive-definite, so `a` and `-b` can't share any
+                        // eigenvalue.
+                        let a = a.map(|e| e.0);
+                        let a = a * a.adjoint() + Matrix2::identity();
+                        let b = b.map(|e| e.0);
+                        let b = b.adjoint() * b + Matrix4::identity();
+                        let c = c.map(|e| e.0);
+
+                        let x = na::solve_sylvester(&a, &b, &c).unwrap();
+                        relative_eq!(a * x + x * b, c, epsilon = 1.0e-7)
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, RandComplex<f64>);
+    gen_tests!(f64, RandScalar<f64>);
+}
@@ -0,0 +1,78 @@
+use na::{DMatrix, DVector, ToeplitzMatrix};
+
+#[test]
+#[rustfmt::skip]
+fn toeplitz_to_dense_and_mul() {
+    let t = ToeplitzMatrix::new(
+        DVector::from_row_slice(&[1.0, 2.0, 3.0]),
+        DVector::from_row_slice(&[1.0, 4.0, 5.0]));
+    let expected = DMatrix::from_row_slice(3, 3, &[
+        1.0, 4.0, 5.0,
+        2.0, 1.0, 4.0,
+        3.0, 2.0, 1.0]);
+
+    assert_eq!(t.to_dense(), expected);
+    assert_eq!(t.get(2, 0), Some(&3.0));
+    assert_eq!(t.get(0, 3), None);
+
+    let x = DVector::from_row_slice(&[1.0, -2.0, 3.0]);
+    assert_relative_eq!(t.mul_vector(&x), expected * x, epsilon = 1.0e-10);
+}
+
+#[test]
+fn toeplitz_mul_vector_matches_dense() {
+    for &n in [0, 1, 2, 7, 16, 33].iter() {
+        let first_column = DVector::<f64>::new_random(n);
+        let mut first_row = DVector::<f64>::new_random(n).map(|e| e - 0.5);
+
+        if n > 0 {
+            first_row[0] = first_column[0];
+        }
+
+        let t = ToeplitzMatrix::new(first_column, first_row);
+        let x = DVector::<f64>::new_random(n);
+
+        assert_relative_eq!(t.mul_vector(&x), t.to_dense() * x, epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn toeplitz_solve_nonsymmetric() {
+    let n = 20;
+    let mut first_column = DVector::<f64>::new_random(n);
+    let mut first_row = DVector::<f64>::new_random(n);
+    first_column[0] = n as f64;
+    first_row[0] = n as f64;
+
+    let t = ToeplitzMatrix::new(first_column, first_row);
+    let b = DMatrix::<f64>::new_random(n, 3);
+    let x = t.solve(&b).unwrap();
+
+    assert_relative_eq!(t.to_dense() * x, b, epsilon = 1.0e-10);
+}
+
+#[test]
+fn toeplitz_solve_yule_walker() {
+    // The autocorrelation of an AR(2) process `x[t] = 0.5 x[t - 1] - 0.3 x[t - 2] + e[t]`.
+    let (a1, a2) = (0.5, -0.3);
+    let mut r = vec![1.0, a1 / (1.0 - a2)];
+    for k in 2..4 {
+        r.push(a1 * r[k - 1] + a2 * r[k - 2]);
+    }
+
+    let t = ToeplitzMatrix::new_symmetric(DVector::from_row_slice(&r[..2]));
+    let coeffs = t.solve(&DVector::from_row_slice(&r[1..3])).unwrap();
+
+    assert_relative_eq!(
+        coeffs,
+        DVector::from_row_slice(&[a1, a2]),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn toeplitz_solve_breakdown() {
+    // The leading 1x1 submatrix is singular.
+    let t = ToeplitzMatrix::new_symmetric(DVector::from_row_slice(&[0.0, 1.0]));
+    assert!(t.solve(&DVector::from_row_slice(&[1.0, 2.0])).is_none());
+}
@@ -6,7 +6,7 @@ macro_rules! gen_tests(
             mod $module {
             use std::cmp;
 
-            use na::{DMatrix, Matrix2, Matrix4};
+            use na::{DMatrix, DVector, Matrix2, Matrix4};
             #[allow(unused_imports)]
             use crate::core::helper::{RandScalar, RandComplex};
 
@@ -20,6 +20,52 @@ macro_rules! gen_tests(
                     relative_eq!(m.lower_triangle(), recomp.lower_triangle(), epsilon = 1.0e-7)
                 }
 
+                fn symm_tridiagonal_q_mul(n: usize) -> bool {
+                    let n = cmp::max(1, cmp::min(n, 30));
+                    let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0).hermitian_part();
+                    let tri = m.symmetric_tridiagonalize();
+
+                    let mut q = DMatrix::identity(n, n);
+                    tri.q_mul(&mut q);
+                    let mut id = q.clone();
+                    tri.q_ad_mul(&mut id);
+
+                    relative_eq!(q, tri.q(), epsilon = 1.0e-7) &&
+                    relative_eq!(id, DMatrix::identity(n, n), epsilon = 1.0e-7)
+                }
+
+                fn symm_tridiagonal_in_place(n: usize) -> bool {
+                    let n = cmp::max(2, cmp::min(n, 30));
+                    let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0).hermitian_part();
+                    let (diag, off_diag) = m.clone().symmetric_tridiagonalize().unpack_tridiagonal();
+
+                    let mut buffer = m.clone();
+                    let mut off_diagonal = vec![na::zero(); n - 1];
+                    let mut work = vec![na::zero(); n - 1];
+                    let view = na::SymmetricTridiagonal::new_in_place(&mut buffer, &mut off_diagonal, &mut work);
+
+                    let view_diag = DVector::from_iterator(n, view.diagonal());
+                    let view_off_diag = DVector::from_iterator(n - 1, view.off_diagonal());
+
+                    // Recompose `m = Q * T * Qᴴ` from the products with `Q`.
+                    let mut t = m.map(|_| na::zero());
+                    for i in 0..n {
+                        t[(i, i)] = na::convert(view_diag[i]);
+                        if i + 1 < n {
+                            t[(i + 1, i)] = na::convert(view_off_diag[i]);
+                            t[(i, i + 1)] = na::convert(view_off_diag[i]);
+                        }
+                    }
+
+                    view.q_mul(&mut t);
+                    let mut recomp = t.adjoint();
+                    view.q_mul(&mut recomp);
+
+                    relative_eq!(view_diag, diag, epsilon = 1.0e-7) &&
+                    relative_eq!(view_off_diag, off_diag, epsilon = 1.0e-7) &&
+                    relative_eq!(recomp, m, epsilon = 1.0e-7)
+                }
+
                 fn symm_tridiagonal_singular(n: usize) -> bool {
                     let n = cmp::max(1, cmp::min(n, 4));
                     let mut m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0).hermitian_part();
@@ -46,6 +92,50 @@ macro_rules! gen_tests(
 
                     relative_eq!(m.lower_triangle(), recomp.lower_triangle(), epsilon = 1.0e-7)
                 }
+
+                fn symm_tridiagonal_solve(n: usize) -> bool {
+                    let n = cmp::max(1, cmp::min(n, 50));
+                    let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                    let m = &m * m.adjoint() + DMatrix::identity(n, n);
+                    let b = DMatrix::<$scalar>::new_random(n, 3).map(|e| e.0);
+                    let x = m.clone().symmetric_tridiagonalize().solve(&b).unwrap();
+
+                    relative_eq!(m * x, b, epsilon = 1.0e-7)
+                }
+
+                fn symm_tridiagonal_eigenvalues_in_range(n: usize) -> bool {
+                    let n = cmp::max(1, cmp::min(n, 30));
+                    let m = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0).hermitian_part();
+                    let tri = m.clone().symmetric_tridiagonalize();
+                    let all = m.symmetric_eigen().sorted(true).eigenvalues;
+
+                    // Eigenvalues between the first and last quarter of the spectrum.
+                    let a = all[n / 4] - 1.0e-7;
+                    let b = all[3 * n / 4] + 1.0e-7;
+                    let expected: Vec<f64> = all.iter().cloned().filter(|e| *e >= a && *e < b).collect();
+                    let in_range = tri.eigenvalues_in_range(a, b);
+
+                    tri.count_eigenvalues_in_range(a, b) == expected.len() &&
+                    tri.count_eigenvalues_below(a) == all.iter().filter(|e| **e < a).count() &&
+                    relative_eq!(in_range, DVector::from_vec(expected), epsilon = 1.0e-7)
+                }
+
+                fn solve_tridiagonal_diagonally_dominant(n: usize) -> bool {
+                    let n = cmp::max(1, cmp::min(n, 50));
+                    let lower = DVector::<$scalar>::new_random(n - 1).map(|e| e.0);
+                    let upper = DVector::<$scalar>::new_random(n - 1).map(|e| e.0);
+                    let diag = DVector::<$scalar>::new_random(n).map(|e| e.0).add_scalar(na::convert(3.0));
+                    let b = DMatrix::<$scalar>::new_random(n, 2).map(|e| e.0);
+                    let x = na::linalg::solve_tridiagonal(&lower, &diag, &upper, &b).unwrap();
+
+                    let mut m = DMatrix::from_diagonal(&diag);
+                    for i in 0..n - 1 {
+                        m[(i + 1, i)] = lower[i];
+                        m[(i, i + 1)] = upper[i];
+                    }
+
+                    relative_eq!(m * x, b, epsilon = 1.0e-7)
+                }
             }
         }
     }
@@ -53,3 +143,36 @@ macro_rules! gen_tests(
 
 gen_tests!(complex, RandComplex<f64>);
 gen_tests!(f64, RandScalar<f64>);
+
+#[test]
+fn solve_tridiagonal_zero_pivot() {
+    let lower = na::Vector2::new(1.0, 1.0);
+    let diag = na::Vector3::new(1.0, 1.0, 1.0);
+    let upper = na::Vector2::new(1.0, 1.0);
+    let b = na::Vector3::new(1.0, 2.0, 3.0);
+
+    // The second pivot is `1 - 1 * 1 = 0`.
+    let mut x = b;
+    assert!(!na::linalg::solve_tridiagonal_mut(
+        &lower, &diag, &upper, &mut x
+    ));
+    assert_eq!(x, b);
+}
+
+#[test]
+fn symm_tridiagonal_eigenvalues_in_range_repeated() {
+    // The eigenvalues are 1, 1, 1, 3 and 3.
+    let q = na::DMatrix::<f64>::new_random(5, 5).qr().q();
+    let d = na::DMatrix::from_diagonal(&na::DVector::from_row_slice(&[1.0, 3.0, 1.0, 3.0, 1.0]));
+    let tri = (&q * d * q.transpose()).symmetric_tridiagonalize();
+
+    assert_eq!(tri.count_eigenvalues_below(1.0 - 1.0e-8), 0);
+    assert_eq!(tri.count_eigenvalues_below(2.0), 3);
+    assert_eq!(tri.count_eigenvalues_in_range(2.0, 4.0), 2);
+    assert_eq!(tri.eigenvalues_in_range(-1.0, 0.5).len(), 0);
+    assert_relative_eq!(
+        tri.eigenvalues_in_range(0.0, 2.0),
+        na::DVector::from_element(3, 1.0),
+        epsilon = 1.0e-10
+    );
+}
@@ -0,0 +1,120 @@
+#![cfg(feature = "arbitrary")]
+
+use na::{ComplexField, DMatrix, UpdatableQR};
+
+fn is_upper_triangular<N: ComplexField>(r: &DMatrix<N>) -> bool {
+    (0..r.ncols()).all(|j| (j + 1..r.nrows()).all(|i| r[(i, j)].modulus() <= na::convert(1.0e-7)))
+}
+
+macro_rules! gen_tests(
+    ($module: ident, $scalar: ty) => {
+        mod $module {
+            use na::{DMatrix, DVector, RowDVector, UpdatableQR};
+            use super::is_upper_triangular;
+            use rand::random;
+            use std::cmp;
+            #[allow(unused_imports)]
+            use crate::core::helper::{RandScalar, RandComplex};
+
+            quickcheck! {
+                fn updatable_qr(m: DMatrix<$scalar>) -> bool {
+                    let m = m.map(|e| e.0);
+                    let qr = UpdatableQR::new(m.clone());
+
+                    relative_eq!(m, qr.recompose(), epsilon = 1.0e-7) &&
+                    qr.q().is_orthogonal(1.0e-7) &&
+                    is_upper_triangular(qr.r())
+                }
+
+                fn updatable_qr_insert_column(nrows: usize, ncols: usize) -> bool {
+                    let nrows = cmp::max(1, cmp::min(nrows, 20));
+                    let ncols = cmp::min(ncols, 20);
+                    let j = random::<usize>() % (ncols + 1);
+                    let m = DMatrix::<$scalar>::new_random(nrows, ncols).map(|e| e.0);
+                    let col = DVector::<$scalar>::new_random(nrows).map(|e| e.0);
+
+                    let qr = UpdatableQR::new(m.clone()).insert_column(j, &col);
+                    let mut m_updated = m.insert_column(j, num::Zero::zero());
+                    m_updated.set_column(j, &col);
+
+                    relative_eq!(m_updated, qr.recompose(), epsilon = 1.0e-7) &&
+                    qr.q().is_orthogonal(1.0e-7) &&
+                    is_upper_triangular(qr.r())
+                }
+
+                fn updatable_qr_remove_column(nrows: usize, ncols: usize) -> bool {
+                    let nrows = cmp::max(1, cmp::min(nrows, 20));
+                    let ncols = cmp::max(1, cmp::min(ncols, 20));
+                    let j = random::<usize>() % ncols;
+                    let m = DMatrix::<$scalar>::new_random(nrows, ncols).map(|e| e.0);
+
+                    let qr = UpdatableQR::new(m.clone()).remove_column(j);
+                    let m_updated = m.remove_column(j);
+
+                    relative_eq!(m_updated, qr.recompose(), epsilon = 1.0e-7) &&
+                    qr.q().is_orthogonal(1.0e-7) &&
+                    is_upper_triangular(qr.r())
+                }
+
+                fn updatable_qr_insert_row(nrows: usize, ncols: usize) -> bool {
+                    let nrows = cmp::min(nrows, 20);
+                    let ncols = cmp::max(1, cmp::min(ncols, 20));
+                    let i = random::<usize>() % (nrows + 1);
+                    let m = DMatrix::<$scalar>::new_random(nrows, ncols).map(|e| e.0);
+                    let row = RowDVector::<$scalar>::new_random(ncols).map(|e| e.0);
+
+                    let qr = UpdatableQR::new(m.clone()).insert_row(i, &row);
+                    let mut m_updated = m.insert_row(i, num::Zero::zero());
+                    m_updated.set_row(i, &row);
+
+                    relative_eq!(m_updated, qr.recompose(), epsilon = 1.0e-7) &&
+                    qr.q().is_orthogonal(1.0e-7) &&
+                    is_upper_triangular(qr.r())
+                }
+
+                fn updatable_qr_remove_row(nrows: usize, ncols: usize) -> bool {
+                    let nrows = cmp::max(1, cmp::min(nrows, 20));
+                    let ncols = cmp::max(1, cmp::min(ncols, 20));
+                    let i = random::<usize>() % nrows;
+                    let m = DMatrix::<$scalar>::new_random(nrows, ncols).map(|e| e.0);
+
+                    let qr = UpdatableQR::new(m.clone()).remove_row(i);
+                    let m_updated = m.remove_row(i);
+
+                    relative_eq!(m_updated, qr.recompose(), epsilon = 1.0e-7) &&
+                    qr.q().is_orthogonal(1.0e-7) &&
+                    is_upper_triangular(qr.r())
+                }
+
+                fn updatable_qr_solve(n: usize) -> bool {
+                    // A sliding window over the rows of a tall matrix.
+                    let n = cmp::max(1, cmp::min(n, 10));
+                    let m = DMatrix::<$scalar>::new_random(3 * n, n).map(|e| e.0);
+                    let b = DVector::<$scalar>::new_random(3 * n).map(|e| e.0);
+
+                    let mut qr = UpdatableQR::new(m.rows(0, 2 * n).into_owned());
+                    for i in 0..n {
+                        qr = qr.remove_row(0).insert_row(2 * n - 1, &m.row(2 * n + i));
+                    }
+
+                    let window = m.rows(n, 2 * n);
+                    match (qr.solve(&b.rows(n, 2 * n)), window.into_owned().svd(true, true).solve(&b.rows(n, 2 * n), 1.0e-12)) {
+                        (Some(x), Ok(expected)) => relative_eq!(x, expected, epsilon = 1.0e-5),
+                        _ => true,
+                    }
+                }
+            }
+        }
+    }
+);
+
+gen_tests!(complex, RandComplex<f64>);
+gen_tests!(f64, RandScalar<f64>);
+
+#[test]
+fn updatable_qr_remove_column_without_rows() {
+    let qr = UpdatableQR::new(DMatrix::<f64>::zeros(0, 2)).remove_column(0);
+
+    assert_eq!(qr.r().shape(), (0, 1));
+    assert_eq!(qr.recompose(), DMatrix::zeros(0, 1));
+}